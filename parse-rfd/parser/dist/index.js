@@ -62042,8 +62042,21 @@ const formatSection = (section, content) => {
   ];
 };
 
+const render = (content, imagesdir) => {
+  const attributes = { showtitle: true, sectanchors: true };
+
+  if (imagesdir) {
+    attributes.imagesdir = imagesdir;
+  }
+
+  return {
+    html: asciidoc.convert(content, { safe: "secure", attributes }),
+  };
+};
+
+const [mode, imagesdir] = process.argv.slice(2);
 let content = (__nccwpck_require__(7147).readFileSync)(0, 'utf-8');
-console.log(JSON.stringify(parse(content)))
+console.log(JSON.stringify(mode === "render" ? render(content, imagesdir) : parse(content)))
 })();
 
 module.exports = __webpack_exports__;
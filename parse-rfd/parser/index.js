@@ -63,5 +63,18 @@ const formatSection = (section, content) => {
   ];
 };
 
+const render = (content, imagesdir) => {
+  const attributes = { showtitle: true, sectanchors: true };
+
+  if (imagesdir) {
+    attributes.imagesdir = imagesdir;
+  }
+
+  return {
+    html: asciidoc.convert(content, { safe: "secure", attributes }),
+  };
+};
+
+const [mode, imagesdir] = process.argv.slice(2);
 let content = require("fs").readFileSync(0, 'utf-8');
-console.log(JSON.stringify(parse(content)))
\ No newline at end of file
+console.log(JSON.stringify(mode === "render" ? render(content, imagesdir) : parse(content)))
\ No newline at end of file
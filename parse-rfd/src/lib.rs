@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use std::{
     error::Error,
     fmt,
@@ -132,11 +132,34 @@ fn parser() -> Result<PathBuf, FailedToCreateParser> {
 }
 
 pub fn parse(content: &str) -> Result<ParsedDoc, ParserError> {
+    run(&[], content)
+}
+
+/// Render a document to HTML. Relative image paths are resolved against `images_dir` when it is
+/// supplied, overriding any images directory that the document itself defines
+pub fn render(content: &str, images_dir: Option<&str>) -> Result<String, ParserError> {
+    let mut args = vec!["render"];
+    args.extend(images_dir);
+
+    let RenderedDoc { html } = run(&args, content)?;
+    Ok(html)
+}
+
+#[derive(Debug, Deserialize)]
+struct RenderedDoc {
+    html: String,
+}
+
+fn run<T>(args: &[&str], content: &str) -> Result<T, ParserError>
+where
+    T: DeserializeOwned,
+{
     let mut tmp = parser()?;
     let path_arg = format!("{}", tmp.display());
 
     let mut cmd = Command::new("node")
-        .args([path_arg])
+        .arg(path_arg)
+        .args(args)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -258,4 +281,23 @@ Third in the list"#,
 
         assert_eq!(expected, value);
     }
+
+    #[test]
+    fn render_document() {
+        let html = crate::render(
+            r#":imagesdir: local
+
+= RFD 123 On Rendering Documents
+
+== Background
+
+image::diagram.svg[]"#,
+            Some("https://assets.example.com/rfd/0123/latest"),
+        )
+        .unwrap();
+
+        assert!(html.contains("<h1>RFD 123 On Rendering Documents</h1>"));
+        assert!(html.contains(r#"<h2 id="_background">"#));
+        assert!(html.contains(r#"src="https://assets.example.com/rfd/0123/latest/diagram.svg""#));
+    }
 }
@@ -16,7 +16,7 @@ use jsonwebtoken::{
 };
 use rfd_model::ApiUserProvider;
 use rsa::traits::PublicKeyParts;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use thiserror::Error;
 use tracing::instrument;
 use uuid::Uuid;
@@ -78,41 +78,58 @@ impl Claims {
     }
 }
 
-impl Jwt {
-    pub async fn new(ctx: &ApiContext, token: &str) -> Result<Self, JwtError> {
-        tracing::trace!("Decode JWT from headers");
-
-        let header = decode_header(token).map_err(|err| {
-            tracing::warn!(?err, "Token header is malformed");
-            JwtError::MalformedHeader(err)
-        })?;
-
-        tracing::trace!("Found header containing JWT");
-
-        // We require that the header contains a kid attribute for determining which decoding key
-        // to use, even in the case that we are using a single key
-        let kid = header.kid.ok_or(JwtError::MissingKid)?;
-
-        tracing::trace!(?kid, "JWT with kid present");
-
-        // The only JWKs supported are those that are available in the server context
-        let jwk = ctx.jwks().await.find(&kid).ok_or(JwtError::NoMatchingKey)?;
-        let (key, algorithm) = DecodingKey::from_jwk(&jwk)
-            .map(|key| (key, Jwt::algo(&jwk)))
-            .map_err(JwtError::InvalidJwk)?;
+// Claims of a token that grants read access to feeds and event streams. Every feed token is
+// backed by an API key record (jti) that determines its permissions and through which it can be
+// revoked
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FeedClaims {
+    pub iss: String,
+    pub aud: String,
+    pub sub: Uuid,
+    pub exp: i64,
+    pub nbf: i64,
+    pub jti: Uuid,
+}
 
-        tracing::trace!(?jwk, ?algorithm, "Kid matched known decoding key");
+impl FeedClaims {
+    pub fn new(
+        ctx: &ApiContext,
+        api_user_id: Uuid,
+        key_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        FeedClaims {
+            iss: ctx.public_url.to_string(),
+            aud: feed_audience(&ctx.public_url),
+            sub: api_user_id,
+            exp: expires_at.timestamp(),
+            nbf: Utc::now().timestamp(),
+            jti: key_id,
+        }
+    }
+}
 
-        let mut validation = Validation::new(algorithm?);
-        validation.set_audience(&[&ctx.public_url]);
-        validation.set_issuer(&[&ctx.public_url]);
+// Feed tokens use a distinct audience so that they are rejected wherever a bearer JWT is expected
+fn feed_audience(public_url: &str) -> String {
+    format!("{}/feed", public_url)
+}
 
-        let data = decode(token, &key, &validation).map_err(JwtError::Decode)?;
+pub struct FeedToken {
+    pub claims: FeedClaims,
+}
 
-        tracing::trace!("Decoded JWT claims from request");
+impl FeedToken {
+    pub async fn new(ctx: &ApiContext, token: &str) -> Result<Self, JwtError> {
+        Ok(FeedToken {
+            claims: decode_claims(ctx, token, &feed_audience(&ctx.public_url)).await?,
+        })
+    }
+}
 
+impl Jwt {
+    pub async fn new(ctx: &ApiContext, token: &str) -> Result<Self, JwtError> {
         Ok(Jwt {
-            claims: data.claims,
+            claims: decode_claims(ctx, token, &ctx.public_url).await?,
         })
     }
 
@@ -128,6 +145,45 @@ impl Jwt {
     }
 }
 
+// Verify a token against the known signing keys and decode its claims for the given audience
+async fn decode_claims<C>(ctx: &ApiContext, token: &str, audience: &str) -> Result<C, JwtError>
+where
+    C: DeserializeOwned,
+{
+    tracing::trace!("Decode JWT from headers");
+
+    let header = decode_header(token).map_err(|err| {
+        tracing::warn!(?err, "Token header is malformed");
+        JwtError::MalformedHeader(err)
+    })?;
+
+    tracing::trace!("Found header containing JWT");
+
+    // We require that the header contains a kid attribute for determining which decoding key
+    // to use, even in the case that we are using a single key
+    let kid = header.kid.ok_or(JwtError::MissingKid)?;
+
+    tracing::trace!(?kid, "JWT with kid present");
+
+    // The only JWKs supported are those that are available in the server context
+    let jwk = ctx.jwks().await.find(&kid).ok_or(JwtError::NoMatchingKey)?;
+    let (key, algorithm) = DecodingKey::from_jwk(&jwk)
+        .map(|key| (key, Jwt::algo(&jwk)))
+        .map_err(JwtError::InvalidJwk)?;
+
+    tracing::trace!(?jwk, ?algorithm, "Kid matched known decoding key");
+
+    let mut validation = Validation::new(algorithm?);
+    validation.set_audience(&[audience]);
+    validation.set_issuer(&[&ctx.public_url]);
+
+    let data = decode(token, &key, &validation).map_err(JwtError::Decode)?;
+
+    tracing::trace!("Decoded JWT claims from request");
+
+    Ok(data.claims)
+}
+
 #[derive(Debug, Error)]
 pub enum JwtSignerError {
     #[error("Failed to encode header")]
@@ -29,7 +29,7 @@ use crate::{
     util::{cloud_kms_client, response::unauthorized},
 };
 
-use self::jwt::Jwt;
+use self::jwt::{FeedToken, Jwt};
 
 pub mod jwt;
 pub mod key;
@@ -46,6 +46,7 @@ pub enum AuthError {
 pub enum AuthToken {
     ApiKey(RawApiKey),
     Jwt(Jwt),
+    Feed(FeedToken),
 }
 
 impl Debug for AuthToken {
@@ -86,6 +87,18 @@ impl AuthToken {
             }
         }
     }
+
+    // Construct an AuthToken from a feed token. Feed tokens are only accepted by endpoints that
+    // authenticate via a query parameter, and can never be used as a bearer token
+    pub async fn from_feed_token(ctx: &ApiContext, token: &str) -> Result<AuthToken, AuthError> {
+        FeedToken::new(ctx, token)
+            .await
+            .map(AuthToken::Feed)
+            .map_err(|err| {
+                tracing::info!(?err, "Failed to verify feed token");
+                AuthError::FailedToExtract
+            })
+    }
 }
 
 impl From<AuthError> for HttpError {
@@ -5,7 +5,7 @@
 use std::{collections::HashMap, path::PathBuf};
 
 use config::{Config, ConfigError, Environment, File};
-use rfd_data::content::RfdTemplate;
+use rfd_data::{content::RfdTemplate, RfdNumberFormat};
 use secrecy::SecretString;
 use serde::{
     de::{self, Visitor},
@@ -150,6 +150,20 @@ pub struct SearchConfig {
 #[derive(Debug, Default, Deserialize)]
 pub struct ContentConfig {
     pub templates: HashMap<String, RfdTemplate>,
+    /// Reject labels that are not part of the label taxonomy when adding labels to a RFD
+    #[serde(default)]
+    pub validate_labels: bool,
+    /// Public url of the storage bucket that the processor uploads RFD assets to. Image links in
+    /// rendered RFDs are rewritten to point at this location
+    pub static_asset_url: Option<String>,
+    /// Storage bucket that the processor uploads RFD assets to. Assets are served through the API
+    /// from this bucket so that RFD visibility is enforced
+    pub asset_storage: Option<AssetStorageConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssetStorageConfig {
+    pub bucket: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -164,6 +178,8 @@ pub struct GitHubConfig {
     pub path: String,
     pub repo: String,
     pub default_branch: String,
+    #[serde(default)]
+    pub number_format: RfdNumberFormat,
 }
 
 #[derive(Debug, Deserialize)]
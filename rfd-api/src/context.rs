@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use base64::{prelude::BASE64_STANDARD, Engine};
 use chrono::{DateTime, Duration, Utc};
 use dropshot::{HttpError, RequestContext};
 use http::StatusCode;
@@ -17,24 +18,31 @@ use octorust::{
 use partial_struct::partial;
 use rfd_data::{
     content::{RfdContent, RfdDocument, RfdTemplate, TemplateError},
-    RfdNumber,
+    transition::RfdStateContext,
+    RfdAuthor, RfdNumber,
 };
 use rfd_github::{GitHubError, GitHubNewRfdNumber, GitHubRfdRepo};
 use rfd_model::{
-    schema_ext::{ContentFormat, LoginAttemptState, Visibility},
+    schema_ext::{ContentFormat, LoginAttemptState, Visibility, WebhookEvent},
     storage::{
-        AccessGroupFilter, AccessGroupStore, AccessTokenStore, ApiKeyFilter, ApiKeyStore,
-        ApiUserFilter, ApiUserProviderFilter, ApiUserProviderStore, ApiUserStore, JobStore,
-        LinkRequestStore, ListPagination, LoginAttemptFilter, LoginAttemptStore, MapperFilter,
-        MapperStore, OAuthClientFilter, OAuthClientRedirectUriStore, OAuthClientSecretStore,
-        OAuthClientStore, RfdFilter, RfdPdfFilter, RfdPdfStore, RfdRevisionFilter,
-        RfdRevisionStore, RfdStore, StoreError,
+        queue_webhook_deliveries, AccessGroupFilter, AccessGroupStore, AccessTokenStore,
+        ApiKeyFilter, ApiKeyStore, ApiUserFilter, ApiUserProviderFilter, ApiUserProviderStore,
+        ApiUserStore, JobFilter, JobStore, LabelFilter, LabelStore, LinkRequestStore,
+        ListPagination, LoginAttemptFilter, LoginAttemptStore, MapperFilter, MapperStore,
+        OAuthClientFilter, OAuthClientRedirectUriStore, OAuthClientSecretStore, OAuthClientStore,
+        RfdAuthorFilter, RfdAuthorStore, RfdEventStore, RfdFilter, RfdPdfFilter, RfdPdfStore,
+        RfdReservationStore, RfdRevisionAssetFilter, RfdRevisionAssetStore, RfdRevisionFilter,
+        RfdRevisionLabelFilter, RfdRevisionLabelStore, RfdRevisionStore, RfdStateTransitionFilter,
+        RfdStateTransitionStore, RfdStore, StoreError, WebhookDeliveryFilter, WebhookDeliveryStore,
+        WebhookSubscriptionFilter, WebhookSubscriptionStore,
     },
     AccessGroup, AccessToken, ApiUser, ApiUserProvider, CommitSha, FileSha, InvalidValueError, Job,
-    LinkRequest, LoginAttempt, Mapper, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser,
-    NewApiUserProvider, NewJob, NewLinkRequest, NewLoginAttempt, NewMapper, NewOAuthClient,
-    NewOAuthClientRedirectUri, NewOAuthClientSecret, OAuthClient, OAuthClientRedirectUri,
-    OAuthClientSecret, Rfd, RfdRevision,
+    Label, LinkRequest, LoginAttempt, Mapper, NewAccessGroup, NewAccessToken, NewApiKey,
+    NewApiUser, NewApiUserProvider, NewJob, NewLabel, NewLinkRequest, NewLoginAttempt, NewMapper,
+    NewOAuthClient, NewOAuthClientRedirectUri, NewOAuthClientSecret, NewRfdAuthor,
+    NewRfdReservation, NewRfdRevisionLabel, NewRfdStateTransition, NewWebhookSubscription,
+    OAuthClient, OAuthClientRedirectUri, OAuthClientSecret, Rfd, RfdEvent, RfdReservation,
+    RfdRevision, RfdStateTransition, WebhookDelivery, WebhookSubscription,
 };
 use rsa::{
     pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey},
@@ -44,9 +52,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Debug,
     ops::Add,
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 use tap::TapFallible;
 use thiserror::Error;
@@ -70,22 +79,37 @@ use crate::{
         UserInfo,
     },
     error::{ApiError, AppError},
+    events::EventContext,
     mapper::{MapperRule, Mapping},
     permissions::{ApiPermission, ApiPermissionError, PermissionStorage},
+    render::{render_html, RenderError},
     search::SearchClient,
-    util::response::{
-        bad_request, client_error, internal_error, resource_error, resource_not_found,
-        resource_restricted, ResourceError, ResourceResult, ToResourceResult, ToResourceResultOpt,
+    util::{
+        cloud_storage_client,
+        response::{
+            bad_request, client_error, internal_error, resource_error, resource_not_found,
+            resource_restricted, ResourceError, ResourceResult, ToResourceResult,
+            ToResourceResultOpt,
+        },
+        CloudStorageClient,
     },
     ApiCaller, ApiPermissions, User, UserToken,
 };
 
 static UNLIMITED: i64 = 9999999;
 
+// Number of recent revisions that are included in a RFD activity feed
+static FEED_LIMIT: i64 = 50;
+
 pub trait Storage:
     RfdStore
     + RfdRevisionStore
     + RfdPdfStore
+    + RfdRevisionAssetStore
+    + RfdAuthorStore
+    + LabelStore
+    + RfdRevisionLabelStore
+    + RfdStateTransitionStore
     + JobStore
     + ApiUserStore<ApiPermission>
     + ApiKeyStore<ApiPermission>
@@ -98,6 +122,8 @@ pub trait Storage:
     + AccessGroupStore<ApiPermission>
     + MapperStore
     + LinkRequestStore
+    + WebhookSubscriptionStore
+    + WebhookDeliveryStore
     + Send
     + Sync
     + 'static
@@ -107,6 +133,11 @@ impl<T> Storage for T where
     T: RfdStore
         + RfdRevisionStore
         + RfdPdfStore
+        + RfdRevisionAssetStore
+        + RfdAuthorStore
+        + LabelStore
+        + RfdRevisionLabelStore
+        + RfdStateTransitionStore
         + JobStore
         + ApiUserStore<ApiPermission>
         + ApiKeyStore<ApiPermission>
@@ -119,6 +150,10 @@ impl<T> Storage for T where
         + AccessGroupStore<ApiPermission>
         + MapperStore
         + LinkRequestStore
+        + WebhookSubscriptionStore
+        + WebhookDeliveryStore
+        + RfdEventStore
+        + RfdReservationStore
         + Send
         + Sync
         + 'static
@@ -137,6 +172,7 @@ pub struct ApiContext {
     pub search: SearchContext,
     pub content: ContentContext,
     pub github: GitHubRfdRepo,
+    pub events: EventContext,
 }
 
 pub struct JwtContext {
@@ -154,10 +190,28 @@ pub struct SearchContext {
 }
 
 pub struct ContentContext {
-    pub placeholder_template: RfdTemplate,
-    pub new_template: RfdTemplate,
+    pub templates: HashMap<String, RfdTemplate>,
+    pub validate_labels: bool,
+    pub static_asset_url: Option<String>,
+    /// Rendered HTML keyed by RFD number, commit and whether the RFD is public. A commit is
+    /// immutable, so entries never need to be invalidated
+    pub rendered: RwLock<HashMap<(i32, String, bool), Arc<String>>>,
+    pub assets: Option<AssetStorage>,
+}
+
+pub struct AssetStorage {
+    pub client: CloudStorageClient,
+    pub bucket: String,
 }
 
+// Maximum number of rendered documents to hold in memory. The cache is emptied once it is full
+static RENDERED_CACHE_LIMIT: usize = 256;
+
+// Templates that must always be configured. They are used when a RFD is created without
+// requesting a specific template
+pub static PLACEHOLDER_TEMPLATE: &str = "placeholder";
+pub static NEW_TEMPLATE: &str = "new";
+
 pub struct RegisteredAccessToken {
     pub access_token: AccessToken,
     pub signed_token: String,
@@ -207,14 +261,52 @@ pub enum UpdateRfdContentError {
     GitHub(#[from] GitHubError),
     #[error("Internal GitHub state does not currently allow for update. This commit appears as the head commit on multiple branches.")]
     InternalState,
+    #[error("RFD has been updated since the expected commit. The latest commit is {}", current.0)]
+    Conflict { current: CommitSha },
     #[error("Failed to construct new RFD template")]
     InvalidTemplate(#[from] TemplateError),
+    #[error("Template {0} does not exist")]
+    UnknownTemplate(String),
     #[error("Unable to perform action. Unable to find the default branch on GitHub.")]
     NoDefaultBranch,
     #[error(transparent)]
     Storage(#[from] StoreError),
 }
 
+// A failed attempt to push a newly reserved RFD to GitHub
+#[derive(Debug)]
+enum PushReservedRfdError {
+    // Nothing that claims the RFD number was left behind on GitHub
+    Clean(ResourceError<UpdateRfdContentError>),
+    // The branch of the RFD was created, but could not be removed after the push failed
+    BranchRemains(ResourceError<UpdateRfdContentError>),
+}
+
+// A change to commit to the branch of a RFD
+enum RfdChange<'a> {
+    Document(&'a RfdContent<'a>),
+    Asset { path: &'a str, content: &'a [u8] },
+}
+
+#[derive(Debug, Error)]
+pub enum RfdAssetError {
+    #[error("Failed to decode asset {0}")]
+    Decode(#[from] base64::DecodeError),
+    #[error(transparent)]
+    GitHub(#[from] GitHubError),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+/// A static file (such as an image) that is stored alongside a RFD
+#[derive(Debug)]
+pub struct RfdAsset {
+    pub content_type: String,
+    pub data: Vec<u8>,
+    pub commit: CommitSha,
+    pub committed_at: DateTime<Utc>,
+}
+
 #[partial(ListRfd)]
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct FullRfd {
@@ -225,6 +317,7 @@ pub struct FullRfd {
     pub title: String,
     pub state: Option<String>,
     pub authors: Option<String>,
+    pub author_list: Vec<RfdAuthorEntry>,
     pub labels: Option<String>,
     #[partial(ListRfd(skip))]
     pub content: String,
@@ -243,6 +336,92 @@ pub struct FullRfdPdfEntry {
     pub link: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdAuthorEntry {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    /// The user that this author has been matched to, if any
+    pub api_user_id: Option<Uuid>,
+}
+
+impl From<RfdAuthor> for RfdAuthorEntry {
+    fn from(value: RfdAuthor) -> Self {
+        Self {
+            name: value.name,
+            email: value.email,
+            api_user_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct LabelSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    /// The number of RFDs visible to the caller whose latest revision has this label
+    pub count: usize,
+}
+
+/// An entry in the activity feed of RFDs. Each entry describes a single revision of a RFD
+#[derive(Debug, Clone, PartialEq)]
+pub struct RfdFeedEntry {
+    pub rfd_number: i32,
+    pub title: String,
+    pub authors: Option<String>,
+    pub commit: CommitSha,
+    pub committed_at: DateTime<Utc>,
+    pub change: RfdFeedChange,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RfdFeedChange {
+    Created,
+    StateChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    Updated,
+}
+
+/// A single change to a RFD, derived from comparing a revision to the one that preceded it
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct RfdTimelineEvent {
+    pub commit: CommitSha,
+    pub committed_at: DateTime<Utc>,
+    pub event: RfdTimelineEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RfdTimelineEventKind {
+    Created {
+        title: String,
+        state: Option<String>,
+    },
+    TitleChanged {
+        from: String,
+        to: String,
+    },
+    StateChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    LabelsChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    AuthorsChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    DiscussionLinked {
+        url: String,
+    },
+    PublishedToDefaultBranch,
+}
+
 #[derive(Debug)]
 enum BasePermissions {
     Full,
@@ -279,6 +458,16 @@ impl ApiContext {
             ))
             .build();
 
+        let assets = match content.asset_storage {
+            Some(config) => Some(AssetStorage {
+                client: cloud_storage_client()
+                    .await
+                    .map_err(AppError::FailedToFindGcpCredentials)?,
+                bucket: config.bucket,
+            }),
+            None => None,
+        };
+
         Ok(Self {
             https_client: hyper::Client::builder().build(
                 HttpsConnectorBuilder::new()
@@ -324,16 +513,17 @@ impl ApiContext {
                 client: SearchClient::new(search.host, search.index, search.key),
             },
             content: ContentContext {
-                placeholder_template: content
-                    .templates
-                    .get("placeholder")
-                    .cloned()
-                    .ok_or(AppError::MissingNewRfdTemplate)?,
-                new_template: content
-                    .templates
-                    .get("new")
-                    .cloned()
-                    .ok_or(AppError::MissingNewRfdTemplate)?,
+                templates: if content.templates.contains_key(PLACEHOLDER_TEMPLATE)
+                    && content.templates.contains_key(NEW_TEMPLATE)
+                {
+                    content.templates
+                } else {
+                    return Err(AppError::MissingNewRfdTemplate);
+                },
+                validate_labels: content.validate_labels,
+                static_asset_url: content.static_asset_url,
+                rendered: RwLock::new(HashMap::new()),
+                assets,
             },
             github: GitHubRfdRepo::new(
                 &match services.github.auth {
@@ -367,8 +557,10 @@ impl ApiContext {
                 services.github.repo,
                 services.github.path,
                 services.github.default_branch,
+                services.github.number_format,
             )
             .await?,
+            events: EventContext::default(),
         })
     }
 
@@ -407,7 +599,10 @@ impl ApiContext {
         &self.jwt.jwks
     }
 
-    pub async fn sign_jwt(&self, claims: &Claims) -> Result<String, JwtSignerError> {
+    pub async fn sign_jwt<C>(&self, claims: &C) -> Result<String, JwtSignerError>
+    where
+        C: Serialize + Debug,
+    {
         let signer = self.jwt.signers.first().unwrap();
         signer.sign(claims).await
     }
@@ -536,6 +731,42 @@ impl ApiContext {
                 };
                 Ok((jwt.claims.sub, permissions))
             }
+            AuthToken::Feed(token) => {
+                async {
+                    tracing::debug!("Attempt to authenticate feed token");
+
+                    // A feed token is only valid for as long as the key that backs it exists
+                    let key = ApiKeyStore::list(
+                        &*self.storage,
+                        ApiKeyFilter {
+                            id: Some(vec![token.claims.jti]),
+                            api_user_id: Some(vec![token.claims.sub]),
+                            expired: false,
+                            deleted: false,
+                            ..Default::default()
+                        },
+                        &ListPagination {
+                            offset: 0,
+                            limit: 1,
+                        },
+                    )
+                    .await?
+                    .pop();
+
+                    match key.and_then(|key| key.permissions.map(|p| (key.api_user_id, p))) {
+                        Some((api_user_id, permissions)) => {
+                            tracing::debug!("Verified feed token");
+                            Ok((api_user_id, BasePermissions::Restricted(permissions)))
+                        }
+                        None => {
+                            tracing::debug!("Failed to find matching key for feed token");
+                            Err(CallerError::FailedToAuthenticate)
+                        }
+                    }
+                }
+                .instrument(info_span!("Test feed token"))
+                .await
+            }
         }?)
     }
 
@@ -624,6 +855,50 @@ impl ApiContext {
         caller: &ApiCaller,
         filter: Option<RfdFilter>,
     ) -> ResourceResult<Vec<ListRfd>, StoreError> {
+        let rfds = self.list_latest_revisions(caller, filter).await?;
+
+        let revisions = rfds
+            .iter()
+            .map(|(_, revision)| revision)
+            .collect::<Vec<_>>();
+        let mut rfd_authors = self
+            .list_rfd_authors(&revisions)
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to lookup RFD authors"))
+            .to_resource_result()?;
+
+        let mut rfd_list = rfds
+            .into_iter()
+            .map(|(rfd, revision)| ListRfd {
+                id: rfd.id,
+                rfd_number: rfd.rfd_number,
+                link: rfd.link,
+                discussion: revision.discussion,
+                title: revision.title,
+                state: revision.state,
+                authors: revision.authors,
+                author_list: rfd_authors.remove(&revision.id).unwrap_or_default(),
+                labels: revision.labels,
+                format: revision.content_format,
+                sha: revision.sha,
+                commit: revision.commit.into(),
+                committed_at: revision.committed_at,
+                visibility: rfd.visibility,
+            })
+            .collect::<Vec<_>>();
+
+        // Finally sort the RFD list by RFD number
+        rfd_list.sort_by(|a, b| b.rfd_number.cmp(&a.rfd_number));
+
+        Ok(rfd_list)
+    }
+
+    // Lookup the RFDs that the caller is allowed to access along with the latest revision of each
+    async fn list_latest_revisions(
+        &self,
+        caller: &ApiCaller,
+        filter: Option<RfdFilter>,
+    ) -> ResourceResult<Vec<(Rfd, RfdRevision)>, StoreError> {
         // List all of the RFDs first and then perform filter. This should be be improved once
         // filters can be combined to support OR expressions. Effectively we need to be able to
         // express "Has access to OR is public" with a filter
@@ -668,30 +943,99 @@ impl ApiContext {
         rfd_revisions.sort_by(|a, b| a.rfd_id.cmp(&b.rfd_id));
 
         // Zip together the RFDs with their associated revision
-        let mut rfd_list = rfds
-            .into_iter()
-            .zip(rfd_revisions)
-            .map(|(rfd, revision)| ListRfd {
-                id: rfd.id,
-                rfd_number: rfd.rfd_number,
-                link: rfd.link,
-                discussion: revision.discussion,
-                title: revision.title,
-                state: revision.state,
-                authors: revision.authors,
-                labels: revision.labels,
-                format: revision.content_format,
-                sha: revision.sha,
-                commit: revision.commit.into(),
-                committed_at: revision.committed_at,
-                visibility: rfd.visibility,
-            })
-            .collect::<Vec<_>>();
+        Ok(rfds.into_iter().zip(rfd_revisions).collect())
+    }
 
-        // Finally sort the RFD list by RFD number
-        rfd_list.sort_by(|a, b| b.rfd_number.cmp(&a.rfd_number));
+    /// List the RFDs whose latest revision includes the given user as an author
+    #[instrument(skip(self, caller))]
+    pub async fn list_rfds_by_author(
+        &self,
+        caller: &ApiCaller,
+        api_user_id: Uuid,
+    ) -> ResourceResult<Vec<ListRfd>, StoreError> {
+        let authored_revisions = RfdAuthorStore::list(
+            &*self.storage,
+            RfdAuthorFilter::default().api_user(Some(vec![api_user_id])),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup authored revisions"))
+        .to_resource_result()?
+        .into_iter()
+        .map(|author| author.rfd_revision_id)
+        .collect::<BTreeSet<_>>();
+
+        if authored_revisions.is_empty() {
+            return Ok(vec![]);
+        }
 
-        Ok(rfd_list)
+        let authored_rfds = RfdRevisionStore::list(
+            &*self.storage,
+            RfdRevisionFilter::default().id(Some(authored_revisions.into_iter().collect())),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup authored RFD revisions"))
+        .to_resource_result()?
+        .into_iter()
+        .map(|revision| revision.rfd_id)
+        .collect::<BTreeSet<_>>();
+
+        // A user may have authored an older revision of an RFD and since been removed as an
+        // author. Only the authors of the latest revision are considered
+        let mut rfds = self
+            .list_rfds(
+                caller,
+                Some(RfdFilter::default().id(Some(authored_rfds.into_iter().collect()))),
+            )
+            .await?;
+        rfds.retain(|rfd| {
+            rfd.author_list
+                .iter()
+                .any(|author| author.api_user_id == Some(api_user_id))
+        });
+
+        Ok(rfds)
+    }
+
+    // Lookup the structured authors for a list of revisions. Revisions that have not had their
+    // authors stored yet fall back to parsing the raw authors line
+    async fn list_rfd_authors(
+        &self,
+        revisions: &[&RfdRevision],
+    ) -> Result<BTreeMap<Uuid, Vec<RfdAuthorEntry>>, StoreError> {
+        let mut authors = BTreeMap::<Uuid, Vec<RfdAuthorEntry>>::new();
+
+        for author in RfdAuthorStore::list(
+            &*self.storage,
+            RfdAuthorFilter::default()
+                .rfd_revision(Some(revisions.iter().map(|revision| revision.id).collect())),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await?
+        {
+            authors
+                .entry(author.rfd_revision_id)
+                .or_default()
+                .push(RfdAuthorEntry {
+                    name: author.name,
+                    email: author.email,
+                    api_user_id: author.api_user_id,
+                });
+        }
+
+        for revision in revisions {
+            if let Some(line) = &revision.authors {
+                authors.entry(revision.id).or_insert_with(|| {
+                    RfdAuthor::parse_list(line)
+                        .into_iter()
+                        .map(RfdAuthorEntry::from)
+                        .collect()
+                });
+            }
+        }
+
+        Ok(authors)
     }
 
     #[instrument(skip(self, caller), err(Debug))]
@@ -700,14 +1044,18 @@ impl ApiContext {
         caller: &ApiCaller,
         title: String,
         content: Option<String>,
+        template: Option<&str>,
+        fields: HashMap<String, String>,
+        format: ContentFormat,
     ) -> ResourceResult<RfdNumber, UpdateRfdContentError> {
         if caller.can(&ApiPermission::CreateRfd) {
             tracing::info!("Reserving new RFD");
 
-            // We acknowledge that there are race conditions here, as there would be if an end user
-            // were to attempt to reserve a RFD number manually
+            // GitHub reports the lowest number that is not used by a published RFD or an open
+            // branch. The number is then claimed in the database, which hands out a higher number
+            // if a concurrent request has already claimed it
             let GitHubNewRfdNumber {
-                number: next_rfd_number,
+                number: github_rfd_number,
                 commit,
             } = self
                 .github
@@ -716,58 +1064,155 @@ impl ApiContext {
                 .map_err(UpdateRfdContentError::GitHub)
                 .to_resource_result()?;
 
-            let content = match content {
-                Some(content) => self
-                    .content
-                    .new_template
-                    .clone()
-                    .field("number".to_string(), next_rfd_number.to_string())
-                    .field("title".to_string(), title)
-                    .field("body".to_string(), content),
-                None => self
-                    .content
-                    .placeholder_template
-                    .clone()
-                    .field("number".to_string(), next_rfd_number.to_string())
-                    .field("title".to_string(), title),
+            let template_name = template.unwrap_or(if content.is_some() {
+                NEW_TEMPLATE
+            } else {
+                PLACEHOLDER_TEMPLATE
+            });
+            let mut template = self
+                .content
+                .templates
+                .get(template_name)
+                .cloned()
+                .ok_or_else(|| UpdateRfdContentError::UnknownTemplate(template_name.to_string()))
+                .to_resource_result()?;
+
+            // Caller supplied fields may not override the fields that are derived from the RFD
+            // itself
+            for (field, value) in fields {
+                template = template.field(field, value);
             }
-            .build()
-            .map_err(UpdateRfdContentError::InvalidTemplate)
+
+            template = template
+                .field("title".to_string(), title)
+                .format(format.clone());
+
+            if let Some(content) = content {
+                template = template.field("body".to_string(), content);
+            }
+
+            let reservation = RfdReservationStore::reserve(
+                &*self.storage,
+                NewRfdReservation {
+                    id: Uuid::new_v4(),
+                    rfd_number: github_rfd_number.into(),
+                    api_user_id: caller.id,
+                },
+            )
+            .await
+            .map_err(UpdateRfdContentError::Storage)
             .to_resource_result()?;
+            let next_rfd_number: RfdNumber = reservation.rfd_number.into();
 
-            tracing::info!(?next_rfd_number, ?commit, "Creating new RFD branch");
+            tracing::info!(?github_rfd_number, ?next_rfd_number, "Reserved RFD number");
 
-            // Branch off of the default branch with a new branch with the padded form of the RFD number
-            self.github
-                .create_branch(&next_rfd_number, &commit)
+            let pushed = self
+                .push_reserved_rfd(caller, next_rfd_number, commit, template, format)
+                .await;
+
+            self.settle_reservation(&reservation, pushed)
                 .await
-                .map_err(UpdateRfdContentError::GitHub)
-                .to_resource_result()?;
+                .map(|_| next_rfd_number)
+        } else {
+            Err(ResourceError::Restricted)
+        }
+    }
 
-            tracing::info!(
-                ?next_rfd_number,
-                ?commit,
-                "Created new branch for reserving RFD off of default branch"
-            );
+    // Create the branch and placeholder document of a newly reserved RFD number. If the document
+    // can not be pushed, the branch is removed again so that it does not hold on to the number
+    async fn push_reserved_rfd(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: RfdNumber,
+        commit: CommitSha,
+        template: RfdTemplate,
+        format: ContentFormat,
+    ) -> Result<(), PushReservedRfdError> {
+        let content = template
+            .field("number".to_string(), rfd_number.to_string())
+            .build()
+            .map_err(UpdateRfdContentError::InvalidTemplate)
+            .to_resource_result()
+            .map_err(PushReservedRfdError::Clean)?;
+
+        tracing::info!(?rfd_number, ?commit, "Creating new RFD branch");
+
+        // Branch off of the default branch with a new branch with the padded form of the RFD number
+        self.github
+            .create_branch(&rfd_number, &commit)
+            .await
+            .map_err(UpdateRfdContentError::GitHub)
+            .to_resource_result()
+            .map_err(PushReservedRfdError::Clean)?;
+
+        tracing::info!(
+            ?rfd_number,
+            ?commit,
+            "Created new branch for reserving RFD off of default branch"
+        );
 
-            self.commit_rfd_document(
+        let pushed = self
+            .commit_rfd_change(
                 caller,
-                next_rfd_number.into(),
-                &content.render(),
+                rfd_number,
+                RfdChange::Document(&RfdContent::new(content.render(), format)),
                 Some("Reserving RFD number"),
                 commit,
-                Some(&next_rfd_number.as_number_string()),
+                Some(&self.github.number_format.branch(&rfd_number)),
             )
-            .await?;
+            .await;
+
+        if let Err(err) = pushed {
+            return match self.github.delete_branch(&rfd_number).await {
+                Ok(()) => Err(PushReservedRfdError::Clean(err)),
+                Err(delete_err) => {
+                    tracing::error!(
+                        ?delete_err,
+                        ?rfd_number,
+                        "Failed to remove branch of RFD that could not be pushed"
+                    );
+                    Err(PushReservedRfdError::BranchRemains(err))
+                }
+            };
+        }
 
-            tracing::info!(
-                ?next_rfd_number,
-                "Pushed placeholder RFD to reserved branch"
-            );
+        tracing::info!(?rfd_number, "Pushed placeholder RFD to reserved branch");
 
-            Ok(next_rfd_number)
-        } else {
-            Err(ResourceError::Restricted)
+        Ok(())
+    }
+
+    // Resolve the reservation of a RFD number once its push has completed. The number is handed
+    // back if the push failed without leaving anything behind on GitHub, so that it can be claimed
+    // by the next reservation. A branch that could not be removed still claims the number, and
+    // the reservation is kept so that it is not handed out again
+    async fn settle_reservation(
+        &self,
+        reservation: &RfdReservation,
+        pushed: Result<(), PushReservedRfdError>,
+    ) -> ResourceResult<(), UpdateRfdContentError> {
+        match pushed {
+            Ok(()) => Ok(()),
+            Err(PushReservedRfdError::Clean(err)) => {
+                let _ = RfdReservationStore::release(&*self.storage, &reservation.id)
+                    .await
+                    .tap_err(|err| {
+                        tracing::error!(
+                            ?err,
+                            rfd_number = reservation.rfd_number,
+                            "Failed to release RFD number"
+                        )
+                    });
+
+                Err(err)
+            }
+            Err(PushReservedRfdError::BranchRemains(err)) => {
+                tracing::warn!(
+                    rfd_number = reservation.rfd_number,
+                    "Keeping reservation of RFD number as its branch remains"
+                );
+
+                Err(err)
+            }
         }
     }
 
@@ -802,37 +1247,8 @@ impl ApiContext {
             .to_resource_result()?;
 
             if let Some(revision) = latest_revision.into_iter().nth(0) {
-                let pdfs = RfdPdfStore::list(
-                    &*self.storage,
-                    RfdPdfFilter::default().rfd_revision(Some(vec![revision.id])),
-                    &ListPagination::default(),
-                )
-                .await
-                .to_resource_result()?;
-
-                Ok(FullRfd {
-                    id: rfd.id,
-                    rfd_number: rfd.rfd_number,
-                    link: rfd.link,
-                    discussion: revision.discussion,
-                    title: revision.title,
-                    state: revision.state,
-                    authors: revision.authors,
-                    labels: revision.labels,
-                    content: revision.content,
-                    format: revision.content_format,
-                    sha: revision.sha,
-                    commit: revision.commit.into(),
-                    committed_at: revision.committed_at,
-                    pdfs: pdfs
-                        .into_iter()
-                        .map(|pdf| FullRfdPdfEntry {
-                            source: pdf.source.to_string(),
-                            link: pdf.link,
-                        })
-                        .collect(),
-                    visibility: rfd.visibility,
-                })
+                let mut rfds = self.build_full_rfds(vec![(rfd, revision)]).await?;
+                Ok(rfds.remove(0))
             } else {
                 // It should not be possible to reach this branch. If we have then the database
                 // has entered an inconsistent state
@@ -845,26 +1261,284 @@ impl ApiContext {
         }
     }
 
+    /// Get the latest representation of many RFDs at once. RFDs that do not exist, or that the
+    /// caller is not allowed to access, are omitted from the result
     #[instrument(skip(self, caller))]
-    pub async fn get_rfd_revision(
+    pub async fn get_rfds(
         &self,
         caller: &ApiCaller,
-        rfd_number: i32,
-        sha: Option<String>,
-    ) -> ResourceResult<RfdRevision, StoreError> {
-        if caller.any(&[
-            &ApiPermission::GetRfd(rfd_number),
-            &ApiPermission::GetRfdsAll,
-        ]) {
-            let rfds = RfdStore::list(
-                &*self.storage,
-                RfdFilter::default().rfd_number(Some(vec![rfd_number])),
-                &ListPagination::default().limit(1),
+        rfd_numbers: Vec<i32>,
+    ) -> ResourceResult<Vec<FullRfd>, StoreError> {
+        let rfds = self
+            .list_latest_revisions(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(rfd_numbers))),
             )
-            .await
-            .to_resource_result()?;
-            if let Some(rfd) = rfds.into_iter().nth(0) {
-                let latest_revision = RfdRevisionStore::list(
+            .await?;
+
+        let mut rfd_list = self.build_full_rfds(rfds).await?;
+
+        rfd_list.sort_by(|a, b| b.rfd_number.cmp(&a.rfd_number));
+
+        Ok(rfd_list)
+    }
+
+    // Assemble the full representation of RFD revisions. The authors and PDFs of all of the
+    // revisions are looked up at once
+    async fn build_full_rfds(
+        &self,
+        rfds: Vec<(Rfd, RfdRevision)>,
+    ) -> ResourceResult<Vec<FullRfd>, StoreError> {
+        let revisions = rfds
+            .iter()
+            .map(|(_, revision)| revision)
+            .collect::<Vec<_>>();
+        let mut rfd_authors = self
+            .list_rfd_authors(&revisions)
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to lookup RFD authors"))
+            .to_resource_result()?;
+
+        let mut rfd_pdfs = BTreeMap::<Uuid, Vec<FullRfdPdfEntry>>::new();
+        for pdf in RfdPdfStore::list(
+            &*self.storage,
+            RfdPdfFilter::default()
+                .rfd_revision(Some(revisions.iter().map(|revision| revision.id).collect())),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup RFD pdfs"))
+        .to_resource_result()?
+        {
+            rfd_pdfs
+                .entry(pdf.rfd_revision_id)
+                .or_default()
+                .push(FullRfdPdfEntry {
+                    source: pdf.source.to_string(),
+                    link: pdf.link,
+                });
+        }
+
+        Ok(rfds
+            .into_iter()
+            .map(|(rfd, revision)| FullRfd {
+                id: rfd.id,
+                rfd_number: rfd.rfd_number,
+                link: rfd.link,
+                discussion: revision.discussion,
+                title: revision.title,
+                state: revision.state,
+                authors: revision.authors,
+                author_list: rfd_authors.remove(&revision.id).unwrap_or_default(),
+                labels: revision.labels,
+                content: revision.content,
+                format: revision.content_format,
+                sha: revision.sha,
+                commit: revision.commit.into(),
+                committed_at: revision.committed_at,
+                pdfs: rfd_pdfs.remove(&revision.id).unwrap_or_default(),
+                visibility: rfd.visibility,
+            })
+            .collect())
+    }
+
+    /// Read a static asset of the latest revision of a RFD. Assets are served from the configured
+    /// asset storage, falling back to reading them from GitHub when they have not been copied to
+    /// storage. The caller must be allowed to read the RFD itself
+    #[instrument(skip(self, caller))]
+    pub async fn get_rfd_asset(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        path: &str,
+    ) -> ResourceResult<RfdAsset, RfdAssetError> {
+        let rfd = self
+            .get_rfd(caller, rfd_number, None)
+            .await
+            .map_err(|err| err.inner_into())?;
+        let content_type = mime_guess::from_path(path)
+            .first_or_octet_stream()
+            .to_string();
+
+        // Assets are only copied to storage while a RFD is public. The assets of any other RFD are
+        // always read from GitHub
+        let assets = self
+            .content
+            .assets
+            .as_ref()
+            .filter(|_| rfd.visibility == Visibility::Public);
+
+        if let Some(assets) = assets {
+            let object_name = format!("rfd/{}/latest/{}", rfd_number, path);
+            let response = assets
+                .client
+                .objects()
+                .get(&assets.bucket, &object_name)
+                .param("alt", "media")
+                .doit()
+                .await;
+
+            match response {
+                Ok((response, _)) => match hyper::body::to_bytes(response.into_body()).await {
+                    Ok(data) => {
+                        return Ok(RfdAsset {
+                            content_type,
+                            data: data.to_vec(),
+                            commit: rfd.commit,
+                            committed_at: rfd.committed_at,
+                        })
+                    }
+                    Err(err) => {
+                        tracing::warn!(?err, ?object_name, "Failed to read asset from storage")
+                    }
+                },
+                Err(err) => {
+                    tracing::info!(?err, ?object_name, "Asset could not be read from storage")
+                }
+            }
+        }
+
+        let image = self
+            .github
+            .location(self.github.default_branch.clone(), rfd.commit.clone())
+            .get_asset(&self.github.client, &RfdNumber::from(rfd_number), path)
+            .await
+            .map_err(RfdAssetError::GitHub)
+            .to_resource_result()?
+            .ok_or(ResourceError::DoesNotExist)?;
+
+        let data = BASE64_STANDARD
+            .decode(image.content.replace('\n', ""))
+            .map_err(RfdAssetError::Decode)
+            .to_resource_result()?;
+
+        Ok(RfdAsset {
+            content_type,
+            data,
+            commit: rfd.commit,
+            committed_at: rfd.committed_at,
+        })
+    }
+
+    /// Render a RFD to sanitized HTML, optionally at a specific commit
+    #[instrument(skip(self, caller))]
+    pub async fn get_rfd_html(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        commit: Option<String>,
+    ) -> ResourceResult<Arc<String>, RenderError> {
+        // list_rfds performs authorization checks, if the caller does not have access to the
+        // requested RFD an empty Vec will be returned
+        let rfds = self
+            .list_rfds(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(vec![rfd_number]))),
+            )
+            .await
+            .map_err(|err| err.inner_into())?;
+
+        let Some(rfd) = rfds.into_iter().nth(0) else {
+            return resource_restricted();
+        };
+
+        let revision = RfdRevisionStore::list(
+            &*self.storage,
+            RfdRevisionFilter::default()
+                .rfd(Some(vec![rfd.id]))
+                .commit(commit.map(|commit| vec![commit])),
+            &ListPagination::default().limit(1),
+        )
+        .await
+        .map_err(RenderError::Storage)
+        .to_resource_result()?
+        .into_iter()
+        .nth(0)
+        .ok_or(ResourceError::DoesNotExist)?;
+
+        // Documents are rendered separately for public and non-public RFDs as they reference
+        // their assets from different locations
+        let is_public = rfd.visibility == Visibility::Public;
+        let key = (rfd_number, revision.commit.0, is_public);
+        if let Some(html) = self.content.rendered.read().unwrap().get(&key) {
+            return Ok(html.clone());
+        }
+
+        let images_dir = if is_public {
+            // Revisions that have an asset manifest reference the copies of their assets that
+            // were stored for their commit. Older revisions only have access to the latest assets
+            let has_assets = !RfdRevisionAssetStore::list(
+                &*self.storage,
+                RfdRevisionAssetFilter::default().rfd_revision(Some(vec![revision.id])),
+                &ListPagination::default().limit(1),
+            )
+            .await
+            .map_err(RenderError::Storage)
+            .to_resource_result()?
+            .is_empty();
+            let version = if has_assets { key.1.as_str() } else { "latest" };
+
+            self.content.static_asset_url.as_ref().map(|url| {
+                format!(
+                    "{}/rfd/{}/{}",
+                    url.trim_end_matches('/'),
+                    rfd_number,
+                    version
+                )
+            })
+        } else {
+            // Assets of RFDs that are not public can not be read from static storage, and are
+            // instead served by the API so that access to the RFD is checked
+            Some(format!(
+                "{}/rfd/{}/assets",
+                self.public_url.trim_end_matches('/'),
+                rfd_number
+            ))
+        };
+
+        // Rendering AsciiDoc documents shells out to the parser and must not block the executor
+        let html = tokio::task::spawn_blocking(move || {
+            render_html(
+                &revision.content,
+                &revision.content_format,
+                images_dir.as_deref(),
+            )
+        })
+        .await
+        .map_err(RenderError::Task)
+        .and_then(|html| html)
+        .map(Arc::new)
+        .to_resource_result()?;
+
+        let mut rendered = self.content.rendered.write().unwrap();
+        if rendered.len() >= RENDERED_CACHE_LIMIT {
+            rendered.clear();
+        }
+        rendered.insert(key, html.clone());
+
+        Ok(html)
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn get_rfd_revision(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        sha: Option<String>,
+    ) -> ResourceResult<RfdRevision, StoreError> {
+        if caller.any(&[
+            &ApiPermission::GetRfd(rfd_number),
+            &ApiPermission::GetRfdsAll,
+        ]) {
+            let rfds = RfdStore::list(
+                &*self.storage,
+                RfdFilter::default().rfd_number(Some(vec![rfd_number])),
+                &ListPagination::default().limit(1),
+            )
+            .await
+            .to_resource_result()?;
+            if let Some(rfd) = rfds.into_iter().nth(0) {
+                let latest_revision = RfdRevisionStore::list(
                     &*self.storage,
                     RfdRevisionFilter::default()
                         .rfd(Some(vec![rfd.id]))
@@ -931,6 +1605,7 @@ impl ApiContext {
         rfd_number: i32,
         content: &str,
         message: Option<&str>,
+        expected_commit: Option<&CommitSha>,
         branch_name: Option<&str>,
     ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
         if caller.any(&[
@@ -941,15 +1616,16 @@ impl ApiContext {
                 .get_latest_rfd_revision(caller, rfd_number)
                 .await
                 .map_err(|err| err.inner_into())?;
+            check_expected_commit(&latest_revision.commit, expected_commit)?;
 
             let sha = latest_revision.commit.clone();
             let mut updated_content: RfdContent = latest_revision.into();
             updated_content.update_body(content);
 
-            self.commit_rfd_document(
+            self.commit_rfd_change(
                 caller,
                 rfd_number.into(),
-                updated_content.raw(),
+                RfdChange::Document(&updated_content),
                 message,
                 sha,
                 branch_name,
@@ -967,6 +1643,7 @@ impl ApiContext {
         rfd_number: i32,
         document: &str,
         message: Option<&str>,
+        expected_commit: Option<&CommitSha>,
         branch_name: Option<&str>,
     ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
         if caller.any(&[
@@ -977,14 +1654,15 @@ impl ApiContext {
                 .get_latest_rfd_revision(caller, rfd_number)
                 .await
                 .map_err(|err| err.inner_into())?;
+            check_expected_commit(&latest_revision.commit, expected_commit)?;
             let sha = latest_revision.commit;
 
             tracing::info!(?sha, "Found commit to update from");
 
-            self.commit_rfd_document(
+            self.commit_rfd_change(
                 caller,
                 rfd_number.into(),
-                document,
+                RfdChange::Document(&RfdContent::new(document, latest_revision.content_format)),
                 message,
                 sha,
                 branch_name,
@@ -995,12 +1673,305 @@ impl ApiContext {
         }
     }
 
-    #[instrument(skip(self, caller, document), err(Debug))]
-    async fn commit_rfd_document(
+    /// Commit a static asset (such as an image) to the directory of a RFD. The path is relative to
+    /// the RFD directory, and is the path that the document uses to reference the asset
+    #[instrument(skip(self, caller, content))]
+    pub async fn upload_rfd_asset(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        path: &str,
+        content: &[u8],
+        message: Option<&str>,
+        expected_commit: Option<&CommitSha>,
+    ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
+        if caller.any(&[
+            &ApiPermission::UpdateRfd(rfd_number),
+            &ApiPermission::UpdateRfdsAll,
+        ]) {
+            let latest_revision = self
+                .get_latest_rfd_revision(caller, rfd_number)
+                .await
+                .map_err(|err| err.inner_into())?;
+            check_expected_commit(&latest_revision.commit, expected_commit)?;
+
+            self.commit_rfd_change(
+                caller,
+                rfd_number.into(),
+                RfdChange::Asset { path, content },
+                message,
+                latest_revision.commit,
+                None,
+            )
+            .await
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Restore the document of a RFD to its contents at a previous commit. The restored document is
+    /// committed on top of the current head of the RFD, leaving its history intact
+    #[instrument(skip(self, caller))]
+    pub async fn revert_rfd(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        commit: &CommitSha,
+        expected_commit: Option<&CommitSha>,
+    ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
+        if caller.any(&[
+            &ApiPermission::UpdateRfd(rfd_number),
+            &ApiPermission::UpdateRfdsAll,
+        ]) {
+            let latest_revision = self
+                .get_latest_rfd_revision(caller, rfd_number)
+                .await
+                .map_err(|err| err.inner_into())?;
+            check_expected_commit(&latest_revision.commit, expected_commit)?;
+
+            let target_revision = RfdRevisionStore::list(
+                &*self.storage,
+                RfdRevisionFilter::default()
+                    .rfd(Some(vec![latest_revision.rfd_id]))
+                    .commit(Some(vec![commit.0.clone()])),
+                &ListPagination::default().limit(1),
+            )
+            .await
+            .map_err(UpdateRfdContentError::Storage)
+            .to_resource_result()?
+            .into_iter()
+            .nth(0)
+            .ok_or(ResourceError::DoesNotExist)?;
+
+            tracing::info!(from = ?latest_revision.commit, to = ?target_revision.commit, "Reverting RFD");
+
+            let message = format!("Revert RFD {} to {}", rfd_number, target_revision.commit.0);
+            let head = latest_revision.commit;
+            let content: RfdContent = target_revision.into();
+
+            self.commit_rfd_change(
+                caller,
+                rfd_number.into(),
+                RfdChange::Document(&content),
+                Some(&message),
+                head,
+                None,
+            )
+            .await
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Determine where the RFD at the given commit currently lives on GitHub. This is used to
+    /// evaluate the guards on state transitions
+    #[instrument(skip(self), err(Debug))]
+    pub async fn get_rfd_state_context(
+        &self,
+        commit: &CommitSha,
+    ) -> Result<RfdStateContext, GitHubError> {
+        let locations = self.github.locations_for_commit(commit.clone()).await?;
+
+        let mut context = RfdStateContext::default();
+
+        for location in locations {
+            if location.is_default() {
+                context.on_default_branch = true;
+            } else {
+                context.has_open_pr |= location
+                    .find_pull_requests(&self.github.client)
+                    .await?
+                    .iter()
+                    .any(|pr| pr.state == "open");
+            }
+        }
+
+        Ok(context)
+    }
+
+    /// Record that a RFD was moved between states. Callers are expected to have already applied the
+    /// transition to the RFD document
+    #[instrument(skip(self, caller), err(Debug))]
+    pub async fn record_rfd_state_transition(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+        new_transition: NewRfdStateTransition,
+    ) -> ResourceResult<RfdStateTransition, StoreError> {
+        if caller.any(&[
+            &ApiPermission::UpdateRfd(rfd_number),
+            &ApiPermission::UpdateRfdsAll,
+        ]) {
+            RfdStateTransitionStore::upsert(&*self.storage, new_transition)
+                .await
+                .to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// List the state transitions that have been made to a RFD through the API, oldest first
+    #[instrument(skip(self, caller))]
+    pub async fn list_rfd_state_transitions(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+    ) -> ResourceResult<Vec<RfdStateTransition>, StoreError> {
+        // list_rfds performs authorization checks, if the caller does not have access to the
+        // requested RFD an empty Vec will be returned
+        let rfds = self
+            .list_rfds(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(vec![rfd_number]))),
+            )
+            .await?;
+
+        if let Some(rfd) = rfds.into_iter().nth(0) {
+            RfdStateTransitionStore::list(
+                &*self.storage,
+                RfdStateTransitionFilter::default().rfd(Some(vec![rfd.id])),
+                &ListPagination::default().limit(UNLIMITED),
+            )
+            .await
+            .to_resource_result()
+        } else {
+            // Either the RFD does not exist, or the caller is not allowed to access it
+            resource_restricted()
+        }
+    }
+
+    /// Build the lifecycle timeline of a RFD from its stored revisions, oldest first
+    #[instrument(skip(self, caller))]
+    pub async fn get_rfd_timeline(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: i32,
+    ) -> ResourceResult<Vec<RfdTimelineEvent>, StoreError> {
+        // list_rfds performs authorization checks, if the caller does not have access to the
+        // requested RFD an empty Vec will be returned
+        let rfds = self
+            .list_rfds(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(vec![rfd_number]))),
+            )
+            .await?;
+
+        if let Some(rfd) = rfds.into_iter().nth(0) {
+            let mut revisions = RfdRevisionStore::list(
+                &*self.storage,
+                RfdRevisionFilter::default().rfd(Some(vec![rfd.id])),
+                &ListPagination::default().limit(UNLIMITED),
+            )
+            .await
+            .to_resource_result()?;
+            revisions.sort_by(|a, b| a.committed_at.cmp(&b.committed_at));
+
+            // Revisions do not record the branch they were read from, but the jobs that produced
+            // them do
+            let commits = revisions
+                .iter()
+                .map(|revision| revision.commit.0.clone())
+                .collect::<Vec<_>>();
+            let default_branch_commits = JobStore::list(
+                &*self.storage,
+                JobFilter::default().sha(Some(commits)),
+                &ListPagination::default().limit(UNLIMITED),
+            )
+            .await
+            .to_resource_result()?
+            .into_iter()
+            .filter(|job| job.branch == self.github.default_branch)
+            .map(|job| job.sha.0)
+            .collect::<BTreeSet<_>>();
+
+            Ok(build_rfd_timeline(&revisions, &default_branch_commits))
+        } else {
+            // Either the RFD does not exist, or the caller is not allowed to access it
+            resource_restricted()
+        }
+    }
+
+    /// List the most recent revisions of the RFDs that the caller is allowed to access, newest
+    /// first. When a RFD number is supplied only revisions of that RFD are included
+    #[instrument(skip(self, caller))]
+    pub async fn list_rfd_feed(
+        &self,
+        caller: &ApiCaller,
+        rfd_number: Option<i32>,
+    ) -> ResourceResult<Vec<RfdFeedEntry>, StoreError> {
+        let rfds = self
+            .list_latest_revisions(
+                caller,
+                rfd_number.map(|number| RfdFilter::default().rfd_number(Some(vec![number]))),
+            )
+            .await?
+            .into_iter()
+            .map(|(rfd, _)| (rfd.id, rfd))
+            .collect::<BTreeMap<_, _>>();
+
+        if rfd_number.is_some() && rfds.is_empty() {
+            // Either the RFD does not exist, or the caller is not allowed to access it
+            return resource_restricted();
+        }
+
+        let recent = RfdRevisionStore::list(
+            &*self.storage,
+            RfdRevisionFilter::default().rfd(Some(rfds.keys().copied().collect())),
+            &ListPagination::default().limit(FEED_LIMIT),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup recent RFD revisions"))
+        .to_resource_result()?;
+
+        let feed_ids = recent
+            .iter()
+            .map(|revision| revision.id)
+            .collect::<Vec<_>>();
+        let mut revisions = BTreeMap::<Uuid, Vec<RfdRevision>>::new();
+        for revision in recent {
+            revisions.entry(revision.rfd_id).or_default().push(revision);
+        }
+        let counts = revisions
+            .iter()
+            .map(|(rfd_id, revisions)| (*rfd_id, revisions.len()))
+            .collect::<BTreeMap<_, _>>();
+
+        // The feed holds the most recent revisions of each RFD that appears in it, so the latest
+        // revision of each of those RFDs that is not in the feed is the one that preceded the
+        // oldest revision in the feed. It is needed to describe the change that revision made
+        let preceding = RfdRevisionStore::list_unique_rfd(
+            &*self.storage,
+            RfdRevisionFilter::default()
+                .rfd(Some(revisions.keys().copied().collect()))
+                .exclude_id(Some(feed_ids)),
+            &ListPagination::default().limit(revisions.len() as i64),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup preceding RFD revisions"))
+        .to_resource_result()?;
+
+        for revision in preceding {
+            revisions.entry(revision.rfd_id).or_default().push(revision);
+        }
+
+        let mut entries = vec![];
+        for (rfd_id, revisions) in revisions {
+            if let Some(rfd) = rfds.get(&rfd_id) {
+                entries.extend(build_rfd_feed(rfd, &revisions, counts[&rfd_id]));
+            }
+        }
+
+        entries.sort_by(|a, b| b.committed_at.cmp(&a.committed_at));
+
+        Ok(entries)
+    }
+
+    #[instrument(skip(self, caller, change), err(Debug))]
+    async fn commit_rfd_change(
         &self,
         caller: &ApiCaller,
         rfd_number: RfdNumber,
-        document: &str,
+        change: RfdChange<'_>,
         message: Option<&str>,
         head: CommitSha,
         branch_name: Option<&str>,
@@ -1069,11 +2040,25 @@ impl ApiContext {
 
                 // Unwrap is checked by the location length
                 let location = github_locations.pop().unwrap();
-                let commit = location
-                    .upsert(&rfd_number, document.as_bytes(), &message)
-                    .await
-                    .map_err(UpdateRfdContentError::GitHub)
-                    .to_resource_result()?;
+                let commit = match change {
+                    RfdChange::Document(document) => {
+                        location
+                            .upsert(
+                                &rfd_number,
+                                &document.format(),
+                                document.raw().as_bytes(),
+                                &message,
+                            )
+                            .await
+                    }
+                    RfdChange::Asset { path, content } => {
+                        location
+                            .upsert_asset(&rfd_number, path, content, &message)
+                            .await
+                    }
+                }
+                .map_err(UpdateRfdContentError::GitHub)
+                .to_resource_result()?;
 
                 // If we committed a change, immediately register a job as well. This may conflict with
                 // a job already added by a webhook, this is fine and we can ignore the error
@@ -1081,7 +2066,7 @@ impl ApiContext {
                     let new_job = NewJob {
                         owner: self.github.owner.clone(),
                         repository: self.github.repo.clone(),
-                        branch: rfd_number.as_number_string(),
+                        branch: self.github.number_format.branch(&rfd_number),
                         sha: commit.clone(),
                         rfd: rfd_number.into(),
                         // This job is not being triggered by a webhook
@@ -1125,10 +2110,27 @@ impl ApiContext {
             .to_resource_result()?;
 
             if let Some(mut rfd) = rfds.pop() {
+                let previous = rfd.visibility.clone();
                 rfd.visibility = visibility;
-                RfdStore::upsert(&*self.storage, rfd.into())
+
+                // Updating the RFD bumps its updated_at timestamp, which the processor's search
+                // sync uses to push the new visibility out to the search indexes
+                let rfd = RfdStore::upsert(&*self.storage, rfd.into())
                     .await
-                    .to_resource_result()
+                    .to_resource_result()?;
+
+                if rfd.visibility != previous {
+                    // The visibility change has already been stored, so a failure to publish the
+                    // change is only logged
+                    let _ = self
+                        .emit_visibility_changed(&rfd, previous)
+                        .await
+                        .tap_err(|err| {
+                            tracing::error!(?err, "Failed to emit visibility change event")
+                        });
+                }
+
+                Ok(rfd)
             } else {
                 Err(ResourceError::DoesNotExist)
             }
@@ -1137,6 +2139,307 @@ impl ApiContext {
         }
     }
 
+    // Label Operations
+
+    #[instrument(skip(self, caller))]
+    pub async fn list_labels(
+        &self,
+        caller: &ApiCaller,
+    ) -> ResourceResult<Vec<LabelSummary>, StoreError> {
+        let labels = LabelStore::list(
+            &*self.storage,
+            LabelFilter::default(),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup labels"))
+        .to_resource_result()?;
+
+        // Counts are only computed over the RFDs that the caller is allowed to see
+        let revisions = self
+            .list_latest_revisions(caller, None)
+            .await?
+            .into_iter()
+            .map(|(_, revision)| revision.id)
+            .collect::<Vec<_>>();
+
+        let mut counts = HashMap::<Uuid, usize>::new();
+        for link in RfdRevisionLabelStore::list(
+            &*self.storage,
+            RfdRevisionLabelFilter::default().rfd_revision(Some(revisions)),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to lookup RFD labels"))
+        .to_resource_result()?
+        {
+            *counts.entry(link.label_id).or_default() += 1;
+        }
+
+        Ok(labels
+            .into_iter()
+            .map(|label| LabelSummary {
+                count: counts.get(&label.id).copied().unwrap_or(0),
+                id: label.id,
+                name: label.name,
+                description: label.description,
+                color: label.color,
+            })
+            .collect())
+    }
+
+    /// Find the label in the taxonomy that matches the given name. Names are compared
+    /// case-insensitively
+    pub async fn find_label(&self, name: &str) -> Result<Option<Label>, StoreError> {
+        Ok(LabelStore::list(
+            &*self.storage,
+            LabelFilter::default().name(Some(vec![name.trim().to_string()])),
+            &ListPagination::latest(),
+        )
+        .await?
+        .pop())
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn upsert_label(
+        &self,
+        caller: &ApiCaller,
+        name: &str,
+        description: Option<String>,
+        color: Option<String>,
+    ) -> ResourceResult<Label, StoreError> {
+        if caller.can(&ApiPermission::ManageLabels) {
+            // Deleted labels are included so that re-adding a label restores it
+            let existing = LabelStore::list(
+                &*self.storage,
+                LabelFilter::default()
+                    .name(Some(vec![name.trim().to_string()]))
+                    .deleted(true),
+                &ListPagination::latest(),
+            )
+            .await
+            .to_resource_result()?
+            .pop();
+
+            let label = LabelStore::upsert(
+                &*self.storage,
+                NewLabel {
+                    id: existing
+                        .as_ref()
+                        .map(|label| label.id)
+                        .unwrap_or_else(Uuid::new_v4),
+                    // Keep the existing spelling so that a label can not be split in two by a
+                    // difference in case
+                    name: existing
+                        .map(|label| label.name)
+                        .unwrap_or_else(|| name.trim().to_string()),
+                    description,
+                    color,
+                },
+            )
+            .await
+            .to_resource_result()?;
+
+            self.link_label_to_revisions(&label)
+                .await
+                .tap_err(|err| tracing::error!(?err, "Failed to link label to RFD revisions"))
+                .to_resource_result()?;
+
+            Ok(label)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    // Revisions are linked to labels when they are processed. Labels that are added to the
+    // taxonomy after the fact need to be linked to the latest revisions that already use them
+    async fn link_label_to_revisions(&self, label: &Label) -> Result<(), StoreError> {
+        let name = label.name.to_lowercase();
+
+        let revisions = RfdRevisionStore::list_unique_rfd(
+            &*self.storage,
+            RfdRevisionFilter::default(),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await?;
+
+        for revision in revisions.iter().filter(|revision| {
+            revision
+                .labels
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .any(|existing| existing.trim().to_lowercase() == name)
+        }) {
+            RfdRevisionLabelStore::upsert(
+                &*self.storage,
+                NewRfdRevisionLabel {
+                    id: Uuid::new_v4(),
+                    rfd_revision_id: revision.id,
+                    label_id: label.id,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn delete_label(
+        &self,
+        caller: &ApiCaller,
+        name: &str,
+    ) -> ResourceResult<Label, StoreError> {
+        if caller.can(&ApiPermission::ManageLabels) {
+            match self.find_label(name).await.to_resource_result()? {
+                Some(label) => LabelStore::delete(&*self.storage, &label.id)
+                    .await
+                    .opt_to_resource_result(),
+                None => resource_not_found(),
+            }
+        } else {
+            resource_restricted()
+        }
+    }
+
+    async fn emit_visibility_changed(
+        &self,
+        rfd: &Rfd,
+        previous: Visibility,
+    ) -> Result<Vec<WebhookDelivery>, StoreError> {
+        let revision = RfdRevisionStore::list(
+            &*self.storage,
+            RfdRevisionFilter::default().rfd(Some(vec![rfd.id])),
+            &ListPagination::latest(),
+        )
+        .await?
+        .pop();
+
+        match revision {
+            Some(revision) => {
+                self.emit_rfd_event(&RfdEvent {
+                    id: Uuid::new_v4(),
+                    event: WebhookEvent::VisibilityChanged,
+                    occurred_at: Utc::now(),
+                    rfd_number: rfd.rfd_number,
+                    title: revision.title,
+                    state: revision.state,
+                    visibility: rfd.visibility.clone(),
+                    commit: revision.commit,
+                    previous_state: None,
+                    previous_visibility: Some(previous),
+                })
+                .await
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    // Webhook Subscription Operations
+
+    #[instrument(skip(self, caller))]
+    pub async fn list_webhook_subscriptions(
+        &self,
+        caller: &ApiCaller,
+    ) -> ResourceResult<Vec<WebhookSubscription>, StoreError> {
+        if caller.can(&ApiPermission::GetWebhookSubscriptions) {
+            WebhookSubscriptionStore::list(
+                &*self.storage,
+                WebhookSubscriptionFilter::default(),
+                &ListPagination::default().limit(UNLIMITED),
+            )
+            .await
+            .to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn get_webhook_subscription(
+        &self,
+        caller: &ApiCaller,
+        id: &Uuid,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        if caller.can(&ApiPermission::GetWebhookSubscriptions) {
+            WebhookSubscriptionStore::get(&*self.storage, id, false)
+                .await
+                .opt_to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    #[instrument(skip(self, caller, new_subscription), fields(id = ?new_subscription.id, url = new_subscription.url))]
+    pub async fn upsert_webhook_subscription(
+        &self,
+        caller: &ApiCaller,
+        new_subscription: NewWebhookSubscription,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        if caller.can(&ApiPermission::ManageWebhookSubscriptions) {
+            WebhookSubscriptionStore::upsert(&*self.storage, new_subscription)
+                .await
+                .to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn delete_webhook_subscription(
+        &self,
+        caller: &ApiCaller,
+        id: &Uuid,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        if caller.can(&ApiPermission::ManageWebhookSubscriptions) {
+            WebhookSubscriptionStore::delete(&*self.storage, id)
+                .await
+                .opt_to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// List the deliveries that have been queued for a subscription, newest first
+    #[instrument(skip(self, caller))]
+    pub async fn list_webhook_deliveries(
+        &self,
+        caller: &ApiCaller,
+        id: &Uuid,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<WebhookDelivery>, StoreError> {
+        if caller.can(&ApiPermission::GetWebhookSubscriptions) {
+            WebhookDeliveryStore::list(
+                &*self.storage,
+                WebhookDeliveryFilter::default().webhook_subscription(Some(vec![*id])),
+                pagination,
+            )
+            .await
+            .to_resource_result()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Queue an event for delivery to every webhook subscription that it matches, and publish it to
+    /// live event streams. The processor is responsible for sending queued deliveries
+    #[instrument(skip(self, event), fields(event = %event.event, rfd_number = event.rfd_number))]
+    pub async fn emit_rfd_event(
+        &self,
+        event: &RfdEvent,
+    ) -> Result<Vec<WebhookDelivery>, StoreError> {
+        let queued = queue_webhook_deliveries(&*self.storage, std::slice::from_ref(event)).await;
+
+        // Live event streams are notified even when the webhook deliveries could not be queued
+        let notified = RfdEventStore::notify(&*self.storage, event).await;
+
+        let deliveries = queued?;
+        notified?;
+
+        Ok(deliveries)
+    }
+
     // Webhook Operations
 
     pub async fn register_job(&self, new_job: NewJob) -> Result<Job, StoreError> {
@@ -1170,6 +2473,14 @@ impl ApiContext {
             .map_err(|err| ApiError::from(err))
             .to_resource_result()?;
 
+        // Emails are stored lowercased so that they can be matched against RFD authors regardless
+        // of how they were written
+        let emails = info
+            .verified_emails
+            .iter()
+            .map(|email| email.to_lowercase())
+            .collect::<Vec<_>>();
+
         match api_user_providers.len() {
             0 => {
                 tracing::info!(
@@ -1190,7 +2501,7 @@ impl ApiContext {
                         NewApiUserProvider {
                             id: Uuid::new_v4(),
                             api_user_id: user.id,
-                            emails: info.verified_emails,
+                            emails,
                             display_names: info.display_name.into_iter().collect::<Vec<_>>(),
                             provider: info.external_id.provider().to_string(),
                             provider_id: info.external_id.id().to_string(),
@@ -1209,7 +2520,7 @@ impl ApiContext {
                 let mut provider = api_user_providers.into_iter().nth(0).unwrap();
 
                 // Update the provider with the newest user info
-                provider.emails = info.verified_emails;
+                provider.emails = emails;
                 provider.display_names = info.display_name.into_iter().collect::<Vec<_>>();
 
                 tracing::info!(?provider.id, "Updating provider for user");
@@ -1594,14 +2905,57 @@ impl ApiContext {
             &ApiPermission::UpdateApiUser(api_user_provider.id),
             &ApiPermission::UpdateApiUserAll,
         ]) {
-            ApiUserProviderStore::upsert(&*self.storage, api_user_provider)
+            let provider = ApiUserProviderStore::upsert(&*self.storage, api_user_provider)
                 .await
-                .to_resource_result()
+                .to_resource_result()?;
+            self.match_rfd_authors(&provider)
+                .await
+                .to_resource_result()?;
+
+            Ok(provider)
         } else {
             resource_restricted()
         }
     }
 
+    // Authors are matched to users when a revision is stored by the processor. When a provider
+    // gains new emails or is moved to a different user, the authors that have already been stored
+    // are updated to point at the provider's current user
+    async fn match_rfd_authors(&self, provider: &ApiUserProvider) -> Result<(), StoreError> {
+        if provider.emails.is_empty() {
+            return Ok(());
+        }
+
+        let authors = RfdAuthorStore::list(
+            &*self.storage,
+            RfdAuthorFilter::default().email(Some(provider.emails.clone())),
+            &ListPagination::default().limit(UNLIMITED),
+        )
+        .await?;
+
+        for author in authors
+            .into_iter()
+            .filter(|author| author.api_user_id != Some(provider.api_user_id))
+        {
+            tracing::info!(?author.id, ?provider.api_user_id, "Matching RFD author to user");
+
+            RfdAuthorStore::upsert(
+                &*self.storage,
+                NewRfdAuthor {
+                    id: author.id,
+                    rfd_revision_id: author.rfd_revision_id,
+                    position: author.position,
+                    name: author.name,
+                    email: author.email,
+                    api_user_id: Some(provider.api_user_id),
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn delete_api_user_token(
         &self,
         caller: &ApiCaller,
@@ -2146,39 +3500,176 @@ impl ApiContext {
                 .await
                 .to_resource_result()?;
 
-            ApiUserProviderStore::transfer(&*self.storage, provider.into(), source_api_user_id)
+            let provider =
+                ApiUserProviderStore::transfer(&*self.storage, provider.into(), source_api_user_id)
+                    .await
+                    .to_resource_result()?;
+            self.match_rfd_authors(&provider)
                 .await
-                .to_resource_result()
+                .to_resource_result()?;
+
+            Ok(provider)
         } else {
             resource_restricted()
         }
     }
 }
 
+// Walk a list of revisions, ordered by commit time, and emit an event for each attribute that
+// changed between consecutive revisions
+fn build_rfd_timeline(
+    revisions: &[RfdRevision],
+    default_branch_commits: &BTreeSet<String>,
+) -> Vec<RfdTimelineEvent> {
+    let mut events = vec![];
+    let mut published = false;
+
+    for (i, revision) in revisions.iter().enumerate() {
+        let mut kinds = vec![];
+
+        match i.checked_sub(1).map(|prev| &revisions[prev]) {
+            None => {
+                kinds.push(RfdTimelineEventKind::Created {
+                    title: revision.title.clone(),
+                    state: revision.state.clone(),
+                });
+
+                if let Some(discussion) = &revision.discussion {
+                    kinds.push(RfdTimelineEventKind::DiscussionLinked {
+                        url: discussion.clone(),
+                    });
+                }
+            }
+            Some(prev) => {
+                if prev.title != revision.title {
+                    kinds.push(RfdTimelineEventKind::TitleChanged {
+                        from: prev.title.clone(),
+                        to: revision.title.clone(),
+                    });
+                }
+
+                if prev.state != revision.state {
+                    kinds.push(RfdTimelineEventKind::StateChanged {
+                        from: prev.state.clone(),
+                        to: revision.state.clone(),
+                    });
+                }
+
+                if prev.labels != revision.labels {
+                    kinds.push(RfdTimelineEventKind::LabelsChanged {
+                        from: prev.labels.clone(),
+                        to: revision.labels.clone(),
+                    });
+                }
+
+                if prev.authors != revision.authors {
+                    kinds.push(RfdTimelineEventKind::AuthorsChanged {
+                        from: prev.authors.clone(),
+                        to: revision.authors.clone(),
+                    });
+                }
+
+                if let Some(discussion) = &revision.discussion {
+                    if prev.discussion.as_ref() != Some(discussion) {
+                        kinds.push(RfdTimelineEventKind::DiscussionLinked {
+                            url: discussion.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if !published && default_branch_commits.contains(&revision.commit.0) {
+            published = true;
+            kinds.push(RfdTimelineEventKind::PublishedToDefaultBranch);
+        }
+
+        events.extend(kinds.into_iter().map(|event| RfdTimelineEvent {
+            commit: revision.commit.clone(),
+            committed_at: revision.committed_at,
+            event,
+        }));
+    }
+
+    events
+}
+
+// Build feed entries for the newest `count` revisions of a RFD. Revisions are expected to be sorted
+// newest first
+fn build_rfd_feed(rfd: &Rfd, revisions: &[RfdRevision], count: usize) -> Vec<RfdFeedEntry> {
+    revisions
+        .iter()
+        .enumerate()
+        .take(count)
+        .map(|(i, revision)| {
+            let change = match revisions.get(i + 1) {
+                None => RfdFeedChange::Created,
+                Some(prev) if prev.state != revision.state => RfdFeedChange::StateChanged {
+                    from: prev.state.clone(),
+                    to: revision.state.clone(),
+                },
+                Some(_) => RfdFeedChange::Updated,
+            };
+
+            RfdFeedEntry {
+                rfd_number: rfd.rfd_number,
+                title: revision.title.clone(),
+                authors: revision.authors.clone(),
+                commit: revision.commit.clone(),
+                committed_at: revision.committed_at,
+                change,
+            }
+        })
+        .collect()
+}
+
+// Edits that were made against an outdated copy of a RFD must not silently overwrite newer changes
+fn check_expected_commit(
+    latest: &CommitSha,
+    expected: Option<&CommitSha>,
+) -> ResourceResult<(), UpdateRfdContentError> {
+    match expected {
+        Some(expected) if expected != latest => Err(UpdateRfdContentError::Conflict {
+            current: latest.clone(),
+        })
+        .to_resource_result(),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Duration, Utc};
     use mockall::predicate::eq;
     use rfd_model::{
-        storage::{AccessGroupFilter, ListPagination, MockAccessGroupStore, MockApiUserStore},
-        AccessGroup, ApiUser, ApiUserProvider,
+        schema_ext::{ContentFormat, Visibility, WebhookEvent},
+        storage::{
+            AccessGroupFilter, ApiKeyFilter, ListPagination, MockAccessGroupStore, MockApiKeyStore,
+            MockApiUserStore, MockRfdEventStore, MockRfdReservationStore,
+            MockWebhookSubscriptionStore, StoreError,
+        },
+        AccessGroup, ApiKey, ApiUser, ApiUserProvider, CommitSha, Rfd, RfdEvent, RfdReservation,
+        RfdRevision,
     };
     use std::{collections::BTreeSet, ops::Add, sync::Arc};
     use uuid::Uuid;
 
     use crate::{
         authn::{
-            jwt::{Claims, Jwt},
+            jwt::{Claims, FeedClaims, Jwt},
             AuthToken,
         },
         context::UNLIMITED,
         permissions::ApiPermission,
+        util::response::ResourceError,
         ApiPermissions, User,
     };
 
     use super::{
+        build_rfd_feed, build_rfd_timeline, check_expected_commit,
         test_mocks::{mock_context, MockStorage},
-        ApiContext,
+        ApiContext, PushReservedRfdError, RfdFeedChange, RfdTimelineEventKind,
+        UpdateRfdContentError,
     };
 
     async fn create_token(
@@ -2288,25 +3779,366 @@ mod tests {
             permissions.permissions
         );
     }
+
+    #[tokio::test]
+    async fn test_failed_reservations_release_numbers() {
+        let reservation = RfdReservation {
+            id: Uuid::new_v4(),
+            rfd_number: 123,
+            api_user_id: Uuid::new_v4(),
+            created_at: Utc::now(),
+        };
+
+        // Only a push that failed without leaving its branch behind hands the number back
+        let mut reservation_store = MockRfdReservationStore::new();
+        let released = reservation.clone();
+        reservation_store
+            .expect_release()
+            .with(eq(reservation.id))
+            .times(1)
+            .returning(move |_| Ok(Some(released.clone())));
+
+        let mut storage = MockStorage::new();
+        storage.rfd_reservation_store = Some(Arc::new(reservation_store));
+        let ctx = mock_context(storage).await;
+
+        assert!(ctx.settle_reservation(&reservation, Ok(())).await.is_ok());
+
+        let result = ctx
+            .settle_reservation(
+                &reservation,
+                Err(PushReservedRfdError::BranchRemains(
+                    ResourceError::DoesNotExist,
+                )),
+            )
+            .await;
+        assert!(matches!(result, Err(ResourceError::DoesNotExist)));
+
+        let result = ctx
+            .settle_reservation(
+                &reservation,
+                Err(PushReservedRfdError::Clean(ResourceError::DoesNotExist)),
+            )
+            .await;
+        assert!(matches!(result, Err(ResourceError::DoesNotExist)));
+    }
+
+    #[tokio::test]
+    async fn test_events_are_streamed_when_webhooks_fail() {
+        let mut subscription_store = MockWebhookSubscriptionStore::new();
+        subscription_store
+            .expect_list()
+            .returning(|_, _| Err(StoreError::Unknown));
+
+        let mut event_store = MockRfdEventStore::new();
+        event_store.expect_notify().times(1).returning(|_| Ok(()));
+
+        let mut storage = MockStorage::new();
+        storage.webhook_subscription_store = Some(Arc::new(subscription_store));
+        storage.rfd_event_store = Some(Arc::new(event_store));
+        let ctx = mock_context(storage).await;
+
+        let result = ctx
+            .emit_rfd_event(&RfdEvent {
+                id: Uuid::new_v4(),
+                event: WebhookEvent::ContentUpdated,
+                occurred_at: Utc::now(),
+                rfd_number: 123,
+                title: "Live updates".to_string(),
+                state: None,
+                visibility: Visibility::Public,
+                commit: CommitSha("commit".to_string()),
+                previous_state: None,
+                previous_visibility: None,
+            })
+            .await;
+        assert!(matches!(result, Err(StoreError::Unknown)));
+    }
+
+    #[tokio::test]
+    async fn test_feed_token_permissions() {
+        let user_id = Uuid::new_v4();
+        let user = ApiUser {
+            id: user_id,
+            permissions: vec![ApiPermission::GetRfd(5), ApiPermission::UpdateRfd(5)].into(),
+            groups: BTreeSet::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        };
+
+        let mut user_store = MockApiUserStore::new();
+        user_store
+            .expect_get()
+            .with(eq(user.id), eq(false))
+            .returning(move |_, _| Ok(Some(user.clone())));
+
+        let key_id = Uuid::new_v4();
+        let revoked_key_id = Uuid::new_v4();
+        let key = ApiKey {
+            id: key_id,
+            api_user_id: user_id,
+            key_signature: "signature".to_string(),
+            permissions: Some(
+                vec![ApiPermission::GetRfdsAssigned, ApiPermission::GetRfdsAll].into(),
+            ),
+            expires_at: Utc::now().add(Duration::seconds(300)),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        };
+
+        let mut key_store = MockApiKeyStore::new();
+        key_store
+            .expect_list()
+            .withf(move |filter: &ApiKeyFilter, _| {
+                filter.id == Some(vec![key_id]) && filter.api_user_id == Some(vec![user_id])
+            })
+            .returning(move |_, _| Ok(vec![key.clone()]));
+        key_store
+            .expect_list()
+            .withf(move |filter: &ApiKeyFilter, _| filter.id == Some(vec![revoked_key_id]))
+            .returning(|_, _| Ok(vec![]));
+
+        let mut group_store = MockAccessGroupStore::new();
+        group_store.expect_list().returning(|_, _| Ok(vec![]));
+
+        let mut storage = MockStorage::new();
+        storage.access_group_store = Some(Arc::new(group_store));
+        storage.api_user_store = Some(Arc::new(user_store));
+        storage.api_user_token_store = Some(Arc::new(key_store));
+        let ctx = mock_context(storage).await;
+
+        let expires_at = Utc::now().add(Duration::seconds(300));
+        let token = ctx
+            .sign_jwt(&FeedClaims::new(&ctx, user_id, key_id, expires_at))
+            .await
+            .unwrap();
+
+        // Feed tokens are never accepted in place of a bearer token
+        assert!(Jwt::new(&ctx, &token).await.is_err());
+
+        // Feed tokens are limited to reading RFDs
+        let feed_token = AuthToken::from_feed_token(&ctx, &token).await.unwrap();
+        let caller = ctx.get_caller(Some(&feed_token)).await.unwrap();
+        assert_eq!(
+            ApiPermissions::from(vec![ApiPermission::GetRfd(5)]),
+            caller.permissions
+        );
+
+        // Feed tokens stop working once their key has been revoked
+        let revoked = ctx
+            .sign_jwt(&FeedClaims::new(&ctx, user_id, revoked_key_id, expires_at))
+            .await
+            .unwrap();
+        let revoked_token = AuthToken::from_feed_token(&ctx, &revoked).await.unwrap();
+        assert!(ctx.get_caller(Some(&revoked_token)).await.is_err());
+
+        // Bearer tokens are not accepted as feed tokens
+        let bearer = ctx
+            .sign_jwt(&Claims {
+                iss: ctx.public_url.to_string(),
+                aud: ctx.public_url.to_string(),
+                sub: user_id,
+                prv: Uuid::new_v4(),
+                scp: None,
+                exp: expires_at.timestamp(),
+                nbf: Utc::now().timestamp(),
+                jti: key_id,
+            })
+            .await
+            .unwrap();
+        assert!(AuthToken::from_feed_token(&ctx, &bearer).await.is_err());
+    }
+
+    fn revision(
+        commit: &str,
+        title: &str,
+        state: Option<&str>,
+        discussion: Option<&str>,
+    ) -> RfdRevision {
+        RfdRevision {
+            id: Uuid::new_v4(),
+            rfd_id: Uuid::nil(),
+            title: title.to_string(),
+            state: state.map(|state| state.to_string()),
+            discussion: discussion.map(|discussion| discussion.to_string()),
+            authors: Some("Jane Doe <jane@example.com>".to_string()),
+            labels: None,
+            content: String::new(),
+            content_format: ContentFormat::Asciidoc,
+            sha: String::new().into(),
+            commit: commit.to_string().into(),
+            committed_at: Utc::now(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_timeline_from_revisions() {
+        let revisions = vec![
+            revision("a", "Draft", Some("prediscussion"), None),
+            revision(
+                "b",
+                "Draft",
+                Some("discussion"),
+                Some("https://example.com/pull/1"),
+            ),
+            revision(
+                "c",
+                "Final",
+                Some("published"),
+                Some("https://example.com/pull/1"),
+            ),
+            revision(
+                "d",
+                "Final",
+                Some("published"),
+                Some("https://example.com/pull/1"),
+            ),
+        ];
+        let default_branch_commits = ["c".to_string(), "d".to_string()].into_iter().collect();
+
+        let events = build_rfd_timeline(&revisions, &default_branch_commits)
+            .into_iter()
+            .map(|event| (event.commit.0, event.event))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (
+                    "a".to_string(),
+                    RfdTimelineEventKind::Created {
+                        title: "Draft".to_string(),
+                        state: Some("prediscussion".to_string()),
+                    }
+                ),
+                (
+                    "b".to_string(),
+                    RfdTimelineEventKind::StateChanged {
+                        from: Some("prediscussion".to_string()),
+                        to: Some("discussion".to_string()),
+                    }
+                ),
+                (
+                    "b".to_string(),
+                    RfdTimelineEventKind::DiscussionLinked {
+                        url: "https://example.com/pull/1".to_string(),
+                    }
+                ),
+                (
+                    "c".to_string(),
+                    RfdTimelineEventKind::TitleChanged {
+                        from: "Draft".to_string(),
+                        to: "Final".to_string(),
+                    }
+                ),
+                (
+                    "c".to_string(),
+                    RfdTimelineEventKind::StateChanged {
+                        from: Some("discussion".to_string()),
+                        to: Some("published".to_string()),
+                    }
+                ),
+                (
+                    "c".to_string(),
+                    RfdTimelineEventKind::PublishedToDefaultBranch
+                ),
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn test_feed_from_revisions() {
+        let rfd = Rfd {
+            id: Uuid::nil(),
+            rfd_number: 123,
+            link: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            visibility: Visibility::Public,
+        };
+        let revisions = vec![
+            revision("d", "Final", Some("published"), None),
+            revision("c", "Final", Some("discussion"), None),
+            revision("b", "Draft", Some("discussion"), None),
+            revision("a", "Draft", Some("prediscussion"), None),
+        ];
+
+        let entries = build_rfd_feed(&rfd, &revisions, 3)
+            .into_iter()
+            .map(|entry| (entry.commit.0, entry.change))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (
+                    "d".to_string(),
+                    RfdFeedChange::StateChanged {
+                        from: Some("discussion".to_string()),
+                        to: Some("published".to_string()),
+                    }
+                ),
+                ("c".to_string(), RfdFeedChange::Updated),
+                (
+                    "b".to_string(),
+                    RfdFeedChange::StateChanged {
+                        from: Some("prediscussion".to_string()),
+                        to: Some("discussion".to_string()),
+                    }
+                ),
+            ],
+            entries
+        );
+
+        let entries = build_rfd_feed(&rfd, &revisions[3..], 1);
+        assert_eq!(RfdFeedChange::Created, entries[0].change);
+    }
+
+    #[test]
+    fn test_expected_commit_must_match_latest() {
+        let latest = CommitSha("latest".to_string());
+
+        assert!(check_expected_commit(&latest, None).is_ok());
+        assert!(check_expected_commit(&latest, Some(&latest)).is_ok());
+
+        match check_expected_commit(&latest, Some(&CommitSha("outdated".to_string()))) {
+            Err(ResourceError::InternalError(UpdateRfdContentError::Conflict { current })) => {
+                assert_eq!(latest, current)
+            }
+            other => panic!("Expected a conflict, found {:?}", other),
+        }
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod test_mocks {
     use async_trait::async_trait;
-    use rfd_data::content::RfdTemplate;
+    use rfd_data::{content::RfdTemplate, RfdNumberFormat};
     use rfd_model::{
         storage::{
             AccessGroupStore, AccessTokenStore, ApiKeyStore, ApiUserProviderStore, ApiUserStore,
-            JobStore, LinkRequestStore, ListPagination, LoginAttemptStore, MapperStore,
+            JobStore, LabelStore, LinkRequestStore, ListPagination, LoginAttemptStore, MapperStore,
             MockAccessGroupStore, MockAccessTokenStore, MockApiKeyStore, MockApiUserProviderStore,
-            MockApiUserStore, MockJobStore, MockLinkRequestStore, MockLoginAttemptStore,
-            MockMapperStore, MockOAuthClientRedirectUriStore, MockOAuthClientSecretStore,
-            MockOAuthClientStore, MockRfdPdfStore, MockRfdRevisionStore, MockRfdStore,
-            OAuthClientRedirectUriStore, OAuthClientSecretStore, OAuthClientStore, RfdPdfStore,
-            RfdRevisionStore, RfdStore,
+            MockApiUserStore, MockJobStore, MockLabelStore, MockLinkRequestStore,
+            MockLoginAttemptStore, MockMapperStore, MockOAuthClientRedirectUriStore,
+            MockOAuthClientSecretStore, MockOAuthClientStore, MockRfdAuthorStore,
+            MockRfdEventStore, MockRfdPdfStore, MockRfdReservationStore, MockRfdRevisionAssetStore,
+            MockRfdRevisionLabelStore, MockRfdRevisionStore, MockRfdStateTransitionStore,
+            MockRfdStore, MockWebhookDeliveryStore, MockWebhookSubscriptionStore,
+            OAuthClientRedirectUriStore, OAuthClientSecretStore, OAuthClientStore, RfdAuthorStore,
+            RfdEventStore, RfdPdfStore, RfdReservationStore, RfdRevisionAssetStore,
+            RfdRevisionLabelStore, RfdRevisionStore, RfdStateTransitionStore, RfdStore,
+            WebhookDeliveryStore, WebhookSubscriptionStore,
         },
         ApiKey, ApiUserProvider, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser,
-        NewApiUserProvider, NewJob, NewLoginAttempt, NewMapper, NewRfd, NewRfdPdf, NewRfdRevision,
+        NewApiUserProvider, NewJob, NewLabel, NewLoginAttempt, NewMapper, NewRfd, NewRfdAuthor,
+        NewRfdPdf, NewRfdRevision, NewRfdRevisionAsset, NewRfdRevisionLabel, NewRfdStateTransition,
+        NewWebhookDelivery, NewWebhookSubscription,
     };
     use std::sync::Arc;
     use w_api_permissions::Caller;
@@ -2348,6 +4180,7 @@ pub(crate) mod test_mocks {
                     repo: String::new(),
                     path: String::new(),
                     default_branch: String::new(),
+                    number_format: RfdNumberFormat::default(),
                     auth: GitHubAuthConfig::User {
                         token: String::default(),
                     },
@@ -2378,6 +4211,11 @@ pub(crate) mod test_mocks {
         pub rfd_store: Option<Arc<MockRfdStore>>,
         pub rfd_revision_store: Option<Arc<MockRfdRevisionStore>>,
         pub rfd_pdf_store: Option<Arc<MockRfdPdfStore>>,
+        pub rfd_revision_asset_store: Option<Arc<MockRfdRevisionAssetStore>>,
+        pub rfd_author_store: Option<Arc<MockRfdAuthorStore>>,
+        pub label_store: Option<Arc<MockLabelStore>>,
+        pub rfd_revision_label_store: Option<Arc<MockRfdRevisionLabelStore>>,
+        pub rfd_state_transition_store: Option<Arc<MockRfdStateTransitionStore>>,
         pub job_store: Option<Arc<MockJobStore>>,
         pub api_user_store: Option<Arc<MockApiUserStore<ApiPermission>>>,
         pub api_user_token_store: Option<Arc<MockApiKeyStore<ApiPermission>>>,
@@ -2390,6 +4228,10 @@ pub(crate) mod test_mocks {
         pub access_group_store: Option<Arc<MockAccessGroupStore<ApiPermission>>>,
         pub mapper_store: Option<Arc<MockMapperStore>>,
         pub link_request_store: Option<Arc<MockLinkRequestStore>>,
+        pub webhook_subscription_store: Option<Arc<MockWebhookSubscriptionStore>>,
+        pub webhook_delivery_store: Option<Arc<MockWebhookDeliveryStore>>,
+        pub rfd_event_store: Option<Arc<MockRfdEventStore>>,
+        pub rfd_reservation_store: Option<Arc<MockRfdReservationStore>>,
     }
 
     impl MockStorage {
@@ -2399,6 +4241,11 @@ pub(crate) mod test_mocks {
                 rfd_store: None,
                 rfd_revision_store: None,
                 rfd_pdf_store: None,
+                rfd_revision_asset_store: None,
+                rfd_author_store: None,
+                label_store: None,
+                rfd_revision_label_store: None,
+                rfd_state_transition_store: None,
                 job_store: None,
                 api_user_store: None,
                 api_user_token_store: None,
@@ -2411,26 +4258,124 @@ pub(crate) mod test_mocks {
                 access_group_store: None,
                 mapper_store: None,
                 link_request_store: None,
+                webhook_subscription_store: None,
+                webhook_delivery_store: None,
+                rfd_event_store: None,
+                rfd_reservation_store: None,
             }
         }
     }
 
     #[async_trait]
-    impl RfdStore for MockStorage {
+    impl RfdStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+            deleted: bool,
+        ) -> Result<Option<rfd_model::Rfd>, rfd_model::storage::StoreError> {
+            self.rfd_store.as_ref().unwrap().get(id, deleted).await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::RfdFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::Rfd>, rfd_model::storage::StoreError> {
+            self.rfd_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_rfd: NewRfd,
+        ) -> Result<rfd_model::Rfd, rfd_model::storage::StoreError> {
+            self.rfd_store.as_ref().unwrap().upsert(new_rfd).await
+        }
+
+        async fn delete(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::Rfd>, rfd_model::storage::StoreError> {
+            self.rfd_store.as_ref().unwrap().delete(id).await
+        }
+    }
+
+    #[async_trait]
+    impl RfdRevisionStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+            deleted: bool,
+        ) -> Result<Option<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
+            self.rfd_revision_store
+                .as_ref()
+                .unwrap()
+                .get(id, deleted)
+                .await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::RfdRevisionFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
+            self.rfd_revision_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn list_unique_rfd(
+            &self,
+            filter: rfd_model::storage::RfdRevisionFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
+            self.rfd_revision_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_revision: NewRfdRevision,
+        ) -> Result<rfd_model::RfdRevision, rfd_model::storage::StoreError> {
+            self.rfd_revision_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_revision)
+                .await
+        }
+
+        async fn delete(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
+            self.rfd_revision_store.as_ref().unwrap().delete(id).await
+        }
+    }
+
+    #[async_trait]
+    impl RfdPdfStore for MockStorage {
         async fn get(
             &self,
             id: &uuid::Uuid,
             deleted: bool,
-        ) -> Result<Option<rfd_model::Rfd>, rfd_model::storage::StoreError> {
-            self.rfd_store.as_ref().unwrap().get(id, deleted).await
+        ) -> Result<Option<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
+            self.rfd_pdf_store.as_ref().unwrap().get(id, deleted).await
         }
 
         async fn list(
             &self,
-            filter: rfd_model::storage::RfdFilter,
+            filter: rfd_model::storage::RfdPdfFilter,
             pagination: &ListPagination,
-        ) -> Result<Vec<rfd_model::Rfd>, rfd_model::storage::StoreError> {
-            self.rfd_store
+        ) -> Result<Vec<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
+            self.rfd_pdf_store
                 .as_ref()
                 .unwrap()
                 .list(filter, pagination)
@@ -2439,27 +4384,27 @@ pub(crate) mod test_mocks {
 
         async fn upsert(
             &self,
-            new_rfd: NewRfd,
-        ) -> Result<rfd_model::Rfd, rfd_model::storage::StoreError> {
-            self.rfd_store.as_ref().unwrap().upsert(new_rfd).await
+            new_pdf: NewRfdPdf,
+        ) -> Result<rfd_model::RfdPdf, rfd_model::storage::StoreError> {
+            self.rfd_pdf_store.as_ref().unwrap().upsert(new_pdf).await
         }
 
         async fn delete(
             &self,
             id: &uuid::Uuid,
-        ) -> Result<Option<rfd_model::Rfd>, rfd_model::storage::StoreError> {
-            self.rfd_store.as_ref().unwrap().delete(id).await
+        ) -> Result<Option<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
+            self.rfd_pdf_store.as_ref().unwrap().delete(id).await
         }
     }
 
     #[async_trait]
-    impl RfdRevisionStore for MockStorage {
+    impl RfdRevisionAssetStore for MockStorage {
         async fn get(
             &self,
             id: &uuid::Uuid,
             deleted: bool,
-        ) -> Result<Option<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
-            self.rfd_revision_store
+        ) -> Result<Option<rfd_model::RfdRevisionAsset>, rfd_model::storage::StoreError> {
+            self.rfd_revision_asset_store
                 .as_ref()
                 .unwrap()
                 .get(id, deleted)
@@ -2468,22 +4413,59 @@ pub(crate) mod test_mocks {
 
         async fn list(
             &self,
-            filter: rfd_model::storage::RfdRevisionFilter,
+            filter: rfd_model::storage::RfdRevisionAssetFilter,
             pagination: &ListPagination,
-        ) -> Result<Vec<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
-            self.rfd_revision_store
+        ) -> Result<Vec<rfd_model::RfdRevisionAsset>, rfd_model::storage::StoreError> {
+            self.rfd_revision_asset_store
                 .as_ref()
                 .unwrap()
                 .list(filter, pagination)
                 .await
         }
 
-        async fn list_unique_rfd(
+        async fn upsert(
             &self,
-            filter: rfd_model::storage::RfdRevisionFilter,
+            new_asset: NewRfdRevisionAsset,
+        ) -> Result<rfd_model::RfdRevisionAsset, rfd_model::storage::StoreError> {
+            self.rfd_revision_asset_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_asset)
+                .await
+        }
+
+        async fn delete(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::RfdRevisionAsset>, rfd_model::storage::StoreError> {
+            self.rfd_revision_asset_store
+                .as_ref()
+                .unwrap()
+                .delete(id)
+                .await
+        }
+    }
+
+    #[async_trait]
+    impl RfdAuthorStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+            deleted: bool,
+        ) -> Result<Option<rfd_model::RfdAuthor>, rfd_model::storage::StoreError> {
+            self.rfd_author_store
+                .as_ref()
+                .unwrap()
+                .get(id, deleted)
+                .await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::RfdAuthorFilter,
             pagination: &ListPagination,
-        ) -> Result<Vec<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
-            self.rfd_revision_store
+        ) -> Result<Vec<rfd_model::RfdAuthor>, rfd_model::storage::StoreError> {
+            self.rfd_author_store
                 .as_ref()
                 .unwrap()
                 .list(filter, pagination)
@@ -2492,39 +4474,39 @@ pub(crate) mod test_mocks {
 
         async fn upsert(
             &self,
-            new_revision: NewRfdRevision,
-        ) -> Result<rfd_model::RfdRevision, rfd_model::storage::StoreError> {
-            self.rfd_revision_store
+            new_author: NewRfdAuthor,
+        ) -> Result<rfd_model::RfdAuthor, rfd_model::storage::StoreError> {
+            self.rfd_author_store
                 .as_ref()
                 .unwrap()
-                .upsert(new_revision)
+                .upsert(new_author)
                 .await
         }
 
         async fn delete(
             &self,
             id: &uuid::Uuid,
-        ) -> Result<Option<rfd_model::RfdRevision>, rfd_model::storage::StoreError> {
-            self.rfd_revision_store.as_ref().unwrap().delete(id).await
+        ) -> Result<Option<rfd_model::RfdAuthor>, rfd_model::storage::StoreError> {
+            self.rfd_author_store.as_ref().unwrap().delete(id).await
         }
     }
 
     #[async_trait]
-    impl RfdPdfStore for MockStorage {
+    impl LabelStore for MockStorage {
         async fn get(
             &self,
             id: &uuid::Uuid,
             deleted: bool,
-        ) -> Result<Option<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
-            self.rfd_pdf_store.as_ref().unwrap().get(id, deleted).await
+        ) -> Result<Option<rfd_model::Label>, rfd_model::storage::StoreError> {
+            self.label_store.as_ref().unwrap().get(id, deleted).await
         }
 
         async fn list(
             &self,
-            filter: rfd_model::storage::RfdPdfFilter,
+            filter: rfd_model::storage::LabelFilter,
             pagination: &ListPagination,
-        ) -> Result<Vec<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
-            self.rfd_pdf_store
+        ) -> Result<Vec<rfd_model::Label>, rfd_model::storage::StoreError> {
+            self.label_store
                 .as_ref()
                 .unwrap()
                 .list(filter, pagination)
@@ -2533,16 +4515,102 @@ pub(crate) mod test_mocks {
 
         async fn upsert(
             &self,
-            new_pdf: NewRfdPdf,
-        ) -> Result<rfd_model::RfdPdf, rfd_model::storage::StoreError> {
-            self.rfd_pdf_store.as_ref().unwrap().upsert(new_pdf).await
+            new_label: NewLabel,
+        ) -> Result<rfd_model::Label, rfd_model::storage::StoreError> {
+            self.label_store.as_ref().unwrap().upsert(new_label).await
         }
 
         async fn delete(
             &self,
             id: &uuid::Uuid,
-        ) -> Result<Option<rfd_model::RfdPdf>, rfd_model::storage::StoreError> {
-            self.rfd_pdf_store.as_ref().unwrap().delete(id).await
+        ) -> Result<Option<rfd_model::Label>, rfd_model::storage::StoreError> {
+            self.label_store.as_ref().unwrap().delete(id).await
+        }
+    }
+
+    #[async_trait]
+    impl RfdRevisionLabelStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+            deleted: bool,
+        ) -> Result<Option<rfd_model::RfdRevisionLabel>, rfd_model::storage::StoreError> {
+            self.rfd_revision_label_store
+                .as_ref()
+                .unwrap()
+                .get(id, deleted)
+                .await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::RfdRevisionLabelFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::RfdRevisionLabel>, rfd_model::storage::StoreError> {
+            self.rfd_revision_label_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_revision_label: NewRfdRevisionLabel,
+        ) -> Result<rfd_model::RfdRevisionLabel, rfd_model::storage::StoreError> {
+            self.rfd_revision_label_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_revision_label)
+                .await
+        }
+
+        async fn delete(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::RfdRevisionLabel>, rfd_model::storage::StoreError> {
+            self.rfd_revision_label_store
+                .as_ref()
+                .unwrap()
+                .delete(id)
+                .await
+        }
+    }
+
+    #[async_trait]
+    impl RfdStateTransitionStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::RfdStateTransition>, rfd_model::storage::StoreError> {
+            self.rfd_state_transition_store
+                .as_ref()
+                .unwrap()
+                .get(id)
+                .await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::RfdStateTransitionFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::RfdStateTransition>, rfd_model::storage::StoreError> {
+            self.rfd_state_transition_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_transition: NewRfdStateTransition,
+        ) -> Result<rfd_model::RfdStateTransition, rfd_model::storage::StoreError> {
+            self.rfd_state_transition_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_transition)
+                .await
         }
     }
 
@@ -3030,4 +5098,150 @@ pub(crate) mod test_mocks {
                 .await
         }
     }
+
+    #[async_trait]
+    impl WebhookSubscriptionStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+            deleted: bool,
+        ) -> Result<Option<rfd_model::WebhookSubscription>, rfd_model::storage::StoreError>
+        {
+            self.webhook_subscription_store
+                .as_ref()
+                .unwrap()
+                .get(id, deleted)
+                .await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::WebhookSubscriptionFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::WebhookSubscription>, rfd_model::storage::StoreError> {
+            self.webhook_subscription_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_subscription: NewWebhookSubscription,
+        ) -> Result<rfd_model::WebhookSubscription, rfd_model::storage::StoreError> {
+            self.webhook_subscription_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_subscription)
+                .await
+        }
+
+        async fn delete(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::WebhookSubscription>, rfd_model::storage::StoreError>
+        {
+            self.webhook_subscription_store
+                .as_ref()
+                .unwrap()
+                .delete(id)
+                .await
+        }
+    }
+
+    #[async_trait]
+    impl WebhookDeliveryStore for MockStorage {
+        async fn get(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::WebhookDelivery>, rfd_model::storage::StoreError> {
+            self.webhook_delivery_store.as_ref().unwrap().get(id).await
+        }
+
+        async fn list(
+            &self,
+            filter: rfd_model::storage::WebhookDeliveryFilter,
+            pagination: &ListPagination,
+        ) -> Result<Vec<rfd_model::WebhookDelivery>, rfd_model::storage::StoreError> {
+            self.webhook_delivery_store
+                .as_ref()
+                .unwrap()
+                .list(filter, pagination)
+                .await
+        }
+
+        async fn upsert(
+            &self,
+            new_delivery: NewWebhookDelivery,
+        ) -> Result<rfd_model::WebhookDelivery, rfd_model::storage::StoreError> {
+            self.webhook_delivery_store
+                .as_ref()
+                .unwrap()
+                .upsert(new_delivery)
+                .await
+        }
+
+        async fn start(
+            &self,
+            id: &uuid::Uuid,
+            retry_at: chrono::DateTime<chrono::Utc>,
+        ) -> Result<Option<rfd_model::WebhookDelivery>, rfd_model::storage::StoreError> {
+            self.webhook_delivery_store
+                .as_ref()
+                .unwrap()
+                .start(id, retry_at)
+                .await
+        }
+
+        async fn complete(
+            &self,
+            id: &uuid::Uuid,
+            delivered: bool,
+            response_status: Option<i32>,
+            error: Option<String>,
+            next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+        ) -> Result<Option<rfd_model::WebhookDelivery>, rfd_model::storage::StoreError> {
+            self.webhook_delivery_store
+                .as_ref()
+                .unwrap()
+                .complete(id, delivered, response_status, error, next_attempt_at)
+                .await
+        }
+    }
+
+    #[async_trait]
+    impl RfdEventStore for MockStorage {
+        async fn notify(
+            &self,
+            event: &rfd_model::RfdEvent,
+        ) -> Result<(), rfd_model::storage::StoreError> {
+            self.rfd_event_store.as_ref().unwrap().notify(event).await
+        }
+    }
+
+    #[async_trait]
+    impl RfdReservationStore for MockStorage {
+        async fn reserve(
+            &self,
+            new_reservation: rfd_model::NewRfdReservation,
+        ) -> Result<rfd_model::RfdReservation, rfd_model::storage::StoreError> {
+            self.rfd_reservation_store
+                .as_ref()
+                .unwrap()
+                .reserve(new_reservation)
+                .await
+        }
+
+        async fn release(
+            &self,
+            id: &uuid::Uuid,
+        ) -> Result<Option<rfd_model::RfdReservation>, rfd_model::storage::StoreError> {
+            self.rfd_reservation_store
+                .as_ref()
+                .unwrap()
+                .release(id)
+                .await
+        }
+    }
 }
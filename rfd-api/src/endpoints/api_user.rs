@@ -4,7 +4,7 @@
 
 use std::collections::BTreeSet;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use dropshot::{
     endpoint, HttpError, HttpResponseCreated, HttpResponseOk, HttpResponseUpdatedNoContent, Path,
     RequestContext, TypedBody,
@@ -15,6 +15,7 @@ use rfd_model::{
     ApiUser, ApiUserProvider, NewApiKey, NewApiUser,
 };
 use schemars::JsonSchema;
+use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 use tap::TapFallible;
 use trace_request::trace_request;
@@ -23,10 +24,10 @@ use uuid::Uuid;
 use w_api_permissions::Permissions;
 
 use crate::{
-    authn::key::RawApiKey,
+    authn::{jwt::FeedClaims, key::RawApiKey},
     context::ApiContext,
     error::ApiError,
-    permissions::ApiPermissionResponse,
+    permissions::{ApiPermission, ApiPermissionResponse},
     secrets::OpenApiSecretString,
     util::response::{bad_request, not_found, to_internal_error, unauthorized},
     ApiCaller, ApiPermissions, User,
@@ -388,6 +389,104 @@ async fn delete_api_user_token_op(
     }))
 }
 
+// Feed tokens are sent in query strings, where they are recorded in request logs, so they are
+// limited to a shorter lifetime than API tokens
+static MAX_FEED_TOKEN_LIFETIME_DAYS: i64 = 90;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FeedTokenCreateParams {
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct InitialFeedTokenResponse {
+    pub id: Uuid,
+    pub token: OpenApiSecretString,
+    pub created_at: DateTime<Utc>,
+}
+
+// Create a read-only token for a given user that authenticates feed and event stream requests.
+// Feed tokens are listed alongside the user's API tokens and are revoked by deleting the API token
+// with the returned id. This is the only time that the returned token will be accessible
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/api-user/{identifier}/feed-token",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn create_api_user_feed_token(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<ApiUserPath>,
+    body: TypedBody<FeedTokenCreateParams>,
+) -> Result<HttpResponseCreated<InitialFeedTokenResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    create_api_user_feed_token_op(ctx, &caller, &path.into_inner(), body.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller, body), fields(caller = ?caller.id), err(Debug))]
+async fn create_api_user_feed_token_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    path: &ApiUserPath,
+    body: FeedTokenCreateParams,
+) -> Result<HttpResponseCreated<InitialFeedTokenResponse>, HttpError> {
+    let now = Utc::now();
+    if body.expires_at <= now
+        || body.expires_at > now + Duration::days(MAX_FEED_TOKEN_LIFETIME_DAYS)
+    {
+        return Err(bad_request(format!(
+            "Feed tokens must expire within {} days",
+            MAX_FEED_TOKEN_LIFETIME_DAYS
+        )));
+    }
+
+    let api_user = ctx.get_api_user(caller, &path.identifier).await?;
+
+    // A feed token is backed by an API key that limits it to reading RFDs. The key itself is never
+    // handed out, it only exists so that the token can be listed and revoked
+    let key_id = Uuid::new_v4();
+    let key = RawApiKey::generate::<24>(&key_id)
+        .sign(&*ctx.secrets.signer)
+        .await
+        .map_err(to_internal_error)?;
+
+    let user_key = ctx
+        .create_api_user_token(
+            caller,
+            NewApiKey {
+                id: key_id,
+                api_user_id: path.identifier,
+                key_signature: key.signature().to_string(),
+                permissions: Some(feed_token_permissions()),
+                expires_at: body.expires_at,
+            },
+            &api_user.id,
+        )
+        .await?;
+
+    let token = ctx
+        .sign_jwt(&FeedClaims::new(
+            ctx,
+            user_key.api_user_id,
+            user_key.id,
+            user_key.expires_at,
+        ))
+        .await
+        .map_err(to_internal_error)?;
+
+    Ok(HttpResponseCreated(InitialFeedTokenResponse {
+        id: user_key.id,
+        token: SecretString::new(token).into(),
+        created_at: user_key.created_at,
+    }))
+}
+
+fn feed_token_permissions() -> ApiPermissions {
+    vec![ApiPermission::GetRfdsAssigned, ApiPermission::GetRfdsAll].into()
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct AddGroupBody {
     group_id: Uuid,
@@ -557,9 +656,10 @@ mod tests {
     use crate::{
         context::test_mocks::{mock_context, MockStorage},
         endpoints::api_user::{
-            create_api_user_token_op, delete_api_user_token_op, get_api_user_token_op,
-            into_permissions_response, list_api_user_tokens_op, update_api_user_op,
-            ApiKeyCreateParams, ApiUserPath, ApiUserTokenPath,
+            create_api_user_feed_token_op, create_api_user_token_op, delete_api_user_token_op,
+            get_api_user_token_op, into_permissions_response, list_api_user_tokens_op,
+            update_api_user_op, ApiKeyCreateParams, ApiUserPath, ApiUserTokenPath,
+            FeedTokenCreateParams,
         },
         permissions::ApiPermission,
         util::tests::get_status,
@@ -1309,4 +1409,43 @@ mod tests {
         assert!(resp.is_err());
         assert_eq!(get_status(&resp), StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[tokio::test]
+    async fn test_create_api_user_feed_token_lifetime() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let user = mock_user();
+        let caller = ApiCaller {
+            id: user.id,
+            permissions: vec![ApiPermission::CreateApiUserToken(user.id).into()].into(),
+        };
+        let path = ApiUserPath {
+            identifier: user.id,
+        };
+
+        // 1. Fail to create a token that has already expired
+        let resp = create_api_user_feed_token_op(
+            &ctx,
+            &caller,
+            &path,
+            FeedTokenCreateParams {
+                expires_at: Utc::now() - Duration::seconds(60),
+            },
+        )
+        .await;
+
+        assert_eq!(get_status(&resp), StatusCode::BAD_REQUEST);
+
+        // 2. Fail to create a token that outlives the maximum lifetime
+        let resp = create_api_user_feed_token_op(
+            &ctx,
+            &caller,
+            &path,
+            FeedTokenCreateParams {
+                expires_at: Utc::now() + Duration::days(365),
+            },
+        )
+        .await;
+
+        assert_eq!(get_status(&resp), StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{endpoint, HttpError, HttpResponseOk, Path, RequestContext, TypedBody};
+use rfd_model::Label;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use trace_request::trace_request;
+use tracing::instrument;
+
+use crate::context::{ApiContext, LabelSummary};
+
+/// List the labels in the label taxonomy along with the number of RFDs using each label
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/label",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_labels(
+    rqctx: RequestContext<ApiContext>,
+) -> Result<HttpResponseOk<Vec<LabelSummary>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+
+    Ok(HttpResponseOk(ctx.list_labels(&caller).await?))
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct LabelPath {
+    name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct UpdateLabel {
+    description: Option<String>,
+    color: Option<String>,
+}
+
+/// Add a label to the label taxonomy, or update the description and color of an existing label
+#[trace_request]
+#[endpoint {
+    method = PUT,
+    path = "/label/{name}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn update_label(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<LabelPath>,
+    body: TypedBody<UpdateLabel>,
+) -> Result<HttpResponseOk<Label>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+
+    Ok(HttpResponseOk(
+        ctx.upsert_label(&caller, &path.name, body.description, body.color)
+            .await?,
+    ))
+}
+
+/// Remove a label from the label taxonomy
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/label/{name}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn delete_label(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<LabelPath>,
+) -> Result<HttpResponseOk<Label>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+
+    Ok(HttpResponseOk(ctx.delete_label(&caller, &path.name).await?))
+}
@@ -5,8 +5,10 @@
 pub mod api_user;
 pub mod api_user_provider;
 pub mod group;
+pub mod label;
 pub mod login;
 pub mod mappers;
 pub mod rfd;
 pub mod webhook;
+pub mod webhook_subscription;
 pub mod well_known;
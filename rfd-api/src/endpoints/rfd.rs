@@ -2,33 +2,68 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::Utc;
 use dropshot::{
-    endpoint, HttpError, HttpResponseAccepted, HttpResponseOk, Path, Query, RequestContext,
-    TypedBody,
+    endpoint, DropshotState, HttpError, HttpResponseAccepted, HttpResponseOk, Path, Query,
+    RequestContext, RequestInfo, TypedBody,
 };
-use http::StatusCode;
+use http::{
+    header::{CACHE_CONTROL, CONTENT_TYPE, IF_MATCH},
+    HeaderValue, StatusCode,
+};
+use hyper::{Body, Response};
 use rfd_data::{
-    content::{RfdAsciidoc, RfdContent, RfdDocument, RfdMarkdown},
+    content::{
+        replace_section_body, source_sections, RfdAsciidoc, RfdContent, RfdDocument, RfdMarkdown,
+        SourceSection, TemplateError,
+    },
     RfdState,
 };
+use rfd_github::is_image;
 use rfd_model::{
     schema_ext::{ContentFormat, Visibility},
-    Rfd,
+    storage::RfdFilter,
+    CommitSha, NewRfdStateTransition, Rfd, RfdRevision, RfdStateTransition,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{sync::broadcast::error::RecvError, time::interval};
 use trace_request::trace_request;
 use tracing::instrument;
+use uuid::Uuid;
 
 use crate::{
+    authn::AuthToken,
     caller::CallerExt,
-    context::{ApiContext, FullRfd, ListRfd},
+    context::{
+        ApiContext, FullRfd, ListRfd, RfdFeedChange, RfdFeedEntry, RfdTimelineEvent,
+        UpdateRfdContentError,
+    },
+    events::{can_receive, sse_message, SSE_KEEPALIVE},
+    feed::{AtomEntry, AtomFeed},
     permissions::ApiPermission,
-    search::{MeiliSearchResult, SearchRequest},
-    util::response::{client_error, internal_error, unauthorized},
+    search::{MeiliSearchResult, MeiliSuggestResult, SearchRequest},
+    util::{
+        cache::{CacheValidators, CachedResponse},
+        response::{
+            client_error, internal_error, not_found, to_internal_error, unauthorized, ResourceError,
+        },
+    },
     ApiCaller,
 };
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct RfdListQuery {
+    /// Only return RFDs that are authored by the given user
+    pub author: Option<Uuid>,
+}
+
 /// List all available RFDs
 #[trace_request]
 #[endpoint {
@@ -38,18 +73,42 @@ use crate::{
 #[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
 pub async fn get_rfds(
     rqctx: RequestContext<ApiContext>,
-) -> Result<HttpResponseOk<Vec<ListRfd>>, HttpError> {
+    query: Query<RfdListQuery>,
+) -> Result<CachedResponse<HttpResponseOk<Vec<ListRfd>>>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
-    get_rfds_op(ctx, &ctx.get_caller(auth.as_ref()).await?).await
+    let HttpResponseOk(rfds) = get_rfds_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        query.into_inner(),
+    )
+    .await?;
+
+    // The list changes when a RFD is added, removed or updated, none of which are reflected in
+    // the latest commit date, so only an entity tag is provided
+    let validators = CacheValidators::new(
+        rfds.iter().flat_map(|rfd| {
+            [
+                rfd.rfd_number.to_string(),
+                rfd.commit.0.clone(),
+                rfd.visibility.to_string(),
+            ]
+        }),
+        None,
+    );
+    Ok(validators.respond(&rqctx.request, HttpResponseOk(rfds)))
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn get_rfds_op(
     ctx: &ApiContext,
     caller: &ApiCaller,
+    query: RfdListQuery,
 ) -> Result<HttpResponseOk<Vec<ListRfd>>, HttpError> {
-    let rfds = ctx.list_rfds(caller, None).await?;
+    let rfds = match query.author {
+        Some(author) => ctx.list_rfds_by_author(caller, author).await?,
+        None => ctx.list_rfds(caller, None).await?,
+    };
     Ok(HttpResponseOk(rfds))
 }
 
@@ -59,6 +118,14 @@ pub struct ReserveRfdBody {
     pub title: String,
     /// Optional contents of the RFD
     pub content: Option<String>,
+    /// Optional name of the template to create the RFD from. Defaults to the "new" template when
+    /// contents are supplied and the "placeholder" template otherwise
+    pub template: Option<String>,
+    /// Values for additional fields defined by the template
+    #[serde(default)]
+    pub fields: HashMap<String, String>,
+    /// Format to write the RFD in. Defaults to AsciiDoc
+    pub format: Option<ContentFormat>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -93,12 +160,104 @@ async fn reserve_rfd_op(
     caller: &ApiCaller,
     body: ReserveRfdBody,
 ) -> Result<HttpResponseAccepted<ReserveRfdResponse>, HttpError> {
-    let number = ctx.create_rfd(caller, body.title, body.content).await?;
+    if let Some(name) = &body.template {
+        match ctx.content.templates.get(name) {
+            Some(template) => {
+                // Supplied contents are written to the body field, and would otherwise be
+                // silently dropped by templates that do not declare one
+                if body.content.is_some() && !template.supports_field("body") {
+                    return Err(client_error(
+                        StatusCode::BAD_REQUEST,
+                        format!("Template {} does not accept RFD contents", name),
+                    ));
+                }
+            }
+            None => {
+                return Err(client_error(
+                    StatusCode::BAD_REQUEST,
+                    format!("Unknown template {}", name),
+                ));
+            }
+        }
+    }
+
+    let number = ctx
+        .create_rfd(
+            caller,
+            body.title,
+            body.content,
+            body.template.as_deref(),
+            body.fields,
+            body.format.unwrap_or(ContentFormat::Asciidoc),
+        )
+        .await
+        .map_err(|err| match err {
+            ResourceError::InternalError(UpdateRfdContentError::InvalidTemplate(
+                TemplateError::MissingRequiredFields { values, .. },
+            )) => client_error(
+                StatusCode::BAD_REQUEST,
+                format!("Missing required template fields: {}", values.join(", ")),
+            ),
+            ResourceError::InternalError(UpdateRfdContentError::InvalidTemplate(
+                TemplateError::UnsupportedFormat { format, .. },
+            )) => client_error(
+                StatusCode::BAD_REQUEST,
+                format!("Template does not support the {:?} format", format),
+            ),
+            err => err.into(),
+        })?;
     Ok(HttpResponseAccepted(ReserveRfdResponse {
         number: number.into(),
     }))
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RfdTemplateSummary {
+    name: String,
+    description: Option<String>,
+    /// Formats that the template can be rendered as
+    formats: Vec<ContentFormat>,
+    /// Fields that must be supplied when creating a RFD from this template
+    required_fields: Vec<String>,
+    /// Fields that may be supplied, along with their default values
+    optional_fields: BTreeMap<String, String>,
+}
+
+/// List the templates that can be used to create a new RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-templates",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_templates(
+    rqctx: RequestContext<ApiContext>,
+) -> Result<HttpResponseOk<Vec<RfdTemplateSummary>>, HttpError> {
+    get_rfd_templates_op(rqctx.context()).await
+}
+
+async fn get_rfd_templates_op(
+    ctx: &ApiContext,
+) -> Result<HttpResponseOk<Vec<RfdTemplateSummary>>, HttpError> {
+    let mut templates = ctx
+        .content
+        .templates
+        .iter()
+        .map(|(name, template)| RfdTemplateSummary {
+            name: name.clone(),
+            description: template
+                .description()
+                .map(|description| description.to_string()),
+            formats: template.formats(),
+            required_fields: template.required_fields().to_vec(),
+            optional_fields: template.optional_fields().clone(),
+        })
+        .collect::<Vec<_>>();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(HttpResponseOk(templates))
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RfdPathParams {
     /// The RFD number (examples: 1 or 123)
@@ -115,15 +274,17 @@ pub struct RfdPathParams {
 pub async fn get_rfd(
     rqctx: RequestContext<ApiContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseOk<FullRfd>, HttpError> {
+) -> Result<CachedResponse<HttpResponseOk<FullRfd>>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
-    get_rfd_op(
+    let HttpResponseOk(rfd) = get_rfd_op(
         ctx,
         &ctx.get_caller(auth.as_ref()).await?,
         path.into_inner().number,
     )
-    .await
+    .await?;
+
+    Ok(rfd_cache_validators(&rfd).respond(&rqctx.request, HttpResponseOk(rfd)))
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
@@ -142,12 +303,101 @@ async fn get_rfd_op(
     }
 }
 
+// Cached copies of a RFD are invalidated by new commits and by changes to its visibility
+fn rfd_cache_validators(rfd: &FullRfd) -> CacheValidators {
+    CacheValidators::new(
+        [rfd.commit.0.clone(), rfd.visibility.to_string()],
+        Some(rfd.committed_at),
+    )
+}
+
+// Upper bound on the number of RFDs that can be requested by a single batch read
+const RFD_BATCH_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdBatchBody {
+    /// The RFD numbers to fetch (examples: 1 or 123)
+    numbers: Vec<i32>,
+    /// The fields to return for each RFD. Summaries contain the fields that are returned when
+    /// listing RFDs, while full RFDs also include their content. Defaults to summaries
+    fields: Option<RfdBatchFields>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RfdBatchFields {
+    #[default]
+    Summary,
+    Full,
+}
+
+// Responses are tagged by the fields that were requested so that clients can tell summaries and
+// full RFDs apart
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "fields", content = "rfds", rename_all = "snake_case")]
+pub enum RfdBatchResponse {
+    Summary(Vec<ListRfd>),
+    Full(Vec<FullRfd>),
+}
+
+/// Get the latest representation of many RFDs at once. RFDs that do not exist or that the caller
+/// is not allowed to access are omitted from the response
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd-batch",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_batch(
+    rqctx: RequestContext<ApiContext>,
+    body: TypedBody<RfdBatchBody>,
+) -> Result<HttpResponseOk<RfdBatchResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    get_rfd_batch_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        body.into_inner(),
+    )
+    .await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_batch_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    body: RfdBatchBody,
+) -> Result<HttpResponseOk<RfdBatchResponse>, HttpError> {
+    if body.numbers.len() > RFD_BATCH_LIMIT {
+        return Err(client_error(
+            StatusCode::BAD_REQUEST,
+            format!("At most {} RFDs may be requested at once", RFD_BATCH_LIMIT),
+        ));
+    }
+
+    let response = match body.fields.unwrap_or_default() {
+        RfdBatchFields::Summary => RfdBatchResponse::Summary(
+            ctx.list_rfds(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(body.numbers))),
+            )
+            .await?,
+        ),
+        RfdBatchFields::Full => RfdBatchResponse::Full(ctx.get_rfds(caller, body.numbers).await?),
+    };
+
+    Ok(HttpResponseOk(response))
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RfdUpdateBody {
     /// Full Asciidoc document to store for this RFD
     document: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
 }
 
 /// Replace the full document of a RFD
@@ -163,13 +413,17 @@ pub async fn set_rfd_document(
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
-    set_rfd_document_op(
-        ctx,
-        &ctx.get_caller(auth.as_ref()).await?,
-        path.into_inner().number,
-        body.into_inner(),
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
     )
-    .await
+    .await?;
+    set_rfd_document_op(ctx, &caller, path.number, body, expected_commit).await
 }
 
 async fn set_rfd_document_op(
@@ -177,6 +431,7 @@ async fn set_rfd_document_op(
     caller: &ApiCaller,
     number: String,
     body: RfdUpdateBody,
+    expected_commit: Option<CommitSha>,
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         ctx.update_rfd_document(
@@ -184,9 +439,11 @@ async fn set_rfd_document_op(
             rfd_number.into(),
             &body.document,
             body.message.as_deref(),
+            expected_commit.as_ref(),
             None,
         )
-        .await?;
+        .await
+        .map_err(update_error)?;
         Ok(HttpResponseAccepted(()))
     } else {
         Err(client_error(
@@ -202,6 +459,9 @@ pub struct RfdUpdateContentBody {
     content: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
 }
 
 /// Replace the contents of a RFD
@@ -217,13 +477,17 @@ pub async fn set_rfd_content(
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
-    set_rfd_content_op(
-        ctx,
-        &ctx.get_caller(auth.as_ref()).await?,
-        path.into_inner().number,
-        body.into_inner(),
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
     )
-    .await
+    .await?;
+    set_rfd_content_op(ctx, &caller, path.number, body, expected_commit).await
 }
 
 async fn set_rfd_content_op(
@@ -231,6 +495,7 @@ async fn set_rfd_content_op(
     caller: &ApiCaller,
     number: String,
     body: RfdUpdateContentBody,
+    expected_commit: Option<CommitSha>,
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         ctx.update_rfd_document(
@@ -238,9 +503,73 @@ async fn set_rfd_content_op(
             rfd_number.into(),
             &body.content,
             body.message.as_deref(),
+            expected_commit.as_ref(),
             None,
         )
-        .await?;
+        .await
+        .map_err(update_error)?;
+        Ok(HttpResponseAccepted(()))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdRevertBody {
+    /// Commit from the history of the RFD to restore the document to
+    commit: String,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
+}
+
+/// Restore a RFD to the document stored at a previous commit
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/revert",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn revert_rfd(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+    body: TypedBody<RfdRevertBody>,
+) -> Result<HttpResponseAccepted<()>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
+    )
+    .await?;
+    revert_rfd_op(ctx, &caller, path.number, body, expected_commit).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn revert_rfd_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    body: RfdRevertBody,
+    expected_commit: Option<CommitSha>,
+) -> Result<HttpResponseAccepted<()>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        ctx.revert_rfd(
+            caller,
+            rfd_number,
+            &CommitSha(body.commit),
+            expected_commit.as_ref(),
+        )
+        .await
+        .map_err(update_error)?;
         Ok(HttpResponseAccepted(()))
     } else {
         Err(client_error(
@@ -250,6 +579,114 @@ async fn set_rfd_content_op(
     }
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdAssetUploadBody {
+    /// Path to store the asset at, relative to the RFD directory (examples: diagram.svg or
+    /// images/diagram.svg)
+    path: String,
+    /// Base64 encoded contents of the asset
+    content: String,
+    /// Optional Git commit message to send with this update (recommended)
+    message: Option<String>,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RfdAssetUploadResponse {
+    /// Path to use when referencing the asset from the RFD document
+    path: String,
+}
+
+/// Upload an image or other attachment to a RFD
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/assets",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn upload_rfd_asset(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+    body: TypedBody<RfdAssetUploadBody>,
+) -> Result<HttpResponseAccepted<RfdAssetUploadResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
+    )
+    .await?;
+    upload_rfd_asset_op(ctx, &caller, path.number, body, expected_commit).await
+}
+
+#[instrument(skip(ctx, caller, body), fields(caller = ?caller.id), err(Debug))]
+async fn upload_rfd_asset_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    body: RfdAssetUploadBody,
+    expected_commit: Option<CommitSha>,
+) -> Result<HttpResponseAccepted<RfdAssetUploadResponse>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let path = asset_path(&body.path).ok_or_else(|| {
+            client_error(
+                StatusCode::BAD_REQUEST,
+                "Asset path must be a relative path within the RFD directory",
+            )
+        })?;
+        let content = BASE64_STANDARD
+            .decode(&body.content)
+            .map_err(|_| client_error(StatusCode::BAD_REQUEST, "Asset content must be base64"))?;
+
+        ctx.upload_rfd_asset(
+            caller,
+            rfd_number,
+            &path,
+            &content,
+            body.message.as_deref(),
+            expected_commit.as_ref(),
+        )
+        .await
+        .map_err(update_error)?;
+
+        Ok(HttpResponseAccepted(RfdAssetUploadResponse { path }))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+// Normalize the path of an uploaded asset. Assets must stay within the RFD directory and may not
+// replace the RFD document itself
+fn asset_path(path: &str) -> Option<String> {
+    let segments = path
+        .trim()
+        .trim_start_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    let is_valid = segments
+        .iter()
+        .all(|segment| !segment.is_empty() && *segment != "." && *segment != "..");
+    let is_document = segments.len() == 1
+        && (segments[0].eq_ignore_ascii_case("README.adoc")
+            || segments[0].eq_ignore_ascii_case("README.md"));
+
+    if is_valid && !is_document {
+        Some(segments.join("/"))
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RfdAttrPathParams {
     number: String,
@@ -282,40 +719,24 @@ pub enum RfdAttr {
 pub async fn get_rfd_attr(
     rqctx: RequestContext<ApiContext>,
     path: Path<RfdAttrPathParams>,
-) -> Result<HttpResponseOk<RfdAttr>, HttpError> {
+) -> Result<CachedResponse<HttpResponseOk<RfdAttr>>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
     let path = path.into_inner();
-    get_rfd_attr_op(
-        ctx,
-        &ctx.get_caller(auth.as_ref()).await?,
-        path.number,
-        path.attr,
-    )
-    .await
+    let HttpResponseOk(rfd) =
+        get_rfd_op(ctx, &ctx.get_caller(auth.as_ref()).await?, path.number).await?;
+
+    let validators = rfd_cache_validators(&rfd);
+    Ok(validators.respond(&rqctx.request, get_rfd_attr_op(rfd, path.attr)?))
 }
 
-#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
-async fn get_rfd_attr_op(
-    ctx: &ApiContext,
-    caller: &ApiCaller,
-    number: String,
-    attr: RfdAttrName,
-) -> Result<HttpResponseOk<RfdAttr>, HttpError> {
-    if let Ok(rfd_number) = number.parse::<i32>() {
-        let rfd = ctx.get_rfd(caller, rfd_number, None).await?;
-        let content = match rfd.format {
-            ContentFormat::Asciidoc => RfdContent::Asciidoc(RfdAsciidoc::new(rfd.content)),
-            ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(rfd.content)),
-        };
+fn get_rfd_attr_op(rfd: FullRfd, attr: RfdAttrName) -> Result<HttpResponseOk<RfdAttr>, HttpError> {
+    let content = match rfd.format {
+        ContentFormat::Asciidoc => RfdContent::Asciidoc(RfdAsciidoc::new(rfd.content)),
+        ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(rfd.content)),
+    };
 
-        extract_attr(&attr, &content).map(HttpResponseOk)
-    } else {
-        Err(client_error(
-            StatusCode::BAD_REQUEST,
-            "Malformed RFD number",
-        ))
-    }
+    extract_attr(&attr, &content).map(HttpResponseOk)
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -324,6 +745,9 @@ pub struct RfdAttrValue {
     value: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
 }
 
 /// Set an attribute of a RFD
@@ -340,15 +764,17 @@ pub async fn set_rfd_attr(
 ) -> Result<HttpResponseAccepted<RfdAttr>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
     let path = path.into_inner();
-    set_rfd_attr_op(
-        ctx,
-        &ctx.get_caller(auth.as_ref()).await?,
-        path.number,
-        path.attr,
-        &body.into_inner(),
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
     )
-    .await
+    .await?;
+    set_rfd_attr_op(ctx, &caller, path.number, path.attr, &body, expected_commit).await
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
@@ -358,10 +784,27 @@ async fn set_rfd_attr_op(
     number: String,
     attr: RfdAttrName,
     body: &RfdAttrValue,
+    expected_commit: Option<CommitSha>,
 ) -> Result<HttpResponseAccepted<RfdAttr>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         // Get the latest revision
         let revision = ctx.get_rfd_revision(caller, rfd_number, None).await?;
+        let rfd_id = revision.rfd_id;
+        let mut transition = None;
+
+        // When the caller has not supplied the commit they are editing against, the update is
+        // pinned to the revision read above so that a concurrent change is not overwritten
+        let expected_commit = expected_commit.unwrap_or_else(|| revision.commit.clone());
+
+        // State changes must follow the transitions allowed by the RFD process
+        if matches!(attr, RfdAttrName::State) {
+            let state: RfdState = body.value.as_str().try_into().map_err(|err| {
+                tracing::info!(?err, "Invalid state was supplied");
+                HttpError::for_bad_request(None, "Invalid RFD state".to_string())
+            })?;
+            let from = check_rfd_transition(ctx, &revision, &state).await?;
+            transition = Some((from, state));
+        }
 
         // TODO: Get rid of these clones
         let mut content = match revision.content_format {
@@ -370,31 +813,55 @@ async fn set_rfd_attr_op(
         };
 
         // Update the requested attribute
+        let mut header = content.get_header();
         match &attr {
-            RfdAttrName::Discussion => content.update_discussion(&body.value),
-            RfdAttrName::Labels => content.update_labels(&body.value),
+            RfdAttrName::Discussion => {
+                header.discussion = Some(body.value.trim().to_string()).filter(|v| !v.is_empty());
+            }
+            RfdAttrName::Labels => {
+                header.labels = body
+                    .value
+                    .split(',')
+                    .map(|label| label.trim().to_string())
+                    .filter(|label| !label.is_empty())
+                    .collect();
+            }
             RfdAttrName::State => {
-                let state: RfdState = body.value.as_str().try_into().map_err(|err| {
-                    tracing::info!(?err, "Invalid state was supplied");
-                    HttpError::for_bad_request(None, "Invalid RFD state".to_string())
-                })?;
-                content.update_state(&state.to_string());
+                if let Some((_, state)) = &transition {
+                    header.state = Some(state.clone());
+                }
             }
         };
+        content.update_header(&header);
 
         tracing::info!("Updated attribute in RFD document");
 
         // Persist the data back to GitHub. Note that we do not store this back to the database.
         // We rely on GitHub as the source of truth and revisions are required to tbe linked to
         // commits
-        ctx.update_rfd_document(
-            caller,
-            rfd_number,
-            content.raw(),
-            body.message.as_deref(),
-            None,
-        )
-        .await?;
+        let commit = ctx
+            .update_rfd_document(
+                caller,
+                rfd_number,
+                content.raw(),
+                body.message.as_deref(),
+                Some(&expected_commit),
+                None,
+            )
+            .await
+            .map_err(update_error)?;
+
+        if let Some((from, to)) = transition {
+            if from.as_ref() != Some(&to) {
+                record_state_transition(
+                    ctx,
+                    caller,
+                    rfd_number,
+                    new_state_transition(caller, rfd_id, from, &to, None, commit),
+                )
+                .await;
+            }
+        }
 
         extract_attr(&attr, &content).map(HttpResponseAccepted)
     } else {
@@ -405,36 +872,1063 @@ async fn set_rfd_attr_op(
     }
 }
 
-/// Open a RFD for discussion
+// The commit that an update was made against may be supplied either in the request body or as an
+// If-Match header. The body takes precedence when both are present. If-Match is expected to hold
+// the entity tag of a previous read of the RFD, and as tags are not derived from the commit alone
+// it is resolved against the tag of the current version. Any other value is treated as a commit,
+// such that a stale tag is rejected as a conflict
+async fn expected_commit(
+    rqctx: &RequestContext<ApiContext>,
+    caller: &ApiCaller,
+    number: &str,
+    body: Option<&str>,
+) -> Result<Option<CommitSha>, HttpError> {
+    let header = rqctx
+        .request
+        .headers()
+        .get(IF_MATCH)
+        .map(|value| value.to_str())
+        .transpose()
+        .map_err(|_| client_error(StatusCode::BAD_REQUEST, "Malformed If-Match header"))?;
+
+    if let Some(commit) = body.and_then(parse_expected_commit) {
+        return Ok(Some(commit));
+    }
+
+    match header.filter(|tag| parse_expected_commit(tag).is_some()) {
+        Some(tag) => {
+            let rfd_number = number
+                .parse::<i32>()
+                .map_err(|_| client_error(StatusCode::BAD_REQUEST, "Malformed RFD number"))?;
+            let rfd = rqctx.context().get_rfd(caller, rfd_number, None).await?;
+
+            Ok(resolve_expected_commit(tag, &rfd))
+        }
+        None => Ok(None),
+    }
+}
+
+fn parse_expected_commit(value: &str) -> Option<CommitSha> {
+    Some(value.trim().trim_start_matches("W/").trim_matches('"'))
+        .filter(|value| !value.is_empty() && *value != "*")
+        .map(|value| CommitSha(value.to_string()))
+}
+
+fn resolve_expected_commit(tag: &str, rfd: &FullRfd) -> Option<CommitSha> {
+    if rfd_cache_validators(rfd).matches(tag) {
+        Some(rfd.commit.clone())
+    } else {
+        parse_expected_commit(tag)
+    }
+}
+
+fn update_error(err: ResourceError<UpdateRfdContentError>) -> HttpError {
+    match err {
+        ResourceError::InternalError(err @ UpdateRfdContentError::Conflict { .. }) => {
+            client_error(StatusCode::CONFLICT, err)
+        }
+        err => err.into(),
+    }
+}
+
+// Check that the RFD at the given revision is allowed to move to the requested state. Returns the
+// state that the RFD is moving from
+async fn check_rfd_transition(
+    ctx: &ApiContext,
+    revision: &RfdRevision,
+    state: &RfdState,
+) -> Result<Option<RfdState>, HttpError> {
+    let current_state = revision
+        .state
+        .as_deref()
+        .and_then(|state| RfdState::try_from(state).ok());
+    let state_context = ctx
+        .get_rfd_state_context(&revision.commit)
+        .await
+        .map_err(to_internal_error)?;
+
+    RfdState::check_transition(current_state.as_ref(), state, &state_context)
+        .map_err(|err| client_error(StatusCode::CONFLICT, err))?;
+
+    Ok(current_state)
+}
+
+fn new_state_transition(
+    caller: &ApiCaller,
+    rfd_id: Uuid,
+    from: Option<RfdState>,
+    to: &RfdState,
+    reason: Option<String>,
+    commit: Option<CommitSha>,
+) -> NewRfdStateTransition {
+    NewRfdStateTransition {
+        id: Uuid::new_v4(),
+        rfd_id,
+        api_user_id: caller.id,
+        from_state: from.map(|state| state.to_string()),
+        to_state: to.to_string(),
+        reason,
+        commit,
+    }
+}
+
+// Record a state transition that has already been committed to GitHub. The commit is the source of
+// truth for the state of the RFD, so a failure to store the transition is logged rather than
+// reported to the caller as a failed update
+async fn record_state_transition(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    rfd_number: i32,
+    new_transition: NewRfdStateTransition,
+) -> RfdStateTransition {
+    match ctx
+        .record_rfd_state_transition(caller, rfd_number, new_transition.clone())
+        .await
+    {
+        Ok(transition) => transition,
+        Err(err) => {
+            tracing::error!(
+                ?err,
+                ?new_transition,
+                "Failed to record RFD state transition"
+            );
+
+            RfdStateTransition {
+                id: new_transition.id,
+                rfd_id: new_transition.rfd_id,
+                api_user_id: new_transition.api_user_id,
+                from_state: new_transition.from_state,
+                to_state: new_transition.to_state,
+                reason: new_transition.reason,
+                commit: new_transition.commit,
+                created_at: Utc::now(),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RfdTransitionBody {
+    /// State to move the RFD to
+    state: RfdState,
+    /// Optional explanation for the transition. This is included in the Git commit message
+    reason: Option<String>,
+}
+
+/// Move a RFD to a new state
 #[trace_request]
 #[endpoint {
     method = POST,
-    path = "/rfd/{number}/discuss",
+    path = "/rfd/{number}/transition",
 }]
 #[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
-pub async fn discuss_rfd(
+pub async fn transition_rfd(
     rqctx: RequestContext<ApiContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseAccepted<RfdAttr>, HttpError> {
+    body: TypedBody<RfdTransitionBody>,
+) -> Result<HttpResponseAccepted<RfdStateTransition>, HttpError> {
     let ctx = rqctx.context();
     let auth = ctx.authn_token(&rqctx).await?;
     let path = path.into_inner();
-    set_rfd_attr_op(
+    transition_rfd_op(
         ctx,
         &ctx.get_caller(auth.as_ref()).await?,
         path.number,
-        RfdAttrName::State,
-        &RfdAttrValue {
-            value: RfdState::Discussion.to_string(),
-            message: Some("Move to discussion".to_string()),
-        },
+        body.into_inner(),
     )
     .await
 }
 
-/// Publish a RFD
-#[trace_request]
-#[endpoint {
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn transition_rfd_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    body: RfdTransitionBody,
+) -> Result<HttpResponseAccepted<RfdStateTransition>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let revision = ctx.get_rfd_revision(caller, rfd_number, None).await?;
+        let from = check_rfd_transition(ctx, &revision, &body.state).await?;
+
+        if from.as_ref() == Some(&body.state) {
+            return Err(client_error(
+                StatusCode::CONFLICT,
+                format!("RFD is already in the {} state", body.state),
+            ));
+        }
+
+        let rfd_id = revision.rfd_id;
+        let mut content = match revision.content_format {
+            ContentFormat::Asciidoc => RfdContent::Asciidoc(RfdAsciidoc::new(revision.content)),
+            ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(revision.content)),
+        };
+        let mut header = content.get_header();
+        header.state = Some(body.state.clone());
+        content.update_header(&header);
+
+        let message = match &body.reason {
+            Some(reason) => format!("Move to {}\n\n{}", body.state, reason),
+            None => format!("Move to {}", body.state),
+        };
+
+        let commit = ctx
+            .update_rfd_document(
+                caller,
+                rfd_number,
+                content.raw(),
+                Some(&message),
+                Some(&revision.commit),
+                None,
+            )
+            .await
+            .map_err(update_error)?;
+
+        let transition = record_state_transition(
+            ctx,
+            caller,
+            rfd_number,
+            new_state_transition(caller, rfd_id, from, &body.state, body.reason, commit),
+        )
+        .await;
+
+        Ok(HttpResponseAccepted(transition))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+/// List the state transitions that have been made to a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/transition",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_transitions(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdStateTransition>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_transitions_op(ctx, &ctx.get_caller(auth.as_ref()).await?, path.number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_transitions_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdStateTransition>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.list_rfd_state_transitions(caller, rfd_number).await?,
+        ))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+/// Get the lifecycle timeline of a RFD, derived from its revision history
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/timeline",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_timeline(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdTimelineEvent>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_timeline_op(ctx, &ctx.get_caller(auth.as_ref()).await?, path.number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_timeline_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdTimelineEvent>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.get_rfd_timeline(caller, rfd_number).await?,
+        ))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdHtmlQuery {
+    /// Render the RFD as of the given commit instead of the latest revision
+    pub commit: Option<String>,
+}
+
+/// Get a RFD rendered as sanitized HTML
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/html",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_html(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+    query: Query<RfdHtmlQuery>,
+) -> Result<Response<Body>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_html_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        query.into_inner().commit,
+    )
+    .await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_html_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    commit: Option<String>,
+) -> Result<Response<Body>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let html = ctx.get_rfd_html(caller, rfd_number, commit).await?;
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Body::from(html.as_ref().clone()))
+            .map_err(|err| {
+                tracing::error!(?err, "Failed to construct HTML response");
+                internal_error("Failed to construct HTML response")
+            })
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdFeedQuery {
+    /// Feed token to authenticate with. Feed readers are generally unable to send an Authorization
+    /// header, so feeds accept a read-only feed token as a query parameter instead
+    token: Option<String>,
+}
+
+/// Atom feed of recent changes to all RFDs that the caller is allowed to access
+// Feed requests skip the trace_request middleware so that the feed token is not recorded a second
+// time. Dropshot's request log still records the full URI, including the token, which is why feed
+// tokens are read-only, short-lived and revocable
+#[endpoint {
+    method = GET,
+    path = "/rfd-feed.atom",
+    unpublished = true,
+}]
+#[instrument(skip(rqctx, query), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfds_feed(
+    rqctx: RequestContext<ApiContext>,
+    query: Query<RfdFeedQuery>,
+) -> Result<Response<Body>, HttpError> {
+    let caller = feed_caller(&rqctx, query.into_inner().token.as_deref()).await?;
+    get_rfd_feed_op(rqctx.context(), &rqctx.request, &caller, None).await
+}
+
+/// Atom feed of recent changes to a RFD
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/feed.atom",
+    unpublished = true,
+}]
+#[instrument(skip(rqctx, query), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_feed(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+    query: Query<RfdFeedQuery>,
+) -> Result<Response<Body>, HttpError> {
+    let caller = feed_caller(&rqctx, query.into_inner().token.as_deref()).await?;
+
+    if let Ok(rfd_number) = path.into_inner().number.parse::<i32>() {
+        get_rfd_feed_op(rqctx.context(), &rqctx.request, &caller, Some(rfd_number)).await
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+// Determine the caller of a feed request
+async fn feed_caller(
+    rqctx: &RequestContext<ApiContext>,
+    token: Option<&str>,
+) -> Result<ApiCaller, HttpError> {
+    let auth = query_authn_token(rqctx, token).await?;
+    Ok(rqctx.context().get_caller(auth.as_ref()).await?)
+}
+
+// Read the token of a request that may authenticate with a query parameter. Only feed tokens are
+// accepted as a query parameter, and they take precedence over the Authorization header
+async fn query_authn_token(
+    rqctx: &RequestContext<ApiContext>,
+    token: Option<&str>,
+) -> Result<Option<AuthToken>, HttpError> {
+    match token {
+        Some(token) => Ok(Some(
+            AuthToken::from_feed_token(rqctx.context(), token).await?,
+        )),
+        None => rqctx.context().authn_token(rqctx).await,
+    }
+}
+
+#[instrument(skip(ctx, request, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_feed_op(
+    ctx: &ApiContext,
+    request: &RequestInfo,
+    caller: &ApiCaller,
+    rfd_number: Option<i32>,
+) -> Result<Response<Body>, HttpError> {
+    let entries = ctx.list_rfd_feed(caller, rfd_number).await?;
+
+    let (title, link) = match rfd_number {
+        Some(number) => (
+            format!("RFD {} activity", number),
+            format!("{}/rfd/{}/feed.atom", ctx.public_url, number),
+        ),
+        None => (
+            "RFD activity".to_string(),
+            format!("{}/rfd-feed.atom", ctx.public_url),
+        ),
+    };
+    let updated = entries.iter().map(|entry| entry.committed_at).max();
+    let validators =
+        CacheValidators::new(entries.iter().map(|entry| entry.commit.0.as_str()), updated);
+
+    let mut response = if validators.is_fresh(request.headers()) {
+        Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+    } else {
+        let feed = AtomFeed {
+            id: link.clone(),
+            title,
+            link,
+            updated: updated.unwrap_or_else(Utc::now),
+            entries: entries
+                .into_iter()
+                .map(|entry| feed_entry(&ctx.public_url, entry))
+                .collect(),
+        };
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/atom+xml; charset=utf-8")
+            .body(Body::from(feed.render()))
+    }
+    .map_err(|err| {
+        tracing::error!(?err, "Failed to construct feed response");
+        internal_error("Failed to construct feed response")
+    })?;
+
+    validators.insert_headers(response.headers_mut());
+
+    Ok(response)
+}
+
+fn feed_entry(public_url: &str, entry: RfdFeedEntry) -> AtomEntry {
+    let summary = match &entry.change {
+        RfdFeedChange::Created => format!("RFD {} was created", entry.rfd_number),
+        RfdFeedChange::StateChanged { from, to } => format!(
+            "RFD {} moved from {} to {}",
+            entry.rfd_number,
+            from.as_deref().unwrap_or("no state"),
+            to.as_deref().unwrap_or("no state"),
+        ),
+        RfdFeedChange::Updated => format!("RFD {} was updated", entry.rfd_number),
+    };
+
+    AtomEntry {
+        id: format!("{}/rfd/{}#{}", public_url, entry.rfd_number, entry.commit.0),
+        title: format!("RFD {} {}", entry.rfd_number, entry.title),
+        link: format!("{}/rfd/{}/html", public_url, entry.rfd_number),
+        updated: entry.committed_at,
+        summary,
+        author: entry.authors,
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdEventsQuery {
+    /// Feed token to authenticate with. Browsers are unable to send an Authorization header when
+    /// opening an event stream, so a read-only feed token may be supplied as a query parameter
+    /// instead
+    token: Option<String>,
+    /// Only stream events for the given RFD
+    number: Option<String>,
+}
+
+// Interval at which a keepalive comment is written to an idle event stream
+static EVENT_STREAM_KEEPALIVE_SECONDS: u64 = 30;
+
+// Interval at which the caller of an open event stream is authenticated again, so that streams do
+// not outlive an expired or revoked token, or permissions that have since been removed
+static EVENT_STREAM_REAUTH_SECONDS: u64 = 60;
+
+/// Stream changes to the RFDs that the caller is allowed to access as Server-Sent Events
+// Event stream requests skip the trace_request middleware so that the feed token is not recorded a
+// second time. Dropshot's request log still records the full URI, including the token
+#[endpoint {
+    method = GET,
+    path = "/rfd-events",
+    unpublished = true,
+}]
+#[instrument(skip(rqctx, query), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_events(
+    rqctx: RequestContext<ApiContext>,
+    query: Query<RfdEventsQuery>,
+) -> Result<Response<Body>, HttpError> {
+    let ctx = rqctx.context();
+    let query = query.into_inner();
+    let auth = query_authn_token(&rqctx, query.token.as_deref())
+        .await?
+        .ok_or_else(unauthorized)?;
+    let caller = ctx.get_caller(Some(&auth)).await?;
+
+    let rfd_number = match query.number {
+        Some(number) => Some(
+            number
+                .parse::<i32>()
+                .map_err(|_| client_error(StatusCode::BAD_REQUEST, "Malformed RFD number"))?,
+        ),
+        None => None,
+    };
+
+    get_rfd_events_op(rqctx.server.clone(), auth, caller, rfd_number)
+}
+
+#[instrument(skip(server, auth, caller), fields(caller = ?caller.id), err(Debug))]
+fn get_rfd_events_op(
+    server: Arc<DropshotState<ApiContext>>,
+    auth: AuthToken,
+    mut caller: ApiCaller,
+    rfd_number: Option<i32>,
+) -> Result<Response<Body>, HttpError> {
+    let mut events = server.private.events.subscribe();
+    let (mut sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        let mut keepalive = interval(Duration::from_secs(EVENT_STREAM_KEEPALIVE_SECONDS));
+        let mut reauth = interval(Duration::from_secs(EVENT_STREAM_REAUTH_SECONDS));
+        reauth.tick().await;
+
+        loop {
+            let message = tokio::select! {
+                _ = keepalive.tick() => SSE_KEEPALIVE.to_string(),
+                _ = reauth.tick() => {
+                    if is_expired(&auth) {
+                        tracing::info!(caller = ?caller.id, "Closing event stream of expired token");
+                        break;
+                    }
+
+                    match server.private.get_caller(Some(&auth)).await {
+                        Ok(current) => {
+                            caller = current;
+                            continue;
+                        }
+                        Err(err) => {
+                            tracing::info!(
+                                ?err,
+                                caller = ?caller.id,
+                                "Closing event stream of caller that can no longer be authenticated"
+                            );
+                            break;
+                        }
+                    }
+                }
+                event = events.recv() => match event {
+                    Ok(event) if can_receive(&caller, &event)
+                        && rfd_number.map(|number| number == event.rfd_number).unwrap_or(true) => {
+                        sse_message(&event)
+                    }
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::info!(skipped, "Event stream fell behind and skipped events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            // Writing only fails once the client has disconnected
+            if sender.send_data(message.into()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "text/event-stream")
+        .header(CACHE_CONTROL, "no-cache")
+        .body(body)
+        .map_err(|err| {
+            tracing::error!(?err, "Failed to construct event stream response");
+            internal_error("Failed to construct event stream response")
+        })
+}
+
+// Tokens that carry an expiration must not be able to hold an event stream open past it. API keys
+// are checked against their expiration when the caller is authenticated again
+fn is_expired(auth: &AuthToken) -> bool {
+    let expires_at = match auth {
+        AuthToken::Jwt(jwt) => Some(jwt.claims.exp),
+        AuthToken::Feed(feed) => Some(feed.claims.exp),
+        AuthToken::ApiKey(_) => None,
+    };
+
+    expires_at
+        .map(|expires_at| expires_at <= Utc::now().timestamp())
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdAssetPathParams {
+    number: String,
+    path: Vec<String>,
+}
+
+// Assets are private to the RFD they belong to, and may only be cached by the client
+static ASSET_CACHE_CONTROL: &str = "private, max-age=300";
+
+/// Get a static asset, such as an image, that belongs to a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/assets/{path:.*}",
+    unpublished = true,
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_asset(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdAssetPathParams>,
+) -> Result<Response<Body>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_asset_op(
+        ctx,
+        &rqctx.request,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        path.path,
+    )
+    .await
+}
+
+#[instrument(skip(ctx, request, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_asset_op(
+    ctx: &ApiContext,
+    request: &RequestInfo,
+    caller: &ApiCaller,
+    number: String,
+    path: Vec<String>,
+) -> Result<Response<Body>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        // Assets are addressed relative to the RFD directory and may not escape it
+        let is_valid = !path.is_empty()
+            && path
+                .iter()
+                .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+        let path = path.join("/");
+
+        if !is_valid || !is_image(&path) {
+            return Err(not_found("Unknown RFD asset"));
+        }
+
+        let asset = ctx.get_rfd_asset(caller, rfd_number, &path).await?;
+        let validators =
+            CacheValidators::new([asset.commit.0.as_str(), &path], Some(asset.committed_at));
+
+        let mut response = if validators.is_fresh(request.headers()) {
+            Response::builder()
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+        } else {
+            Response::builder()
+                .status(StatusCode::OK)
+                .header(CONTENT_TYPE, asset.content_type)
+                .body(Body::from(asset.data))
+        }
+        .map_err(|err| {
+            tracing::error!(?err, "Failed to construct asset response");
+            internal_error("Failed to construct asset response")
+        })?;
+
+        let headers = response.headers_mut();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static(ASSET_CACHE_CONTROL));
+        validators.insert_headers(headers);
+
+        Ok(response)
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdSection {
+    /// Anchor that identifies this section within the document
+    anchor: String,
+    name: String,
+    /// Nesting depth of the section, where top level sections are level 1
+    level: usize,
+    /// Names of the sections that this section is nested within
+    parents: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RfdSectionContent {
+    #[serde(flatten)]
+    section: RfdSection,
+    /// Source of the section body, excluding its heading and any nested sections
+    content: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdSectionPathParams {
+    number: String,
+    anchor: String,
+}
+
+/// Get the table of contents of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/sections",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_sections(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdSection>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_sections_op(ctx, &ctx.get_caller(auth.as_ref()).await?, path.number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_sections_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdSection>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let rfd = ctx.get_rfd(caller, rfd_number, None).await?;
+        let sections = locate_sections(&rfd.content, &rfd.format)
+            .await?
+            .into_iter()
+            .map(|(section, _)| section)
+            .collect::<Vec<_>>();
+
+        Ok(HttpResponseOk(sections))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+/// Get a single section of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/sections/{anchor}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_rfd_section(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdSectionPathParams>,
+) -> Result<HttpResponseOk<RfdSectionContent>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    get_rfd_section_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        path.anchor,
+    )
+    .await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn get_rfd_section_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    anchor: String,
+) -> Result<HttpResponseOk<RfdSectionContent>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let rfd = ctx.get_rfd(caller, rfd_number, None).await?;
+        let (section, source) = find_section(&rfd.content, &rfd.format, &anchor).await?;
+
+        Ok(HttpResponseOk(RfdSectionContent {
+            section,
+            content: rfd.content[source.body].trim_matches('\n').to_string(),
+        }))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdSectionUpdateBody {
+    /// Content to replace the body of the section with. The section heading and any nested
+    /// sections are left unchanged
+    content: String,
+    /// Optional Git commit message to send with this update (recommended)
+    message: Option<String>,
+    /// Optional commit that this update was made against. If the RFD has been updated since this
+    /// commit, the update will be rejected. May also be supplied as an If-Match header
+    expected_commit: Option<String>,
+}
+
+/// Replace the body of a single section of a RFD
+#[trace_request]
+#[endpoint {
+    method = PUT,
+    path = "/rfd/{number}/sections/{anchor}",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn set_rfd_section(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdSectionPathParams>,
+    body: TypedBody<RfdSectionUpdateBody>,
+) -> Result<HttpResponseAccepted<RfdSectionContent>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+    let expected_commit = expected_commit(
+        &rqctx,
+        &caller,
+        &path.number,
+        body.expected_commit.as_deref(),
+    )
+    .await?;
+    set_rfd_section_op(
+        ctx,
+        &caller,
+        path.number,
+        path.anchor,
+        body,
+        expected_commit,
+    )
+    .await
+}
+
+#[instrument(skip(ctx, caller, body), fields(caller = ?caller.id), err(Debug))]
+async fn set_rfd_section_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    anchor: String,
+    body: RfdSectionUpdateBody,
+    expected_commit: Option<CommitSha>,
+) -> Result<HttpResponseAccepted<RfdSectionContent>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let rfd = ctx.get_rfd(caller, rfd_number, None).await?;
+
+        if expected_commit.is_some_and(|expected| expected != rfd.commit) {
+            return Err(client_error(
+                StatusCode::CONFLICT,
+                UpdateRfdContentError::Conflict {
+                    current: rfd.commit,
+                },
+            ));
+        }
+
+        let sections = locate_sections(&rfd.content, &rfd.format).await?;
+        let (section, source) = sections
+            .iter()
+            .find(|(section, _)| section.anchor == anchor)
+            .cloned()
+            .ok_or_else(|| not_found("Failed to find section"))?;
+        let updated = replace_section_body(&rfd.content, &source, &body.content);
+
+        // The new body must not alter the structure of the document, either by adding headings of
+        // its own or by leaving a delimited block open that swallows the headings that follow it
+        let unchanged = locate_sections(&updated, &rfd.format)
+            .await
+            .map(|updated| outline(&updated) == outline(&sections))
+            .unwrap_or(false);
+
+        if !unchanged {
+            return Err(client_error(
+                StatusCode::BAD_REQUEST,
+                "Section content must not add or remove sections",
+            ));
+        }
+
+        let message = body
+            .message
+            .unwrap_or_else(|| format!("Update section {}", section.name));
+        // The section was spliced into the revision that was just read, so the update must be
+        // rejected if the RFD has changed since
+        ctx.update_rfd_document(
+            caller,
+            rfd_number.into(),
+            &updated,
+            Some(&message),
+            Some(&rfd.commit),
+            None,
+        )
+        .await
+        .map_err(update_error)?;
+
+        Ok(HttpResponseAccepted(RfdSectionContent {
+            section,
+            content: body.content.trim_matches('\n').to_string(),
+        }))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+// Sections are identified using the same parser that is used to build the search index so that
+// anchors match those in rendered documents. The parser only reports rendered content, so each
+// parsed section is paired with the matching section located in the document source. The parser
+// only understands AsciiDoc
+async fn locate_sections(
+    content: &str,
+    format: &ContentFormat,
+) -> Result<Vec<(RfdSection, SourceSection)>, HttpError> {
+    if *format != ContentFormat::Asciidoc {
+        return Err(client_error(
+            StatusCode::BAD_REQUEST,
+            format!("Sections are not supported for the {} format", format),
+        ));
+    }
+
+    // Parsing shells out to the parser and must not block the executor
+    let document = content.to_string();
+    let parsed = tokio::task::spawn_blocking(move || parse_rfd::parse(&document))
+        .await
+        .map_err(to_internal_error)?
+        .map_err(to_internal_error)?;
+    let sources = source_sections(content);
+
+    let sections = parsed
+        .sections
+        .into_iter()
+        .map(|section| RfdSection {
+            level: section.parents.len() + 1,
+            anchor: section.section_id,
+            name: section.name,
+            parents: section.parents,
+        })
+        .collect::<Vec<_>>();
+
+    let aligned = sections.len() == sources.len()
+        && sections
+            .iter()
+            .zip(sources.iter())
+            .all(|(section, source)| section.level == source.level);
+
+    if aligned {
+        Ok(sections.into_iter().zip(sources).collect())
+    } else {
+        tracing::warn!(
+            parsed = sections.len(),
+            source = sources.len(),
+            "Parsed sections do not match the document source"
+        );
+        Err(internal_error(
+            "Failed to locate sections in the document source",
+        ))
+    }
+}
+
+// The levels and anchors of the sections of a document, in document order
+fn outline(sections: &[(RfdSection, SourceSection)]) -> Vec<(usize, &str)> {
+    sections
+        .iter()
+        .map(|(section, _)| (section.level, section.anchor.as_str()))
+        .collect()
+}
+
+async fn find_section(
+    content: &str,
+    format: &ContentFormat,
+    anchor: &str,
+) -> Result<(RfdSection, SourceSection), HttpError> {
+    locate_sections(content, format)
+        .await?
+        .into_iter()
+        .find(|(section, _)| section.anchor == anchor)
+        .ok_or_else(|| not_found("Failed to find section"))
+}
+
+/// Open a RFD for discussion
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/discuss",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn discuss_rfd(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseAccepted<RfdAttr>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    set_rfd_attr_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        RfdAttrName::State,
+        &RfdAttrValue {
+            value: RfdState::Discussion.to_string(),
+            message: Some("Move to discussion".to_string()),
+            expected_commit: None,
+        },
+        None,
+    )
+    .await
+}
+
+/// Publish a RFD
+#[trace_request]
+#[endpoint {
     method = POST,
     path = "/rfd/{number}/publish",
 }]
@@ -454,11 +1948,147 @@ pub async fn publish_rfd(
         &RfdAttrValue {
             value: RfdState::Published.to_string(),
             message: Some("Publish".to_string()),
+            expected_commit: None,
         },
+        None,
+    )
+    .await
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdLabelPathParams {
+    number: String,
+    label: String,
+}
+
+/// Add a label to a RFD
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/labels/{label}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn add_rfd_label(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdLabelPathParams>,
+) -> Result<HttpResponseAccepted<Vec<String>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    update_rfd_labels_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        &path.label,
+        LabelChange::Add,
+    )
+    .await
+}
+
+/// Remove a label from a RFD
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/rfd/{number}/labels/{label}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn remove_rfd_label(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<RfdLabelPathParams>,
+) -> Result<HttpResponseAccepted<Vec<String>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let path = path.into_inner();
+    update_rfd_labels_op(
+        ctx,
+        &ctx.get_caller(auth.as_ref()).await?,
+        path.number,
+        &path.label,
+        LabelChange::Remove,
     )
     .await
 }
 
+#[derive(Debug, PartialEq)]
+enum LabelChange {
+    Add,
+    Remove,
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn update_rfd_labels_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    number: String,
+    label: &str,
+    change: LabelChange,
+) -> Result<HttpResponseAccepted<Vec<String>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        // Prefer the spelling from the label taxonomy so that labels do not drift apart
+        let label = match ctx.find_label(label).await.map_err(to_internal_error)? {
+            Some(known) => known.name,
+            None if change == LabelChange::Add && ctx.content.validate_labels => {
+                return Err(client_error(
+                    StatusCode::BAD_REQUEST,
+                    "Label is not part of the label taxonomy",
+                ));
+            }
+            None => label.trim().to_string(),
+        };
+
+        if label.is_empty() || label.contains(',') {
+            return Err(client_error(StatusCode::BAD_REQUEST, "Malformed label"));
+        }
+
+        let revision = ctx.get_rfd_revision(caller, rfd_number, None).await?;
+        let mut content = match revision.content_format {
+            ContentFormat::Asciidoc => RfdContent::Asciidoc(RfdAsciidoc::new(revision.content)),
+            ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(revision.content)),
+        };
+
+        let mut header = content.get_header();
+        let has_label = header
+            .labels
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(&label));
+
+        let message = match change {
+            LabelChange::Add if !has_label => {
+                header.labels.push(label.clone());
+                format!("Add label {}", label)
+            }
+            LabelChange::Remove if has_label => {
+                header
+                    .labels
+                    .retain(|existing| !existing.eq_ignore_ascii_case(&label));
+                format!("Remove label {}", label)
+            }
+            // The document already matches the requested change
+            _ => return Ok(HttpResponseAccepted(header.labels)),
+        };
+
+        content.update_header(&header);
+
+        ctx.update_rfd_document(
+            caller,
+            rfd_number,
+            content.raw(),
+            Some(&message),
+            Some(&revision.commit),
+            None,
+        )
+        .await
+        .map_err(update_error)?;
+
+        Ok(HttpResponseAccepted(header.labels))
+    } else {
+        Err(client_error(
+            StatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
 fn extract_attr(attr: &RfdAttrName, content: &RfdContent) -> Result<RfdAttr, HttpError> {
     match attr {
         RfdAttrName::Discussion => content
@@ -560,70 +2190,165 @@ pub struct FormattedSearchResultHit {
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
-async fn search_rfds_op(
+async fn search_rfds_op(
+    ctx: &ApiContext,
+    caller: &ApiCaller,
+    query: RfdSearchQuery,
+) -> Result<HttpResponseOk<SearchResults>, HttpError> {
+    // TODO: Move all of this into a ctx
+
+    // Ensure that the user has the search permission before searching
+    if caller.can(&ApiPermission::SearchRfds) {
+        tracing::debug!("Fetching from remote search API");
+
+        // Transform the inbound query into a meilisearch request
+        let mut search_request: SearchRequest = query.into();
+
+        search_request.filter = search_filter(caller);
+
+        // Pass the search request off to the meilisearch backend
+        let results = ctx
+            .search
+            .client
+            .search::<MeiliSearchResult>(&search_request)
+            .await;
+
+        tracing::debug!("Fetched results from remote search");
+
+        match results {
+            Ok(results) => {
+                let results = SearchResults {
+                    hits: results
+                        .hits
+                        .into_iter()
+                        .map(|hit| hit.into())
+                        .collect::<Vec<_>>(),
+                    query: results.query,
+                    limit: results.limit,
+                    offset: results.offset,
+                };
+
+                tracing::debug!(count = ?results.hits.len(), "Transformed search results");
+
+                Ok(HttpResponseOk(results))
+            }
+            Err(err) => {
+                tracing::error!(?err, "Search request failed");
+                Err(internal_error("Search failed".to_string()))
+            }
+        }
+    } else {
+        Err(unauthorized())
+    }
+}
+
+// Construct a meilisearch formatted filter. Either the caller has permission to search across all
+// RFDs or they access to some smaller set. If we need to filter down the RFD list we construct a
+// filter that will search across the RFDs the caller has direct access to as well as any RFDs that
+// are marked as publicly accessible.
+fn search_filter(caller: &ApiCaller) -> Option<String> {
+    if caller.can(&ApiPermission::GetRfdsAll) {
+        None
+    } else {
+        let mut filter = "public = true".to_string();
+
+        let allowed_rfds = caller
+            .allow_rfds()
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if allowed_rfds.len() > 0 {
+            filter = filter + &format!(" OR rfd_number IN [{}]", allowed_rfds);
+        }
+
+        Some(filter)
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdSuggestQuery {
+    pub q: String,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct SearchSuggestion {
+    pub rfd_number: u64,
+    pub title: Option<String>,
+    pub section: Option<String>,
+    pub anchor: Option<String>,
+}
+
+// Number of index documents to request per suggestion that will be returned. Multiple sections of a
+// single RFD will often match the same query, and are collapsed down to a single suggestion
+static SUGGEST_OVERFETCH: u32 = 5;
+
+/// Get a list of RFDs whose number, title, or section headings match the start of a query
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-search/suggest",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn suggest_rfds(
+    rqctx: RequestContext<ApiContext>,
+    query: Query<RfdSuggestQuery>,
+) -> Result<HttpResponseOk<Vec<SearchSuggestion>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    suggest_rfds_op(ctx, &caller, query.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn suggest_rfds_op(
     ctx: &ApiContext,
     caller: &ApiCaller,
-    query: RfdSearchQuery,
-) -> Result<HttpResponseOk<SearchResults>, HttpError> {
-    // TODO: Move all of this into a ctx
-
-    // Ensure that the user has the search permission before searching
+    query: RfdSuggestQuery,
+) -> Result<HttpResponseOk<Vec<SearchSuggestion>>, HttpError> {
     if caller.can(&ApiPermission::SearchRfds) {
-        tracing::debug!("Fetching from remote search API");
-
-        // Transform the inbound query into a meilisearch request
-        let mut search_request: SearchRequest = query.into();
-
-        // Construct a meilisearch formatted filter. Either the caller has permission to search across
-        // all RFDs or they access to some smaller set. If we need to filter down the RFD list we
-        // construct a filter that will search across the RFDs the caller has direct access to as
-        // well as any RFDs that are marked as publicly accessible.
-        search_request.filter = if caller.can(&ApiPermission::GetRfdsAll) {
-            None
-        } else {
-            let mut filter = "public = true".to_string();
-
-            let allowed_rfds = caller
-                .allow_rfds()
-                .iter()
-                .map(|num| num.to_string())
-                .collect::<Vec<_>>()
-                .join(", ");
-            if allowed_rfds.len() > 0 {
-                filter = filter + &format!("OR rfd_number in [{}]", allowed_rfds);
-            }
+        let limit = query.limit.unwrap_or(10).min(100);
 
-            Some(filter)
-        };
+        let mut search_request: SearchRequest = RfdSuggestQuery {
+            limit: Some(limit),
+            ..query
+        }
+        .into();
+        search_request.filter = search_filter(caller);
+        search_request.limit = search_request
+            .limit
+            .map(|limit| limit.saturating_mul(SUGGEST_OVERFETCH));
 
-        // Pass the search request off to the meilisearch backend
         let results = ctx
             .search
             .client
-            .search::<MeiliSearchResult>(&search_request)
+            .search::<MeiliSuggestResult>(&search_request)
             .await;
 
-        tracing::debug!("Fetched results from remote search");
-
         match results {
             Ok(results) => {
-                let results = SearchResults {
-                    hits: results
-                        .hits
-                        .into_iter()
-                        .map(|hit| hit.into())
-                        .collect::<Vec<_>>(),
-                    query: results.query,
-                    limit: results.limit,
-                    offset: results.offset,
-                };
+                let mut suggestions: Vec<SearchSuggestion> = vec![];
 
-                tracing::debug!(count = ?results.hits.len(), "Transformed search results");
+                for hit in results.hits {
+                    let suggestion = SearchSuggestion::from(hit);
 
-                Ok(HttpResponseOk(results))
+                    if !suggestions
+                        .iter()
+                        .any(|existing| existing.rfd_number == suggestion.rfd_number)
+                    {
+                        suggestions.push(suggestion);
+                    }
+                }
+
+                suggestions.truncate(limit as usize);
+
+                tracing::debug!(count = ?suggestions.len(), "Transformed suggestion results");
+
+                Ok(HttpResponseOk(suggestions))
             }
             Err(err) => {
-                tracing::error!(?err, "Search request failed");
+                tracing::error!(?err, "Suggestion request failed");
                 Err(internal_error("Search failed".to_string()))
             }
         }
@@ -689,8 +2414,11 @@ mod tests {
     use dropshot::HttpResponseOk;
     use http::StatusCode;
     use rfd_model::{
-        storage::{MockRfdPdfStore, MockRfdRevisionStore, MockRfdStore},
-        Rfd, RfdRevision,
+        storage::{
+            MockLabelStore, MockRfdAuthorStore, MockRfdPdfStore, MockRfdRevisionLabelStore,
+            MockRfdRevisionStore, MockRfdStateTransitionStore, MockRfdStore,
+        },
+        CommitSha, Label, Rfd, RfdAuthor, RfdRevision, RfdRevisionLabel, RfdStateTransition,
     };
     use uuid::Uuid;
     use w_api_permissions::Caller;
@@ -700,17 +2428,33 @@ mod tests {
             test_mocks::{mock_context, MockStorage},
             ApiContext,
         },
-        endpoints::rfd::get_rfd_op,
+        endpoints::rfd::{
+            asset_path, get_rfd_batch_op, get_rfd_op, get_rfd_templates_op, get_rfd_transitions_op,
+            locate_sections, reserve_rfd_op, resolve_expected_commit, rfd_cache_validators,
+            search_filter, ReserveRfdBody, RfdBatchBody, RfdBatchFields, RfdBatchResponse,
+            RfdListQuery,
+        },
         permissions::ApiPermission,
     };
 
     use super::get_rfds_op;
 
+    // The user that is matched as the author of RFDs 123 and 456
+    const AUTHOR_ID: Uuid = Uuid::from_u128(0x1234);
+
+    // Labels applied to RFDs 123 and 456 (design) and to RFD 456 (api)
+    const DESIGN_LABEL_ID: Uuid = Uuid::from_u128(0x5678);
+    const API_LABEL_ID: Uuid = Uuid::from_u128(0x9abc);
+
     async fn ctx() -> ApiContext {
         let private_rfd_id_1 = Uuid::new_v4();
         let private_rfd_id_2 = Uuid::new_v4();
         let public_rfd_id = Uuid::new_v4();
 
+        let private_revision_id_1 = Uuid::new_v4();
+        let private_revision_id_2 = Uuid::new_v4();
+        let public_revision_id = Uuid::new_v4();
+
         let mut rfd_store = MockRfdStore::new();
         rfd_store.expect_list().returning(move |filter, _| {
             let mut results = vec![
@@ -744,12 +2488,13 @@ mod tests {
             ];
 
             results.retain(|rfd| {
-                filter.rfd_number.is_none()
+                (filter.rfd_number.is_none()
                     || filter
                         .rfd_number
                         .as_ref()
                         .unwrap()
-                        .contains(&rfd.rfd_number)
+                        .contains(&rfd.rfd_number))
+                    && (filter.id.is_none() || filter.id.as_ref().unwrap().contains(&rfd.id))
             });
 
             Ok(results)
@@ -761,7 +2506,7 @@ mod tests {
             .returning(move |filter, _| {
                 let mut results = vec![
                     RfdRevision {
-                        id: Uuid::new_v4(),
+                        id: private_revision_id_1,
                         rfd_id: private_rfd_id_1,
                         title: "Private Test RFD 1".to_string(),
                         state: None,
@@ -778,7 +2523,7 @@ mod tests {
                         deleted_at: None,
                     },
                     RfdRevision {
-                        id: Uuid::new_v4(),
+                        id: public_revision_id,
                         rfd_id: public_rfd_id,
                         title: "Public Test RFD".to_string(),
                         state: None,
@@ -795,12 +2540,12 @@ mod tests {
                         deleted_at: None,
                     },
                     RfdRevision {
-                        id: Uuid::new_v4(),
+                        id: private_revision_id_2,
                         rfd_id: private_rfd_id_2,
                         title: "Private Test RFD 2".to_string(),
                         state: None,
                         discussion: None,
-                        authors: None,
+                        authors: Some("Jane Doe <jane@company>".to_string()),
                         labels: None,
                         content: String::new(),
                         content_format: rfd_model::schema_ext::ContentFormat::Asciidoc,
@@ -814,7 +2559,10 @@ mod tests {
                 ];
 
                 results.retain(|revision| {
-                    filter.rfd.is_none() || filter.rfd.as_ref().unwrap().contains(&revision.rfd_id)
+                    (filter.rfd.is_none()
+                        || filter.rfd.as_ref().unwrap().contains(&revision.rfd_id))
+                        && (filter.id.is_none()
+                            || filter.id.as_ref().unwrap().contains(&revision.id))
                 });
 
                 Ok(results)
@@ -825,8 +2573,119 @@ mod tests {
             .expect_list()
             .returning(move |_, _| Ok(vec![]));
 
+        let mut rfd_author_store = MockRfdAuthorStore::new();
+        rfd_author_store.expect_list().returning(move |filter, _| {
+            let mut results = [private_revision_id_1, public_revision_id]
+                .into_iter()
+                .map(|revision_id| RfdAuthor {
+                    id: Uuid::new_v4(),
+                    rfd_revision_id: revision_id,
+                    position: 0,
+                    name: Some("Author".to_string()),
+                    email: Some("author@company".to_string()),
+                    api_user_id: Some(AUTHOR_ID),
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                    deleted_at: None,
+                })
+                .collect::<Vec<_>>();
+
+            results.retain(|author| {
+                (filter.rfd_revision.is_none()
+                    || filter
+                        .rfd_revision
+                        .as_ref()
+                        .unwrap()
+                        .contains(&author.rfd_revision_id))
+                    && (filter.api_user.is_none()
+                        || author
+                            .api_user_id
+                            .map(|id| filter.api_user.as_ref().unwrap().contains(&id))
+                            .unwrap_or(false))
+            });
+
+            Ok(results)
+        });
+
+        let mut label_store = MockLabelStore::new();
+        label_store.expect_list().returning(move |_, _| {
+            Ok([(API_LABEL_ID, "api"), (DESIGN_LABEL_ID, "design")]
+                .into_iter()
+                .map(|(id, name)| Label {
+                    id,
+                    name: name.to_string(),
+                    description: None,
+                    color: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                    deleted_at: None,
+                })
+                .collect())
+        });
+
+        let mut rfd_revision_label_store = MockRfdRevisionLabelStore::new();
+        rfd_revision_label_store
+            .expect_list()
+            .returning(move |filter, _| {
+                let mut results = [
+                    (private_revision_id_1, DESIGN_LABEL_ID),
+                    (public_revision_id, DESIGN_LABEL_ID),
+                    (public_revision_id, API_LABEL_ID),
+                ]
+                .into_iter()
+                .map(|(revision_id, label_id)| RfdRevisionLabel {
+                    id: Uuid::new_v4(),
+                    rfd_revision_id: revision_id,
+                    label_id,
+                    created_at: Utc::now(),
+                    deleted_at: None,
+                })
+                .collect::<Vec<_>>();
+
+                results.retain(|link| {
+                    filter.rfd_revision.is_none()
+                        || filter
+                            .rfd_revision
+                            .as_ref()
+                            .unwrap()
+                            .contains(&link.rfd_revision_id)
+                });
+
+                Ok(results)
+            });
+
+        let mut rfd_state_transition_store = MockRfdStateTransitionStore::new();
+        rfd_state_transition_store
+            .expect_list()
+            .returning(move |filter, _| {
+                let mut results = [(None, "ideation"), (Some("ideation"), "discussion")]
+                    .into_iter()
+                    .map(|(from, to)| RfdStateTransition {
+                        id: Uuid::new_v4(),
+                        rfd_id: private_rfd_id_1,
+                        api_user_id: AUTHOR_ID,
+                        from_state: from.map(|state: &str| state.to_string()),
+                        to_state: to.to_string(),
+                        reason: None,
+                        commit: None,
+                        created_at: Utc::now(),
+                    })
+                    .collect::<Vec<_>>();
+
+                results.retain(|transition| {
+                    filter.rfd.is_none()
+                        || filter.rfd.as_ref().unwrap().contains(&transition.rfd_id)
+                });
+
+                Ok(results)
+            });
+
         let mut storage = MockStorage::new();
         storage.rfd_store = Some(Arc::new(rfd_store));
+        storage.rfd_state_transition_store = Some(Arc::new(rfd_state_transition_store));
+        storage.label_store = Some(Arc::new(label_store));
+        storage.rfd_revision_label_store = Some(Arc::new(rfd_revision_label_store));
+        storage.rfd_author_store = Some(Arc::new(rfd_author_store));
         storage.rfd_revision_store = Some(Arc::new(rfd_revision_store));
         storage.rfd_pdf_store = Some(Arc::new(rfd_pdf_store));
 
@@ -843,7 +2702,9 @@ mod tests {
             permissions: vec![ApiPermission::GetRfdsAll].into(),
         };
 
-        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller).await.unwrap();
+        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller, RfdListQuery::default())
+            .await
+            .unwrap();
         assert_eq!(3, rfds.len());
         assert_eq!(789, rfds[0].rfd_number);
         assert_eq!(456, rfds[1].rfd_number);
@@ -875,7 +2736,9 @@ mod tests {
             permissions: vec![ApiPermission::GetRfd(123)].into(),
         };
 
-        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller).await.unwrap();
+        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller, RfdListQuery::default())
+            .await
+            .unwrap();
         assert_eq!(2, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
         assert_eq!(123, rfds[1].rfd_number);
@@ -896,6 +2759,36 @@ mod tests {
         assert_eq!(456, rfd.rfd_number);
     }
 
+    #[tokio::test]
+    async fn get_rfd_batch_via_all_permission() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+
+        let HttpResponseOk(response) = get_rfd_batch_op(
+            &ctx,
+            &caller,
+            RfdBatchBody {
+                numbers: vec![123, 789, 1000],
+                fields: Some(RfdBatchFields::Full),
+            },
+        )
+        .await
+        .unwrap();
+
+        match response {
+            RfdBatchResponse::Full(rfds) => {
+                assert_eq!(2, rfds.len());
+                assert_eq!(789, rfds[0].rfd_number);
+                assert_eq!(123, rfds[1].rfd_number);
+                assert_eq!(1, rfds[1].author_list.len());
+            }
+            RfdBatchResponse::Summary(_) => panic!("Expected full RFDs to be returned"),
+        }
+    }
+
     // Test RFD access fails when a caller does not have permission
 
     #[tokio::test]
@@ -906,7 +2799,9 @@ mod tests {
             permissions: vec![].into(),
         };
 
-        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller).await.unwrap();
+        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &caller, RfdListQuery::default())
+            .await
+            .unwrap();
         assert_eq!(1, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
     }
@@ -933,15 +2828,50 @@ mod tests {
         assert_eq!(456, rfd.rfd_number);
     }
 
+    #[tokio::test]
+    async fn get_rfd_batch_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![].into(),
+        };
+
+        let HttpResponseOk(response) = get_rfd_batch_op(
+            &ctx,
+            &caller,
+            RfdBatchBody {
+                numbers: vec![123, 456, 789],
+                fields: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let serialized = serde_json::to_value(&response).unwrap();
+        assert_eq!("summary", serialized["fields"]);
+
+        match response {
+            RfdBatchResponse::Summary(rfds) => {
+                assert_eq!(1, rfds.len());
+                assert_eq!(456, rfds[0].rfd_number);
+            }
+            RfdBatchResponse::Full(_) => panic!("Expected RFD summaries to be returned"),
+        }
+    }
+
     // Test RFD access to public RFDs as the unauthenticated user
 
     #[tokio::test]
     async fn list_rfds_as_unauthenticated() {
         let ctx = ctx().await;
 
-        let HttpResponseOk(rfds) = get_rfds_op(&ctx, &ctx.builtin_unauthenticated_caller())
-            .await
-            .unwrap();
+        let HttpResponseOk(rfds) = get_rfds_op(
+            &ctx,
+            &ctx.builtin_unauthenticated_caller(),
+            RfdListQuery::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(1, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
     }
@@ -963,4 +2893,268 @@ mod tests {
         let HttpResponseOk(rfd) = get_rfd_op(&ctx, caller, "0456".to_string()).await.unwrap();
         assert_eq!(456, rfd.rfd_number);
     }
+
+    // Test listing RFDs by author
+
+    #[tokio::test]
+    async fn list_rfds_by_author() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+
+        let HttpResponseOk(rfds) = get_rfds_op(
+            &ctx,
+            &caller,
+            RfdListQuery {
+                author: Some(AUTHOR_ID),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(2, rfds.len());
+        assert_eq!(456, rfds[0].rfd_number);
+        assert_eq!(123, rfds[1].rfd_number);
+        assert_eq!(Some(AUTHOR_ID), rfds[0].author_list[0].api_user_id);
+
+        let HttpResponseOk(rfds) = get_rfds_op(
+            &ctx,
+            &caller,
+            RfdListQuery {
+                author: Some(Uuid::new_v4()),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(rfds.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_rfds_by_author_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![].into(),
+        };
+
+        let HttpResponseOk(rfds) = get_rfds_op(
+            &ctx,
+            &caller,
+            RfdListQuery {
+                author: Some(AUTHOR_ID),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(1, rfds.len());
+        assert_eq!(456, rfds[0].rfd_number);
+    }
+
+    #[tokio::test]
+    async fn get_rfd_authors_fall_back_to_authors_line() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+
+        let HttpResponseOk(rfd) = get_rfd_op(&ctx, &caller, "0789".to_string()).await.unwrap();
+        assert_eq!(1, rfd.author_list.len());
+        assert_eq!(Some("Jane Doe"), rfd.author_list[0].name.as_deref());
+        assert_eq!(Some("jane@company"), rfd.author_list[0].email.as_deref());
+        assert_eq!(None, rfd.author_list[0].api_user_id);
+    }
+
+    // Test label counts only include RFDs the caller can access
+
+    #[tokio::test]
+    async fn list_rfd_transitions() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfd(123)].into(),
+        };
+
+        let HttpResponseOk(transitions) = get_rfd_transitions_op(&ctx, &caller, "0123".to_string())
+            .await
+            .unwrap();
+        assert_eq!(2, transitions.len());
+        assert_eq!(None, transitions[0].from_state);
+        assert_eq!("discussion", transitions[1].to_state);
+
+        let HttpResponseOk(transitions) = get_rfd_transitions_op(&ctx, &caller, "0456".to_string())
+            .await
+            .unwrap();
+        assert!(transitions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_rfd_transitions_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![].into(),
+        };
+
+        let result = get_rfd_transitions_op(&ctx, &caller, "0123".to_string()).await;
+
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 403 error, but instead found transitions {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_rfd_templates() {
+        let ctx = ctx().await;
+
+        let HttpResponseOk(templates) = get_rfd_templates_op(&ctx).await.unwrap();
+        assert_eq!(
+            vec!["new", "placeholder"],
+            templates
+                .iter()
+                .map(|template| template.name.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_if_match_tags_to_commits() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+
+        let HttpResponseOk(rfd) = get_rfd_op(&ctx, &caller, "0123".to_string()).await.unwrap();
+        let mut headers = http::HeaderMap::new();
+        rfd_cache_validators(&rfd).insert_headers(&mut headers);
+        let tag = headers.get(http::header::ETAG).unwrap().to_str().unwrap();
+
+        assert_eq!(Some(rfd.commit.clone()), resolve_expected_commit(tag, &rfd));
+        assert_eq!(
+            Some(CommitSha("outdated".to_string())),
+            resolve_expected_commit("\"outdated\"", &rfd)
+        );
+        assert_eq!(None, resolve_expected_commit("*", &rfd));
+    }
+
+    #[tokio::test]
+    async fn sections_are_not_supported_for_markdown() {
+        let result = locate_sections(
+            "# Title\n\n## Section\n\nBody\n",
+            &rfd_model::schema_ext::ContentFormat::Markdown,
+        )
+        .await;
+
+        match result {
+            Err(err) => assert_eq!(StatusCode::BAD_REQUEST, err.status_code),
+            Ok(sections) => panic!(
+                "Expected a 400 error, but instead found {} sections",
+                sections.len()
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn reserve_rejects_contents_for_template_without_body() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::CreateRfd].into(),
+        };
+
+        let result = reserve_rfd_op(
+            &ctx,
+            &caller,
+            ReserveRfdBody {
+                title: "Title".to_string(),
+                content: Some("Contents".to_string()),
+                template: Some("placeholder".to_string()),
+                fields: Default::default(),
+                format: None,
+            },
+        )
+        .await;
+
+        match result {
+            Err(err) => assert_eq!(StatusCode::BAD_REQUEST, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 400 error, but instead found reservation {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_labels_counts_accessible_rfds() {
+        let ctx = ctx().await;
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+
+        let labels = ctx.list_labels(&caller).await.unwrap();
+        assert_eq!(2, labels.len());
+        assert_eq!(("api", 1), (labels[0].name.as_str(), labels[0].count));
+        assert_eq!(("design", 2), (labels[1].name.as_str(), labels[1].count));
+
+        let labels = ctx
+            .list_labels(ctx.builtin_unauthenticated_caller())
+            .await
+            .unwrap();
+        assert_eq!(("api", 1), (labels[0].name.as_str(), labels[0].count));
+        assert_eq!(("design", 1), (labels[1].name.as_str(), labels[1].count));
+    }
+
+    // Test search filter construction
+
+    #[test]
+    fn search_filter_for_callers() {
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfdsAll].into(),
+        };
+        assert_eq!(None, search_filter(&caller));
+
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::SearchRfds].into(),
+        };
+        assert_eq!(Some("public = true".to_string()), search_filter(&caller));
+
+        let caller = Caller {
+            id: Uuid::new_v4(),
+            permissions: vec![ApiPermission::GetRfd(123), ApiPermission::GetRfd(456)].into(),
+        };
+        assert_eq!(
+            Some("public = true OR rfd_number IN [123, 456]".to_string()),
+            search_filter(&caller)
+        );
+    }
+
+    // Test asset path validation
+
+    #[test]
+    fn asset_paths_stay_within_rfd() {
+        assert_eq!(Some("diagram.svg".to_string()), asset_path("diagram.svg"));
+        assert_eq!(
+            Some("images/diagram.svg".to_string()),
+            asset_path("/images/diagram.svg")
+        );
+        assert_eq!(None, asset_path(""));
+        assert_eq!(None, asset_path("images//diagram.svg"));
+        assert_eq!(None, asset_path("../0124/diagram.svg"));
+        assert_eq!(None, asset_path("images/./diagram.svg"));
+        assert_eq!(None, asset_path("README.adoc"));
+        assert_eq!(None, asset_path("readme.md"));
+        assert_eq!(
+            Some("images/README.md".to_string()),
+            asset_path("images/README.md")
+        );
+    }
 }
@@ -10,7 +10,7 @@ use dropshot::{
 };
 use dropshot_verified_body::{hmac::HmacVerifiedBody, services::github::GitHubWebhookVerification};
 use http::HeaderName;
-use regex::Regex;
+use rfd_data::RfdNumberFormat;
 use rfd_model::NewJob;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -41,7 +41,9 @@ pub async fn github_webhook(
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     // The HmacVerifiedBody extractor ensures that we have verified the signature of the incoming
     // request body
-    let new_jobs = body.into_inner()?.create_jobs(delivery_id);
+    let new_jobs = body
+        .into_inner()?
+        .create_jobs(delivery_id, &rqctx.context().github.number_format);
 
     for new_job in new_jobs {
         let job = rqctx
@@ -67,8 +69,8 @@ pub struct GitHubCommitPayload {
 }
 
 impl GitHubCommitPayload {
-    pub fn create_jobs(&self, delivery_id: Uuid) -> Vec<NewJob> {
-        self.affected_rfds()
+    pub fn create_jobs(&self, delivery_id: Uuid, format: &RfdNumberFormat) -> Vec<NewJob> {
+        self.affected_rfds(format)
             .into_iter()
             .filter_map(|rfd| {
                 if let Some(head_commit) = &self.head_commit {
@@ -92,32 +94,20 @@ impl GitHubCommitPayload {
         self.ref_.trim_start_matches("refs/heads/")
     }
 
-    fn affected_rfds(&self) -> Vec<i32> {
+    fn affected_rfds(&self, format: &RfdNumberFormat) -> Vec<i32> {
         // Check the committed files for changes to specific RFDs. Depending on the branch of the
         // commit, changes will be accepted to rejected. Changes on the default repository branch
         // are accepted for all RFDs, but on a RFD specific branch (i.e. 0123) on changes to
         // RFD 123 are accepted. Changes on non-default, non-rfd branches are always rejected
-        let pattern = Regex::new(r#"^rfd/(\d{4})/"#).unwrap();
-        let branch = self.branch();
+        let branch_rfd = format.parse(self.branch()).map(i32::from);
 
         self.changed_files()
             .into_iter()
             .filter_map(|path| {
-                pattern.captures(path).and_then(|captures| {
-                    if self.is_on_default_branch() {
-                        captures
-                            .get(1)
-                            .and_then(|number| number.as_str().parse::<i32>().ok())
-                    } else {
-                        captures.get(1).and_then(|number| {
-                            if branch == number.as_str() {
-                                number.as_str().parse::<i32>().ok()
-                            } else {
-                                None
-                            }
-                        })
-                    }
-                })
+                format
+                    .parse_path(path)
+                    .map(i32::from)
+                    .filter(|number| self.is_on_default_branch() || branch_rfd == Some(*number))
             })
             .collect()
     }
@@ -0,0 +1,347 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Utc};
+use dropshot::{
+    endpoint, HttpError, HttpResponseCreated, HttpResponseOk, Path, Query, RequestContext,
+    TypedBody,
+};
+use http::Uri;
+use rfd_model::{
+    schema_ext::WebhookEvent, storage::ListPagination, NewWebhookSubscription, WebhookDelivery,
+    WebhookHostError, WebhookSubscription,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use trace_request::trace_request;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{context::ApiContext, util::response::bad_request};
+
+/// A webhook subscription. The signing secret is never returned
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct WebhookSubscriptionResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub rfds: Option<Vec<i32>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionResponse {
+    fn from(value: WebhookSubscription) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            events: value.events,
+            rfds: value.rfds,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// List the configured webhook subscriptions
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_webhook_subscriptions(
+    rqctx: RequestContext<ApiContext>,
+) -> Result<HttpResponseOk<Vec<WebhookSubscriptionResponse>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+
+    Ok(HttpResponseOk(
+        ctx.list_webhook_subscriptions(&caller)
+            .await?
+            .into_iter()
+            .map(WebhookSubscriptionResponse::from)
+            .collect(),
+    ))
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct CreateWebhookSubscription {
+    /// Https endpoint that events are POSTed to. The endpoint must resolve to a public address
+    url: String,
+    /// Shared secret used to compute the X-RFD-Signature-256 header of each delivery
+    secret: String,
+    events: Vec<WebhookEvent>,
+    /// RFDs to deliver events for. Events for every RFD are delivered when this is omitted
+    rfds: Option<Vec<i32>>,
+}
+
+/// Subscribe an external endpoint to RFD events
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/webhook-subscription",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn create_webhook_subscription(
+    rqctx: RequestContext<ApiContext>,
+    body: TypedBody<CreateWebhookSubscription>,
+) -> Result<HttpResponseCreated<WebhookSubscriptionResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let body = body.into_inner();
+
+    validate_subscription(&body.url, &body.secret, &body.events)?;
+    check_resolved_addresses(&body.url).await?;
+
+    let subscription = ctx
+        .upsert_webhook_subscription(
+            &caller,
+            NewWebhookSubscription {
+                id: Uuid::new_v4(),
+                url: body.url,
+                secret: body.secret,
+                events: body.events,
+                rfds: body.rfds,
+            },
+        )
+        .await?;
+
+    Ok(HttpResponseCreated(subscription.into()))
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct WebhookSubscriptionPath {
+    identifier: Uuid,
+}
+
+/// Get a webhook subscription
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription/{identifier}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_webhook_subscription(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<WebhookSubscriptionPath>,
+) -> Result<HttpResponseOk<WebhookSubscriptionResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+
+    Ok(HttpResponseOk(
+        ctx.get_webhook_subscription(&caller, &path.identifier)
+            .await?
+            .into(),
+    ))
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct UpdateWebhookSubscription {
+    url: String,
+    /// Replacement signing secret. The existing secret is kept when this is omitted
+    secret: Option<String>,
+    events: Vec<WebhookEvent>,
+    rfds: Option<Vec<i32>>,
+}
+
+/// Replace the endpoint, filters, and optionally the secret of a webhook subscription
+#[trace_request]
+#[endpoint {
+    method = PUT,
+    path = "/webhook-subscription/{identifier}",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn update_webhook_subscription(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<WebhookSubscriptionPath>,
+    body: TypedBody<UpdateWebhookSubscription>,
+) -> Result<HttpResponseOk<WebhookSubscriptionResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let body = body.into_inner();
+
+    let existing = ctx
+        .get_webhook_subscription(&caller, &path.identifier)
+        .await?;
+    let secret = body.secret.unwrap_or(existing.secret);
+
+    validate_subscription(&body.url, &secret, &body.events)?;
+    check_resolved_addresses(&body.url).await?;
+
+    let subscription = ctx
+        .upsert_webhook_subscription(
+            &caller,
+            NewWebhookSubscription {
+                id: existing.id,
+                url: body.url,
+                secret,
+                events: body.events,
+                rfds: body.rfds,
+            },
+        )
+        .await?;
+
+    Ok(HttpResponseOk(subscription.into()))
+}
+
+/// Delete a webhook subscription. Queued deliveries for the subscription are not sent
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/webhook-subscription/{identifier}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn delete_webhook_subscription(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<WebhookSubscriptionPath>,
+) -> Result<HttpResponseOk<WebhookSubscriptionResponse>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+
+    Ok(HttpResponseOk(
+        ctx.delete_webhook_subscription(&caller, &path.identifier)
+            .await?
+            .into(),
+    ))
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WebhookDeliveryQuery {
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+/// List the delivery log of a webhook subscription, newest first
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription/{identifier}/delivery",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn get_webhook_deliveries(
+    rqctx: RequestContext<ApiContext>,
+    path: Path<WebhookSubscriptionPath>,
+    query: Query<WebhookDeliveryQuery>,
+) -> Result<HttpResponseOk<Vec<WebhookDelivery>>, HttpError> {
+    let ctx = rqctx.context();
+    let auth = ctx.authn_token(&rqctx).await?;
+    let caller = ctx.get_caller(auth.as_ref()).await?;
+    let path = path.into_inner();
+    let query = query.into_inner();
+
+    let pagination = ListPagination::default()
+        .limit(query.limit.unwrap_or(20).min(100) as i64)
+        .offset(query.offset.unwrap_or(0) as i64);
+
+    Ok(HttpResponseOk(
+        ctx.list_webhook_deliveries(&caller, &path.identifier, &pagination)
+            .await?,
+    ))
+}
+
+fn validate_subscription(
+    url: &str,
+    secret: &str,
+    events: &[WebhookEvent],
+) -> Result<(), HttpError> {
+    let host = url
+        .parse::<Uri>()
+        .ok()
+        .filter(|uri| uri.scheme_str() == Some("https"))
+        .and_then(|uri| uri.host().map(|host| host.to_lowercase()));
+
+    match host {
+        None => Err(bad_request("Webhook url must be an absolute https url")),
+        Some(host) if !WebhookSubscription::accepts_host(&host) => Err(bad_request(
+            "Webhook url must not target a loopback, link-local, or private address",
+        )),
+        Some(_) if secret.is_empty() => Err(bad_request("Webhook secret must not be empty")),
+        Some(_) if events.is_empty() => Err(bad_request(
+            "Webhook subscription must include at least one event",
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+// Resolve the host of an already validated webhook url and ensure that every address it resolves
+// to may receive deliveries. Addresses are checked again at delivery time, as they may change
+async fn check_resolved_addresses(url: &str) -> Result<(), HttpError> {
+    let uri = url
+        .parse::<Uri>()
+        .map_err(|_| bad_request("Webhook url must be an absolute https url"))?;
+    let host = uri
+        .host()
+        .ok_or_else(|| bad_request("Webhook url must be an absolute https url"))?;
+
+    match WebhookSubscription::resolve_host(host, uri.port_u16().unwrap_or(443)).await {
+        Ok(_) => Ok(()),
+        Err(WebhookHostError::NotAccepted) => Err(bad_request(
+            "Webhook url must not resolve to a loopback, link-local, or private address",
+        )),
+        Err(err) => {
+            tracing::info!(?err, ?host, "Failed to resolve webhook host");
+            Err(bad_request("Webhook url host could not be resolved"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rfd_model::schema_ext::WebhookEvent;
+
+    use super::validate_subscription;
+
+    #[test]
+    fn test_validates_subscription_fields() {
+        let events = vec![WebhookEvent::Created];
+
+        assert!(validate_subscription("https://example.com/hook", "secret", &events).is_ok());
+        assert!(validate_subscription("https://93.184.216.34/hook", "secret", &events).is_ok());
+
+        assert!(validate_subscription("http://example.com/hook", "secret", &events).is_err());
+        assert!(validate_subscription("ftp://example.com/hook", "secret", &events).is_err());
+        assert!(validate_subscription("/hook", "secret", &events).is_err());
+        assert!(validate_subscription("https://example.com/hook", "", &events).is_err());
+        assert!(validate_subscription("https://example.com/hook", "secret", &[]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_public_hosts() {
+        let events = vec![WebhookEvent::Created];
+
+        for url in [
+            "https://localhost:8080/hook",
+            "https://api.localhost/hook",
+            "https://127.0.0.1/hook",
+            "https://10.0.0.1/hook",
+            "https://172.16.0.1/hook",
+            "https://192.168.1.1/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/hook",
+            "https://0.0.0.0/hook",
+            "https://[::1]/hook",
+            "https://[fe80::1]/hook",
+            "https://[fd00::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+            "https://[::127.0.0.1]/hook",
+            "https://[64:ff9b::a00:1]/hook",
+            "https://198.18.0.1/hook",
+        ] {
+            assert!(
+                validate_subscription(url, "secret", &events).is_err(),
+                "{} should be rejected",
+                url
+            );
+        }
+    }
+}
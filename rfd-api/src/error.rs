@@ -19,6 +19,8 @@ use crate::{
 pub enum AppError {
     #[error("Failed to construct HTTP client")]
     ClientConstruction(ReqwestError),
+    #[error("Failed to find GCP credentials {0}")]
+    FailedToFindGcpCredentials(std::io::Error),
     #[error("GitHub communication error")]
     GitHub(#[from] GitHubError),
     #[error("Invalid GitHub private key")]
@@ -0,0 +1,216 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use futures::{stream, StreamExt};
+use rfd_model::{schema_ext::Visibility, storage::RFD_EVENT_CHANNEL, RfdEvent};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::JoinError,
+};
+use tokio_postgres::AsyncMessage;
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+use crate::{permissions::ApiPermission, ApiCaller};
+
+// Number of events held for each open stream. A stream that falls further behind than this skips
+// the events that it missed
+static EVENT_BUFFER_SIZE: usize = 256;
+
+// Delay before reconnecting after the listener connection has been lost
+static RECONNECT_DELAY_SECONDS: u64 = 5;
+
+/// Comment line sent to keep idle streams from being closed by intermediate proxies
+pub static SSE_KEEPALIVE: &str = ":keepalive\n\n";
+
+#[derive(Clone)]
+pub struct EventContext {
+    sender: broadcast::Sender<RfdEvent>,
+}
+
+#[derive(Debug, Error)]
+pub enum EventListenerError {
+    #[error("Listener connection task failed")]
+    Connection(#[from] JoinError),
+    #[error(transparent)]
+    Postgres(#[from] tokio_postgres::Error),
+}
+
+impl Default for EventContext {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+        Self { sender }
+    }
+}
+
+impl EventContext {
+    pub fn subscribe(&self) -> broadcast::Receiver<RfdEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Forward the events published to the database to every open stream. Runs until the process
+    /// exits, reconnecting whenever the connection to the database is lost
+    pub async fn listen(&self, database_url: String) {
+        loop {
+            match self.forward(&database_url).await {
+                Ok(()) => tracing::warn!("RFD event listener connection closed"),
+                Err(err) => tracing::error!(?err, "RFD event listener failed"),
+            }
+
+            tokio::time::sleep(Duration::from_secs(RECONNECT_DELAY_SECONDS)).await;
+        }
+    }
+
+    async fn forward(&self, database_url: &str) -> Result<(), EventListenerError> {
+        let (client, mut connection) =
+            tokio_postgres::connect(database_url, tls_connector()).await?;
+
+        // Notifications arrive on the connection itself, which must be driven independently of the
+        // client for the LISTEN statement below to complete
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let connection = tokio::spawn(async move {
+            let messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+            futures::pin_mut!(messages);
+
+            while let Some(message) = messages.next().await {
+                if tx.send(message?).is_err() {
+                    break;
+                }
+            }
+
+            Ok::<_, tokio_postgres::Error>(())
+        });
+
+        client
+            .batch_execute(&format!("LISTEN {}", RFD_EVENT_CHANNEL))
+            .await?;
+
+        tracing::info!(channel = RFD_EVENT_CHANNEL, "Listening for RFD events");
+
+        while let Some(message) = rx.recv().await {
+            if let AsyncMessage::Notification(notification) = message {
+                match serde_json::from_str::<RfdEvent>(notification.payload()) {
+                    Ok(event) => {
+                        // Sending only fails when there are no open streams to receive the event
+                        let _ = self.sender.send(event);
+                    }
+                    Err(err) => tracing::warn!(?err, "Received a malformed RFD event"),
+                }
+            }
+        }
+
+        Ok(connection.await??)
+    }
+}
+
+// Connections negotiate TLS according to the sslmode of the database url, in the same way as the
+// connections of the main storage pool. Server certificates are verified against the system roots
+fn tls_connector() -> MakeRustlsConnect {
+    let mut roots = rustls::RootCertStore::empty();
+    let certificates = rustls_native_certs::load_native_certs()
+        .map_err(|err| tracing::warn!(?err, "Failed to load system root certificates"))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|certificate| certificate.0)
+        .collect::<Vec<_>>();
+    roots.add_parsable_certificates(&certificates);
+
+    MakeRustlsConnect::new(
+        rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth(),
+    )
+}
+
+/// Check if the caller is allowed to read the RFD that an event describes
+pub fn can_receive(caller: &ApiCaller, event: &RfdEvent) -> bool {
+    event.visibility == Visibility::Public
+        || caller.any(&[
+            &ApiPermission::GetRfd(event.rfd_number),
+            &ApiPermission::GetRfdsAll,
+        ])
+}
+
+/// Format an event as a Server-Sent Events message
+pub fn sse_message(event: &RfdEvent) -> String {
+    // Events only hold plain data and can always be serialized. Serialized JSON does not contain
+    // line breaks, so the payload always fits on a single data line
+    format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        event.id,
+        event.event,
+        serde_json::to_string(event).unwrap()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rfd_model::{
+        schema_ext::{Visibility, WebhookEvent},
+        RfdEvent,
+    };
+    use uuid::Uuid;
+
+    use crate::{permissions::ApiPermission, ApiCaller};
+
+    use super::{can_receive, sse_message};
+
+    fn event(visibility: Visibility) -> RfdEvent {
+        RfdEvent {
+            id: Uuid::new_v4(),
+            event: WebhookEvent::ContentUpdated,
+            occurred_at: Utc::now(),
+            rfd_number: 123,
+            title: "Live updates".to_string(),
+            state: Some("discussion".to_string()),
+            visibility,
+            commit: String::new().into(),
+            previous_state: None,
+            previous_visibility: None,
+        }
+    }
+
+    fn caller(permissions: Vec<ApiPermission>) -> ApiCaller {
+        ApiCaller {
+            id: Uuid::new_v4(),
+            permissions: permissions.into(),
+        }
+    }
+
+    #[test]
+    fn test_filters_events_by_rfd_access() {
+        let public = event(Visibility::Public);
+        let private = event(Visibility::Private);
+
+        assert!(can_receive(&caller(vec![]), &public));
+        assert!(!can_receive(&caller(vec![]), &private));
+        assert!(!can_receive(
+            &caller(vec![ApiPermission::GetRfd(124)]),
+            &private
+        ));
+        assert!(can_receive(
+            &caller(vec![ApiPermission::GetRfd(123)]),
+            &private
+        ));
+        assert!(can_receive(
+            &caller(vec![ApiPermission::GetRfdsAll]),
+            &private
+        ));
+    }
+
+    #[test]
+    fn test_formats_sse_message() {
+        let event = event(Visibility::Public);
+        let message = sse_message(&event);
+        let lines = message.split('\n').collect::<Vec<_>>();
+
+        assert_eq!(format!("id: {}", event.id), lines[0]);
+        assert_eq!("event: content_updated", lines[1]);
+        assert!(lines[2].starts_with("data: {"));
+        assert_eq!(vec!["", ""], &lines[3..]);
+    }
+}
@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt::Write;
+
+/// A minimal Atom (RFC 4287) feed
+#[derive(Debug)]
+pub struct AtomFeed {
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub updated: DateTime<Utc>,
+    pub entries: Vec<AtomEntry>,
+}
+
+#[derive(Debug)]
+pub struct AtomEntry {
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub updated: DateTime<Utc>,
+    pub summary: String,
+    pub author: Option<String>,
+}
+
+impl AtomFeed {
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        // Writing to a String can not fail
+        let _ = write!(
+            out,
+            r#"<?xml version="1.0" encoding="utf-8"?><feed xmlns="http://www.w3.org/2005/Atom"><id>{}</id><title>{}</title><link rel="self" href="{}"/><updated>{}</updated>"#,
+            escape(&self.id),
+            escape(&self.title),
+            escape(&self.link),
+            timestamp(&self.updated),
+        );
+
+        for entry in &self.entries {
+            let _ = write!(
+                out,
+                r#"<entry><id>{}</id><title>{}</title><link href="{}"/><updated>{}</updated><summary>{}</summary>"#,
+                escape(&entry.id),
+                escape(&entry.title),
+                escape(&entry.link),
+                timestamp(&entry.updated),
+                escape(&entry.summary),
+            );
+
+            // Atom requires an author on every entry when the feed itself does not declare one
+            let _ = write!(
+                out,
+                "<author><name>{}</name></author></entry>",
+                escape(entry.author.as_deref().unwrap_or("Unknown")),
+            );
+        }
+
+        out.push_str("</feed>");
+        out
+    }
+}
+
+fn timestamp(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{AtomEntry, AtomFeed};
+
+    #[test]
+    fn test_renders_escaped_feed() {
+        let updated = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let feed = AtomFeed {
+            id: "https://rfd.example.com/rfd-feed.atom".to_string(),
+            title: "RFD activity".to_string(),
+            link: "https://rfd.example.com/rfd-feed.atom".to_string(),
+            updated,
+            entries: vec![AtomEntry {
+                id: "https://rfd.example.com/rfd/123#abc".to_string(),
+                title: "RFD 123 Pipes & <Filters>".to_string(),
+                link: "https://rfd.example.com/rfd/123/html".to_string(),
+                updated,
+                summary: "Updated".to_string(),
+                author: None,
+            }],
+        };
+
+        let xml = feed.render();
+
+        assert!(xml.contains("<updated>2024-06-01T12:00:00Z</updated>"));
+        assert!(xml.contains("<title>RFD 123 Pipes &amp; &lt;Filters&gt;</title>"));
+        assert!(xml.contains("<author><name>Unknown</name></author>"));
+        assert!(xml.ends_with("</entry></feed>"));
+    }
+}
@@ -29,9 +29,12 @@ mod config;
 mod context;
 mod endpoints;
 mod error;
+mod events;
+mod feed;
 mod initial_data;
 mod mapper;
 mod permissions;
+mod render;
 mod search;
 mod secrets;
 mod server;
@@ -90,6 +93,10 @@ async fn main() -> anyhow::Result<()> {
 
     tracing::info!("Configured server context");
 
+    let events = context.events.clone();
+    let database_url = config.database_url.clone();
+    tokio::spawn(async move { events.listen(database_url).await });
+
     let init_data = InitialData::new(config.initial_mappers.map(|p| vec![p])).tap_err(|err| {
         tracing::error!(?err, "Failed to load initial data from configuration");
     })?;
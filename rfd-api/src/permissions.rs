@@ -95,6 +95,11 @@ pub enum ApiPermission {
     GetDiscussionsAssigned,
     GetDiscussionsAll,
     SearchRfds,
+    ManageLabels,
+
+    // Webhook subscription permissions
+    GetWebhookSubscriptions,
+    ManageWebhookSubscriptions,
 
     // OAuth client manage permissions
     CreateOAuthClient,
@@ -193,6 +198,10 @@ impl ApiPermission {
             ApiPermission::GetDiscussionsAll => "rfd:discussion:r",
             ApiPermission::GetDiscussionsAssigned => "rfd:discussion:r",
             ApiPermission::SearchRfds => "search",
+            ApiPermission::ManageLabels => "label:w",
+
+            ApiPermission::GetWebhookSubscriptions => "webhook:r",
+            ApiPermission::ManageWebhookSubscriptions => "webhook:w",
 
             ApiPermission::CreateOAuthClient => "oauth:client:w",
             ApiPermission::GetOAuthClient(_) => "oauth:client:r",
@@ -294,6 +303,15 @@ impl ApiPermission {
                 "search" => {
                     permissions.insert(ApiPermission::SearchRfds);
                 }
+                "label:w" => {
+                    permissions.insert(ApiPermission::ManageLabels);
+                }
+                "webhook:r" => {
+                    permissions.insert(ApiPermission::GetWebhookSubscriptions);
+                }
+                "webhook:w" => {
+                    permissions.insert(ApiPermission::ManageWebhookSubscriptions);
+                }
                 "oauth:client:r" => {
                     permissions.insert(ApiPermission::GetOAuthClientsAssigned);
                     permissions.insert(ApiPermission::GetOAuthClientsAll);
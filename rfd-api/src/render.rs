@@ -0,0 +1,197 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use ammonia::Builder;
+use parse_rfd::ParserError;
+use pulldown_cmark::{html, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use rfd_model::{schema_ext::ContentFormat, storage::StoreError};
+use std::collections::HashSet;
+use thiserror::Error;
+use tokio::task::JoinError;
+
+#[derive(Debug, Error)]
+pub enum RenderError {
+    #[error("Failed to render AsciiDoc document {0}")]
+    Asciidoc(#[from] ParserError),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+    #[error("Rendering task failed to complete {0}")]
+    Task(#[from] JoinError),
+}
+
+/// Render the content of a RFD to sanitized HTML. Relative image paths are rewritten to be served
+/// from `images_dir` when it is supplied. Sections are given the same anchors that are generated
+/// for the search index
+pub fn render_html(
+    content: &str,
+    format: &ContentFormat,
+    images_dir: Option<&str>,
+) -> Result<String, RenderError> {
+    let html = match format {
+        ContentFormat::Asciidoc => parse_rfd::render(content, images_dir)?,
+        ContentFormat::Markdown => render_markdown(content, images_dir),
+    };
+
+    Ok(Builder::default()
+        .add_generic_attributes(&["id", "class"])
+        .clean(&html)
+        .to_string())
+}
+
+fn render_markdown(content: &str, images_dir: Option<&str>) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    let mut events = Parser::new_ext(content, options).collect::<Vec<_>>();
+    let mut ids = SectionIds::default();
+
+    for i in 0..events.len() {
+        // The first level heading is the document title and is not a section
+        let is_section = matches!(
+            &events[i],
+            Event::Start(Tag::Heading { level, .. }) if *level != HeadingLevel::H1
+        );
+
+        if is_section {
+            let section_id = ids.generate(&heading_text(&events[i + 1..]));
+
+            if let Event::Start(Tag::Heading { id, .. }) = &mut events[i] {
+                *id = Some(CowStr::from(section_id));
+            }
+        }
+
+        if let (Some(images_dir), Event::Start(Tag::Image { dest_url, .. })) =
+            (images_dir, &mut events[i])
+        {
+            if is_relative(dest_url) {
+                *dest_url = CowStr::from(format!(
+                    "{}/{}",
+                    images_dir.trim_end_matches('/'),
+                    dest_url.trim_start_matches("./")
+                ));
+            }
+        }
+    }
+
+    let mut output = String::with_capacity(content.len());
+    html::push_html(&mut output, events.into_iter());
+    output
+}
+
+// Collect the plain text of a heading from the events that follow its start tag
+fn heading_text(events: &[Event]) -> String {
+    let mut text = String::new();
+
+    for event in events {
+        match event {
+            Event::End(TagEnd::Heading(_)) => break,
+            Event::Text(value) | Event::Code(value) => text.push_str(value),
+            _ => (),
+        }
+    }
+
+    text
+}
+
+fn is_relative(url: &str) -> bool {
+    !(url.contains("://")
+        || url.starts_with('/')
+        || url.starts_with('#')
+        || url.starts_with("data:"))
+}
+
+/// Generates section ids following the same rules as Asciidoctor, which is used to produce the
+/// anchors stored in the search index
+#[derive(Debug, Default)]
+struct SectionIds {
+    used: HashSet<String>,
+}
+
+impl SectionIds {
+    fn generate(&mut self, title: &str) -> String {
+        let title = title
+            .trim()
+            .to_lowercase()
+            .replace(" -- ", "")
+            .replace("--", "")
+            .replace("...", "");
+
+        let mut id = String::from("_");
+        let mut separated = false;
+
+        for c in title.chars() {
+            if c == ' ' || c == '.' || c == '-' {
+                if !separated {
+                    id.push('_');
+                }
+                separated = true;
+            } else if c.is_alphanumeric() || c == '_' {
+                id.push(c);
+                separated = false;
+            }
+        }
+
+        if id.ends_with('_') {
+            id.pop();
+        }
+
+        let mut unique = id.clone();
+        let mut count = 2;
+        while self.used.contains(&unique) {
+            unique = format!("{}_{}", id, count);
+            count += 1;
+        }
+
+        self.used.insert(unique.clone());
+        unique
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rfd_model::schema_ext::ContentFormat;
+
+    use super::{render_html, SectionIds};
+
+    #[test]
+    fn test_section_ids_match_asciidoctor() {
+        let mut ids = SectionIds::default();
+
+        assert_eq!("_background", ids.generate("Background"));
+        assert_eq!(
+            "_background_code_more",
+            ids.generate("Background code & more")
+        );
+        assert_eq!(
+            "_background_code_more_2",
+            ids.generate("Background code & more")
+        );
+        assert_eq!("_sub_sectionx", ids.generate("Sub.section -- x"));
+        assert_eq!("_hello_world", ids.generate("Hello, World!"));
+    }
+
+    #[test]
+    fn test_renders_markdown() {
+        let html = render_html(
+            r#"# RFD 123 On Rendering Documents
+
+## Background `code`
+
+![Diagram](./diagram.svg)
+![Remote](https://example.com/remote.png)
+
+<script>alert("hello")</script>"#,
+            &ContentFormat::Markdown,
+            Some("https://assets.example.com/rfd/123/latest"),
+        )
+        .unwrap();
+
+        assert!(html.contains("<h1>RFD 123 On Rendering Documents</h1>"));
+        assert!(html.contains(r#"<h2 id="_background_code">"#));
+        assert!(html.contains(r#"src="https://assets.example.com/rfd/123/latest/diagram.svg""#));
+        assert!(html.contains(r#"src="https://example.com/remote.png""#));
+        assert!(!html.contains("<script>"));
+    }
+}
@@ -9,7 +9,9 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fmt::Display;
 use thiserror::Error;
 
-use crate::endpoints::rfd::{FormattedSearchResultHit, RfdSearchQuery, SearchResultHit};
+use crate::endpoints::rfd::{
+    FormattedSearchResultHit, RfdSearchQuery, RfdSuggestQuery, SearchResultHit, SearchSuggestion,
+};
 
 #[derive(Debug, Error)]
 pub enum SearchError {
@@ -69,6 +71,10 @@ pub struct SearchRequest {
     pub highlight_post_tag: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub attributes_to_crop: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attributes_to_retrieve: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attributes_to_search_on: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,12 +96,41 @@ impl From<RfdSearchQuery> for SearchRequest {
                 .split(",")
                 .map(|s| s.to_string())
                 .collect::<Vec<_>>(),
+            attributes_to_retrieve: vec![],
+            attributes_to_search_on: vec![],
             limit: query.limit,
             offset: query.offset,
         }
     }
 }
 
+impl From<RfdSuggestQuery> for SearchRequest {
+    fn from(query: RfdSuggestQuery) -> Self {
+        SearchRequest {
+            q: query.q,
+            filter: None,
+            attributes_to_highlight: vec![],
+            highlight_pre_tag: None,
+            highlight_post_tag: None,
+            attributes_to_crop: vec![],
+            attributes_to_retrieve: vec![
+                "rfd_number".to_string(),
+                "hierarchy_lvl0".to_string(),
+                "hierarchy_lvl1".to_string(),
+                "anchor".to_string(),
+            ],
+            // Suggestions only match against the RFD number and title (stored together in the top
+            // level of the hierarchy) and section headings. Section bodies are ignored
+            attributes_to_search_on: vec![
+                "hierarchy_lvl0".to_string(),
+                "hierarchy_lvl1".to_string(),
+            ],
+            limit: Some(query.limit.unwrap_or(10)),
+            offset: None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct MeiliSearchResult {
     hierarchy_radio_lvl0: Option<String>,
@@ -201,3 +236,22 @@ impl From<SearchResult<MeiliSearchResult>> for SearchResultHit {
         }
     }
 }
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct MeiliSuggestResult {
+    rfd_number: u64,
+    hierarchy_lvl0: Option<String>,
+    hierarchy_lvl1: Option<String>,
+    anchor: Option<String>,
+}
+
+impl From<SearchResult<MeiliSuggestResult>> for SearchSuggestion {
+    fn from(hit: SearchResult<MeiliSuggestResult>) -> Self {
+        SearchSuggestion {
+            rfd_number: hit.result.rfd_number,
+            title: hit.result.hierarchy_lvl0,
+            section: hit.result.hierarchy_lvl1,
+            anchor: hit.result.anchor,
+        }
+    }
+}
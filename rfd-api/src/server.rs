@@ -14,11 +14,12 @@ use crate::{
     context::ApiContext,
     endpoints::{
         api_user::{
-            add_api_user_to_group, create_api_user, create_api_user_token, delete_api_user_token,
-            get_api_user, get_api_user_token, get_self, list_api_user_tokens,
-            remove_api_user_from_group, update_api_user,
+            add_api_user_to_group, create_api_user, create_api_user_feed_token,
+            create_api_user_token, delete_api_user_token, get_api_user, get_api_user_token,
+            get_self, list_api_user_tokens, remove_api_user_from_group, update_api_user,
         },
         group::{create_group, delete_group, get_groups, update_group},
+        label::{delete_label, get_labels, update_label},
         login::{
             local::local_login,
             oauth::{
@@ -33,10 +34,18 @@ use crate::{
         },
         mappers::{create_mapper, delete_mapper, get_mappers},
         rfd::{
-            discuss_rfd, get_rfd, get_rfd_attr, get_rfds, publish_rfd, reserve_rfd, search_rfds,
-            set_rfd_attr, set_rfd_content, set_rfd_document, update_rfd_visibility,
+            add_rfd_label, discuss_rfd, get_rfd, get_rfd_asset, get_rfd_attr, get_rfd_batch,
+            get_rfd_events, get_rfd_feed, get_rfd_html, get_rfd_section, get_rfd_sections,
+            get_rfd_templates, get_rfd_timeline, get_rfd_transitions, get_rfds, get_rfds_feed,
+            publish_rfd, remove_rfd_label, reserve_rfd, revert_rfd, search_rfds, set_rfd_attr,
+            set_rfd_content, set_rfd_document, set_rfd_section, suggest_rfds, transition_rfd,
+            update_rfd_visibility, upload_rfd_asset,
         },
         webhook::github_webhook,
+        webhook_subscription::{
+            create_webhook_subscription, delete_webhook_subscription, get_webhook_deliveries,
+            get_webhook_subscription, get_webhook_subscriptions, update_webhook_subscription,
+        },
         well_known::{jwks_json, openid_configuration},
     },
 };
@@ -94,8 +103,12 @@ pub fn server(
     // RFDs
     api.register(get_rfds).expect("Failed to register endpoint");
     api.register(get_rfd).expect("Failed to register endpoint");
+    api.register(get_rfd_batch)
+        .expect("Failed to register endpoint");
     api.register(reserve_rfd)
         .expect("Failed to register endpoint");
+    api.register(get_rfd_templates)
+        .expect("Failed to register endpoint");
     api.register(set_rfd_document)
         .expect("Failed to register endpoint");
     api.register(set_rfd_content)
@@ -108,15 +121,61 @@ pub fn server(
         .expect("Failed to register endpoint");
     api.register(publish_rfd)
         .expect("Failed to register endpoint");
+    api.register(transition_rfd)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_transitions)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_timeline)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_html)
+        .expect("Failed to register endpoint");
+    api.register(get_rfds_feed)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_feed)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_events)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_asset)
+        .expect("Failed to register endpoint");
+    api.register(upload_rfd_asset)
+        .expect("Failed to register endpoint");
+    api.register(revert_rfd)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_sections)
+        .expect("Failed to register endpoint");
+    api.register(get_rfd_section)
+        .expect("Failed to register endpoint");
+    api.register(set_rfd_section)
+        .expect("Failed to register endpoint");
     api.register(update_rfd_visibility)
         .expect("Failed to register endpoint");
+    api.register(add_rfd_label)
+        .expect("Failed to register endpoint");
+    api.register(remove_rfd_label)
+        .expect("Failed to register endpoint");
     api.register(search_rfds)
         .expect("Failed to register endpoint");
+    api.register(suggest_rfds)
+        .expect("Failed to register endpoint");
 
     // Webhooks
     api.register(github_webhook)
         .expect("Failed to register endpoint");
 
+    // Webhook Subscription Management
+    api.register(get_webhook_subscriptions)
+        .expect("Failed to register endpoint");
+    api.register(create_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(get_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(update_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(delete_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(get_webhook_deliveries)
+        .expect("Failed to register endpoint");
+
     // User Management
     api.register(get_self).expect("Failed to register endpoint");
     api.register(get_api_user)
@@ -133,6 +192,8 @@ pub fn server(
         .expect("Failed to register endpoint");
     api.register(delete_api_user_token)
         .expect("Failed to register endpoint");
+    api.register(create_api_user_feed_token)
+        .expect("Failed to register endpoint");
     api.register(add_api_user_to_group)
         .expect("Failed to register endpoint");
     api.register(remove_api_user_from_group)
@@ -148,6 +209,14 @@ pub fn server(
     api.register(delete_group)
         .expect("Failed to register endpoint");
 
+    // Label Management
+    api.register(get_labels)
+        .expect("Failed to register endpoint");
+    api.register(update_label)
+        .expect("Failed to register endpoint");
+    api.register(delete_label)
+        .expect("Failed to register endpoint");
+
     // Mapper Management
     api.register(get_mappers)
         .expect("Failed to register endpoint");
@@ -7,6 +7,7 @@ use google_cloudkms1::{
     hyper_rustls::{self, HttpsConnector},
     CloudKMS,
 };
+use google_storage1::Storage;
 use hyper::client::HttpConnector;
 use rfd_model::storage::StoreError;
 
@@ -41,6 +42,245 @@ pub mod request {
     }
 }
 
+pub mod cache {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+    use dropshot::{ApiEndpointResponse, HttpError, HttpResponse, RequestInfo};
+    use http::{
+        header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+        HeaderMap, HeaderValue, StatusCode,
+    };
+    use hyper::{Body, Response};
+    use sha2::{Digest, Sha256};
+
+    static HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+    /// Identifies the version of a resource so that clients can avoid fetching it again when it
+    /// has not changed
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CacheValidators {
+        etag: String,
+        last_modified: Option<DateTime<Utc>>,
+    }
+
+    impl CacheValidators {
+        /// Construct validators from the values that determine the state of a resource. The tag
+        /// changes whenever any of the parts change
+        pub fn new<I, T>(parts: I, last_modified: Option<DateTime<Utc>>) -> Self
+        where
+            I: IntoIterator<Item = T>,
+            T: AsRef<str>,
+        {
+            let mut hasher = Sha256::new();
+
+            for part in parts {
+                hasher.update(part.as_ref().as_bytes());
+                hasher.update([0]);
+            }
+
+            Self {
+                etag: format!("\"{}\"", hex::encode(&hasher.finalize()[..16])),
+                last_modified,
+            }
+        }
+
+        /// Determine if an entity tag supplied by a client identifies this version of the resource.
+        /// Weak tags are compared by their opaque value
+        pub fn matches(&self, tag: &str) -> bool {
+            tag.trim().trim_start_matches("W/") == self.etag
+        }
+
+        /// Determine if a client already holds this version of the resource. If-None-Match takes
+        /// precedence over If-Modified-Since when both are supplied
+        pub fn is_fresh(&self, headers: &HeaderMap) -> bool {
+            if let Some(if_none_match) = headers.get(IF_NONE_MATCH) {
+                if_none_match
+                    .to_str()
+                    .map(|tags| {
+                        tags.split(',')
+                            .map(|tag| tag.trim())
+                            .any(|tag| tag == "*" || self.matches(tag))
+                    })
+                    .unwrap_or(false)
+            } else if let Some(if_modified_since) = headers.get(IF_MODIFIED_SINCE) {
+                let since = if_modified_since
+                    .to_str()
+                    .ok()
+                    .and_then(|since| NaiveDateTime::parse_from_str(since, HTTP_DATE_FORMAT).ok())
+                    .map(|since| since.and_utc());
+
+                match (since, self.last_modified) {
+                    // HTTP dates only have second precision
+                    (Some(since), Some(last_modified)) => {
+                        last_modified.timestamp() <= since.timestamp()
+                    }
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        }
+
+        /// Attach the validators to a response, or respond with 304 Not Modified if the client
+        /// already holds this version of the resource
+        pub fn respond<T>(self, request: &RequestInfo, response: T) -> CachedResponse<T>
+        where
+            T: HttpResponse,
+        {
+            CachedResponse {
+                fresh: self.is_fresh(request.headers()),
+                validators: self,
+                response,
+            }
+        }
+
+        /// Attach the validators to an existing set of response headers
+        pub fn insert_headers(&self, headers: &mut HeaderMap) {
+            // The tag is constructed from hex characters and is always a valid header value
+            headers.insert(ETAG, HeaderValue::from_str(&self.etag).unwrap());
+
+            if let Some(last_modified) = self.last_modified {
+                if let Ok(value) =
+                    HeaderValue::from_str(&last_modified.format(HTTP_DATE_FORMAT).to_string())
+                {
+                    headers.insert(LAST_MODIFIED, value);
+                }
+            }
+        }
+    }
+
+    /// A typed response that is replaced by a bodyless 304 Not Modified when the client already
+    /// holds the current version of the resource. The validators are attached in either case
+    pub struct CachedResponse<T> {
+        validators: CacheValidators,
+        fresh: bool,
+        response: T,
+    }
+
+    impl<T> HttpResponse for CachedResponse<T>
+    where
+        T: HttpResponse,
+    {
+        fn to_result(self) -> Result<Response<Body>, HttpError> {
+            let mut response = if self.fresh {
+                Response::builder()
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(Body::empty())
+                    .map_err(|err| HttpError::for_internal_error(err.to_string()))?
+            } else {
+                self.response.to_result()?
+            };
+
+            self.validators.insert_headers(response.headers_mut());
+
+            Ok(response)
+        }
+
+        // Only the successful response is documented. A 304 is never returned to a client that
+        // has not sent a validator
+        fn response_metadata() -> ApiEndpointResponse {
+            T::response_metadata()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use chrono::{TimeZone, Utc};
+        use dropshot::{HttpResponse, HttpResponseOk};
+        use http::{
+            header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+            HeaderMap, HeaderValue, StatusCode,
+        };
+        use hyper::body::HttpBody;
+
+        use super::{CacheValidators, CachedResponse};
+
+        fn validators() -> CacheValidators {
+            CacheValidators::new(
+                ["commit", "public"],
+                Some(Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap()),
+            )
+        }
+
+        #[test]
+        fn test_matches_entity_tags() {
+            let validators = validators();
+            let mut headers = HeaderMap::new();
+            assert!(!validators.is_fresh(&headers));
+
+            headers.insert(
+                IF_NONE_MATCH,
+                HeaderValue::from_str(&format!("\"other\", W/{}", validators.etag)).unwrap(),
+            );
+            assert!(validators.is_fresh(&headers));
+
+            let changed = CacheValidators::new(["commit", "private"], validators.last_modified);
+            assert!(!changed.is_fresh(&headers));
+        }
+
+        #[test]
+        fn test_matches_single_tag() {
+            let validators = validators();
+            assert!(validators.matches(&validators.etag));
+            assert!(validators.matches(&format!("W/{}", validators.etag)));
+            assert!(!validators.matches("\"commit\""));
+        }
+
+        #[test]
+        fn test_not_modified_response_has_no_body() {
+            let validators = validators();
+            let response = CachedResponse {
+                validators: validators.clone(),
+                fresh: true,
+                response: HttpResponseOk(1),
+            }
+            .to_result()
+            .unwrap();
+
+            assert_eq!(StatusCode::NOT_MODIFIED, response.status());
+            assert!(response.body().is_end_stream());
+            assert_eq!(response.headers()[ETAG], validators.etag.as_str());
+            assert!(response.headers().contains_key(LAST_MODIFIED));
+
+            let response = CachedResponse {
+                validators: validators.clone(),
+                fresh: false,
+                response: HttpResponseOk(1),
+            }
+            .to_result()
+            .unwrap();
+
+            assert_eq!(StatusCode::OK, response.status());
+            assert_eq!(response.headers()[ETAG], validators.etag.as_str());
+        }
+
+        #[test]
+        fn test_matches_modified_since() {
+            let validators = validators();
+            let mut headers = HeaderMap::new();
+
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_static("Wed, 01 May 2024 12:30:00 GMT"),
+            );
+            assert!(validators.is_fresh(&headers));
+
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_static("Wed, 01 May 2024 12:29:59 GMT"),
+            );
+            assert!(!validators.is_fresh(&headers));
+
+            // Entity tags take precedence over modification dates
+            headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+            headers.insert(
+                IF_MODIFIED_SINCE,
+                HeaderValue::from_static("Wed, 01 May 2024 12:30:00 GMT"),
+            );
+            assert!(!validators.is_fresh(&headers));
+        }
+    }
+}
+
 pub mod response {
     use dropshot::HttpError;
     use http::StatusCode;
@@ -230,6 +470,48 @@ pub async fn cloud_kms_client() -> Result<CloudKMS<HttpsConnector<HttpConnector>
     Ok(gcp_kms)
 }
 
+pub type CloudStorageClient = Storage<HttpsConnector<HttpConnector>>;
+
+pub async fn cloud_storage_client() -> Result<CloudStorageClient, std::io::Error> {
+    let opts = yup_oauth2::ApplicationDefaultCredentialsFlowOpts::default();
+    let gcp_auth = match yup_oauth2::ApplicationDefaultCredentialsAuthenticator::builder(opts).await
+    {
+        yup_oauth2::authenticator::ApplicationDefaultCredentialsTypes::ServiceAccount(auth) => {
+            tracing::debug!("Create GCP service account based credentials");
+
+            auth.build().await.map_err(|err| {
+                tracing::error!(
+                    ?err,
+                    "Failed to construct Cloud Storage credentials from service account"
+                );
+                err
+            })?
+        }
+        yup_oauth2::authenticator::ApplicationDefaultCredentialsTypes::InstanceMetadata(auth) => {
+            tracing::debug!("Create GCP instance based credentials");
+
+            auth.build().await.map_err(|err| {
+                tracing::error!(
+                    ?err,
+                    "Failed to construct Cloud Storage credentials from instance metadata"
+                );
+                err
+            })?
+        }
+    };
+
+    Ok(Storage::new(
+        hyper::Client::builder().build(
+            hyper_rustls::HttpsConnectorBuilder::new()
+                .with_native_roots()
+                .https_only()
+                .enable_http2()
+                .build(),
+        ),
+        gcp_auth,
+    ))
+}
+
 #[cfg(test)]
 pub mod tests {
     use dropshot::{HttpCodedResponse, HttpError};
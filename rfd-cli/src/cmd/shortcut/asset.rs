@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use clap::Parser;
+
+use crate::Context;
+
+#[derive(Debug, Parser)]
+/// Upload an image or other attachment to a RFD
+pub struct UploadAsset {
+    /// The RFD number to upload the file to (examples: 1 or 123)
+    #[clap(index = 1)]
+    number: String,
+    /// The local file to upload
+    #[clap(index = 2)]
+    file: PathBuf,
+    /// Path to store the file at, relative to the RFD directory. Defaults to the name of the file
+    #[clap(long)]
+    path: Option<String>,
+    /// Optional Git commit message to send with this update (recommended)
+    #[clap(long)]
+    message: Option<String>,
+    /// Optional commit that this upload was made against. If the RFD has been updated since this
+    /// commit, the upload will be rejected
+    #[clap(long)]
+    expected_commit: Option<String>,
+}
+
+impl UploadAsset {
+    pub async fn run(&self, ctx: &mut Context) -> Result<()> {
+        let path = match &self.path {
+            Some(path) => path.clone(),
+            None => self
+                .file
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.to_string())
+                .ok_or_else(|| anyhow!("Unable to determine a path for {:?}", self.file))?,
+        };
+        let content = BASE64_STANDARD.encode(std::fs::read(&self.file)?);
+
+        let response = ctx
+            .client()?
+            .upload_rfd_asset()
+            .number(&self.number)
+            .body_map(|body| {
+                body.path(path)
+                    .content(content)
+                    .message(self.message.clone())
+                    .expected_commit(self.expected_commit.clone())
+            })
+            .send()
+            .await?;
+
+        println!("{}", response.into_inner().path);
+
+        Ok(())
+    }
+}
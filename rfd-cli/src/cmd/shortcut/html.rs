@@ -0,0 +1,53 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{io::Write, path::PathBuf};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use futures::TryStreamExt;
+
+use crate::Context;
+
+#[derive(Debug, Parser)]
+/// Render a RFD as sanitized HTML
+pub struct RenderHtml {
+    /// The RFD number to render (examples: 1 or 123)
+    #[clap(index = 1)]
+    number: String,
+    /// Render the RFD as of the given commit instead of the latest revision
+    #[clap(long)]
+    commit: Option<String>,
+    /// File to write the rendered document to. Defaults to stdout
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+}
+
+impl RenderHtml {
+    pub async fn run(&self, ctx: &mut Context) -> Result<()> {
+        let mut request = ctx.client()?.get_rfd_html().number(&self.number);
+
+        if let Some(commit) = &self.commit {
+            request = request.commit(commit);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|err| anyhow!("Failed to render RFD {}: {}", self.number, err))?;
+
+        let mut stream = response.into_inner().into_inner();
+        let mut html = vec![];
+        while let Some(chunk) = stream.try_next().await? {
+            html.extend_from_slice(&chunk);
+        }
+
+        match &self.output {
+            Some(path) => std::fs::write(path, html)?,
+            None => std::io::stdout().write_all(&html)?,
+        }
+
+        Ok(())
+    }
+}
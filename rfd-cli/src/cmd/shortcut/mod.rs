@@ -7,8 +7,14 @@ use clap::{Parser, Subcommand};
 
 use crate::Context;
 
-use self::mapper::{EmailMapper, GitHubMapper};
-
+use self::{
+    asset::UploadAsset,
+    html::RenderHtml,
+    mapper::{EmailMapper, GitHubMapper},
+};
+
+mod asset;
+mod html;
 mod mapper;
 
 #[derive(Debug, Parser)]
@@ -24,6 +30,10 @@ pub enum Shortcut {
     EmailMapper(EmailMapper),
     #[command(name = "github-mapper")]
     GitHubMapper(GitHubMapper),
+    #[command(name = "upload")]
+    UploadAsset(UploadAsset),
+    #[command(name = "html")]
+    RenderHtml(RenderHtml),
 }
 
 impl ShortcutCmd {
@@ -31,6 +41,8 @@ impl ShortcutCmd {
         match &self.shortcut {
             Shortcut::EmailMapper(cmd) => cmd.run(ctx).await?,
             Shortcut::GitHubMapper(cmd) => cmd.run(ctx).await?,
+            Shortcut::UploadAsset(cmd) => cmd.run(ctx).await?,
+            Shortcut::RenderHtml(cmd) => cmd.run(ctx).await?,
         }
 
         Ok(())
@@ -20,6 +20,7 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::CreateApiUser => Self::cli_create_api_user(),
             CliCommand::GetApiUser => Self::cli_get_api_user(),
             CliCommand::UpdateApiUser => Self::cli_update_api_user(),
+            CliCommand::CreateApiUserFeedToken => Self::cli_create_api_user_feed_token(),
             CliCommand::AddApiUserToGroup => Self::cli_add_api_user_to_group(),
             CliCommand::RemoveApiUserFromGroup => Self::cli_remove_api_user_from_group(),
             CliCommand::ListApiUserTokens => Self::cli_list_api_user_tokens(),
@@ -31,6 +32,9 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::CreateGroup => Self::cli_create_group(),
             CliCommand::UpdateGroup => Self::cli_update_group(),
             CliCommand::DeleteGroup => Self::cli_delete_group(),
+            CliCommand::GetLabels => Self::cli_get_labels(),
+            CliCommand::UpdateLabel => Self::cli_update_label(),
+            CliCommand::DeleteLabel => Self::cli_delete_label(),
             CliCommand::LocalLogin => Self::cli_local_login(),
             CliCommand::AuthzCodeRedirect => Self::cli_authz_code_redirect(),
             CliCommand::AuthzCodeCallback => Self::cli_authz_code_callback(),
@@ -55,14 +59,34 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::ReserveRfd => Self::cli_reserve_rfd(),
             CliCommand::GetRfd => Self::cli_get_rfd(),
             CliCommand::SetRfdDocument => Self::cli_set_rfd_document(),
+            CliCommand::UploadRfdAsset => Self::cli_upload_rfd_asset(),
             CliCommand::GetRfdAttr => Self::cli_get_rfd_attr(),
             CliCommand::SetRfdAttr => Self::cli_set_rfd_attr(),
             CliCommand::SetRfdContent => Self::cli_set_rfd_content(),
             CliCommand::DiscussRfd => Self::cli_discuss_rfd(),
+            CliCommand::GetRfdHtml => Self::cli_get_rfd_html(),
+            CliCommand::AddRfdLabel => Self::cli_add_rfd_label(),
+            CliCommand::RemoveRfdLabel => Self::cli_remove_rfd_label(),
             CliCommand::PublishRfd => Self::cli_publish_rfd(),
+            CliCommand::RevertRfd => Self::cli_revert_rfd(),
+            CliCommand::GetRfdSections => Self::cli_get_rfd_sections(),
+            CliCommand::GetRfdSection => Self::cli_get_rfd_section(),
+            CliCommand::SetRfdSection => Self::cli_set_rfd_section(),
+            CliCommand::GetRfdTimeline => Self::cli_get_rfd_timeline(),
+            CliCommand::GetRfdTransitions => Self::cli_get_rfd_transitions(),
+            CliCommand::TransitionRfd => Self::cli_transition_rfd(),
             CliCommand::UpdateRfdVisibility => Self::cli_update_rfd_visibility(),
+            CliCommand::GetRfdBatch => Self::cli_get_rfd_batch(),
             CliCommand::SearchRfds => Self::cli_search_rfds(),
+            CliCommand::SuggestRfds => Self::cli_suggest_rfds(),
+            CliCommand::GetRfdTemplates => Self::cli_get_rfd_templates(),
             CliCommand::GetSelf => Self::cli_get_self(),
+            CliCommand::GetWebhookSubscriptions => Self::cli_get_webhook_subscriptions(),
+            CliCommand::CreateWebhookSubscription => Self::cli_create_webhook_subscription(),
+            CliCommand::GetWebhookSubscription => Self::cli_get_webhook_subscription(),
+            CliCommand::UpdateWebhookSubscription => Self::cli_update_webhook_subscription(),
+            CliCommand::DeleteWebhookSubscription => Self::cli_delete_webhook_subscription(),
+            CliCommand::GetWebhookDeliveries => Self::cli_get_webhook_deliveries(),
         }
     }
 
@@ -129,6 +153,36 @@ impl<T: CliConfig> Cli<T> {
             .about("Update the permissions assigned to a given user")
     }
 
+    pub fn cli_create_api_user_feed_token() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("expires-at")
+                    .long("expires-at")
+                    .value_parser(clap::value_parser!(chrono::DateTime<chrono::offset::Utc>))
+                    .required_unless_present("json-body"),
+            )
+            .arg(
+                clap::Arg::new("identifier")
+                    .long("identifier")
+                    .value_parser(clap::value_parser!(uuid::Uuid))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+    }
+
     pub fn cli_add_api_user_to_group() -> clap::Command {
         clap::Command::new("")
             .arg(
@@ -339,6 +393,63 @@ impl<T: CliConfig> Cli<T> {
         )
     }
 
+    pub fn cli_get_labels() -> clap::Command {
+        clap::Command::new("").about(
+            "List the labels in the label taxonomy along with the number of RFDs using each label",
+        )
+    }
+
+    pub fn cli_update_label() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("color")
+                    .long("color")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("description")
+                    .long("description")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("name")
+                    .long("name")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Add a label to the label taxonomy, or update the description and color of an \
+                 existing label",
+            )
+    }
+
+    pub fn cli_delete_label() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("name")
+                    .long("name")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .about("Remove a label from the label taxonomy")
+    }
+
     pub fn cli_local_login() -> clap::Command {
         clap::Command::new("")
             .arg(
@@ -738,6 +849,30 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("Optional contents of the RFD"),
             )
+            .arg(
+                clap::Arg::new("format")
+                    .long("format")
+                    .value_parser(clap::builder::TypedValueParser::map(
+                        clap::builder::PossibleValuesParser::new([
+                            types::ContentFormat::Asciidoc.to_string(),
+                            types::ContentFormat::Markdown.to_string(),
+                        ]),
+                        |s| types::ContentFormat::try_from(s).unwrap(),
+                    ))
+                    .required(false)
+                    .help("Format to write the RFD in. Defaults to AsciiDoc"),
+            )
+            .arg(
+                clap::Arg::new("template")
+                    .long("template")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional name of the template to create the RFD from. Defaults to the \
+                         \"new\" template when contents are supplied and the \"placeholder\" \
+                         template otherwise",
+                    ),
+            )
             .arg(
                 clap::Arg::new("title")
                     .long("title")
@@ -783,6 +918,17 @@ impl<T: CliConfig> Cli<T> {
                     .required_unless_present("json-body")
                     .help("Full Asciidoc document to store for this RFD"),
             )
+            .arg(
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
             .arg(
                 clap::Arg::new("message")
                     .long("message")
@@ -814,6 +960,67 @@ impl<T: CliConfig> Cli<T> {
             .about("Replace the full document of a RFD")
     }
 
+    pub fn cli_upload_rfd_asset() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("content")
+                    .long("content")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help("Base64 encoded contents of the asset"),
+            )
+            .arg(
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("message")
+                    .long("message")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help("Optional Git commit message to send with this update (recommended)"),
+            )
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                clap::Arg::new("path")
+                    .long("path")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help(
+                        "Path to store the asset at, relative to the RFD directory (examples: \
+                         diagram.svg or images/diagram.svg)",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Upload an image or other attachment to a RFD")
+    }
+
     pub fn cli_get_rfd_attr() -> clap::Command {
         clap::Command::new("")
             .arg(
@@ -853,6 +1060,17 @@ impl<T: CliConfig> Cli<T> {
                     ))
                     .required(true),
             )
+            .arg(
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
             .arg(
                 clap::Arg::new("message")
                     .long("message")
@@ -899,6 +1117,17 @@ impl<T: CliConfig> Cli<T> {
                     .required_unless_present("json-body")
                     .help("Asciidoc content to store for this RFD"),
             )
+            .arg(
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
             .arg(
                 clap::Arg::new("message")
                     .long("message")
@@ -942,8 +1171,15 @@ impl<T: CliConfig> Cli<T> {
             .about("Open a RFD for discussion")
     }
 
-    pub fn cli_publish_rfd() -> clap::Command {
+    pub fn cli_get_rfd_html() -> clap::Command {
         clap::Command::new("")
+            .arg(
+                clap::Arg::new("commit")
+                    .long("commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help("Render the RFD as of the given commit instead of the latest revision"),
+            )
             .arg(
                 clap::Arg::new("number")
                     .long("number")
@@ -951,10 +1187,44 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The RFD number (examples: 1 or 123)"),
             )
-            .about("Publish a RFD")
+            .about("Get a RFD rendered as sanitized HTML")
     }
 
-    pub fn cli_update_rfd_visibility() -> clap::Command {
+    pub fn cli_add_rfd_label() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("label")
+                    .long("label")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .about("Add a label to a RFD")
+    }
+
+    pub fn cli_remove_rfd_label() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("label")
+                    .long("label")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .about("Remove a label from a RFD")
+    }
+
+    pub fn cli_publish_rfd() -> clap::Command {
         clap::Command::new("")
             .arg(
                 clap::Arg::new("number")
@@ -963,17 +1233,35 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The RFD number (examples: 1 or 123)"),
             )
+            .about("Publish a RFD")
+    }
+
+    pub fn cli_revert_rfd() -> clap::Command {
+        clap::Command::new("")
             .arg(
-                clap::Arg::new("visibility")
-                    .long("visibility")
-                    .value_parser(clap::builder::TypedValueParser::map(
-                        clap::builder::PossibleValuesParser::new([
-                            types::Visibility::Public.to_string(),
-                            types::Visibility::Private.to_string(),
-                        ]),
-                        |s| types::Visibility::try_from(s).unwrap(),
-                    ))
-                    .required_unless_present("json-body"),
+                clap::Arg::new("commit")
+                    .long("commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help("Commit from the history of the RFD to restore the document to"),
+            )
+            .arg(
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
             )
             .arg(
                 clap::Arg::new("json-body")
@@ -989,116 +1277,1204 @@ impl<T: CliConfig> Cli<T> {
                     .action(clap::ArgAction::SetTrue)
                     .help("XXX"),
             )
-            .about("Modify the visibility of a RFD")
+            .about("Restore a RFD to the document stored at a previous commit")
     }
 
-    pub fn cli_search_rfds() -> clap::Command {
+    pub fn cli_get_rfd_sections() -> clap::Command {
         clap::Command::new("")
             .arg(
-                clap::Arg::new("attributes-to-crop")
-                    .long("attributes-to-crop")
+                clap::Arg::new("number")
+                    .long("number")
                     .value_parser(clap::value_parser!(String))
-                    .required(false),
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
             )
+            .about("Get the table of contents of a RFD")
+    }
+
+    pub fn cli_get_rfd_section() -> clap::Command {
+        clap::Command::new("")
             .arg(
-                clap::Arg::new("highlight-post-tag")
-                    .long("highlight-post-tag")
+                clap::Arg::new("anchor")
+                    .long("anchor")
                     .value_parser(clap::value_parser!(String))
-                    .required(false),
+                    .required(true),
             )
             .arg(
-                clap::Arg::new("highlight-pre-tag")
-                    .long("highlight-pre-tag")
+                clap::Arg::new("number")
+                    .long("number")
                     .value_parser(clap::value_parser!(String))
-                    .required(false),
+                    .required(true),
             )
+            .about("Get a single section of a RFD")
+    }
+
+    pub fn cli_set_rfd_section() -> clap::Command {
+        clap::Command::new("")
             .arg(
-                clap::Arg::new("limit")
-                    .long("limit")
-                    .value_parser(clap::value_parser!(u32))
-                    .required(false),
+                clap::Arg::new("anchor")
+                    .long("anchor")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
             )
             .arg(
-                clap::Arg::new("offset")
-                    .long("offset")
-                    .value_parser(clap::value_parser!(u32))
-                    .required(false),
+                clap::Arg::new("content")
+                    .long("content")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help(
+                        "Content to replace the body of the section with. The section heading and \
+                         any nested sections are left unchanged",
+                    ),
             )
             .arg(
-                clap::Arg::new("q")
-                    .long("q")
+                clap::Arg::new("expected-commit")
+                    .long("expected-commit")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional commit that this update was made against. If the RFD has been \
+                         updated since this commit, the update will be rejected. May also be \
+                         supplied as an If-Match header",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("message")
+                    .long("message")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help("Optional Git commit message to send with this update (recommended)"),
+            )
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Replace the body of a single section of a RFD")
+    }
+
+    pub fn cli_get_rfd_timeline() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Get the lifecycle timeline of a RFD, derived from its revision history")
+    }
+
+    pub fn cli_get_rfd_transitions() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("List the state transitions that have been made to a RFD")
+    }
+
+    pub fn cli_transition_rfd() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                clap::Arg::new("reason")
+                    .long("reason")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Optional explanation for the transition. This is included in the Git \
+                         commit message",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("state")
+                    .long("state")
+                    .value_parser(clap::builder::TypedValueParser::map(
+                        clap::builder::PossibleValuesParser::new([
+                            types::RfdState::Abandoned.to_string(),
+                            types::RfdState::Committed.to_string(),
+                            types::RfdState::Discussion.to_string(),
+                            types::RfdState::Ideation.to_string(),
+                            types::RfdState::Prediscussion.to_string(),
+                            types::RfdState::Published.to_string(),
+                        ]),
+                        |s| types::RfdState::try_from(s).unwrap(),
+                    ))
+                    .required_unless_present("json-body")
+                    .help("State to move the RFD to"),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Move a RFD to a new state")
+    }
+
+    pub fn cli_update_rfd_visibility() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                clap::Arg::new("visibility")
+                    .long("visibility")
+                    .value_parser(clap::builder::TypedValueParser::map(
+                        clap::builder::PossibleValuesParser::new([
+                            types::Visibility::Public.to_string(),
+                            types::Visibility::Private.to_string(),
+                        ]),
+                        |s| types::Visibility::try_from(s).unwrap(),
+                    ))
+                    .required_unless_present("json-body"),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Modify the visibility of a RFD")
+    }
+
+    pub fn cli_get_rfd_batch() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("fields")
+                    .long("fields")
+                    .value_parser(clap::builder::TypedValueParser::map(
+                        clap::builder::PossibleValuesParser::new([
+                            types::RfdBatchFields::Summary.to_string(),
+                            types::RfdBatchFields::Full.to_string(),
+                        ]),
+                        |s| types::RfdBatchFields::try_from(s).unwrap(),
+                    ))
+                    .required(false)
+                    .help(
+                        "The fields to return for each RFD. Summaries contain the fields that are \
+                         returned when listing RFDs, while full RFDs also include their content. \
+                         Defaults to summaries",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(true)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Get the latest representation of many RFDs at once. RFDs that do not exist or \
+                 that the caller is not allowed to access are omitted from the response",
+            )
+    }
+
+    pub fn cli_search_rfds() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("attributes-to-crop")
+                    .long("attributes-to-crop")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("highlight-post-tag")
+                    .long("highlight-post-tag")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("highlight-pre-tag")
+                    .long("highlight-pre-tag")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(clap::value_parser!(u32))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(clap::value_parser!(u32))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("q")
+                    .long("q")
                     .value_parser(clap::value_parser!(String))
                     .required(true),
             )
             .about("Search the RFD index and get a list of results")
     }
 
-    pub fn cli_get_self() -> clap::Command {
-        clap::Command::new("").about("Retrieve the user information of the calling user")
+    pub fn cli_suggest_rfds() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(clap::value_parser!(u32))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("q")
+                    .long("q")
+                    .value_parser(clap::value_parser!(String))
+                    .required(true),
+            )
+            .about(
+                "Get a list of RFDs whose number, title, or section headings match the start of a \
+                 query",
+            )
+    }
+
+    pub fn cli_get_rfd_templates() -> clap::Command {
+        clap::Command::new("").about("List the templates that can be used to create a new RFD")
+    }
+
+    pub fn cli_get_self() -> clap::Command {
+        clap::Command::new("").about("Retrieve the user information of the calling user")
+    }
+
+    pub fn cli_get_webhook_subscriptions() -> clap::Command {
+        clap::Command::new("").about("List the configured webhook subscriptions")
+    }
+
+    pub fn cli_create_webhook_subscription() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("secret")
+                    .long("secret")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help(
+                        "Shared secret used to compute the X-RFD-Signature-256 header of each \
+                         delivery",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("url")
+                    .long("url")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body")
+                    .help(
+                        "Https endpoint that events are POSTed to. The endpoint must resolve to a \
+                         public address",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(true)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Subscribe an external endpoint to RFD events")
+    }
+
+    pub fn cli_get_webhook_subscription() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("identifier")
+                    .long("identifier")
+                    .value_parser(clap::value_parser!(uuid::Uuid))
+                    .required(true),
+            )
+            .about("Get a webhook subscription")
+    }
+
+    pub fn cli_update_webhook_subscription() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("identifier")
+                    .long("identifier")
+                    .value_parser(clap::value_parser!(uuid::Uuid))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("secret")
+                    .long("secret")
+                    .value_parser(clap::value_parser!(String))
+                    .required(false)
+                    .help(
+                        "Replacement signing secret. The existing secret is kept when this is \
+                         omitted",
+                    ),
+            )
+            .arg(
+                clap::Arg::new("url")
+                    .long("url")
+                    .value_parser(clap::value_parser!(String))
+                    .required_unless_present("json-body"),
+            )
+            .arg(
+                clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(true)
+                    .value_parser(clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Replace the endpoint, filters, and optionally the secret of a webhook \
+                 subscription",
+            )
+    }
+
+    pub fn cli_delete_webhook_subscription() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("identifier")
+                    .long("identifier")
+                    .value_parser(clap::value_parser!(uuid::Uuid))
+                    .required(true),
+            )
+            .about(
+                "Delete a webhook subscription. Queued deliveries for the subscription are not \
+                 sent",
+            )
+    }
+
+    pub fn cli_get_webhook_deliveries() -> clap::Command {
+        clap::Command::new("")
+            .arg(
+                clap::Arg::new("identifier")
+                    .long("identifier")
+                    .value_parser(clap::value_parser!(uuid::Uuid))
+                    .required(true),
+            )
+            .arg(
+                clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(clap::value_parser!(u32))
+                    .required(false),
+            )
+            .arg(
+                clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(clap::value_parser!(u32))
+                    .required(false),
+            )
+            .about("List the delivery log of a webhook subscription, newest first")
+    }
+
+    pub async fn execute(&self, cmd: CliCommand, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        match cmd {
+            CliCommand::JwksJson => self.execute_jwks_json(matches).await,
+            CliCommand::OpenidConfiguration => self.execute_openid_configuration(matches).await,
+            CliCommand::CreateApiUser => self.execute_create_api_user(matches).await,
+            CliCommand::GetApiUser => self.execute_get_api_user(matches).await,
+            CliCommand::UpdateApiUser => self.execute_update_api_user(matches).await,
+            CliCommand::CreateApiUserFeedToken => {
+                self.execute_create_api_user_feed_token(matches).await
+            }
+            CliCommand::AddApiUserToGroup => self.execute_add_api_user_to_group(matches).await,
+            CliCommand::RemoveApiUserFromGroup => {
+                self.execute_remove_api_user_from_group(matches).await
+            }
+            CliCommand::ListApiUserTokens => self.execute_list_api_user_tokens(matches).await,
+            CliCommand::CreateApiUserToken => self.execute_create_api_user_token(matches).await,
+            CliCommand::GetApiUserToken => self.execute_get_api_user_token(matches).await,
+            CliCommand::DeleteApiUserToken => self.execute_delete_api_user_token(matches).await,
+            CliCommand::GithubWebhook => self.execute_github_webhook(matches).await,
+            CliCommand::GetGroups => self.execute_get_groups(matches).await,
+            CliCommand::CreateGroup => self.execute_create_group(matches).await,
+            CliCommand::UpdateGroup => self.execute_update_group(matches).await,
+            CliCommand::DeleteGroup => self.execute_delete_group(matches).await,
+            CliCommand::GetLabels => self.execute_get_labels(matches).await,
+            CliCommand::UpdateLabel => self.execute_update_label(matches).await,
+            CliCommand::DeleteLabel => self.execute_delete_label(matches).await,
+            CliCommand::LocalLogin => self.execute_local_login(matches).await,
+            CliCommand::AuthzCodeRedirect => self.execute_authz_code_redirect(matches).await,
+            CliCommand::AuthzCodeCallback => self.execute_authz_code_callback(matches).await,
+            CliCommand::AuthzCodeExchange => self.execute_authz_code_exchange(matches).await,
+            CliCommand::GetDeviceProvider => self.execute_get_device_provider(matches).await,
+            CliCommand::ExchangeDeviceToken => self.execute_exchange_device_token(matches).await,
+            CliCommand::GetMappers => self.execute_get_mappers(matches).await,
+            CliCommand::CreateMapper => self.execute_create_mapper(matches).await,
+            CliCommand::DeleteMapper => self.execute_delete_mapper(matches).await,
+            CliCommand::ListOauthClients => self.execute_list_oauth_clients(matches).await,
+            CliCommand::CreateOauthClient => self.execute_create_oauth_client(matches).await,
+            CliCommand::GetOauthClient => self.execute_get_oauth_client(matches).await,
+            CliCommand::CreateOauthClientRedirectUri => {
+                self.execute_create_oauth_client_redirect_uri(matches).await
+            }
+            CliCommand::DeleteOauthClientRedirectUri => {
+                self.execute_delete_oauth_client_redirect_uri(matches).await
+            }
+            CliCommand::CreateOauthClientSecret => {
+                self.execute_create_oauth_client_secret(matches).await
+            }
+            CliCommand::DeleteOauthClientSecret => {
+                self.execute_delete_oauth_client_secret(matches).await
+            }
+            CliCommand::GetRfds => self.execute_get_rfds(matches).await,
+            CliCommand::ReserveRfd => self.execute_reserve_rfd(matches).await,
+            CliCommand::GetRfd => self.execute_get_rfd(matches).await,
+            CliCommand::SetRfdDocument => self.execute_set_rfd_document(matches).await,
+            CliCommand::UploadRfdAsset => self.execute_upload_rfd_asset(matches).await,
+            CliCommand::GetRfdAttr => self.execute_get_rfd_attr(matches).await,
+            CliCommand::SetRfdAttr => self.execute_set_rfd_attr(matches).await,
+            CliCommand::SetRfdContent => self.execute_set_rfd_content(matches).await,
+            CliCommand::DiscussRfd => self.execute_discuss_rfd(matches).await,
+            CliCommand::GetRfdHtml => self.execute_get_rfd_html(matches).await,
+            CliCommand::AddRfdLabel => self.execute_add_rfd_label(matches).await,
+            CliCommand::RemoveRfdLabel => self.execute_remove_rfd_label(matches).await,
+            CliCommand::PublishRfd => self.execute_publish_rfd(matches).await,
+            CliCommand::RevertRfd => self.execute_revert_rfd(matches).await,
+            CliCommand::GetRfdSections => self.execute_get_rfd_sections(matches).await,
+            CliCommand::GetRfdSection => self.execute_get_rfd_section(matches).await,
+            CliCommand::SetRfdSection => self.execute_set_rfd_section(matches).await,
+            CliCommand::GetRfdTimeline => self.execute_get_rfd_timeline(matches).await,
+            CliCommand::GetRfdTransitions => self.execute_get_rfd_transitions(matches).await,
+            CliCommand::TransitionRfd => self.execute_transition_rfd(matches).await,
+            CliCommand::UpdateRfdVisibility => self.execute_update_rfd_visibility(matches).await,
+            CliCommand::GetRfdBatch => self.execute_get_rfd_batch(matches).await,
+            CliCommand::SearchRfds => self.execute_search_rfds(matches).await,
+            CliCommand::SuggestRfds => self.execute_suggest_rfds(matches).await,
+            CliCommand::GetRfdTemplates => self.execute_get_rfd_templates(matches).await,
+            CliCommand::GetSelf => self.execute_get_self(matches).await,
+            CliCommand::GetWebhookSubscriptions => {
+                self.execute_get_webhook_subscriptions(matches).await
+            }
+            CliCommand::CreateWebhookSubscription => {
+                self.execute_create_webhook_subscription(matches).await
+            }
+            CliCommand::GetWebhookSubscription => {
+                self.execute_get_webhook_subscription(matches).await
+            }
+            CliCommand::UpdateWebhookSubscription => {
+                self.execute_update_webhook_subscription(matches).await
+            }
+            CliCommand::DeleteWebhookSubscription => {
+                self.execute_delete_webhook_subscription(matches).await
+            }
+            CliCommand::GetWebhookDeliveries => self.execute_get_webhook_deliveries(matches).await,
+        }
+    }
+
+    pub async fn execute_jwks_json(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.jwks_json();
+        self.config.execute_jwks_json(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_openid_configuration(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.openid_configuration();
+        self.config
+            .execute_openid_configuration(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.create_api_user();
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::ApiUserUpdateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_create_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_api_user();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        self.config.execute_get_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_update_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.update_api_user();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::ApiUserUpdateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_update_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_api_user_feed_token(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_api_user_feed_token();
+        if let Some(value) = matches.get_one::<chrono::DateTime<chrono::offset::Utc>>("expires-at")
+        {
+            request = request.body_map(|body| body.expires_at(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::FeedTokenCreateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_create_api_user_feed_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_add_api_user_to_group(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.add_api_user_to_group();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
+            request = request.body_map(|body| body.group_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::AddGroupBody>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_add_api_user_to_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_remove_api_user_from_group(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.remove_api_user_from_group();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        self.config
+            .execute_remove_api_user_from_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_list_api_user_tokens(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.list_api_user_tokens();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        self.config
+            .execute_list_api_user_tokens(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_api_user_token(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_api_user_token();
+        if let Some(value) = matches.get_one::<chrono::DateTime<chrono::offset::Utc>>("expires-at")
+        {
+            request = request.body_map(|body| body.expires_at(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::ApiKeyCreateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_create_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_api_user_token(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_api_user_token();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("token-identifier") {
+            request = request.token_identifier(value.clone());
+        }
+
+        self.config
+            .execute_get_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_delete_api_user_token(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.delete_api_user_token();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("token-identifier") {
+            request = request.token_identifier(value.clone());
+        }
+
+        self.config
+            .execute_delete_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_github_webhook(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.github_webhook();
+        if let Some(value) = matches.get_one::<String>("ref") {
+            request = request.body_map(|body| body.ref_(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::GitHubCommitPayload>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_github_webhook(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_groups(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_groups();
+        self.config.execute_get_groups(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.create_group();
+        if let Some(value) = matches.get_one::<String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::AccessGroupUpdateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_create_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_update_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.update_group();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::AccessGroupUpdateParams>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_update_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_delete_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.delete_group();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        self.config.execute_delete_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
     }
 
-    pub async fn execute(&self, cmd: CliCommand, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        match cmd {
-            CliCommand::JwksJson => self.execute_jwks_json(matches).await,
-            CliCommand::OpenidConfiguration => self.execute_openid_configuration(matches).await,
-            CliCommand::CreateApiUser => self.execute_create_api_user(matches).await,
-            CliCommand::GetApiUser => self.execute_get_api_user(matches).await,
-            CliCommand::UpdateApiUser => self.execute_update_api_user(matches).await,
-            CliCommand::AddApiUserToGroup => self.execute_add_api_user_to_group(matches).await,
-            CliCommand::RemoveApiUserFromGroup => {
-                self.execute_remove_api_user_from_group(matches).await
+    pub async fn execute_get_labels(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_labels();
+        self.config.execute_get_labels(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
             }
-            CliCommand::ListApiUserTokens => self.execute_list_api_user_tokens(matches).await,
-            CliCommand::CreateApiUserToken => self.execute_create_api_user_token(matches).await,
-            CliCommand::GetApiUserToken => self.execute_get_api_user_token(matches).await,
-            CliCommand::DeleteApiUserToken => self.execute_delete_api_user_token(matches).await,
-            CliCommand::GithubWebhook => self.execute_github_webhook(matches).await,
-            CliCommand::GetGroups => self.execute_get_groups(matches).await,
-            CliCommand::CreateGroup => self.execute_create_group(matches).await,
-            CliCommand::UpdateGroup => self.execute_update_group(matches).await,
-            CliCommand::DeleteGroup => self.execute_delete_group(matches).await,
-            CliCommand::LocalLogin => self.execute_local_login(matches).await,
-            CliCommand::AuthzCodeRedirect => self.execute_authz_code_redirect(matches).await,
-            CliCommand::AuthzCodeCallback => self.execute_authz_code_callback(matches).await,
-            CliCommand::AuthzCodeExchange => self.execute_authz_code_exchange(matches).await,
-            CliCommand::GetDeviceProvider => self.execute_get_device_provider(matches).await,
-            CliCommand::ExchangeDeviceToken => self.execute_exchange_device_token(matches).await,
-            CliCommand::GetMappers => self.execute_get_mappers(matches).await,
-            CliCommand::CreateMapper => self.execute_create_mapper(matches).await,
-            CliCommand::DeleteMapper => self.execute_delete_mapper(matches).await,
-            CliCommand::ListOauthClients => self.execute_list_oauth_clients(matches).await,
-            CliCommand::CreateOauthClient => self.execute_create_oauth_client(matches).await,
-            CliCommand::GetOauthClient => self.execute_get_oauth_client(matches).await,
-            CliCommand::CreateOauthClientRedirectUri => {
-                self.execute_create_oauth_client_redirect_uri(matches).await
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
             }
-            CliCommand::DeleteOauthClientRedirectUri => {
-                self.execute_delete_oauth_client_redirect_uri(matches).await
+        }
+    }
+
+    pub async fn execute_update_label(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.update_label();
+        if let Some(value) = matches.get_one::<String>("color") {
+            request = request.body_map(|body| body.color(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("description") {
+            request = request.body_map(|body| body.description(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("name") {
+            request = request.name(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::UpdateLabel>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_update_label(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
             }
-            CliCommand::CreateOauthClientSecret => {
-                self.execute_create_oauth_client_secret(matches).await
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
             }
-            CliCommand::DeleteOauthClientSecret => {
-                self.execute_delete_oauth_client_secret(matches).await
+        }
+    }
+
+    pub async fn execute_delete_label(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.delete_label();
+        if let Some(value) = matches.get_one::<String>("name") {
+            request = request.name(value.clone());
+        }
+
+        self.config.execute_delete_label(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
             }
-            CliCommand::GetRfds => self.execute_get_rfds(matches).await,
-            CliCommand::ReserveRfd => self.execute_reserve_rfd(matches).await,
-            CliCommand::GetRfd => self.execute_get_rfd(matches).await,
-            CliCommand::SetRfdDocument => self.execute_set_rfd_document(matches).await,
-            CliCommand::GetRfdAttr => self.execute_get_rfd_attr(matches).await,
-            CliCommand::SetRfdAttr => self.execute_set_rfd_attr(matches).await,
-            CliCommand::SetRfdContent => self.execute_set_rfd_content(matches).await,
-            CliCommand::DiscussRfd => self.execute_discuss_rfd(matches).await,
-            CliCommand::PublishRfd => self.execute_publish_rfd(matches).await,
-            CliCommand::UpdateRfdVisibility => self.execute_update_rfd_visibility(matches).await,
-            CliCommand::SearchRfds => self.execute_search_rfds(matches).await,
-            CliCommand::GetSelf => self.execute_get_self(matches).await,
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_local_login(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.local_login();
+        if let Some(value) = matches.get_one::<String>("email") {
+            request = request.body_map(|body| body.email(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("external-id") {
+            request = request.body_map(|body| body.external_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::LocalLogin>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_local_login(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
+        }
+    }
+
+    pub async fn execute_authz_code_redirect(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.authz_code_redirect();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("redirect-uri") {
+            request = request.redirect_uri(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("response-type") {
+            request = request.response_type(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("scope") {
+            request = request.scope(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("state") {
+            request = request.state(value.clone());
+        }
+
+        self.config
+            .execute_authz_code_redirect(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
+        }
+    }
+
+    pub async fn execute_authz_code_callback(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.authz_code_callback();
+        if let Some(value) = matches.get_one::<String>("code") {
+            request = request.code(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("error") {
+            request = request.error(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("state") {
+            request = request.state(value.clone());
+        }
+
+        self.config
+            .execute_authz_code_callback(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_authz_code_exchange(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.authz_code_exchange();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.body_map(|body| body.client_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::SecretString>("client-secret") {
+            request = request.body_map(|body| body.client_secret(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("code") {
+            request = request.body_map(|body| body.code(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("grant-type") {
+            request = request.body_map(|body| body.grant_type(value.clone()))
         }
-    }
 
-    pub async fn execute_jwks_json(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.jwks_json();
-        self.config.execute_jwks_json(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<String>("pkce-verifier") {
+            request = request.body_map(|body| body.pkce_verifier(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::OAuthAuthzCodeExchangeBody>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_authz_code_exchange(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1112,13 +2488,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_openid_configuration(
+    pub async fn execute_get_device_provider(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.openid_configuration();
+        let mut request = self.client.get_device_provider();
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
         self.config
-            .execute_openid_configuration(matches, &mut request)?;
+            .execute_get_device_provider(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1132,35 +2512,55 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.create_api_user();
+    pub async fn execute_exchange_device_token(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.exchange_device_token();
+        if let Some(value) = matches.get_one::<String>("device-code") {
+            request = request.body_map(|body| body.device_code(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<chrono::DateTime<chrono::offset::Utc>>("expires-at")
+        {
+            request = request.body_map(|body| body.expires_at(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("grant-type") {
+            request = request.body_map(|body| body.grant_type(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::ApiUserUpdateParams>(&body_txt).unwrap();
+            let body_value =
+                serde_json::from_str::<types::AccessTokenExchangeRequest>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_create_api_user(matches, &mut request)?;
+        self.config
+            .execute_exchange_device_token(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                self.config.item_success(&r);
-                Ok(())
+                todo!()
             }
             Err(r) => {
-                self.config.item_error(&r);
-                Err(anyhow::Error::new(r))
+                todo!()
             }
         }
     }
 
-    pub async fn execute_get_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_api_user();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
+    pub async fn execute_get_mappers(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_mappers();
+        if let Some(value) = matches.get_one::<bool>("include-depleted") {
+            request = request.include_depleted(value.clone());
         }
 
-        self.config.execute_get_api_user(matches, &mut request)?;
+        self.config.execute_get_mappers(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1174,19 +2574,23 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_api_user(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.update_api_user();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
+    pub async fn execute_create_mapper(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.create_mapper();
+        if let Some(value) = matches.get_one::<i32>("max-activations") {
+            request = request.body_map(|body| body.max_activations(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::ApiUserUpdateParams>(&body_txt).unwrap();
+            let body_value = serde_json::from_str::<types::CreateMapper>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_update_api_user(matches, &mut request)?;
+        self.config.execute_create_mapper(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1200,27 +2604,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_add_api_user_to_group(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.add_api_user_to_group();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
-            request = request.body_map(|body| body.group_id(value.clone()))
-        }
-
+    pub async fn execute_delete_mapper(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.delete_mapper();
         if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
             request = request.identifier(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::AddGroupBody>(&body_txt).unwrap();
-            request = request.body(body_value);
-        }
-
-        self.config
-            .execute_add_api_user_to_group(matches, &mut request)?;
+        self.config.execute_delete_mapper(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1234,21 +2624,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_remove_api_user_from_group(
+    pub async fn execute_list_oauth_clients(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.remove_api_user_from_group();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
-            request = request.group_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
-        }
-
+        let mut request = self.client.list_oauth_clients();
         self.config
-            .execute_remove_api_user_from_group(matches, &mut request)?;
+            .execute_list_oauth_clients(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1262,17 +2644,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_api_user_tokens(
+    pub async fn execute_create_oauth_client(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.list_api_user_tokens();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
-        }
-
+        let mut request = self.client.create_oauth_client();
         self.config
-            .execute_list_api_user_tokens(matches, &mut request)?;
+            .execute_create_oauth_client(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1286,28 +2664,14 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_api_user_token(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_api_user_token();
-        if let Some(value) = matches.get_one::<chrono::DateTime<chrono::offset::Utc>>("expires-at")
-        {
-            request = request.body_map(|body| body.expires_at(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::ApiKeyCreateParams>(&body_txt).unwrap();
-            request = request.body(body_value);
+    pub async fn execute_get_oauth_client(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_oauth_client();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
         }
 
         self.config
-            .execute_create_api_user_token(matches, &mut request)?;
+            .execute_get_oauth_client(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1321,21 +2685,28 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_api_user_token(
+    pub async fn execute_create_oauth_client_redirect_uri(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.get_api_user_token();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
+        let mut request = self.client.create_oauth_client_redirect_uri();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<uuid::Uuid>("token-identifier") {
-            request = request.token_identifier(value.clone());
+        if let Some(value) = matches.get_one::<String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::AddOAuthClientRedirectBody>(&body_txt).unwrap();
+            request = request.body(body_value);
         }
 
         self.config
-            .execute_get_api_user_token(matches, &mut request)?;
+            .execute_create_oauth_client_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1349,21 +2720,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_api_user_token(
+    pub async fn execute_delete_oauth_client_redirect_uri(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_api_user_token();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
+        let mut request = self.client.delete_oauth_client_redirect_uri();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<uuid::Uuid>("token-identifier") {
-            request = request.token_identifier(value.clone());
+        if let Some(value) = matches.get_one::<uuid::Uuid>("redirect-uri-id") {
+            request = request.redirect_uri_id(value.clone());
         }
 
         self.config
-            .execute_delete_api_user_token(matches, &mut request)?;
+            .execute_delete_oauth_client_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1377,19 +2748,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_github_webhook(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.github_webhook();
-        if let Some(value) = matches.get_one::<String>("ref") {
-            request = request.body_map(|body| body.ref_(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::GitHubCommitPayload>(&body_txt).unwrap();
-            request = request.body(body_value);
+    pub async fn execute_create_oauth_client_secret(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_oauth_client_secret();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        self.config.execute_github_webhook(matches, &mut request)?;
+        self.config
+            .execute_create_oauth_client_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1403,9 +2772,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_groups(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_groups();
-        self.config.execute_get_groups(matches, &mut request)?;
+    pub async fn execute_delete_oauth_client_secret(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.delete_oauth_client_secret();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<uuid::Uuid>("secret-id") {
+            request = request.secret_id(value.clone());
+        }
+
+        self.config
+            .execute_delete_oauth_client_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1414,25 +2795,14 @@ impl<T: CliConfig> Cli<T> {
             }
             Err(r) => {
                 self.config.item_error(&r);
-                Err(anyhow::Error::new(r))
-            }
-        }
-    }
-
-    pub async fn execute_create_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.create_group();
-        if let Some(value) = matches.get_one::<String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value =
-                serde_json::from_str::<types::AccessGroupUpdateParams>(&body_txt).unwrap();
-            request = request.body(body_value);
+                Err(anyhow::Error::new(r))
+            }
         }
+    }
 
-        self.config.execute_create_group(matches, &mut request)?;
+    pub async fn execute_get_rfds(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfds();
+        self.config.execute_get_rfds(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1446,24 +2816,31 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.update_group();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
-            request = request.group_id(value.clone());
+    pub async fn execute_reserve_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.reserve_rfd();
+        if let Some(value) = matches.get_one::<String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
+        if let Some(value) = matches.get_one::<types::ContentFormat>("format") {
+            request = request.body_map(|body| body.format(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("template") {
+            request = request.body_map(|body| body.template(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("title") {
+            request = request.body_map(|body| body.title(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value =
-                serde_json::from_str::<types::AccessGroupUpdateParams>(&body_txt).unwrap();
+            let body_value = serde_json::from_str::<types::ReserveRfdBody>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_update_group(matches, &mut request)?;
+        self.config.execute_reserve_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1477,13 +2854,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_group(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.delete_group();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("group-id") {
-            request = request.group_id(value.clone());
+    pub async fn execute_get_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config.execute_delete_group(matches, &mut request)?;
+        self.config.execute_get_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1497,103 +2874,104 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_local_login(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.local_login();
-        if let Some(value) = matches.get_one::<String>("email") {
-            request = request.body_map(|body| body.email(value.clone()))
+    pub async fn execute_set_rfd_document(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.set_rfd_document();
+        if let Some(value) = matches.get_one::<String>("document") {
+            request = request.body_map(|body| body.document(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("external-id") {
-            request = request.body_map(|body| body.external_id(value.clone()))
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::LocalLogin>(&body_txt).unwrap();
+            let body_value = serde_json::from_str::<types::RfdUpdateBody>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_local_login(matches, &mut request)?;
+        self.config
+            .execute_set_rfd_document(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.item_success(&r);
+                Ok(())
             }
             Err(r) => {
-                todo!()
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
             }
         }
     }
 
-    pub async fn execute_authz_code_redirect(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_redirect();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
+    pub async fn execute_upload_rfd_asset(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.upload_rfd_asset();
+        if let Some(value) = matches.get_one::<String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("redirect-uri") {
-            request = request.redirect_uri(value.clone());
+        if let Some(value) = matches.get_one::<String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("response-type") {
-            request = request.response_type(value.clone());
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("scope") {
-            request = request.scope(value.clone());
+        if let Some(value) = matches.get_one::<String>("path") {
+            request = request.body_map(|body| body.path(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("state") {
-            request = request.state(value.clone());
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::RfdAssetUploadBody>(&body_txt).unwrap();
+            request = request.body(body_value);
         }
 
         self.config
-            .execute_authz_code_redirect(matches, &mut request)?;
+            .execute_upload_rfd_asset(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.item_success(&r);
+                Ok(())
             }
             Err(r) => {
-                todo!()
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
             }
         }
     }
 
-    pub async fn execute_authz_code_callback(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_callback();
-        if let Some(value) = matches.get_one::<String>("code") {
-            request = request.code(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<String>("error") {
-            request = request.error(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+    pub async fn execute_get_rfd_attr(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_attr();
+        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
+            request = request.attr(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("state") {
-            request = request.state(value.clone());
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config
-            .execute_authz_code_callback(matches, &mut request)?;
+        self.config.execute_get_rfd_attr(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.item_success(&r);
+                Ok(())
             }
             Err(r) => {
                 self.config.item_error(&r);
@@ -1602,48 +2980,74 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_authz_code_exchange(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_exchange();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.body_map(|body| body.client_id(value.clone()))
+    pub async fn execute_set_rfd_attr(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.set_rfd_attr();
+        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
+            request = request.attr(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::SecretString>("client-secret") {
-            request = request.body_map(|body| body.client_secret(value.clone()))
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("code") {
-            request = request.body_map(|body| body.code(value.clone()))
+        if let Some(value) = matches.get_one::<String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("grant-type") {
-            request = request.body_map(|body| body.grant_type(value.clone()))
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("pkce-verifier") {
-            request = request.body_map(|body| body.pkce_verifier(value.clone()))
+        if let Some(value) = matches.get_one::<String>("value") {
+            request = request.body_map(|body| body.value(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::RfdAttrValue>(&body_txt).unwrap();
+            request = request.body(body_value);
         }
 
-        if let Some(value) = matches.get_one::<String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        self.config.execute_set_rfd_attr(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_set_rfd_content(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.set_rfd_content();
+        if let Some(value) = matches.get_one::<String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
             let body_value =
-                serde_json::from_str::<types::OAuthAuthzCodeExchangeBody>(&body_txt).unwrap();
+                serde_json::from_str::<types::RfdUpdateContentBody>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config
-            .execute_authz_code_exchange(matches, &mut request)?;
+        self.config.execute_set_rfd_content(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1657,17 +3061,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_device_provider(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.get_device_provider();
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+    pub async fn execute_discuss_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.discuss_rfd();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config
-            .execute_get_device_provider(matches, &mut request)?;
+        self.config.execute_discuss_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1681,37 +3081,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_exchange_device_token(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.exchange_device_token();
-        if let Some(value) = matches.get_one::<String>("device-code") {
-            request = request.body_map(|body| body.device_code(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<chrono::DateTime<chrono::offset::Utc>>("expires-at")
-        {
-            request = request.body_map(|body| body.expires_at(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<String>("grant-type") {
-            request = request.body_map(|body| body.grant_type(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+    pub async fn execute_get_rfd_html(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_html();
+        if let Some(value) = matches.get_one::<String>("commit") {
+            request = request.commit(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value =
-                serde_json::from_str::<types::AccessTokenExchangeRequest>(&body_txt).unwrap();
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config
-            .execute_exchange_device_token(matches, &mut request)?;
+        self.config.execute_get_rfd_html(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1723,13 +3103,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_mappers(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_mappers();
-        if let Some(value) = matches.get_one::<bool>("include-depleted") {
-            request = request.include_depleted(value.clone());
+    pub async fn execute_add_rfd_label(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.add_rfd_label();
+        if let Some(value) = matches.get_one::<String>("label") {
+            request = request.label(value.clone());
         }
 
-        self.config.execute_get_mappers(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_add_rfd_label(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1742,24 +3126,19 @@ impl<T: CliConfig> Cli<T> {
             }
         }
     }
-
-    pub async fn execute_create_mapper(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.create_mapper();
-        if let Some(value) = matches.get_one::<i32>("max-activations") {
-            request = request.body_map(|body| body.max_activations(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
+
+    pub async fn execute_remove_rfd_label(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.remove_rfd_label();
+        if let Some(value) = matches.get_one::<String>("label") {
+            request = request.label(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::CreateMapper>(&body_txt).unwrap();
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config.execute_create_mapper(matches, &mut request)?;
+        self.config
+            .execute_remove_rfd_label(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1773,13 +3152,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_mapper(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.delete_mapper();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
-            request = request.identifier(value.clone());
+    pub async fn execute_publish_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.publish_rfd();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config.execute_delete_mapper(matches, &mut request)?;
+        self.config.execute_publish_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1793,13 +3172,27 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_oauth_clients(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.list_oauth_clients();
-        self.config
-            .execute_list_oauth_clients(matches, &mut request)?;
+    pub async fn execute_revert_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.revert_rfd();
+        if let Some(value) = matches.get_one::<String>("commit") {
+            request = request.body_map(|body| body.commit(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::RfdRevertBody>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_revert_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1813,13 +3206,14 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_oauth_client(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client();
+    pub async fn execute_get_rfd_sections(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_sections();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
+        }
+
         self.config
-            .execute_create_oauth_client(matches, &mut request)?;
+            .execute_get_rfd_sections(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1833,14 +3227,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_oauth_client(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_oauth_client();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
+    pub async fn execute_get_rfd_section(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_section();
+        if let Some(value) = matches.get_one::<String>("anchor") {
+            request = request.anchor(value.clone());
         }
 
-        self.config
-            .execute_get_oauth_client(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_get_rfd_section(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1854,28 +3251,36 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_oauth_client_redirect_uri(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client_redirect_uri();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
+    pub async fn execute_set_rfd_section(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.set_rfd_section();
+        if let Some(value) = matches.get_one::<String>("anchor") {
+            request = request.anchor(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        if let Some(value) = matches.get_one::<String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("expected-commit") {
+            request = request.body_map(|body| body.expected_commit(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
             let body_value =
-                serde_json::from_str::<types::AddOAuthClientRedirectBody>(&body_txt).unwrap();
+                serde_json::from_str::<types::RfdSectionUpdateBody>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config
-            .execute_create_oauth_client_redirect_uri(matches, &mut request)?;
+        self.config.execute_set_rfd_section(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1889,21 +3294,14 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_oauth_client_redirect_uri(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_oauth_client_redirect_uri();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<uuid::Uuid>("redirect-uri-id") {
-            request = request.redirect_uri_id(value.clone());
+    pub async fn execute_get_rfd_timeline(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_timeline();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_delete_oauth_client_redirect_uri(matches, &mut request)?;
+            .execute_get_rfd_timeline(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1917,17 +3315,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_oauth_client_secret(
+    pub async fn execute_get_rfd_transitions(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client_secret();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.get_rfd_transitions();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_create_oauth_client_secret(matches, &mut request)?;
+            .execute_get_rfd_transitions(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1941,37 +3339,27 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_oauth_client_secret(
-        &self,
-        matches: &clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_oauth_client_secret();
-        if let Some(value) = matches.get_one::<uuid::Uuid>("client-id") {
-            request = request.client_id(value.clone());
+    pub async fn execute_transition_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.transition_rfd();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<uuid::Uuid>("secret-id") {
-            request = request.secret_id(value.clone());
+        if let Some(value) = matches.get_one::<String>("reason") {
+            request = request.body_map(|body| body.reason(value.clone()))
         }
 
-        self.config
-            .execute_delete_oauth_client_secret(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.item_success(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.item_error(&r);
-                Err(anyhow::Error::new(r))
-            }
+        if let Some(value) = matches.get_one::<types::RfdState>("state") {
+            request = request.body_map(|body| body.state(value.clone()))
         }
-    }
 
-    pub async fn execute_get_rfds(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_rfds();
-        self.config.execute_get_rfds(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::RfdTransitionBody>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_transition_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1985,23 +3373,27 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_reserve_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.reserve_rfd();
-        if let Some(value) = matches.get_one::<String>("content") {
-            request = request.body_map(|body| body.content(value.clone()))
+    pub async fn execute_update_rfd_visibility(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.update_rfd_visibility();
+        if let Some(value) = matches.get_one::<String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("title") {
-            request = request.body_map(|body| body.title(value.clone()))
+        if let Some(value) = matches.get_one::<types::Visibility>("visibility") {
+            request = request.body_map(|body| body.visibility(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::ReserveRfdBody>(&body_txt).unwrap();
+            let body_value = serde_json::from_str::<types::RfdVisibility>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_reserve_rfd(matches, &mut request)?;
+        self.config
+            .execute_update_rfd_visibility(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2015,13 +3407,19 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_rfd();
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_get_rfd_batch(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_batch();
+        if let Some(value) = matches.get_one::<types::RfdBatchFields>("fields") {
+            request = request.body_map(|body| body.fields(value.clone()))
         }
 
-        self.config.execute_get_rfd(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value = serde_json::from_str::<types::RfdBatchBody>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config.execute_get_rfd_batch(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2035,28 +3433,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_document(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_document();
-        if let Some(value) = matches.get_one::<String>("document") {
-            request = request.body_map(|body| body.document(value.clone()))
+    pub async fn execute_search_rfds(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.search_rfds();
+        if let Some(value) = matches.get_one::<String>("attributes-to-crop") {
+            request = request.attributes_to_crop(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
+        if let Some(value) = matches.get_one::<String>("highlight-post-tag") {
+            request = request.highlight_post_tag(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<String>("highlight-pre-tag") {
+            request = request.highlight_pre_tag(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::RfdUpdateBody>(&body_txt).unwrap();
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<u32>("limit") {
+            request = request.limit(value.clone());
         }
 
-        self.config
-            .execute_set_rfd_document(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<u32>("offset") {
+            request = request.offset(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<String>("q") {
+            request = request.q(value.clone());
+        }
+
+        self.config.execute_search_rfds(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2070,17 +3473,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_rfd_attr(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_rfd_attr();
-        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
-            request = request.attr(value.clone());
+    pub async fn execute_suggest_rfds(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.suggest_rfds();
+        if let Some(value) = matches.get_one::<u32>("limit") {
+            request = request.limit(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<String>("q") {
+            request = request.q(value.clone());
         }
 
-        self.config.execute_get_rfd_attr(matches, &mut request)?;
+        self.config.execute_suggest_rfds(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2094,31 +3497,49 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_attr(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_attr();
-        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
-            request = request.attr(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<String>("value") {
-            request = request.body_map(|body| body.value(value.clone()))
+    pub async fn execute_get_rfd_templates(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_rfd_templates();
+        self.config
+            .execute_get_rfd_templates(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
         }
+    }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::RfdAttrValue>(&body_txt).unwrap();
-            request = request.body(body_value);
+    pub async fn execute_get_self(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_self();
+        self.config.execute_get_self(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.item_success(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.item_error(&r);
+                Err(anyhow::Error::new(r))
+            }
         }
+    }
 
-        self.config.execute_set_rfd_attr(matches, &mut request)?;
+    pub async fn execute_get_webhook_subscriptions(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_webhook_subscriptions();
+        self.config
+            .execute_get_webhook_subscriptions(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2132,28 +3553,28 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_content(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_content();
-        if let Some(value) = matches.get_one::<String>("content") {
-            request = request.body_map(|body| body.content(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
+    pub async fn execute_create_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_webhook_subscription();
+        if let Some(value) = matches.get_one::<String>("secret") {
+            request = request.body_map(|body| body.secret(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<String>("url") {
+            request = request.body_map(|body| body.url(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value).unwrap();
             let body_value =
-                serde_json::from_str::<types::RfdUpdateContentBody>(&body_txt).unwrap();
+                serde_json::from_str::<types::CreateWebhookSubscription>(&body_txt).unwrap();
             request = request.body(body_value);
         }
 
-        self.config.execute_set_rfd_content(matches, &mut request)?;
+        self.config
+            .execute_create_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2167,13 +3588,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_discuss_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.discuss_rfd();
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_get_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_webhook_subscription();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
         }
 
-        self.config.execute_discuss_rfd(matches, &mut request)?;
+        self.config
+            .execute_get_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2187,13 +3612,32 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_publish_rfd(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.publish_rfd();
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_update_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.update_webhook_subscription();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
         }
 
-        self.config.execute_publish_rfd(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<String>("secret") {
+            request = request.body_map(|body| body.secret(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<String>("url") {
+            request = request.body_map(|body| body.url(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value).unwrap();
+            let body_value =
+                serde_json::from_str::<types::UpdateWebhookSubscription>(&body_txt).unwrap();
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_update_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2207,27 +3651,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_rfd_visibility(
+    pub async fn execute_delete_webhook_subscription(
         &self,
         matches: &clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.update_rfd_visibility();
-        if let Some(value) = matches.get_one::<String>("number") {
-            request = request.number(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::Visibility>("visibility") {
-            request = request.body_map(|body| body.visibility(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value).unwrap();
-            let body_value = serde_json::from_str::<types::RfdVisibility>(&body_txt).unwrap();
-            request = request.body(body_value);
+        let mut request = self.client.delete_webhook_subscription();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
         }
 
         self.config
-            .execute_update_rfd_visibility(matches, &mut request)?;
+            .execute_delete_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2241,18 +3675,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_search_rfds(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.search_rfds();
-        if let Some(value) = matches.get_one::<String>("attributes-to-crop") {
-            request = request.attributes_to_crop(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<String>("highlight-post-tag") {
-            request = request.highlight_post_tag(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<String>("highlight-pre-tag") {
-            request = request.highlight_pre_tag(value.clone());
+    pub async fn execute_get_webhook_deliveries(
+        &self,
+        matches: &clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_webhook_deliveries();
+        if let Some(value) = matches.get_one::<uuid::Uuid>("identifier") {
+            request = request.identifier(value.clone());
         }
 
         if let Some(value) = matches.get_one::<u32>("limit") {
@@ -2263,27 +3692,8 @@ impl<T: CliConfig> Cli<T> {
             request = request.offset(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<String>("q") {
-            request = request.q(value.clone());
-        }
-
-        self.config.execute_search_rfds(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.item_success(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.item_error(&r);
-                Err(anyhow::Error::new(r))
-            }
-        }
-    }
-
-    pub async fn execute_get_self(&self, matches: &clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_self();
-        self.config.execute_get_self(matches, &mut request)?;
+        self.config
+            .execute_get_webhook_deliveries(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2357,6 +3767,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_create_api_user_feed_token(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::CreateApiUserFeedToken,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_add_api_user_to_group(
         &self,
         matches: &clap::ArgMatches,
@@ -2445,6 +3863,30 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_get_labels(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetLabels,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_update_label(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::UpdateLabel,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_delete_label(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::DeleteLabel,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_local_login(
         &self,
         matches: &clap::ArgMatches,
@@ -2605,6 +4047,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_upload_rfd_asset(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::UploadRfdAsset,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_get_rfd_attr(
         &self,
         matches: &clap::ArgMatches,
@@ -2637,6 +4087,30 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_get_rfd_html(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdHtml,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_add_rfd_label(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::AddRfdLabel,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_remove_rfd_label(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::RemoveRfdLabel,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_publish_rfd(
         &self,
         matches: &clap::ArgMatches,
@@ -2645,6 +4119,62 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_revert_rfd(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::RevertRfd,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_rfd_sections(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdSections,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_rfd_section(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdSection,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_set_rfd_section(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::SetRfdSection,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_rfd_timeline(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdTimeline,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_rfd_transitions(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdTransitions,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_transition_rfd(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::TransitionRfd,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_update_rfd_visibility(
         &self,
         matches: &clap::ArgMatches,
@@ -2653,6 +4183,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_get_rfd_batch(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdBatch,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_search_rfds(
         &self,
         matches: &clap::ArgMatches,
@@ -2661,6 +4199,22 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_suggest_rfds(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::SuggestRfds,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_rfd_templates(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetRfdTemplates,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_get_self(
         &self,
         matches: &clap::ArgMatches,
@@ -2668,6 +4222,54 @@ pub trait CliConfig {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn execute_get_webhook_subscriptions(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetWebhookSubscriptions,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_create_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::CreateWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_update_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::UpdateWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_delete_webhook_subscription(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::DeleteWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_get_webhook_deliveries(
+        &self,
+        matches: &clap::ArgMatches,
+        request: &mut builder::GetWebhookDeliveries,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -2677,6 +4279,7 @@ pub enum CliCommand {
     CreateApiUser,
     GetApiUser,
     UpdateApiUser,
+    CreateApiUserFeedToken,
     AddApiUserToGroup,
     RemoveApiUserFromGroup,
     ListApiUserTokens,
@@ -2688,6 +4291,9 @@ pub enum CliCommand {
     CreateGroup,
     UpdateGroup,
     DeleteGroup,
+    GetLabels,
+    UpdateLabel,
+    DeleteLabel,
     LocalLogin,
     AuthzCodeRedirect,
     AuthzCodeCallback,
@@ -2708,14 +4314,34 @@ pub enum CliCommand {
     ReserveRfd,
     GetRfd,
     SetRfdDocument,
+    UploadRfdAsset,
     GetRfdAttr,
     SetRfdAttr,
     SetRfdContent,
     DiscussRfd,
+    GetRfdHtml,
+    AddRfdLabel,
+    RemoveRfdLabel,
     PublishRfd,
+    RevertRfd,
+    GetRfdSections,
+    GetRfdSection,
+    SetRfdSection,
+    GetRfdTimeline,
+    GetRfdTransitions,
+    TransitionRfd,
     UpdateRfdVisibility,
+    GetRfdBatch,
     SearchRfds,
+    SuggestRfds,
+    GetRfdTemplates,
     GetSelf,
+    GetWebhookSubscriptions,
+    CreateWebhookSubscription,
+    GetWebhookSubscription,
+    UpdateWebhookSubscription,
+    DeleteWebhookSubscription,
+    GetWebhookDeliveries,
 }
 
 impl CliCommand {
@@ -2726,6 +4352,7 @@ impl CliCommand {
             CliCommand::CreateApiUser,
             CliCommand::GetApiUser,
             CliCommand::UpdateApiUser,
+            CliCommand::CreateApiUserFeedToken,
             CliCommand::AddApiUserToGroup,
             CliCommand::RemoveApiUserFromGroup,
             CliCommand::ListApiUserTokens,
@@ -2737,6 +4364,9 @@ impl CliCommand {
             CliCommand::CreateGroup,
             CliCommand::UpdateGroup,
             CliCommand::DeleteGroup,
+            CliCommand::GetLabels,
+            CliCommand::UpdateLabel,
+            CliCommand::DeleteLabel,
             CliCommand::LocalLogin,
             CliCommand::AuthzCodeRedirect,
             CliCommand::AuthzCodeCallback,
@@ -2757,14 +4387,34 @@ impl CliCommand {
             CliCommand::ReserveRfd,
             CliCommand::GetRfd,
             CliCommand::SetRfdDocument,
+            CliCommand::UploadRfdAsset,
             CliCommand::GetRfdAttr,
             CliCommand::SetRfdAttr,
             CliCommand::SetRfdContent,
             CliCommand::DiscussRfd,
+            CliCommand::GetRfdHtml,
+            CliCommand::AddRfdLabel,
+            CliCommand::RemoveRfdLabel,
             CliCommand::PublishRfd,
+            CliCommand::RevertRfd,
+            CliCommand::GetRfdSections,
+            CliCommand::GetRfdSection,
+            CliCommand::SetRfdSection,
+            CliCommand::GetRfdTimeline,
+            CliCommand::GetRfdTransitions,
+            CliCommand::TransitionRfd,
             CliCommand::UpdateRfdVisibility,
+            CliCommand::GetRfdBatch,
             CliCommand::SearchRfds,
+            CliCommand::SuggestRfds,
+            CliCommand::GetRfdTemplates,
             CliCommand::GetSelf,
+            CliCommand::GetWebhookSubscriptions,
+            CliCommand::CreateWebhookSubscription,
+            CliCommand::GetWebhookSubscription,
+            CliCommand::UpdateWebhookSubscription,
+            CliCommand::DeleteWebhookSubscription,
+            CliCommand::GetWebhookDeliveries,
         ]
         .into_iter()
     }
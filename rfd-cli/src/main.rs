@@ -18,7 +18,7 @@ use std::fmt::Display;
 use std::ops::Deref;
 use std::time::Duration;
 use std::{collections::HashMap, error::Error};
-use store::CliConfig;
+use store::{CliConfig, ReadCommits};
 
 mod cmd;
 mod err;
@@ -55,6 +55,7 @@ pub struct Context {
     client: Option<Client>,
     printer: Option<Printer>,
     verbosity: VerbosityLevel,
+    expected_commit: Option<String>,
 }
 
 impl Context {
@@ -66,6 +67,7 @@ impl Context {
             client: None,
             printer: None,
             verbosity: VerbosityLevel::None,
+            expected_commit: None,
         })
     }
 
@@ -102,6 +104,20 @@ impl Context {
             .as_ref()
             .ok_or_else(|| anyhow!("No printer configured"))
     }
+
+    fn record_read_commit(&self, rfd: &rfd_sdk::types::FullRfd) {
+        if let Ok(host) = self.config.host() {
+            let mut commits = ReadCommits::new();
+            commits.record(host, &rfd.rfd_number.to_string(), rfd.commit.0.clone());
+
+            if let Err(err) = commits.save() {
+                eprintln!(
+                    "Failed to record the commit of RFD {}: {}",
+                    rfd.rfd_number, err
+                );
+            }
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -134,9 +150,16 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         // RFD commands
         CliCommand::GetRfd => Some("view"),
         CliCommand::GetRfds => Some("list"),
+        CliCommand::GetRfdBatch => Some("batch"),
         CliCommand::GetRfdAttr => Some("attr"),
         CliCommand::SearchRfds => Some("search"),
+        CliCommand::SuggestRfds => Some("suggest"),
         CliCommand::ReserveRfd => Some("reserve"),
+        CliCommand::GetRfdTransitions => Some("transitions"),
+        CliCommand::GetRfdTimeline => Some("timeline"),
+        CliCommand::GetRfdTemplates => Some("templates"),
+        CliCommand::GetRfdSections => Some("sections"),
+        CliCommand::GetRfdSection => Some("section"),
 
         CliCommand::SetRfdAttr => Some("edit attr"),
         CliCommand::SetRfdContent => Some("edit content"),
@@ -144,10 +167,27 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::UpdateRfdVisibility => Some("edit visibility"),
         CliCommand::PublishRfd => Some("edit publish"),
         CliCommand::DiscussRfd => Some("edit discuss"),
+        CliCommand::RevertRfd => Some("edit revert"),
+        CliCommand::AddRfdLabel => Some("edit label add"),
+        CliCommand::RemoveRfdLabel => Some("edit label remove"),
+        CliCommand::TransitionRfd => Some("edit transition"),
+        CliCommand::SetRfdSection => Some("edit section"),
+
+        // Label commands
+        CliCommand::GetLabels => Some("label list"),
+        CliCommand::UpdateLabel => Some("label update"),
+        CliCommand::DeleteLabel => Some("label delete"),
+
+        // Asset uploads are handled by the upload shortcut, which reads and encodes local files
+        CliCommand::UploadRfdAsset => None,
+
+        // Rendered documents are handled by the html shortcut, which writes the raw response
+        CliCommand::GetRfdHtml => None,
 
         // User commands
         CliCommand::CreateApiUser => Some("sys user create"),
         CliCommand::CreateApiUserToken => Some("sys user token create"),
+        CliCommand::CreateApiUserFeedToken => Some("sys user feed-token create"),
         CliCommand::DeleteApiUserToken => Some("sys user token delete"),
         CliCommand::GetApiUser => Some("sys user get"),
         CliCommand::GetApiUserToken => Some("sys user token get"),
@@ -183,6 +223,14 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::CreateOauthClientSecret => Some("sys oauth secret create"),
         CliCommand::DeleteOauthClientSecret => Some("sys oauth secret delete"),
 
+        // Webhook subscription commands
+        CliCommand::GetWebhookSubscriptions => Some("sys webhook list"),
+        CliCommand::CreateWebhookSubscription => Some("sys webhook create"),
+        CliCommand::GetWebhookSubscription => Some("sys webhook get"),
+        CliCommand::UpdateWebhookSubscription => Some("sys webhook update"),
+        CliCommand::DeleteWebhookSubscription => Some("sys webhook delete"),
+        CliCommand::GetWebhookDeliveries => Some("sys webhook deliveries"),
+
         // Authentication is handled separately
         CliCommand::ExchangeDeviceToken => None,
         CliCommand::GetDeviceProvider => None,
@@ -299,8 +347,30 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 sm = sub_matches;
             }
 
+            let op = node.cmd.unwrap();
+
+            // Edits are sent along with the commit of the RFD as it was when it was last read, so
+            // that changes made by someone else in the meantime are not silently overwritten
+            if matches!(
+                op,
+                CliCommand::SetRfdAttr
+                    | CliCommand::SetRfdContent
+                    | CliCommand::SetRfdDocument
+                    | CliCommand::RevertRfd
+            ) && sm.get_one::<String>("expected-commit").is_none()
+                && sm.get_one::<std::path::PathBuf>("json-body").is_none()
+            {
+                if let (Some(number), Ok(host)) =
+                    (sm.get_one::<String>("number"), ctx.config.host())
+                {
+                    ctx.expected_commit = ReadCommits::new()
+                        .get(host, number)
+                        .map(|commit| commit.to_string());
+                }
+            }
+
             let cli = Cli::new(ctx.client()?.clone(), ctx);
-            cli.execute(node.cmd.unwrap(), sm).await;
+            cli.execute(op, sm).await;
         }
     };
 
@@ -333,6 +403,10 @@ impl ProgenitorCliConfig for Context {
                 .printer()
                 .unwrap()
                 .output_api_key_initial(reserialize(value)),
+            "InitialFeedTokenResponse" => self
+                .printer()
+                .unwrap()
+                .output_feed_token_initial(reserialize(value)),
             "ApiKeyResponse" => self.printer().unwrap().output_api_key(reserialize(value)),
             "Array_of_AccessGroupForApiPermissionResponse" => self
                 .printer()
@@ -366,14 +440,76 @@ impl ProgenitorCliConfig for Context {
                 .printer()
                 .unwrap()
                 .output_oauth_secret(reserialize(value)),
+            "Array_of_WebhookSubscriptionResponse" => self
+                .printer()
+                .unwrap()
+                .output_webhook_subscription_list(reserialize(value)),
+            "WebhookSubscriptionResponse" => self
+                .printer()
+                .unwrap()
+                .output_webhook_subscription(reserialize(value)),
+            "Array_of_WebhookDelivery" => self
+                .printer()
+                .unwrap()
+                .output_webhook_delivery_list(reserialize(value)),
             "Array_of_ListRfd" => self.printer().unwrap().output_rfd_list(reserialize(value)),
-            "FullRfd" => self.printer().unwrap().output_rfd_full(reserialize(value)),
+            "FullRfd" => {
+                let rfd = reserialize(value);
+                self.record_read_commit(&rfd);
+                self.printer().unwrap().output_rfd_full(rfd)
+            }
+            "RfdBatchResponse" => match reserialize(value) {
+                rfd_sdk::types::RfdBatchResponse::Summary(rfds) => {
+                    self.printer().unwrap().output_rfd_list(rfds)
+                }
+                rfd_sdk::types::RfdBatchResponse::Full(rfds) => rfds.into_iter().for_each(|rfd| {
+                    self.record_read_commit(&rfd);
+                    self.printer().unwrap().output_rfd_full(rfd)
+                }),
+            },
             "Rfd" => self.printer().unwrap().output_rfd(reserialize(value)),
             "SearchResults" => self
                 .printer()
                 .unwrap()
                 .output_search_results(reserialize(value)),
+            "Array_of_SearchSuggestion" => self
+                .printer()
+                .unwrap()
+                .output_search_suggestions(reserialize(value)),
             "RfdAttr" => self.printer().unwrap().output_rfd_attr(reserialize(value)),
+            "Array_of_LabelSummary" => self
+                .printer()
+                .unwrap()
+                .output_label_list(reserialize(value)),
+            "Label" => self.printer().unwrap().output_label(reserialize(value)),
+            "Array_of_String" => self
+                .printer()
+                .unwrap()
+                .output_rfd_labels(reserialize(value)),
+            "Array_of_RfdStateTransition" => self
+                .printer()
+                .unwrap()
+                .output_rfd_transition_list(reserialize(value)),
+            "RfdStateTransition" => self
+                .printer()
+                .unwrap()
+                .output_rfd_transition(reserialize(value)),
+            "Array_of_RfdTimelineEvent" => self
+                .printer()
+                .unwrap()
+                .output_rfd_timeline(reserialize(value)),
+            "Array_of_RfdTemplateSummary" => self
+                .printer()
+                .unwrap()
+                .output_rfd_templates(reserialize(value)),
+            "Array_of_RfdSection" => self
+                .printer()
+                .unwrap()
+                .output_rfd_sections(reserialize(value)),
+            "RfdSectionContent" => self
+                .printer()
+                .unwrap()
+                .output_rfd_section(reserialize(value)),
             "ReserveRfdResponse" => self
                 .printer()
                 .unwrap()
@@ -415,4 +551,60 @@ impl ProgenitorCliConfig for Context {
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug,
     {
     }
+
+    fn execute_set_rfd_attr(
+        &self,
+        _matches: &clap::ArgMatches,
+        request: &mut rfd_sdk::builder::SetRfdAttr,
+    ) -> anyhow::Result<()> {
+        if let Some(commit) = &self.expected_commit {
+            *request = request
+                .clone()
+                .body_map(|body| body.expected_commit(commit.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn execute_set_rfd_content(
+        &self,
+        _matches: &clap::ArgMatches,
+        request: &mut rfd_sdk::builder::SetRfdContent,
+    ) -> anyhow::Result<()> {
+        if let Some(commit) = &self.expected_commit {
+            *request = request
+                .clone()
+                .body_map(|body| body.expected_commit(commit.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn execute_set_rfd_document(
+        &self,
+        _matches: &clap::ArgMatches,
+        request: &mut rfd_sdk::builder::SetRfdDocument,
+    ) -> anyhow::Result<()> {
+        if let Some(commit) = &self.expected_commit {
+            *request = request
+                .clone()
+                .body_map(|body| body.expected_commit(commit.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn execute_revert_rfd(
+        &self,
+        _matches: &clap::ArgMatches,
+        request: &mut rfd_sdk::builder::RevertRfd,
+    ) -> anyhow::Result<()> {
+        if let Some(commit) = &self.expected_commit {
+            *request = request
+                .clone()
+                .body_map(|body| body.expected_commit(commit.clone()));
+        }
+
+        Ok(())
+    }
 }
@@ -27,6 +27,10 @@ impl CliOutput for RfdJsonPrinter {
         println!("{}", serde_json::to_string(&value).unwrap())
     }
 
+    fn output_feed_token_initial(&self, value: types::InitialFeedTokenResponse) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
     fn output_api_key(&self, value: types::ApiKeyResponse) {
         println!("{}", serde_json::to_string(&value).unwrap())
     }
@@ -83,6 +87,10 @@ impl CliOutput for RfdJsonPrinter {
         println!("{}", serde_json::to_string(&value).unwrap())
     }
 
+    fn output_search_suggestions(&self, value: Vec<types::SearchSuggestion>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
     fn output_rfd_attr(&self, value: types::RfdAttr) {
         println!("{}", serde_json::to_string(&value).unwrap())
     }
@@ -91,6 +99,54 @@ impl CliOutput for RfdJsonPrinter {
         println!("{}", serde_json::to_string(&value).unwrap())
     }
 
+    fn output_label_list(&self, value: Vec<types::LabelSummary>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_label(&self, value: types::Label) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_labels(&self, value: Vec<String>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_transition_list(&self, value: Vec<types::RfdStateTransition>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_transition(&self, value: types::RfdStateTransition) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_timeline(&self, value: Vec<types::RfdTimelineEvent>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_templates(&self, value: Vec<types::RfdTemplateSummary>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_sections(&self, value: Vec<types::RfdSection>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_rfd_section(&self, value: types::RfdSectionContent) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_webhook_subscription_list(&self, value: Vec<types::WebhookSubscriptionResponse>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_webhook_subscription(&self, value: types::WebhookSubscriptionResponse) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
+    fn output_webhook_delivery_list(&self, value: Vec<types::WebhookDelivery>) {
+        println!("{}", serde_json::to_string(&value).unwrap())
+    }
+
     fn output_error<T>(&self, value: &progenitor_client::Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug,
@@ -20,6 +20,7 @@ pub trait CliOutput {
     fn output_user(&self, value: types::GetUserResponse) {}
     fn output_api_key_list(&self, value: Vec<types::ApiKeyResponse>) {}
     fn output_api_key_initial(&self, value: types::InitialApiKeyResponse) {}
+    fn output_feed_token_initial(&self, value: types::InitialFeedTokenResponse) {}
     fn output_api_key(&self, value: types::ApiKeyResponse) {}
     fn output_group_list(&self, value: Vec<types::AccessGroupForApiPermissionResponse>) {}
     fn output_group(&self, value: types::AccessGroupForApiPermissionResponse) {}
@@ -35,7 +36,20 @@ pub trait CliOutput {
     fn output_rfd(&self, value: types::Rfd) {}
     fn output_rfd_attr(&self, value: types::RfdAttr) {}
     fn output_search_results(&self, value: types::SearchResults) {}
+    fn output_search_suggestions(&self, value: Vec<types::SearchSuggestion>) {}
     fn output_reserved_rfd(&self, value: types::ReserveRfdResponse) {}
+    fn output_label_list(&self, value: Vec<types::LabelSummary>) {}
+    fn output_label(&self, value: types::Label) {}
+    fn output_rfd_labels(&self, value: Vec<String>) {}
+    fn output_rfd_transition_list(&self, value: Vec<types::RfdStateTransition>) {}
+    fn output_rfd_transition(&self, value: types::RfdStateTransition) {}
+    fn output_rfd_timeline(&self, value: Vec<types::RfdTimelineEvent>) {}
+    fn output_rfd_templates(&self, value: Vec<types::RfdTemplateSummary>) {}
+    fn output_rfd_sections(&self, value: Vec<types::RfdSection>) {}
+    fn output_rfd_section(&self, value: types::RfdSectionContent) {}
+    fn output_webhook_subscription_list(&self, value: Vec<types::WebhookSubscriptionResponse>) {}
+    fn output_webhook_subscription(&self, value: types::WebhookSubscriptionResponse) {}
+    fn output_webhook_delivery_list(&self, value: Vec<types::WebhookDelivery>) {}
     fn output_error<T>(&self, value: &progenitor_client::Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug;
@@ -70,6 +84,13 @@ impl CliOutput for Printer {
         }
     }
 
+    fn output_feed_token_initial(&self, value: types::InitialFeedTokenResponse) {
+        match self {
+            Self::Json(printer) => printer.output_feed_token_initial(value),
+            Self::Tab(printer) => printer.output_feed_token_initial(value),
+        }
+    }
+
     fn output_api_key(&self, value: types::ApiKeyResponse) {
         match self {
             Self::Json(printer) => printer.output_api_key(value),
@@ -175,6 +196,13 @@ impl CliOutput for Printer {
         }
     }
 
+    fn output_search_suggestions(&self, value: Vec<types::SearchSuggestion>) {
+        match self {
+            Self::Json(printer) => printer.output_search_suggestions(value),
+            Self::Tab(printer) => printer.output_search_suggestions(value),
+        }
+    }
+
     fn output_reserved_rfd(&self, value: types::ReserveRfdResponse) {
         match self {
             Self::Json(printer) => printer.output_reserved_rfd(value),
@@ -182,6 +210,90 @@ impl CliOutput for Printer {
         }
     }
 
+    fn output_label_list(&self, value: Vec<types::LabelSummary>) {
+        match self {
+            Self::Json(printer) => printer.output_label_list(value),
+            Self::Tab(printer) => printer.output_label_list(value),
+        }
+    }
+
+    fn output_label(&self, value: types::Label) {
+        match self {
+            Self::Json(printer) => printer.output_label(value),
+            Self::Tab(printer) => printer.output_label(value),
+        }
+    }
+
+    fn output_rfd_labels(&self, value: Vec<String>) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_labels(value),
+            Self::Tab(printer) => printer.output_rfd_labels(value),
+        }
+    }
+
+    fn output_rfd_transition_list(&self, value: Vec<types::RfdStateTransition>) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_transition_list(value),
+            Self::Tab(printer) => printer.output_rfd_transition_list(value),
+        }
+    }
+
+    fn output_rfd_transition(&self, value: types::RfdStateTransition) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_transition(value),
+            Self::Tab(printer) => printer.output_rfd_transition(value),
+        }
+    }
+
+    fn output_rfd_timeline(&self, value: Vec<types::RfdTimelineEvent>) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_timeline(value),
+            Self::Tab(printer) => printer.output_rfd_timeline(value),
+        }
+    }
+
+    fn output_rfd_templates(&self, value: Vec<types::RfdTemplateSummary>) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_templates(value),
+            Self::Tab(printer) => printer.output_rfd_templates(value),
+        }
+    }
+
+    fn output_rfd_sections(&self, value: Vec<types::RfdSection>) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_sections(value),
+            Self::Tab(printer) => printer.output_rfd_sections(value),
+        }
+    }
+
+    fn output_rfd_section(&self, value: types::RfdSectionContent) {
+        match self {
+            Self::Json(printer) => printer.output_rfd_section(value),
+            Self::Tab(printer) => printer.output_rfd_section(value),
+        }
+    }
+
+    fn output_webhook_subscription_list(&self, value: Vec<types::WebhookSubscriptionResponse>) {
+        match self {
+            Self::Json(printer) => printer.output_webhook_subscription_list(value),
+            Self::Tab(printer) => printer.output_webhook_subscription_list(value),
+        }
+    }
+
+    fn output_webhook_subscription(&self, value: types::WebhookSubscriptionResponse) {
+        match self {
+            Self::Json(printer) => printer.output_webhook_subscription(value),
+            Self::Tab(printer) => printer.output_webhook_subscription(value),
+        }
+    }
+
+    fn output_webhook_delivery_list(&self, value: Vec<types::WebhookDelivery>) {
+        match self {
+            Self::Json(printer) => printer.output_webhook_delivery_list(value),
+            Self::Tab(printer) => printer.output_webhook_delivery_list(value),
+        }
+    }
+
     fn output_error<T>(&self, value: &progenitor_client::Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug,
@@ -8,9 +8,12 @@ use progenitor_client::ResponseValue;
 use rfd_sdk::types::{
     self, AccessGroupForApiPermissionResponse, ApiKeyResponse, ApiPermission,
     ApiUserForApiPermissionResponse, Error, FullRfd, FullRfdPdfEntry, GetUserResponse,
-    InitialApiKeyResponse, InitialOAuthClientSecretResponse, ListRfd, Mapper, OAuthClient,
-    OAuthClientRedirectUri, OAuthClientSecret, PermissionsForApiPermissionResponse,
-    ReserveRfdResponse, RfdAttr, SearchResultHit, SearchResults, Visibility,
+    InitialApiKeyResponse, InitialFeedTokenResponse, InitialOAuthClientSecretResponse, Label,
+    LabelSummary, ListRfd, Mapper, OAuthClient, OAuthClientRedirectUri, OAuthClientSecret,
+    PermissionsForApiPermissionResponse, ReserveRfdResponse, RfdAttr, RfdSection,
+    RfdSectionContent, RfdStateTransition, RfdTemplateSummary, RfdTimelineEvent,
+    RfdTimelineEventKind, SearchResultHit, SearchResults, SearchSuggestion, Visibility,
+    WebhookDelivery, WebhookSubscriptionResponse,
 };
 use std::{collections::HashMap, fmt::Display, fs::File, io::Write, process::Command};
 use tabwriter::TabWriter;
@@ -81,6 +84,10 @@ impl CliOutput for RfdTabPrinter {
         self.print_cli_output(&value, None);
     }
 
+    fn output_feed_token_initial(&self, value: types::InitialFeedTokenResponse) {
+        self.print_cli_output(&value, None);
+    }
+
     fn output_api_key(&self, value: types::ApiKeyResponse) {
         self.print_cli_output(&value, None);
     }
@@ -141,10 +148,64 @@ impl CliOutput for RfdTabPrinter {
         self.print_cli_output(&value, Some("results".to_string()));
     }
 
+    fn output_search_suggestions(&self, value: Vec<types::SearchSuggestion>) {
+        self.print_cli_output(&value, Some("suggestions".to_string()));
+    }
+
     fn output_reserved_rfd(&self, value: types::ReserveRfdResponse) {
         self.print_cli_output(&value, None);
     }
 
+    fn output_label_list(&self, value: Vec<types::LabelSummary>) {
+        self.print_cli_output(&value, Some("labels".to_string()));
+    }
+
+    fn output_label(&self, value: types::Label) {
+        self.print_cli_output(&value, None);
+    }
+
+    fn output_rfd_labels(&self, value: Vec<String>) {
+        let mut tw = TabWriter::new(vec![]).ansi(true);
+        self.print_list(&mut tw, 0, "labels", &value);
+        output_writer(tw);
+    }
+
+    fn output_rfd_transition_list(&self, value: Vec<types::RfdStateTransition>) {
+        self.print_cli_output(&value, Some("transitions".to_string()));
+    }
+
+    fn output_rfd_transition(&self, value: types::RfdStateTransition) {
+        self.print_cli_output(&value, None);
+    }
+
+    fn output_rfd_timeline(&self, value: Vec<types::RfdTimelineEvent>) {
+        self.print_cli_output(&value, Some("timeline".to_string()));
+    }
+
+    fn output_rfd_templates(&self, value: Vec<types::RfdTemplateSummary>) {
+        self.print_cli_output(&value, Some("templates".to_string()));
+    }
+
+    fn output_rfd_sections(&self, value: Vec<types::RfdSection>) {
+        self.print_cli_output(&value, Some("sections".to_string()));
+    }
+
+    fn output_rfd_section(&self, value: types::RfdSectionContent) {
+        self.print_cli_output(&value, None);
+    }
+
+    fn output_webhook_subscription_list(&self, value: Vec<types::WebhookSubscriptionResponse>) {
+        self.print_cli_output(&value, Some("webhooks".to_string()));
+    }
+
+    fn output_webhook_subscription(&self, value: types::WebhookSubscriptionResponse) {
+        self.print_cli_output(&value, None);
+    }
+
+    fn output_webhook_delivery_list(&self, value: Vec<types::WebhookDelivery>) {
+        self.print_cli_output(&value, Some("deliveries".to_string()));
+    }
+
     fn output_error<T>(&self, value: &progenitor_client::Error<T>)
     where
         T: schemars::JsonSchema + serde::Serialize + std::fmt::Debug,
@@ -246,6 +307,14 @@ impl TabDisplay for InitialApiKeyResponse {
     }
 }
 
+impl TabDisplay for InitialFeedTokenResponse {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "id", &self.id);
+        printer.print_field(tw, level, "token", &self.token.0);
+        printer.print_field(tw, level, "created_at", &self.created_at);
+    }
+}
+
 impl TabDisplay for AccessGroupForApiPermissionResponse {
     fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
         printer.print_field(tw, level, "id", &self.id);
@@ -564,12 +633,251 @@ impl TabDisplay for SearchResultHit {
     }
 }
 
+impl TabDisplay for SearchSuggestion {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "rfd", &self.rfd_number.to_string());
+        printer.print_field(tw, level, "title", &self.title.as_deref().unwrap_or(""));
+        if let Some(section) = &self.section {
+            printer.print_field(tw, level, "section", section);
+        }
+    }
+}
+
 impl TabDisplay for ReserveRfdResponse {
     fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
         printer.print_field(tw, level, "number", &self.number);
     }
 }
 
+impl TabDisplay for LabelSummary {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "name", &self.name);
+        printer.print_field(tw, level, "count", &self.count);
+        printer.print_field(
+            tw,
+            level,
+            "description",
+            &self.description.as_deref().unwrap_or(""),
+        );
+        printer.print_field(tw, level, "color", &self.color.as_deref().unwrap_or(""));
+    }
+}
+
+impl TabDisplay for Label {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "id", &self.id);
+        printer.print_field(tw, level, "name", &self.name);
+        printer.print_field(
+            tw,
+            level,
+            "description",
+            &self.description.as_deref().unwrap_or(""),
+        );
+        printer.print_field(tw, level, "color", &self.color.as_deref().unwrap_or(""));
+        printer.print_field(tw, level, "created_at", &self.created_at);
+        printer.print_field(tw, level, "updated_at", &self.updated_at);
+        printer.print_field(
+            tw,
+            level,
+            "deleted_at",
+            &self
+                .deleted_at
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "--".to_string()),
+        );
+    }
+}
+
+impl TabDisplay for RfdStateTransition {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "id", &self.id);
+        printer.print_field(
+            tw,
+            level,
+            "from_state",
+            &self.from_state.as_deref().unwrap_or("--"),
+        );
+        printer.print_field(tw, level, "to_state", &self.to_state);
+        printer.print_field(tw, level, "api_user_id", &self.api_user_id);
+        printer.print_field(tw, level, "reason", &self.reason.as_deref().unwrap_or(""));
+        printer.print_field(
+            tw,
+            level,
+            "commit",
+            &self
+                .commit
+                .as_ref()
+                .map(|commit| commit.to_string())
+                .unwrap_or_else(|| "--".to_string()),
+        );
+        printer.print_field(tw, level, "created_at", &self.created_at);
+    }
+}
+
+impl TabDisplay for RfdTimelineEvent {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        let change = |from: &Option<String>, to: &Option<String>| {
+            format!(
+                "{} -> {}",
+                from.as_deref().unwrap_or("--"),
+                to.as_deref().unwrap_or("--")
+            )
+        };
+
+        printer.print_field(tw, level, "commit", &self.commit.to_string());
+        printer.print_field(tw, level, "committed_at", &self.committed_at);
+        match &self.event {
+            RfdTimelineEventKind::Created { title, state } => {
+                printer.print_field(tw, level, "event", &"created");
+                printer.print_field(tw, level, "title", title);
+                printer.print_field(tw, level, "state", &state.as_deref().unwrap_or("--"));
+            }
+            RfdTimelineEventKind::TitleChanged { from, to } => {
+                printer.print_field(tw, level, "event", &"title_changed");
+                printer.print_field(tw, level, "change", &format!("{} -> {}", from, to));
+            }
+            RfdTimelineEventKind::StateChanged { from, to } => {
+                printer.print_field(tw, level, "event", &"state_changed");
+                printer.print_field(tw, level, "change", &change(from, to));
+            }
+            RfdTimelineEventKind::LabelsChanged { from, to } => {
+                printer.print_field(tw, level, "event", &"labels_changed");
+                printer.print_field(tw, level, "change", &change(from, to));
+            }
+            RfdTimelineEventKind::AuthorsChanged { from, to } => {
+                printer.print_field(tw, level, "event", &"authors_changed");
+                printer.print_field(tw, level, "change", &change(from, to));
+            }
+            RfdTimelineEventKind::DiscussionLinked { url } => {
+                printer.print_field(tw, level, "event", &"discussion_linked");
+                printer.print_field(tw, level, "url", url);
+            }
+            RfdTimelineEventKind::PublishedToDefaultBranch => {
+                printer.print_field(tw, level, "event", &"published_to_default_branch");
+            }
+        }
+    }
+}
+
+impl TabDisplay for RfdTemplateSummary {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "name", &self.name);
+        printer.print_field(
+            tw,
+            level,
+            "description",
+            &self.description.as_deref().unwrap_or(""),
+        );
+        printer.print_list(
+            tw,
+            level,
+            "formats",
+            &self
+                .formats
+                .iter()
+                .map(|format| format.to_string())
+                .collect::<Vec<_>>(),
+        );
+        printer.print_list(tw, level, "required_fields", &self.required_fields);
+        printer.print_list(
+            tw,
+            level,
+            "optional_fields",
+            &self
+                .optional_fields
+                .iter()
+                .map(|(field, default)| format!("{} (default: {})", field, default))
+                .collect::<Vec<_>>(),
+        );
+    }
+}
+
+impl TabDisplay for RfdSection {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "anchor", &self.anchor);
+        printer.print_field(tw, level, "name", &self.name);
+        printer.print_field(tw, level, "level", &self.level);
+        printer.print_list(tw, level, "parents", &self.parents);
+    }
+}
+
+impl TabDisplay for RfdSectionContent {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "anchor", &self.anchor);
+        printer.print_field(tw, level, "name", &self.name);
+        printer.print_field(tw, level, "level", &self.level);
+        printer.print_list(tw, level, "parents", &self.parents);
+        printer.print_field(tw, level, "content", &self.content);
+    }
+}
+
+impl TabDisplay for WebhookSubscriptionResponse {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "id", &self.id);
+        printer.print_field(tw, level, "url", &self.url);
+        printer.print_list(
+            tw,
+            level,
+            "events",
+            &self.events.iter().map(|e| e.to_string()).collect_vec(),
+        );
+        printer.print_field(
+            tw,
+            level,
+            "rfds",
+            &self
+                .rfds
+                .as_ref()
+                .map(|rfds| rfds.iter().join(", "))
+                .unwrap_or_else(|| "--".to_string()),
+        );
+        printer.print_field(tw, level, "created_at", &self.created_at);
+        printer.print_field(tw, level, "updated_at", &self.updated_at);
+    }
+}
+
+impl TabDisplay for WebhookDelivery {
+    fn display(&self, tw: &mut TabWriter<Vec<u8>>, level: u8, printer: &RfdTabPrinter) {
+        printer.print_field(tw, level, "id", &self.id);
+        printer.print_field(tw, level, "event", &self.event);
+        printer.print_field(tw, level, "attempts", &self.attempts);
+        printer.print_field(
+            tw,
+            level,
+            "response_status",
+            &self
+                .response_status
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "--".to_string()),
+        );
+        printer.print_field(
+            tw,
+            level,
+            "last_error",
+            &self.last_error.as_deref().unwrap_or("--"),
+        );
+        printer.print_field(
+            tw,
+            level,
+            "next_attempt_at",
+            &self
+                .next_attempt_at
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "--".to_string()),
+        );
+        printer.print_field(
+            tw,
+            level,
+            "delivered_at",
+            &self
+                .delivered_at
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "--".to_string()),
+        );
+        printer.print_field(tw, level, "created_at", &self.created_at);
+    }
+}
+
 impl<T> TabDisplay for Vec<T>
 where
     T: TabDisplay,
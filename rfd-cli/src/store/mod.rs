@@ -3,7 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use std::{
-    fs::{create_dir_all, File as StdFile, OpenOptions},
+    collections::HashMap,
+    fs::{create_dir_all, read_to_string, File as StdFile, OpenOptions},
     io::Write,
     path::PathBuf,
 };
@@ -86,3 +87,65 @@ impl CliConfig {
         Ok(())
     }
 }
+
+// Records the commit of each RFD at the time that it was last read, so that edits made afterwards
+// can be sent along with the commit they were made against
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ReadCommits {
+    #[serde(default)]
+    hosts: HashMap<String, HashMap<String, String>>,
+}
+
+impl ReadCommits {
+    pub fn new() -> Self {
+        Self::path()
+            .ok()
+            .and_then(|path| read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn path() -> Result<PathBuf> {
+        let mut path =
+            dirs::config_dir().ok_or_else(|| anyhow!("Failed to determine configs path"))?;
+        path.push("rfd-cli");
+        create_dir_all(&path)?;
+
+        path.push("read-commits.toml");
+        Ok(path)
+    }
+
+    pub fn get(&self, host: &str, number: &str) -> Option<&str> {
+        self.hosts
+            .get(host)
+            .and_then(|commits| commits.get(normalize_number(number)))
+            .map(|commit| &**commit)
+    }
+
+    pub fn record(&mut self, host: &str, number: &str, commit: String) {
+        self.hosts
+            .entry(host.to_string())
+            .or_default()
+            .insert(normalize_number(number).to_string(), commit);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(Self::path()?)?;
+        file.write_all(toml::to_string(&self)?.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+fn normalize_number(number: &str) -> &str {
+    let trimmed = number.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0"
+    } else {
+        trimmed
+    }
+}
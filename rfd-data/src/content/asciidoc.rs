@@ -5,7 +5,10 @@
 use regex::Regex;
 use std::borrow::Cow;
 
-use super::RfdDocument;
+use super::{
+    header::{split_authors, HeaderSyntax, RfdHeader},
+    RfdDocument,
+};
 
 /// The text data of an Asciidoc RFD
 #[derive(Debug, Clone)]
@@ -32,33 +35,6 @@ impl<'a> RfdAsciidoc<'a> {
         })
     }
 
-    fn set_attr(&mut self, attr: &str, value: &str) {
-        let pattern = self.attr_pattern(attr);
-
-        if let Some(found) = pattern.find(&self.content) {
-            self.content = self
-                .content
-                .replacen(found.as_str(), &format!(":{}: {}\n", attr, value), 1)
-                .into()
-        } else {
-            let title = self.title_line();
-
-            if let Some(title) = title {
-                let new_attr = format!(":{}: {}\n", attr, value);
-                let header = self.header();
-                let body = self.body();
-
-                self.content = (header.unwrap_or_default().to_string()
-                    + "\n"
-                    + &new_attr
-                    + "\n\n"
-                    + title
-                    + body.unwrap_or_default())
-                .into()
-            }
-        }
-    }
-
     fn attr_pattern(&self, attr: &str) -> Regex {
         Regex::new(&format!(r"(?m)^:{}:(.*)$\n", attr)).unwrap()
     }
@@ -93,10 +69,6 @@ impl<'a> RfdDocument for RfdAsciidoc<'a> {
         self.attr("state")
     }
 
-    fn update_state(&mut self, value: &str) {
-        self.set_attr("state", value.trim())
-    }
-
     fn get_discussion(&self) -> Option<&str> {
         let link = self.attr("discussion")?;
         if link.starts_with("http") {
@@ -106,10 +78,6 @@ impl<'a> RfdDocument for RfdAsciidoc<'a> {
         }
     }
 
-    fn update_discussion(&mut self, value: &str) {
-        self.set_attr("discussion", value.trim())
-    }
-
     fn get_authors(&self) -> Option<&str> {
         self.body().and_then(|body| {
             body.lines().nth(0).and_then(|first_line| {
@@ -141,8 +109,14 @@ impl<'a> RfdDocument for RfdAsciidoc<'a> {
         self.attr("labels")
     }
 
-    fn update_labels(&mut self, value: &str) {
-        self.set_attr("labels", value)
+    fn get_header(&self) -> RfdHeader {
+        RfdHeader::parse(HeaderSyntax::Asciidoc, &self.content)
+            .with_implicit_authors(self.get_authors().map(split_authors).unwrap_or_default())
+    }
+
+    fn update_header(&mut self, header: &RfdHeader) {
+        let len = RfdHeader::block_len(HeaderSyntax::Asciidoc, &self.content);
+        self.content = Cow::Owned(header.render() + &self.content[len..]);
     }
 
     fn header(&self) -> Option<&str> {
@@ -274,117 +248,6 @@ sdf
         assert_eq!(expected, discussion);
     }
 
-    #[test]
-    fn test_set_nonexistent_attribute() {
-        let content = r#"sdfsdf
-= sdfgsdfgsdfg
-:discussion: https://github.com/org/repo/pulls/1
-dsfsdf
-sdf
-:discussion: nope"#;
-        let mut rfd = RfdAsciidoc::new(content);
-        println!("Before\n{}", rfd.raw());
-        rfd.set_attr("xrefstyle", "short");
-        println!("After\n{}", rfd.raw());
-        assert_eq!(Some("short"), rfd.attr("xrefstyle"))
-    }
-
-    // Update discussion link tests
-
-    #[test]
-    fn test_update_existing_asciidoc_discussion_link_and_ignores_markdown_link() {
-        let link = "https://github.com/org/repo/pulls/2019";
-
-        let content = r#"sdfsdf
-= sdfgsd
-discussion: fgsdfg
-:discussion: https://github.com/org/repo/pulls/1
-dsfsdf
-sdf
-:discussion: nope"#;
-
-        let mut rfd = RfdAsciidoc::new(content);
-        rfd.update_discussion(link);
-        let expected = r#"sdfsdf
-= sdfgsd
-discussion: fgsdfg
-:discussion: https://github.com/org/repo/pulls/2019
-dsfsdf
-sdf
-:discussion: nope"#;
-        assert_eq!(expected, rfd.raw());
-    }
-
-    #[test]
-    fn test_update_missing_asciidoc_discussion_link_and_ignores_markdown_link() {
-        let link = "https://github.com/org/repo/pulls/2019";
-
-        let content = r#"sdfsdf
-= sdfgsd
-discussion: fgsdfg
-:discussion:
-dsfsdf
-sdf
-:discussion: nope"#;
-
-        let mut rfd = RfdAsciidoc::new(content);
-        rfd.update_discussion(link);
-        let expected = r#"sdfsdf
-= sdfgsd
-discussion: fgsdfg
-:discussion: https://github.com/org/repo/pulls/2019
-dsfsdf
-sdf
-:discussion: nope"#;
-        assert_eq!(expected, rfd.raw());
-    }
-
-    // Update state tests
-
-    #[test]
-    fn test_update_existing_asciidoc_state() {
-        let state = "discussion";
-        let content = r#"sdfsdf
-= sdfgsd
-state: fgsdfg
-:state: prediscussion
-dsfsdf
-sdf
-:state: nope"#;
-        let mut rfd = RfdAsciidoc::new(content);
-        rfd.update_state(state);
-        let expected = r#"sdfsdf
-= sdfgsd
-state: fgsdfg
-:state: discussion
-dsfsdf
-sdf
-:state: nope"#;
-        assert_eq!(expected, rfd.raw());
-    }
-
-    #[test]
-    fn test_update_empty_asciidoc_state() {
-        let state = "discussion";
-        let content = r#"sdfsdf
-= sdfgsd
-state: fgsdfg
-:state:
-dsfsdf
-sdf
-:state: nope"#;
-        let mut rfd = RfdAsciidoc::new(content);
-        rfd.update_state(state);
-        let expected = r#"sdfsdf
-= sdfgsd
-state: fgsdfg
-:state: discussion
-dsfsdf
-sdf
-:state: nope"#;
-        assert_eq!(expected, rfd.raw());
-    }
-
     // Read title tests
 
     #[test]
@@ -516,7 +379,9 @@ in velit.
     #[test]
     fn test_update_asciidoc_labels() {
         let mut rfd = RfdAsciidoc::new(Cow::Borrowed(test_rfd_content()));
-        rfd.update_labels("newlabel1, newlabel2");
+        let mut header = rfd.get_header();
+        header.labels = vec!["newlabel1".to_string(), "newlabel2".to_string()];
+        rfd.update_header(&header);
         let labels = rfd.get_labels().unwrap();
         let expected = "newlabel1, newlabel2".to_string();
         assert_eq!(expected, labels);
@@ -542,4 +407,54 @@ This is the new body"#;
         rfd.update_body(&new_content);
         assert_eq!(expected, rfd.raw());
     }
+
+    // Header tests
+
+    #[test]
+    fn test_update_asciidoc_header_preserves_body() {
+        let content = r#":showtitle:
+:state: prediscussion
+
+= RFD 123 Test Rfd
+{authors}
+
+:state: not-a-header-attribute
+Body
+"#;
+        let mut rfd = RfdAsciidoc::new(content);
+        let mut header = rfd.get_header();
+        header.state = Some(crate::RfdState::Ideation);
+        header.labels = vec!["api".to_string()];
+        rfd.update_header(&header);
+
+        let expected = r#":showtitle:
+:state: ideation
+:labels: api
+
+= RFD 123 Test Rfd
+{authors}
+
+:state: not-a-header-attribute
+Body
+"#;
+        assert_eq!(expected, rfd.raw());
+    }
+
+    #[test]
+    fn test_get_asciidoc_header_with_fallback_authors() {
+        let content = r#"= sdfgsdfgsdfg
+things <things@company>, firstname <email@company>
+dsfsdf"#;
+        let rfd = RfdAsciidoc::new(content);
+        let header = rfd.get_header();
+        assert_eq!(
+            vec!["things <things@company>", "firstname <email@company>"],
+            header.authors
+        );
+
+        // Authors that are read from the author line are not written back out as an attribute
+        let mut rfd = rfd.clone();
+        rfd.update_header(&header);
+        assert_eq!(content, rfd.raw());
+    }
 }
@@ -0,0 +1,599 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use regex::Regex;
+use std::{collections::HashSet, ops::Range};
+
+use crate::{RfdAuthor, RfdState};
+
+/// The attribute syntax used by a document format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderSyntax {
+    /// Attributes in the form of `:name: value`
+    Asciidoc,
+    /// Attributes in the form of `name: value`
+    Markdown,
+}
+
+impl HeaderSyntax {
+    fn attr_pattern(&self) -> Regex {
+        match self {
+            Self::Asciidoc => Regex::new(r"^:([\w-]+):(.*)$").unwrap(),
+            Self::Markdown => Regex::new(r"^([\w-]+):(.*)$").unwrap(),
+        }
+    }
+
+    fn title_pattern(&self) -> Regex {
+        match self {
+            // This pattern also include markdown title handling fallbacks to handle malformed
+            // documents
+            Self::Asciidoc => Regex::new(r"^[=#]").unwrap(),
+            Self::Markdown => Regex::new(r"^#").unwrap(),
+        }
+    }
+
+    fn format_attr(&self, name: &str, value: &str) -> String {
+        match self {
+            Self::Asciidoc => format!(":{}: {}\n", name, value),
+            Self::Markdown => format!("{}: {}\n", name, value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum HeaderLine {
+    Title {
+        raw: String,
+        value: String,
+        // The location of the title value within the raw line
+        span: Range<usize>,
+    },
+    Attribute {
+        name: String,
+        value: String,
+        raw: String,
+    },
+    Other(String),
+}
+
+impl HeaderLine {
+    fn raw(&self) -> &str {
+        match self {
+            Self::Title { raw, .. } => raw,
+            Self::Attribute { raw, .. } => raw,
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+/// The parsed attribute block of an RFD document. Lines of the header that are not modified are
+/// written back out exactly as they were read
+#[derive(Debug, Clone, PartialEq)]
+pub struct RfdHeader {
+    pub title: Option<String>,
+    pub state: Option<RfdState>,
+    pub authors: Vec<String>,
+    pub labels: Vec<String>,
+    pub discussion: Option<String>,
+    attributes: Vec<(String, String)>,
+    syntax: HeaderSyntax,
+    lines: Vec<HeaderLine>,
+    // Authors that were read from outside of the attribute block (i.e. an Asciidoc author line).
+    // These are not written back out as an attribute unless they are changed
+    implicit_authors: Vec<String>,
+}
+
+static STATE_ATTR: &str = "state";
+static AUTHORS_ATTR: &str = "authors";
+static LABELS_ATTR: &str = "labels";
+static DISCUSSION_ATTR: &str = "discussion";
+
+impl RfdHeader {
+    /// Parse the header from the start of a document. A document without a title line has a
+    /// header made up of only the attribute entries at the very start of the document
+    pub fn parse(syntax: HeaderSyntax, content: &str) -> Self {
+        let (lines, _) = Self::parse_lines(syntax, content);
+
+        let mut header = Self {
+            title: None,
+            state: None,
+            authors: vec![],
+            labels: vec![],
+            discussion: None,
+            attributes: vec![],
+            syntax,
+            lines: vec![],
+            implicit_authors: vec![],
+        };
+
+        // Only the first occurrence of an attribute is considered to be its value
+        let mut seen = HashSet::new();
+
+        for line in &lines {
+            match line {
+                HeaderLine::Title { value, .. } if header.title.is_none() => {
+                    header.title = Some(value.to_string());
+                }
+                HeaderLine::Attribute { name, value, .. } if seen.insert(name.as_str()) => {
+                    if name == STATE_ATTR {
+                        header.state = RfdState::try_from(value.as_str()).ok();
+                    } else if name == AUTHORS_ATTR {
+                        header.authors = split_authors(value);
+                    } else if name == LABELS_ATTR {
+                        header.labels = split_list(value);
+                    } else if name == DISCUSSION_ATTR {
+                        header.discussion = Some(value.to_string()).filter(|v| !v.is_empty());
+                    } else {
+                        header
+                            .attributes
+                            .push((name.to_string(), value.to_string()));
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        header.lines = lines;
+        header
+    }
+
+    /// The byte length of the header block at the start of a document
+    pub fn block_len(syntax: HeaderSyntax, content: &str) -> usize {
+        Self::parse_lines(syntax, content).1
+    }
+
+    fn parse_lines(syntax: HeaderSyntax, content: &str) -> (Vec<HeaderLine>, usize) {
+        let attr_pattern = syntax.attr_pattern();
+        let title_pattern = syntax.title_pattern();
+        let title_value_pattern = Regex::new(r"^[=# ]+(?:RFD ?)?(?:\d+:? )?(.*)$").unwrap();
+
+        let mut lines = vec![];
+        let mut len = 0;
+        let mut found_title = false;
+
+        for raw in content.split_inclusive('\n') {
+            let line = raw.trim_end_matches(['\n', '\r']);
+
+            if let Some(caps) = attr_pattern.captures(line) {
+                lines.push(HeaderLine::Attribute {
+                    name: caps.get(1).unwrap().as_str().to_string(),
+                    value: caps.get(2).unwrap().as_str().trim().to_string(),
+                    raw: raw.to_string(),
+                });
+            } else if found_title {
+                // Asciidoc allows for attribute entries to directly follow the document title. The
+                // header ends at the first line that is not an attribute entry
+                break;
+            } else if title_pattern.is_match(line) {
+                let span = title_value_pattern
+                    .captures(line)
+                    .and_then(|caps| caps.get(1))
+                    .map(|value| {
+                        let untrimmed = value.as_str();
+                        let start = value.start() + untrimmed.len() - untrimmed.trim_start().len();
+                        let end = value.end() - (untrimmed.len() - untrimmed.trim_end().len());
+                        start..end.max(start)
+                    })
+                    .unwrap_or(line.len()..line.len());
+
+                lines.push(HeaderLine::Title {
+                    raw: raw.to_string(),
+                    value: line[span.clone()].to_string(),
+                    span,
+                });
+                found_title = true;
+
+                if syntax == HeaderSyntax::Markdown {
+                    len += raw.len();
+                    break;
+                }
+            } else {
+                lines.push(HeaderLine::Other(raw.to_string()));
+            }
+
+            len += raw.len();
+        }
+
+        // Without a title there is nothing that marks the end of the header. Only the attribute
+        // entries at the very start of the document are considered to be part of it
+        if !found_title {
+            let end = lines
+                .iter()
+                .position(|line| !matches!(line, HeaderLine::Attribute { .. }))
+                .unwrap_or(lines.len());
+            lines.truncate(end);
+            len = lines.iter().map(|line| line.raw().len()).sum();
+        }
+
+        (lines, len)
+    }
+
+    /// Use authors that were found outside of the attribute block when the block itself does not
+    /// define any
+    pub(crate) fn with_implicit_authors(mut self, authors: Vec<String>) -> Self {
+        let has_authors_attr = self
+            .lines
+            .iter()
+            .any(|line| matches!(line, HeaderLine::Attribute { name, .. } if name == AUTHORS_ATTR));
+
+        if !has_authors_attr {
+            self.authors = authors.clone();
+            self.implicit_authors = authors;
+        }
+
+        self
+    }
+
+    /// Get the value of an attribute that does not have a typed field
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set the value of an attribute that does not have a typed field
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        if let Some(existing) = self.attributes.iter_mut().find(|(n, _)| n == name) {
+            existing.1 = value.to_string();
+        } else {
+            self.attributes.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    /// Remove an attribute that does not have a typed field
+    pub fn remove_attribute(&mut self, name: &str) {
+        self.attributes.retain(|(n, _)| n != name);
+    }
+
+    /// All of the attributes that do not have a typed field, in the order they were read
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    // The current value of an attribute formatted for writing to the document
+    fn current_value(&self, name: &str) -> Option<String> {
+        if name == STATE_ATTR {
+            self.state.as_ref().map(|state| state.to_string())
+        } else if name == AUTHORS_ATTR {
+            Some(self.authors.join(", ")).filter(|value| !value.is_empty())
+        } else if name == LABELS_ATTR {
+            Some(self.labels.join(", ")).filter(|value| !value.is_empty())
+        } else if name == DISCUSSION_ATTR {
+            self.discussion.clone()
+        } else {
+            self.attribute(name).map(|value| value.to_string())
+        }
+    }
+
+    // Check if the current value of an attribute is equivalent to a value read from the document
+    fn is_unchanged(&self, name: &str, value: &str) -> bool {
+        if name == STATE_ATTR {
+            match RfdState::try_from(value) {
+                Ok(state) => Some(state) == self.state,
+                // Unrecognized states are preserved as is unless a new state has been set
+                Err(_) => self.state.is_none(),
+            }
+        } else if name == AUTHORS_ATTR {
+            split_authors(value) == self.authors
+        } else if name == LABELS_ATTR {
+            split_list(value) == self.labels
+        } else if name == DISCUSSION_ATTR {
+            self.discussion.as_deref().unwrap_or_default() == value
+        } else {
+            self.attribute(name) == Some(value)
+        }
+    }
+
+    /// Render the header block. Any lines whose values have not been modified are emitted
+    /// unchanged
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        let mut emitted = HashSet::new();
+        let mut insert_at = None;
+        let mut before_title = true;
+
+        for line in &self.lines {
+            match line {
+                HeaderLine::Title { raw, value, span } => {
+                    if before_title && insert_at.is_none() {
+                        insert_at = Some(rendered.len());
+                    }
+                    before_title = false;
+
+                    match &self.title {
+                        // Only the title value is replaced so that any prefix (i.e. the RFD number)
+                        // is left as is
+                        Some(title) if title != value && !value.is_empty() => {
+                            rendered.push_str(&raw[..span.start]);
+                            rendered.push_str(title);
+                            rendered.push_str(&raw[span.end..]);
+                        }
+                        _ => rendered.push_str(raw),
+                    }
+                }
+                HeaderLine::Attribute { name, value, raw } => {
+                    if emitted.insert(name.as_str()) {
+                        if self.is_unchanged(name, value) {
+                            rendered.push_str(raw);
+                        } else if let Some(current) = self.current_value(name) {
+                            rendered.push_str(&self.syntax.format_attr(name, &current));
+                        }
+                    } else {
+                        // Repeated attributes are not managed by the header and are left alone
+                        rendered.push_str(raw);
+                    }
+
+                    if before_title {
+                        insert_at = Some(rendered.len());
+                    }
+                }
+                HeaderLine::Other(raw) => rendered.push_str(raw),
+            }
+        }
+
+        // Any attributes that did not previously exist in the header are added directly after the
+        // last attribute that precedes the title, or at the end of the header if there is no title
+        let mut additions = String::new();
+        for name in [STATE_ATTR, AUTHORS_ATTR, LABELS_ATTR, DISCUSSION_ATTR] {
+            let implicit = name == AUTHORS_ATTR && self.authors == self.implicit_authors;
+
+            if !emitted.contains(name) && !implicit {
+                if let Some(value) = self.current_value(name) {
+                    additions.push_str(&self.syntax.format_attr(name, &value));
+                }
+            }
+        }
+        for (name, value) in &self.attributes {
+            if !emitted.contains(name.as_str()) {
+                additions.push_str(&self.syntax.format_attr(name, value));
+            }
+        }
+
+        if !additions.is_empty() {
+            let at = insert_at.unwrap_or(rendered.len());
+
+            // Guard against appending to a final line that does not end in a newline
+            if at > 0 && !rendered[..at].ends_with('\n') {
+                additions.insert(0, '\n');
+            }
+
+            rendered.insert_str(at, &additions);
+        }
+
+        rendered
+    }
+}
+
+// Authors may be separated by either commas or semicolons, and commas may appear within an email
+pub(crate) fn split_authors(value: &str) -> Vec<String> {
+    RfdAuthor::parse_list(value)
+        .iter()
+        .map(|author| author.to_string())
+        .collect()
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeaderSyntax, RfdHeader};
+    use crate::RfdState;
+
+    static ASCIIDOC: &str = r#":showtitle:
+:toc: left
+:numbered:
+:icons: font
+:state: published
+:discussion: https://github.com/org/repo/pull/123
+:revremark: State: {state} | {discussion}
+:authors: Firstname Lastname <first@company>, Other Name <other@company>
+:labels:  api,  storage
+
+= RFD 123 Test Rfd
+{authors}
+
+Body text
+"#;
+
+    static MARKDOWN: &str = r#"authors: Firstname Lastname <first@company>
+state: discussion
+discussion: https://github.com/org/repo/pull/123
+labels: api
+
+# RFD 123 Test Rfd
+
+Body text
+"#;
+
+    // Parsing tests
+
+    #[test]
+    fn test_parse_asciidoc_header() {
+        let header = RfdHeader::parse(HeaderSyntax::Asciidoc, ASCIIDOC);
+
+        assert_eq!(Some("Test Rfd"), header.title.as_deref());
+        assert_eq!(Some(RfdState::Published), header.state);
+        assert_eq!(
+            vec![
+                "Firstname Lastname <first@company>",
+                "Other Name <other@company>"
+            ],
+            header.authors
+        );
+        assert_eq!(vec!["api", "storage"], header.labels);
+        assert_eq!(
+            Some("https://github.com/org/repo/pull/123"),
+            header.discussion.as_deref()
+        );
+        assert_eq!(Some("left"), header.attribute("toc"));
+        assert_eq!(Some(""), header.attribute("numbered"));
+        assert_eq!(
+            Some("State: {state} | {discussion}"),
+            header.attribute("revremark")
+        );
+    }
+
+    #[test]
+    fn test_parse_markdown_header() {
+        let header = RfdHeader::parse(HeaderSyntax::Markdown, MARKDOWN);
+
+        assert_eq!(Some("Test Rfd"), header.title.as_deref());
+        assert_eq!(Some(RfdState::Discussion), header.state);
+        assert_eq!(vec!["Firstname Lastname <first@company>"], header.authors);
+        assert_eq!(vec!["api"], header.labels);
+        assert_eq!(
+            Some("https://github.com/org/repo/pull/123"),
+            header.discussion.as_deref()
+        );
+    }
+
+    #[test]
+    fn test_parse_semicolon_separated_authors() {
+        let content = ":authors: First <first@company>; Other <other@company>\n\n= Title\n";
+        let header = RfdHeader::parse(HeaderSyntax::Asciidoc, content);
+
+        assert_eq!(
+            vec!["First <first@company>", "Other <other@company>"],
+            header.authors
+        );
+        assert_eq!(content, header.render());
+    }
+
+    #[test]
+    fn test_parse_asciidoc_attributes_after_title() {
+        let content = ":toc: left\n= Title\n:state: ideation\n\nBody\n:state: nope\n";
+        let header = RfdHeader::parse(HeaderSyntax::Asciidoc, content);
+
+        assert_eq!(Some(RfdState::Ideation), header.state);
+        assert_eq!(
+            ":toc: left\n= Title\n:state: ideation\n".len(),
+            RfdHeader::block_len(HeaderSyntax::Asciidoc, content)
+        );
+    }
+
+    // Round trip tests
+
+    #[test]
+    fn test_round_trip_unmodified_asciidoc_header() {
+        let header = RfdHeader::parse(HeaderSyntax::Asciidoc, ASCIIDOC);
+        let len = RfdHeader::block_len(HeaderSyntax::Asciidoc, ASCIIDOC);
+
+        assert_eq!(&ASCIIDOC[..len], header.render());
+    }
+
+    #[test]
+    fn test_round_trip_unmodified_markdown_header() {
+        let header = RfdHeader::parse(HeaderSyntax::Markdown, MARKDOWN);
+        let len = RfdHeader::block_len(HeaderSyntax::Markdown, MARKDOWN);
+
+        assert_eq!(&MARKDOWN[..len], header.render());
+    }
+
+    #[test]
+    fn test_round_trip_equivalent_values_are_not_reformatted() {
+        let mut header = RfdHeader::parse(HeaderSyntax::Asciidoc, ASCIIDOC);
+        header.labels = vec!["api".to_string(), "storage".to_string()];
+
+        assert!(header.render().contains(":labels:  api,  storage\n"));
+    }
+
+    // Update tests
+
+    #[test]
+    fn test_update_asciidoc_header_only_touches_modified_lines() {
+        let mut header = RfdHeader::parse(HeaderSyntax::Asciidoc, ASCIIDOC);
+        header.state = Some(RfdState::Abandoned);
+        header.title = Some("New Title".to_string());
+
+        let expected = r#":showtitle:
+:toc: left
+:numbered:
+:icons: font
+:state: abandoned
+:discussion: https://github.com/org/repo/pull/123
+:revremark: State: {state} | {discussion}
+:authors: Firstname Lastname <first@company>, Other Name <other@company>
+:labels:  api,  storage
+
+= RFD 123 New Title
+"#;
+        assert_eq!(expected, header.render());
+    }
+
+    #[test]
+    fn test_update_markdown_header_adds_missing_attributes() {
+        let content = "authors: Firstname Lastname <first@company>\n\n# RFD 123 Test Rfd\n";
+        let mut header = RfdHeader::parse(HeaderSyntax::Markdown, content);
+        header.state = Some(RfdState::Prediscussion);
+        header.labels = vec!["api".to_string(), "storage".to_string()];
+        header.set_attribute("custom", "value");
+
+        let expected = r#"authors: Firstname Lastname <first@company>
+state: prediscussion
+labels: api, storage
+custom: value
+
+# RFD 123 Test Rfd
+"#;
+        assert_eq!(expected, header.render());
+    }
+
+    #[test]
+    fn test_update_header_removes_cleared_attributes() {
+        let mut header = RfdHeader::parse(HeaderSyntax::Markdown, MARKDOWN);
+        header.discussion = None;
+        header.labels = vec![];
+
+        let expected = r#"authors: Firstname Lastname <first@company>
+state: discussion
+
+# RFD 123 Test Rfd
+"#;
+        assert_eq!(expected, header.render());
+    }
+
+    #[test]
+    fn test_update_title_only_replaces_title_value() {
+        let content = ":state: published\n\n= RFD 12 1\n\nBody\n";
+        let mut header = RfdHeader::parse(HeaderSyntax::Asciidoc, content);
+        header.title = Some("Replaced".to_string());
+
+        assert_eq!(":state: published\n\n= RFD 12 Replaced\n", header.render());
+    }
+
+    #[test]
+    fn test_header_without_title_only_contains_leading_attributes() {
+        let content = ":state: published\n:labels: api\nBody text\n:labels: nope\n\nMore body\n";
+        let mut header = RfdHeader::parse(HeaderSyntax::Asciidoc, content);
+
+        assert_eq!(None, header.title);
+        assert_eq!(vec!["api"], header.labels);
+        assert_eq!(
+            ":state: published\n:labels: api\n".len(),
+            RfdHeader::block_len(HeaderSyntax::Asciidoc, content)
+        );
+
+        header.state = Some(RfdState::Committed);
+        assert_eq!(":state: committed\n:labels: api\n", header.render());
+    }
+
+    #[test]
+    fn test_unrecognized_state_is_preserved() {
+        let content = ":state: sdfsdfsdf\n= Title\n";
+        let header = RfdHeader::parse(HeaderSyntax::Asciidoc, content);
+
+        assert_eq!(None, header.state);
+        assert_eq!(content, header.render());
+    }
+}
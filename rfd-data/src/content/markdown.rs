@@ -5,7 +5,10 @@
 use regex::Regex;
 use std::borrow::Cow;
 
-use super::RfdDocument;
+use super::{
+    header::{HeaderSyntax, RfdHeader},
+    RfdDocument,
+};
 
 #[derive(Debug, Clone)]
 pub struct RfdMarkdown<'a> {
@@ -31,32 +34,6 @@ impl<'a> RfdMarkdown<'a> {
         })
     }
 
-    fn set_attr(&mut self, attr: &str, value: &str) {
-        let pattern = self.attr_pattern(attr);
-
-        if let Some(found) = pattern.find(&self.content) {
-            self.content = self
-                .content
-                .replacen(found.as_str(), &format!("{}: {}\n", attr, value), 1)
-                .into()
-        } else {
-            let title = self.title_pattern().find(&self.content);
-
-            if let Some(title) = title {
-                let new_attr = format!("{}: {}\n", attr, value);
-                let header = self.header();
-                let body = self.body();
-
-                self.content = (header.unwrap_or_default().to_string()
-                    + "\n"
-                    + &new_attr
-                    + title.as_str()
-                    + body.unwrap_or_default())
-                .into()
-            }
-        }
-    }
-
     fn attr_pattern(&self, attr: &str) -> Regex {
         Regex::new(&format!(r"(?m)^{}:(.*)$\n", attr)).unwrap()
     }
@@ -84,18 +61,10 @@ impl<'a> RfdDocument for RfdMarkdown<'a> {
         self.attr("state")
     }
 
-    fn update_state(&mut self, value: &str) {
-        self.set_attr("state", value)
-    }
-
     fn get_discussion(&self) -> Option<&str> {
         self.attr("discussion")
     }
 
-    fn update_discussion(&mut self, value: &str) {
-        self.set_attr("discussion", value)
-    }
-
     fn get_authors(&self) -> Option<&str> {
         self.attr("authors")
     }
@@ -104,8 +73,13 @@ impl<'a> RfdDocument for RfdMarkdown<'a> {
         self.attr("labels")
     }
 
-    fn update_labels(&mut self, value: &str) {
-        self.set_attr("labels", value)
+    fn get_header(&self) -> RfdHeader {
+        RfdHeader::parse(HeaderSyntax::Markdown, &self.content)
+    }
+
+    fn update_header(&mut self, header: &RfdHeader) {
+        let len = RfdHeader::block_len(HeaderSyntax::Markdown, &self.content);
+        self.content = Cow::Owned(header.render() + &self.content[len..]);
     }
 
     fn header(&self) -> Option<&str> {
@@ -169,19 +143,6 @@ sdf
         assert!(authors.is_none());
     }
 
-    #[test]
-    fn test_set_nonexistent_attribute() {
-        let content = r#"sdfsdf
-# sdfgsdfgsdfg
-discussion: https://github.com/org/repo/pulls/1
-dsfsdf
-sdf
-discussion: nope"#;
-        let mut rfd = RfdMarkdown::new(content);
-        rfd.set_attr("xrefstyle", "short");
-        assert_eq!(Some("short"), rfd.attr("xrefstyle"))
-    }
-
     // Read state tests
 
     #[test]
@@ -214,54 +175,6 @@ authors: nope"#;
         assert_eq!(expected, discussion);
     }
 
-    // Update discussion link tests
-
-    #[test]
-    fn test_update_existing_markdown_discussion_link() {
-        let link = "https://github.com/org/repo/pulls/2019";
-
-        let content = r#"sdfsdf
-sdfsdf
-discussion:   https://github.com/org/repo/pulls/1
-dsfsdf
-sdf
-authors: nope"#;
-        let mut rfd = RfdMarkdown::new(content);
-        rfd.update_discussion(link);
-
-        let expected = r#"sdfsdf
-sdfsdf
-discussion: https://github.com/org/repo/pulls/2019
-dsfsdf
-sdf
-authors: nope"#;
-
-        assert_eq!(expected, rfd.raw());
-    }
-
-    // Update state tests
-
-    #[test]
-    fn test_update_existing_markdown_state() {
-        let state = "discussion";
-        let content = r#"sdfsdf
-sdfsdf
-state:   sdfsdfsdf
-dsfsdf
-sdf
-authors: nope"#;
-        let mut rfd = RfdMarkdown::new(content);
-        rfd.update_state(state);
-
-        let expected = r#"sdfsdf
-sdfsdf
-state: discussion
-dsfsdf
-sdf
-authors: nope"#;
-        assert_eq!(expected, rfd.raw());
-    }
-
     // Read title tests
 
     #[test]
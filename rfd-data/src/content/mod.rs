@@ -3,14 +3,19 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod asciidoc;
+mod header;
 mod markdown;
+mod section;
 mod template;
 
 pub use asciidoc::RfdAsciidoc;
+pub use header::{HeaderSyntax, RfdHeader};
 pub use markdown::RfdMarkdown;
+pub use section::{replace_section_body, source_sections, SourceSection};
 pub use template::{RenderableRfdTemplate, RfdTemplate, TemplateError};
 
 use rfd_model::{schema_ext::ContentFormat, RfdRevision};
+use std::borrow::Cow;
 
 pub trait RfdDocument {
     /// Extract the title from the internal content
@@ -19,15 +24,9 @@ pub trait RfdDocument {
     /// Get the state value stored within the document
     fn get_state(&self) -> Option<&str>;
 
-    // Update the state value stored within the document or add it if it does not exist
-    fn update_state(&mut self, value: &str);
-
     /// Get the discussion link stored within the document
     fn get_discussion(&self) -> Option<&str>;
 
-    // Update the discussion link stored within the document or add it if it does not exist
-    fn update_discussion(&mut self, value: &str);
-
     /// Get the authors line stored within the document. The returned string may contain multiple
     /// names
     fn get_authors(&self) -> Option<&str>;
@@ -35,8 +34,12 @@ pub trait RfdDocument {
     /// Get the labels stored within the document
     fn get_labels(&self) -> Option<&str>;
 
-    // Update the labels stored within the document or add them if they do not exist
-    fn update_labels(&mut self, value: &str);
+    /// Parse the attribute block of the document into a typed header
+    fn get_header(&self) -> RfdHeader;
+
+    /// Replace the attribute block of the document with the rendered header. Lines that have not
+    /// been modified are left untouched
+    fn update_header(&mut self, header: &RfdHeader);
 
     // Get a reference to the contents of the RFD header
     fn header(&self) -> Option<&str>;
@@ -58,6 +61,16 @@ pub enum RfdContent<'a> {
 }
 
 impl<'a> RfdContent<'a> {
+    pub fn new<T>(content: T, format: ContentFormat) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        match format {
+            ContentFormat::Asciidoc => Self::Asciidoc(RfdAsciidoc::new(content)),
+            ContentFormat::Markdown => Self::Markdown(RfdMarkdown::new(content)),
+        }
+    }
+
     pub fn format(&self) -> ContentFormat {
         match self {
             Self::Asciidoc(_) => ContentFormat::Asciidoc,
@@ -81,13 +94,6 @@ impl<'a> RfdDocument for RfdContent<'a> {
         }
     }
 
-    fn update_state(&mut self, value: &str) {
-        match self {
-            Self::Asciidoc(inner) => inner.update_state(value),
-            Self::Markdown(inner) => inner.update_state(value),
-        }
-    }
-
     fn get_discussion(&self) -> Option<&str> {
         match self {
             Self::Asciidoc(inner) => inner.get_discussion(),
@@ -95,13 +101,6 @@ impl<'a> RfdDocument for RfdContent<'a> {
         }
     }
 
-    fn update_discussion(&mut self, value: &str) {
-        match self {
-            Self::Asciidoc(inner) => inner.update_discussion(value),
-            Self::Markdown(inner) => inner.update_discussion(value),
-        }
-    }
-
     fn get_authors(&self) -> Option<&str> {
         match self {
             Self::Asciidoc(inner) => inner.get_authors(),
@@ -116,10 +115,17 @@ impl<'a> RfdDocument for RfdContent<'a> {
         }
     }
 
-    fn update_labels(&mut self, value: &str) {
+    fn get_header(&self) -> RfdHeader {
+        match self {
+            Self::Asciidoc(inner) => inner.get_header(),
+            Self::Markdown(inner) => inner.get_header(),
+        }
+    }
+
+    fn update_header(&mut self, header: &RfdHeader) {
         match self {
-            Self::Asciidoc(inner) => inner.update_labels(value),
-            Self::Markdown(inner) => inner.update_labels(value),
+            Self::Asciidoc(inner) => inner.update_header(header),
+            Self::Markdown(inner) => inner.update_header(header),
         }
     }
 
@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ops::Range;
+
+/// A section heading located within the source of a RFD document. Sections are listed in document
+/// order, which matches the order produced by the search section parser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSection {
+    /// Nesting level of the section. Top level sections (`==` or `##`) are level 1
+    pub level: usize,
+    pub title: String,
+    /// Byte range of the section body. This starts after the heading line and ends at the start of
+    /// the next section, so it never includes nested sections
+    pub body: Range<usize>,
+}
+
+/// Locate the section headings in the source of a document. The document title and any headings
+/// inside of delimited blocks or marked as discrete are not sections
+pub fn source_sections(content: &str) -> Vec<SourceSection> {
+    let mut sections: Vec<SourceSection> = vec![];
+
+    let mut offset = 0;
+    let mut open_block: Option<&str> = None;
+    let mut discrete = false;
+
+    // Start of the run of block attribute lines (anchors, roles, etc) that immediately precede the
+    // current line. These belong to the heading that follows them
+    let mut attributes_start = None;
+
+    for line in content.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim_end();
+
+        if let Some(delimiter) = open_block {
+            if trimmed == delimiter {
+                open_block = None;
+            }
+            continue;
+        }
+
+        if is_block_delimiter(trimmed) {
+            open_block = Some(trimmed);
+            attributes_start = None;
+            discrete = false;
+            continue;
+        }
+
+        if let Some((level, title)) = heading(trimmed) {
+            if !discrete && level > 0 {
+                let section_start = attributes_start.unwrap_or(start);
+
+                if let Some(prev) = sections.last_mut() {
+                    prev.body.end = section_start;
+                }
+
+                sections.push(SourceSection {
+                    level,
+                    title: title.to_string(),
+                    body: offset..content.len(),
+                });
+            }
+
+            attributes_start = None;
+            discrete = false;
+        } else if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            attributes_start.get_or_insert(start);
+            discrete |= trimmed == "[discrete]" || trimmed == "[float]";
+        } else {
+            attributes_start = None;
+            discrete = false;
+        }
+    }
+
+    sections
+}
+
+/// Replace the body of a section, leaving its heading and all other sections untouched
+pub fn replace_section_body(content: &str, section: &SourceSection, body: &str) -> String {
+    let body = body.trim_matches('\n');
+    let replacement = if section.body.end == content.len() {
+        format!("\n{}\n", body)
+    } else {
+        format!("\n{}\n\n", body)
+    };
+
+    let mut updated = String::with_capacity(content.len() + replacement.len());
+    updated.push_str(&content[..section.body.start]);
+    updated.push_str(&replacement);
+    updated.push_str(&content[section.body.end..]);
+    updated
+}
+
+// Parse a heading line into its level and title. Both AsciiDoc (`=`) and Markdown (`#`) style
+// markers are accepted as AsciiDoc supports both
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let marker = line.chars().next().filter(|c| *c == '=' || *c == '#')?;
+    let depth = line.chars().take_while(|c| *c == marker).count();
+    let rest = &line[depth..];
+
+    if depth <= 6 && rest.starts_with(' ') && !rest.trim().is_empty() {
+        Some((depth - 1, rest.trim()))
+    } else {
+        None
+    }
+}
+
+fn is_block_delimiter(line: &str) -> bool {
+    if line.starts_with("```") {
+        return true;
+    }
+
+    // Tables are delimited by a separator character followed by at least three `=`
+    if let Some(rest) = line
+        .strip_prefix('|')
+        .or_else(|| line.strip_prefix(','))
+        .or_else(|| line.strip_prefix(':'))
+        .or_else(|| line.strip_prefix('!'))
+    {
+        return rest.len() >= 3 && rest.chars().all(|next| next == '=');
+    }
+
+    let mut chars = line.chars();
+    match chars.next() {
+        Some(c) if "-.=*_+/".contains(c) => line.len() >= 4 && chars.all(|next| next == c),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace_section_body, source_sections};
+
+    static DOCUMENT: &str = r#":state: published
+
+= RFD 123 On Parsing Documents
+
+An introductory line about the document
+
+== Background
+
+A paragraph about background topics
+
+----
+== Not a heading
+----
+
+|===
+|Cell
+
+== Not a heading either
+|===
+
+[[possibilities]]
+== Possibilities
+
+Nested sections describing possible options
+
+[discrete]
+=== A discrete heading
+
+=== The First Option
+
+First in the list"#;
+
+    #[test]
+    fn test_locates_sections() {
+        let sections = source_sections(DOCUMENT)
+            .into_iter()
+            .map(|section| (section.level, section.title))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (1, "Background".to_string()),
+                (1, "Possibilities".to_string()),
+                (2, "The First Option".to_string()),
+            ],
+            sections
+        );
+    }
+
+    #[test]
+    fn test_section_body_excludes_nested_sections() {
+        let sections = source_sections(DOCUMENT);
+
+        assert_eq!(
+            "\nA paragraph about background topics\n\n----\n== Not a heading\n----\n\n|===\n|Cell\n\n== Not a heading either\n|===\n\n",
+            &DOCUMENT[sections[0].body.clone()]
+        );
+        assert_eq!(
+            "\nNested sections describing possible options\n\n[discrete]\n=== A discrete heading\n\n",
+            &DOCUMENT[sections[1].body.clone()]
+        );
+        assert_eq!("\nFirst in the list", &DOCUMENT[sections[2].body.clone()]);
+    }
+
+    #[test]
+    fn test_replace_section_body() {
+        let sections = source_sections(DOCUMENT);
+        let updated = replace_section_body(DOCUMENT, &sections[0], "New background\n");
+
+        assert!(updated
+            .contains("== Background\n\nNew background\n\n[[possibilities]]\n== Possibilities"));
+        assert_eq!(3, source_sections(&updated).len());
+
+        let updated = replace_section_body(DOCUMENT, &sections[2], "Last");
+        assert!(updated.ends_with("=== The First Option\n\nLast\n"));
+    }
+}
@@ -2,8 +2,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use rfd_model::schema_ext::ContentFormat;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -13,26 +14,85 @@ pub enum TemplateError {
         template: RfdTemplate,
         values: Vec<String>,
     },
+    #[error("Template does not support the {format:?} format")]
+    UnsupportedFormat { format: ContentFormat },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct RfdTemplate {
+    /// Short explanation of when this template should be used
+    #[serde(default)]
+    description: Option<String>,
+    /// AsciiDoc template
     template: String,
+    /// Optional Markdown variant of the template
+    #[serde(default)]
+    markdown_template: Option<String>,
     #[serde(default)]
     values: HashMap<String, String>,
     required_fields: Vec<String>,
+    /// Fields that may be supplied, along with the value to use when they are not
+    #[serde(default)]
+    optional_fields: BTreeMap<String, String>,
+    #[serde(skip)]
+    format: Option<ContentFormat>,
 }
 
 #[derive(Clone, Debug)]
 pub struct RenderableRfdTemplate(RfdTemplate);
 
 impl RfdTemplate {
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn required_fields(&self) -> &[String] {
+        &self.required_fields
+    }
+
+    pub fn optional_fields(&self) -> &BTreeMap<String, String> {
+        &self.optional_fields
+    }
+
+    /// Whether the template declares the given field as either required or optional
+    pub fn supports_field(&self, field: &str) -> bool {
+        self.required_fields
+            .iter()
+            .any(|required| required == field)
+            || self.optional_fields.contains_key(field)
+    }
+
+    /// The content formats that this template can be rendered as
+    pub fn formats(&self) -> Vec<ContentFormat> {
+        let mut formats = vec![ContentFormat::Asciidoc];
+
+        if self.markdown_template.is_some() {
+            formats.push(ContentFormat::Markdown);
+        }
+
+        formats
+    }
+
     pub fn field(mut self, field: String, value: String) -> Self {
         self.values.insert(field, value);
         self
     }
 
+    /// Select the format to render. Templates are rendered as AsciiDoc by default
+    pub fn format(mut self, format: ContentFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
     pub fn build(self) -> Result<RenderableRfdTemplate, TemplateError> {
+        if let Some(format) = &self.format {
+            if !self.formats().contains(format) {
+                return Err(TemplateError::UnsupportedFormat {
+                    format: format.clone(),
+                });
+            }
+        }
+
         let set_fields = self.values.keys().collect::<Vec<_>>();
         let missing_fields = self
             .required_fields
@@ -53,13 +113,156 @@ impl RfdTemplate {
 }
 
 impl RenderableRfdTemplate {
+    fn value(&self, field: &str) -> Option<&str> {
+        let value = if self
+            .0
+            .required_fields
+            .iter()
+            .any(|required| required == field)
+        {
+            Some(self.0.values.get(field).expect(
+                "Renderable template is missing a required field. This is a bug, please report.",
+            ))
+        } else {
+            self.0
+                .optional_fields
+                .get(field)
+                .map(|default| self.0.values.get(field).unwrap_or(default))
+        };
+
+        value.map(|value| value.as_str())
+    }
+
     pub fn render(self) -> String {
-        let mut rendered = self.0.template;
+        let template = match self.0.format {
+            Some(ContentFormat::Markdown) => self.0.markdown_template.as_deref().expect(
+                "Renderable template is missing its Markdown variant. This is a bug, please report.",
+            ),
+            _ => &self.0.template,
+        };
+
+        // Placeholders are substituted in a single pass over the template so that values are never
+        // themselves scanned for placeholders
+        let mut rendered = String::with_capacity(template.len());
+        let mut remaining = template;
+
+        while let Some(start) = remaining.find('{') {
+            rendered.push_str(&remaining[..start]);
+            remaining = &remaining[start..];
 
-        for field in self.0.required_fields {
-            rendered = rendered.replace(&format!("{{{}}}", field), self.0.values.get(&field).expect("Renderable template is missing a required field. This is a bug, please report."));
+            let value = remaining
+                .find('}')
+                .and_then(|end| self.value(&remaining[1..end]).map(|value| (end, value)));
+
+            match value {
+                Some((end, value)) => {
+                    rendered.push_str(value);
+                    remaining = &remaining[end + 1..];
+                }
+                None => {
+                    rendered.push('{');
+                    remaining = &remaining[1..];
+                }
+            }
         }
 
+        rendered.push_str(remaining);
         rendered
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use rfd_model::schema_ext::ContentFormat;
+
+    use super::{RfdTemplate, TemplateError};
+
+    fn template() -> RfdTemplate {
+        RfdTemplate {
+            description: None,
+            template: "= RFD {number} {title}\n:state: {state}\n".to_string(),
+            markdown_template: None,
+            values: HashMap::new(),
+            required_fields: vec!["number".to_string(), "title".to_string()],
+            optional_fields: BTreeMap::from([("state".to_string(), "prediscussion".to_string())]),
+            format: None,
+        }
+    }
+
+    #[test]
+    fn test_optional_fields_use_defaults() {
+        let rendered = template()
+            .field("number".to_string(), "123".to_string())
+            .field("title".to_string(), "Title".to_string())
+            .build()
+            .unwrap()
+            .render();
+        assert_eq!("= RFD 123 Title\n:state: prediscussion\n", rendered);
+
+        let rendered = template()
+            .field("number".to_string(), "123".to_string())
+            .field("title".to_string(), "Title".to_string())
+            .field("state".to_string(), "ideation".to_string())
+            .build()
+            .unwrap()
+            .render();
+        assert_eq!("= RFD 123 Title\n:state: ideation\n", rendered);
+    }
+
+    #[test]
+    fn test_values_are_not_rendered() {
+        let rendered = template()
+            .field("number".to_string(), "123".to_string())
+            .field("title".to_string(), "{state} of {unknown}".to_string())
+            .build()
+            .unwrap()
+            .render();
+        assert_eq!(
+            "= RFD 123 {state} of {unknown}\n:state: prediscussion\n",
+            rendered
+        );
+    }
+
+    #[test]
+    fn test_missing_required_fields() {
+        let result = template()
+            .field("number".to_string(), "123".to_string())
+            .build();
+
+        match result {
+            Err(TemplateError::MissingRequiredFields { values, .. }) => {
+                assert_eq!(vec!["title".to_string()], values)
+            }
+            other => panic!("Expected missing fields error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_supports_declared_fields() {
+        let template = template();
+        assert!(template.supports_field("title"));
+        assert!(template.supports_field("state"));
+        assert!(!template.supports_field("body"));
+    }
+
+    #[test]
+    fn test_renders_requested_format() {
+        let mut template = template();
+        assert!(matches!(
+            template.clone().format(ContentFormat::Markdown).build(),
+            Err(TemplateError::UnsupportedFormat { .. })
+        ));
+
+        template.markdown_template = Some("# RFD {number} {title}\n".to_string());
+        let rendered = template
+            .field("number".to_string(), "123".to_string())
+            .field("title".to_string(), "Title".to_string())
+            .format(ContentFormat::Markdown)
+            .build()
+            .unwrap()
+            .render();
+        assert_eq!("# RFD 123 Title\n", rendered);
+    }
+}
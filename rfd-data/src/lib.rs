@@ -8,27 +8,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub mod content;
+pub mod transition;
 
 #[derive(Debug, Copy, Clone)]
 pub struct RfdNumber(i32);
 
-impl RfdNumber {
-    /// Get the path to where the source contents of this RFD exists in the RFD repo.
-    pub fn repo_path(&self) -> String {
-        format!("/rfd/{}", self.as_number_string())
-    }
-
-    /// Get a RFD number in its expanded form with leading 0s
-    pub fn as_number_string(&self) -> String {
-        let mut number_string = self.0.to_string();
-        while number_string.len() < 4 {
-            number_string = format!("0{}", number_string);
-        }
-
-        number_string
-    }
-}
-
 impl Display for RfdNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -59,7 +43,73 @@ impl From<&RfdNumber> for i32 {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+/// Describes how RFD numbers are written in branch names and repository paths. A number is written
+/// as the prefix followed by the number padded with leading zeros to the configured width. Numbers
+/// that need more digits than the width are written in full
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RfdNumberFormat {
+    pub prefix: String,
+    pub width: usize,
+}
+
+impl Default for RfdNumberFormat {
+    fn default() -> Self {
+        Self {
+            prefix: String::new(),
+            width: 4,
+        }
+    }
+}
+
+impl RfdNumberFormat {
+    /// Get a RFD number in its formatted form, i.e. 0123
+    pub fn format(&self, number: &RfdNumber) -> String {
+        format!("{}{:0width$}", self.prefix, number.0, width = self.width)
+    }
+
+    /// Get the name of the branch that a RFD is developed on
+    pub fn branch(&self, number: &RfdNumber) -> String {
+        self.format(number)
+    }
+
+    /// Get the directory (relative to the root of the RFD repo) that holds the source of a RFD
+    pub fn directory(&self, number: &RfdNumber) -> String {
+        format!("rfd/{}", self.format(number))
+    }
+
+    /// Get the path to where the source contents of this RFD exists in the RFD repo.
+    pub fn repo_path(&self, number: &RfdNumber) -> String {
+        format!("/{}", self.directory(number))
+    }
+
+    /// Parse a RFD number from its formatted form. Values that are not exactly the formatted form
+    /// of the number they hold (for instance 123 or 00123 when the width is 4) are rejected, so
+    /// that every number maps to a single branch and directory
+    pub fn parse(&self, value: &str) -> Option<RfdNumber> {
+        let digits = value.strip_prefix(self.prefix.as_str())?;
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        digits
+            .parse::<i32>()
+            .ok()
+            .map(RfdNumber)
+            .filter(|number| self.format(number) == value)
+    }
+
+    /// Parse the number of the RFD that a path in the RFD repo belongs to, i.e. rfd/0123/README.adoc
+    pub fn parse_path(&self, path: &str) -> Option<RfdNumber> {
+        path.trim_start_matches('/')
+            .strip_prefix("rfd/")
+            .and_then(|rest| rest.split('/').next())
+            .and_then(|segment| self.parse(segment))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum RfdState {
     Abandoned,
@@ -100,3 +150,195 @@ impl<'a> TryFrom<&'a str> for RfdState {
         }
     }
 }
+
+/// A single author of an RFD as written in a document's authors line
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RfdAuthor {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+impl RfdAuthor {
+    /// Parse a single author entry. Entries are expected to be of the form `Name <email>`, but a
+    /// bare name or a bare email address are also accepted
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return None;
+        }
+
+        let author = match (value.find('<'), value.rfind('>')) {
+            (Some(start), Some(end)) if start < end => Self {
+                name: Some(value[..start].trim().to_string()).filter(|name| !name.is_empty()),
+                email: Some(value[start + 1..end].trim().to_string())
+                    .filter(|email| !email.is_empty()),
+            },
+            _ if value.contains('@') && !value.contains(char::is_whitespace) => Self {
+                name: None,
+                email: Some(value.to_string()),
+            },
+            _ => Self {
+                name: Some(value.to_string()),
+                email: None,
+            },
+        };
+
+        if author.name.is_none() && author.email.is_none() {
+            None
+        } else {
+            Some(author)
+        }
+    }
+
+    /// Parse a full authors line into its individual authors. Authors may be separated by either
+    /// commas or semicolons
+    pub fn parse_list(value: &str) -> Vec<Self> {
+        let mut authors = vec![];
+        let mut start = 0;
+        let mut in_email = false;
+
+        for (i, c) in value.char_indices() {
+            match c {
+                '<' => in_email = true,
+                '>' => in_email = false,
+                ',' | ';' if !in_email => {
+                    authors.extend(Self::parse(&value[start..i]));
+                    start = i + 1;
+                }
+                _ => (),
+            }
+        }
+
+        authors.extend(Self::parse(&value[start..]));
+        authors
+    }
+}
+
+impl Display for RfdAuthor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.name, &self.email) {
+            (Some(name), Some(email)) => write!(f, "{} <{}>", name, email),
+            (Some(name), None) => write!(f, "{}", name),
+            (None, Some(email)) => write!(f, "{}", email),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RfdAuthor, RfdNumber, RfdNumberFormat};
+
+    fn author(name: Option<&str>, email: Option<&str>) -> RfdAuthor {
+        RfdAuthor {
+            name: name.map(|name| name.to_string()),
+            email: email.map(|email| email.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_author_forms() {
+        assert_eq!(
+            Some(author(Some("Jane Doe"), Some("jane@company"))),
+            RfdAuthor::parse(" Jane Doe <jane@company> ")
+        );
+        assert_eq!(
+            Some(author(None, Some("jane@company"))),
+            RfdAuthor::parse("jane@company")
+        );
+        assert_eq!(
+            Some(author(None, Some("jane@company"))),
+            RfdAuthor::parse("<jane@company>")
+        );
+        assert_eq!(
+            Some(author(Some("Jane Doe"), None)),
+            RfdAuthor::parse("Jane Doe")
+        );
+        assert_eq!(None, RfdAuthor::parse("  "));
+        assert_eq!(None, RfdAuthor::parse("<>"));
+    }
+
+    #[test]
+    fn test_parse_author_list() {
+        assert_eq!(
+            vec![
+                author(Some("Jane Doe"), Some("jane@company")),
+                author(Some("Bob"), Some("bob@company")),
+                author(Some("Alice"), None),
+                author(None, Some("eve@company")),
+            ],
+            RfdAuthor::parse_list(
+                "Jane Doe <jane@company>, Bob <bob@company>; Alice, eve@company,"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_author_list_ignores_separators_in_email() {
+        assert_eq!(
+            vec![author(Some("Odd"), Some("odd,name@company"))],
+            RfdAuthor::parse_list("Odd <odd,name@company>")
+        );
+    }
+
+    #[test]
+    fn test_author_display_round_trips() {
+        let line = "Jane Doe <jane@company>, Alice, eve@company";
+        let rendered = RfdAuthor::parse_list(line)
+            .iter()
+            .map(|author| author.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        assert_eq!(line, rendered);
+    }
+
+    #[test]
+    fn test_formats_rfd_numbers() {
+        let default = RfdNumberFormat::default();
+        assert_eq!("0123", default.branch(&RfdNumber::from(123)));
+        assert_eq!("/rfd/0123", default.repo_path(&RfdNumber::from(123)));
+        assert_eq!("rfd/12345", default.directory(&RfdNumber::from(12345)));
+
+        let prefixed = RfdNumberFormat {
+            prefix: "rfd-".to_string(),
+            width: 5,
+        };
+        assert_eq!("rfd-00123", prefixed.branch(&RfdNumber::from(123)));
+        assert_eq!("rfd/rfd-00123", prefixed.directory(&RfdNumber::from(123)));
+    }
+
+    #[test]
+    fn test_parses_rfd_numbers() {
+        let default = RfdNumberFormat::default();
+        assert_eq!(Some(123), default.parse("0123").map(i32::from));
+        assert_eq!(Some(12345), default.parse("12345").map(i32::from));
+        assert_eq!(None, default.parse("123").map(i32::from));
+        assert_eq!(None, default.parse("00123").map(i32::from));
+        assert_eq!(None, default.parse("+123").map(i32::from));
+        assert_eq!(None, default.parse("main").map(i32::from));
+        assert_eq!(
+            Some(123),
+            default.parse_path("rfd/0123/README.adoc").map(i32::from)
+        );
+        assert_eq!(Some(123), default.parse_path("/rfd/0123").map(i32::from));
+        assert_eq!(
+            None,
+            default.parse_path("docs/0123/README.adoc").map(i32::from)
+        );
+
+        let prefixed = RfdNumberFormat {
+            prefix: "rfd-".to_string(),
+            width: 5,
+        };
+        assert_eq!(Some(123), prefixed.parse("rfd-00123").map(i32::from));
+        assert_eq!(None, prefixed.parse("00123").map(i32::from));
+        assert_eq!(
+            Some(123),
+            prefixed
+                .parse_path("rfd/rfd-00123/images/a.png")
+                .map(i32::from)
+        );
+    }
+}
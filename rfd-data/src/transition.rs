@@ -0,0 +1,297 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::RfdState;
+
+/// Facts about where a RFD currently lives that determine which states it is allowed to be in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RfdStateContext {
+    /// The RFD has an open pull request targeting the default branch
+    pub has_open_pr: bool,
+    /// The RFD has been merged to the default branch
+    pub on_default_branch: bool,
+}
+
+/// A condition that must hold for a transition to be taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateGuard {
+    OpenPullRequest,
+    DefaultBranch,
+    OpenPullRequestOrDefaultBranch,
+}
+
+impl StateGuard {
+    pub fn check(&self, context: &RfdStateContext) -> bool {
+        match self {
+            Self::OpenPullRequest => context.has_open_pr,
+            Self::DefaultBranch => context.on_default_branch,
+            Self::OpenPullRequestOrDefaultBranch => {
+                context.has_open_pr || context.on_default_branch
+            }
+        }
+    }
+}
+
+impl Display for StateGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OpenPullRequest => write!(f, "an open pull request"),
+            Self::DefaultBranch => write!(f, "being merged to the default branch"),
+            Self::OpenPullRequestOrDefaultBranch => write!(
+                f,
+                "an open pull request or being merged to the default branch"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateTransition {
+    pub from: RfdState,
+    pub to: RfdState,
+    pub guard: Option<StateGuard>,
+}
+
+const fn transition(from: RfdState, to: RfdState, guard: Option<StateGuard>) -> StateTransition {
+    StateTransition { from, to, guard }
+}
+
+/// Every transition that a RFD may take between states. Moving to the state that a RFD is already
+/// in is always allowed
+pub static TRANSITIONS: &[StateTransition] = &[
+    transition(RfdState::Prediscussion, RfdState::Ideation, None),
+    transition(
+        RfdState::Prediscussion,
+        RfdState::Discussion,
+        Some(StateGuard::OpenPullRequest),
+    ),
+    transition(RfdState::Prediscussion, RfdState::Abandoned, None),
+    transition(RfdState::Ideation, RfdState::Prediscussion, None),
+    transition(
+        RfdState::Ideation,
+        RfdState::Discussion,
+        Some(StateGuard::OpenPullRequest),
+    ),
+    transition(RfdState::Ideation, RfdState::Abandoned, None),
+    transition(RfdState::Discussion, RfdState::Ideation, None),
+    transition(
+        RfdState::Discussion,
+        RfdState::Published,
+        Some(StateGuard::OpenPullRequestOrDefaultBranch),
+    ),
+    transition(
+        RfdState::Discussion,
+        RfdState::Committed,
+        Some(StateGuard::OpenPullRequestOrDefaultBranch),
+    ),
+    transition(RfdState::Discussion, RfdState::Abandoned, None),
+    transition(RfdState::Published, RfdState::Committed, None),
+    transition(RfdState::Published, RfdState::Abandoned, None),
+    transition(RfdState::Committed, RfdState::Abandoned, None),
+    transition(RfdState::Abandoned, RfdState::Ideation, None),
+];
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StateTransitionError {
+    #[error("RFD can not move from {from} to {to}")]
+    NotAllowed { from: RfdState, to: RfdState },
+    #[error("RFD can not move from {from} to {to} without {guard}")]
+    GuardFailed {
+        from: RfdState,
+        to: RfdState,
+        guard: StateGuard,
+    },
+    #[error("RFD can not be in the {state} state while {location}")]
+    InvalidForLocation {
+        state: RfdState,
+        location: &'static str,
+    },
+}
+
+impl RfdState {
+    /// Check that a RFD in this state is allowed to move to the target state. A RFD that does not
+    /// currently have a recognized state may move to any state that is valid for its location
+    pub fn check_transition(
+        from: Option<&RfdState>,
+        to: &RfdState,
+        context: &RfdStateContext,
+    ) -> Result<(), StateTransitionError> {
+        match from {
+            Some(from) if from != to => {
+                let transition = TRANSITIONS
+                    .iter()
+                    .find(|transition| &transition.from == from && &transition.to == to)
+                    .ok_or_else(|| StateTransitionError::NotAllowed {
+                        from: from.clone(),
+                        to: to.clone(),
+                    })?;
+
+                match transition.guard {
+                    Some(guard) if !guard.check(context) => {
+                        Err(StateTransitionError::GuardFailed {
+                            from: from.clone(),
+                            to: to.clone(),
+                            guard,
+                        })
+                    }
+                    _ => to.check_location(context),
+                }
+            }
+            _ => to.check_location(context),
+        }
+    }
+
+    /// Check that a RFD in this state is allowed to exist at the given location
+    pub fn check_location(&self, context: &RfdStateContext) -> Result<(), StateTransitionError> {
+        let valid = if context.on_default_branch {
+            // A RFD that has been merged has finished discussion
+            matches!(
+                self,
+                RfdState::Published | RfdState::Committed | RfdState::Abandoned
+            )
+        } else if context.has_open_pr {
+            // A RFD with an open pull request has moved past prediscussion
+            !matches!(self, RfdState::Prediscussion)
+        } else {
+            true
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(StateTransitionError::InvalidForLocation {
+                state: self.clone(),
+                location: if context.on_default_branch {
+                    "on the default branch"
+                } else {
+                    "it has an open pull request"
+                },
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RfdStateContext, StateGuard, StateTransitionError};
+    use crate::RfdState;
+
+    fn branch() -> RfdStateContext {
+        RfdStateContext::default()
+    }
+
+    fn pull_request() -> RfdStateContext {
+        RfdStateContext {
+            has_open_pr: true,
+            on_default_branch: false,
+        }
+    }
+
+    fn default_branch() -> RfdStateContext {
+        RfdStateContext {
+            has_open_pr: false,
+            on_default_branch: true,
+        }
+    }
+
+    #[test]
+    fn test_allows_unguarded_transitions() {
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(
+                Some(&RfdState::Prediscussion),
+                &RfdState::Ideation,
+                &branch()
+            )
+        );
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(
+                Some(&RfdState::Published),
+                &RfdState::Committed,
+                &default_branch()
+            )
+        );
+    }
+
+    #[test]
+    fn test_rejects_missing_transitions() {
+        assert_eq!(
+            Err(StateTransitionError::NotAllowed {
+                from: RfdState::Published,
+                to: RfdState::Discussion,
+            }),
+            RfdState::check_transition(
+                Some(&RfdState::Published),
+                &RfdState::Discussion,
+                &pull_request()
+            )
+        );
+    }
+
+    #[test]
+    fn test_enforces_guards() {
+        assert_eq!(
+            Err(StateTransitionError::GuardFailed {
+                from: RfdState::Ideation,
+                to: RfdState::Discussion,
+                guard: StateGuard::OpenPullRequest,
+            }),
+            RfdState::check_transition(Some(&RfdState::Ideation), &RfdState::Discussion, &branch())
+        );
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(
+                Some(&RfdState::Ideation),
+                &RfdState::Discussion,
+                &pull_request()
+            )
+        );
+        assert!(RfdState::check_transition(
+            Some(&RfdState::Discussion),
+            &RfdState::Published,
+            &branch()
+        )
+        .is_err());
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(
+                Some(&RfdState::Discussion),
+                &RfdState::Published,
+                &pull_request()
+            )
+        );
+    }
+
+    #[test]
+    fn test_same_state_is_allowed_when_valid_for_location() {
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(
+                Some(&RfdState::Discussion),
+                &RfdState::Discussion,
+                &pull_request()
+            )
+        );
+        assert!(RfdState::check_transition(
+            Some(&RfdState::Discussion),
+            &RfdState::Discussion,
+            &default_branch()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_unknown_state_may_move_to_any_valid_state() {
+        assert_eq!(
+            Ok(()),
+            RfdState::check_transition(None, &RfdState::Discussion, &pull_request())
+        );
+        assert!(RfdState::check_transition(None, &RfdState::Ideation, &default_branch()).is_err());
+    }
+}
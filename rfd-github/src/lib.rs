@@ -18,12 +18,11 @@ use octorust::{
     types::{GitCreateRefRequest, PullRequestSimple, ReposCreateUpdateFileContentsRequest},
     Client, ClientError, Response,
 };
-use regex::Regex;
 use rfd_data::{
     content::{RfdAsciidoc, RfdContent, RfdMarkdown},
-    RfdNumber,
+    RfdNumber, RfdNumberFormat,
 };
-use rfd_model::{CommitSha, FileSha};
+use rfd_model::{schema_ext::ContentFormat, CommitSha, FileSha};
 use thiserror::Error;
 use tracing::{instrument, Instrument};
 
@@ -58,6 +57,7 @@ pub struct GitHubRfdRepo {
     pub repo: String,
     pub path: String,
     pub default_branch: String,
+    pub number_format: RfdNumberFormat,
 }
 
 impl Debug for GitHubRfdRepo {
@@ -67,6 +67,7 @@ impl Debug for GitHubRfdRepo {
             .field("repo", &self.repo)
             .field("path", &self.path)
             .field("default_branch", &self.default_branch)
+            .field("number_format", &self.number_format)
             .finish()
     }
 }
@@ -78,6 +79,7 @@ impl GitHubRfdRepo {
         repo: String,
         path: String,
         default_branch: String,
+        number_format: RfdNumberFormat,
     ) -> Result<Self, GitHubError> {
         Ok(Self {
             client: client.clone(),
@@ -85,6 +87,7 @@ impl GitHubRfdRepo {
             owner,
             repo,
             path,
+            number_format,
         })
     }
 
@@ -128,8 +131,8 @@ impl GitHubRfdRepo {
 
             // There should always be exactly 2 parts "rfd" "{number}"
             if path_parts.len() == 2 {
-                if let Ok(number) = path_parts[1].parse::<i32>() {
-                    rfd_numbers_on_default.push(number);
+                if let Some(number) = self.number_format.parse(path_parts[1]) {
+                    rfd_numbers_on_default.push(i32::from(number));
                 } else {
                     tracing::warn!(?path_parts, "Failed to parse RFD number from file path");
                 }
@@ -143,7 +146,8 @@ impl GitHubRfdRepo {
         let branches = self.branches().await?;
         let max_rfd_number_on_branch = branches
             .iter()
-            .filter_map(|location| location.branch.parse::<i32>().ok())
+            .filter_map(|location| self.number_format.parse(&location.branch))
+            .map(i32::from)
             .max()
             .unwrap_or(0);
 
@@ -157,7 +161,6 @@ impl GitHubRfdRepo {
     }
 
     pub async fn branches(&self) -> Result<Vec<GitHubRfdLocation>, GitHubError> {
-        let branch_pattern = Regex::new(r#"^\d{4}$"#).unwrap();
         let responses = self
             .client
             .repos()
@@ -167,7 +170,9 @@ impl GitHubRfdRepo {
             .body
             .into_iter()
             .filter_map(|branch| {
-                if branch_pattern.is_match(&branch.name) || branch.name == self.default_branch {
+                if self.number_format.parse(&branch.name).is_some()
+                    || branch.name == self.default_branch
+                {
                     Some(self.location(branch.name, branch.commit.sha.into()))
                 } else {
                     None
@@ -182,7 +187,8 @@ impl GitHubRfdRepo {
         number: &RfdNumber,
         commit: &CommitSha,
     ) -> Result<GitHubRfdLocation, GitHubError> {
-        let ref_ = format!("refs/heads/{}", number.as_number_string());
+        let branch = self.number_format.branch(number);
+        let ref_ = format!("refs/heads/{}", branch);
 
         tracing::debug!(ref_, "Creating GitHub ref");
 
@@ -199,7 +205,21 @@ impl GitHubRfdRepo {
             )
             .await?;
 
-        Ok(self.location(number.as_number_string(), commit.clone()))
+        Ok(self.location(branch, commit.clone()))
+    }
+
+    #[instrument(skip(self), fields(owner = self.owner, repo = self.repo))]
+    pub async fn delete_branch(&self, number: &RfdNumber) -> Result<(), GitHubError> {
+        let ref_ = format!("heads/{}", self.number_format.branch(number));
+
+        tracing::debug!(ref_, "Deleting GitHub ref");
+
+        self.client
+            .git()
+            .delete_ref(&self.owner, &self.repo, &ref_)
+            .await?;
+
+        Ok(())
     }
 
     pub fn location(&self, branch: String, commit: CommitSha) -> GitHubRfdLocation {
@@ -210,6 +230,7 @@ impl GitHubRfdRepo {
             default_branch: self.default_branch.clone(),
             branch,
             commit,
+            number_format: self.number_format.clone(),
         }
     }
 
@@ -279,7 +300,7 @@ impl GitHubRfdRepo {
             if path_parts.len() == 2 {
                 tracing::trace!(?path_parts, "Handle RFD on default branch");
 
-                if let Ok(number) = path_parts[1].parse::<i32>() {
+                if let Some(number) = self.number_format.parse(path_parts[1]).map(i32::from) {
                     tracing::trace!(
                         ?number,
                         branch = self.default_branch,
@@ -345,7 +366,7 @@ impl GitHubRfdRepo {
 
             async {
                 // There will be many branches that are not RFD branches, and they are ignored
-                if let Ok(number) = branch.name.parse::<i32>() {
+                if let Some(number) = self.number_format.parse(&branch.name).map(i32::from) {
 
                     // RFDs that are in final state in the dedebugfault branch AND have a branch are ignored
                     if !updates.contains_key(&number) {
@@ -353,14 +374,14 @@ impl GitHubRfdRepo {
                         let rfd_number = RfdNumber::from(number);
 
                         // Only interested in exactly the RFD file that matches the branch name
-                        let mut response = client.repos().get_content_file(&self.owner, &self.repo, &format!("rfd/{}/README.adoc", rfd_number.as_number_string()), &branch.commit.sha).await;
+                        let mut response = client.repos().get_content_file(&self.owner, &self.repo, &format!("{}/README.adoc", self.number_format.directory(&rfd_number)), &branch.commit.sha).await;
 
                         // If we fail to find an Asciidoc readme, try to fall back to a Markdown version
                         if match response {
                             Err(ClientError::HttpError { status, .. }) if status == StatusCode::NOT_FOUND => true,
                             _ => false
                         } {
-                            response = client.repos().get_content_file(&self.owner, &self.repo, &format!("rfd/{}/README.md", rfd_number.as_number_string()), &branch.commit.sha).await;
+                            response = client.repos().get_content_file(&self.owner, &self.repo, &format!("{}/README.md", self.number_format.directory(&rfd_number)), &branch.commit.sha).await;
                         }
 
                         // 404s are returned as errors, but that should not stop processing. This only
@@ -376,7 +397,7 @@ impl GitHubRfdRepo {
                                     tracing::trace!(?number, branch = ?branch.name, "Add RFD to update batch");
 
                                     // Only RFDs that have a number matching the branch name are considered
-                                    if let Ok(number) = path_parts[1].parse::<i32>() {
+                                    if let Some(number) = self.number_format.parse(path_parts[1]).map(i32::from) {
                                         let update = GitHubRfdUpdate {
                                             number: rfd_number,
                                             location: self.location(branch.name, branch.commit.sha.into()),
@@ -416,6 +437,7 @@ pub struct GitHubRfdLocation {
     pub default_branch: String,
     pub branch: String,
     pub commit: CommitSha,
+    pub number_format: RfdNumberFormat,
 }
 
 impl Debug for GitHubRfdLocation {
@@ -436,8 +458,20 @@ impl GitHubRfdLocation {
     }
 
     pub async fn readme_path(&self, client: &Client, rfd_number: &RfdNumber) -> String {
+        self.readme_path_for_format(client, rfd_number, &ContentFormat::Asciidoc)
+            .await
+    }
+
+    /// Determine the path of the README for a RFD. When no README exists yet, the path for a
+    /// README written in the given format is returned
+    pub async fn readme_path_for_format(
+        &self,
+        client: &Client,
+        rfd_number: &RfdNumber,
+        format: &ContentFormat,
+    ) -> String {
         // Use the supplied RFD number to determine the location in the RFD repo to read from
-        let dir = rfd_number.repo_path();
+        let dir = self.number_format.repo_path(rfd_number);
 
         // Get the contents of the file
         let mut path = format!("{}/README.adoc", dir);
@@ -455,10 +489,14 @@ impl GitHubRfdLocation {
                     Err(err) => {
                         tracing::trace!(
                             ?err,
-                            "Failed to find markdown README. With no README detected, defaulting to asciidoc"
+                            ?format,
+                            "Failed to find markdown README. With no README detected, defaulting to the requested format"
                         );
 
-                        format!("{}/README.adoc", dir)
+                        match format {
+                            ContentFormat::Asciidoc => format!("{}/README.adoc", dir),
+                            ContentFormat::Markdown => format!("{}/README.md", dir),
+                        }
                     }
                 }
             }
@@ -532,7 +570,6 @@ impl GitHubRfdLocation {
         let parsed = from_utf8(&decoded)?.to_string();
 
         Ok(FetchedRfdContent {
-            decoded,
             parsed,
             sha: file.sha.into(),
             url: file.html_url,
@@ -545,10 +582,33 @@ impl GitHubRfdLocation {
         client: &Client,
         rfd_number: &RfdNumber,
     ) -> Result<Vec<octorust::types::ContentFile>, GitHubError> {
-        let dir = rfd_number.repo_path();
+        let dir = self.number_format.repo_path(rfd_number);
         Self::get_images_internal(client, &self.owner, &self.repo, &self.commit, dir).await
     }
 
+    /// Get a single static asset (such as an image) from the directory of a RFD in this branch. The
+    /// path is relative to the RFD directory
+    pub async fn get_asset(
+        &self,
+        client: &Client,
+        rfd_number: &RfdNumber,
+        path: &str,
+    ) -> Result<Option<octorust::types::ContentFile>, GitHubError> {
+        let asset_path = format!("{}/{}", self.number_format.repo_path(rfd_number), path);
+
+        match client
+            .repos()
+            .get_content_blob(&self.owner, &self.repo, self.commit.0.as_str(), &asset_path)
+            .await
+        {
+            Ok(file) => Ok(Some(file)),
+            Err(ClientError::HttpError { status, .. }) if status == StatusCode::NOT_FOUND => {
+                Ok(None)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
     #[instrument(skip(client, dir))]
     fn get_images_internal<'a>(
         client: &'a Client,
@@ -650,7 +710,7 @@ impl GitHubRfdLocation {
                 &self.owner,
                 &self.repo,
                 &self.commit.0.as_str(),
-                &rfd_number.repo_path(),
+                &self.number_format.repo_path(rfd_number),
                 "",
                 None,
                 None,
@@ -674,26 +734,55 @@ impl GitHubRfdLocation {
     }
 
     #[instrument(skip(self, content))]
+    /// Write the README of a RFD. The format is only used to pick the file name when the RFD does
+    /// not yet have a README
     pub async fn upsert(
         &self,
         rfd_number: &RfdNumber,
+        format: &ContentFormat,
         content: &[u8],
         message: &str,
     ) -> Result<Option<CommitSha>, GitHubError> {
-        let readme_path = self.readme_path(&self.client, rfd_number).await;
-        let (decoded, sha) = match self
-            .fetch_content(&self.client, &readme_path, &self.commit)
-            .await
-        {
-            Ok(FetchedRfdContent { decoded, sha, .. }) => (decoded, sha.into()),
-            Err(err) => match err {
-                GitHubError::ClientError(ClientError::HttpError { status, .. })
-                    if status == StatusCode::NOT_FOUND =>
-                {
-                    (vec![], String::new())
-                }
-                other => return Err(other),
-            },
+        let readme_path = self
+            .readme_path_for_format(&self.client, rfd_number, format)
+            .await;
+        self.write_file(&readme_path, content, message).await
+    }
+
+    #[instrument(skip(self, content))]
+    /// Write a static asset (such as an image) to the directory of a RFD. The path is relative to
+    /// the RFD directory
+    pub async fn upsert_asset(
+        &self,
+        rfd_number: &RfdNumber,
+        path: &str,
+        content: &[u8],
+        message: &str,
+    ) -> Result<Option<CommitSha>, GitHubError> {
+        let asset_path = format!("{}/{}", self.number_format.repo_path(rfd_number), path);
+        self.write_file(&asset_path, content, message).await
+    }
+
+    // Commit a file to the branch of this location, skipping the commit entirely if the file
+    // already has the requested contents
+    async fn write_file(
+        &self,
+        path: &str,
+        content: &[u8],
+        message: &str,
+    ) -> Result<Option<CommitSha>, GitHubError> {
+        // Assets may be binary files, so the existing content is compared without being parsed
+        let existing = self
+            .client
+            .repos()
+            .get_content_blob(&self.owner, &self.repo, self.commit.0.as_str(), path)
+            .await;
+        let (decoded, sha) = match existing {
+            Ok(file) => (decode_base64(&file.content)?, file.sha),
+            Err(ClientError::HttpError { status, .. }) if status == StatusCode::NOT_FOUND => {
+                (vec![], String::new())
+            }
+            Err(err) => return Err(err.into()),
         };
 
         // We can short circuit if the new and old content are the same
@@ -714,7 +803,7 @@ impl GitHubRfdLocation {
             .create_or_update_file_contents(
                 &self.owner,
                 &self.repo,
-                &readme_path.trim_start_matches('/'),
+                &path.trim_start_matches('/'),
                 &ReposCreateUpdateFileContentsRequest {
                     message: format!("{}\nCommitted via rfd-api", message),
                     sha,
@@ -731,7 +820,6 @@ impl GitHubRfdLocation {
 }
 
 struct FetchedRfdContent {
-    decoded: Vec<u8>,
     parsed: String,
     sha: FileSha,
     url: String,
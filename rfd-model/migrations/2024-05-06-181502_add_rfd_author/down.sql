@@ -0,0 +1 @@
+DROP TABLE rfd_author;
@@ -0,0 +1,17 @@
+CREATE TABLE rfd_author (
+  id UUID PRIMARY KEY,
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  position INTEGER NOT NULL,
+  name VARCHAR,
+  email VARCHAR,
+  api_user_id UUID REFERENCES api_user (id),
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ,
+
+  CONSTRAINT rfd_author_revision_position_unique UNIQUE (rfd_revision_id, position)
+);
+
+CREATE INDEX rfd_author_email ON rfd_author (lower(email));
+CREATE INDEX rfd_author_api_user_id ON rfd_author (api_user_id);
@@ -0,0 +1,2 @@
+DROP TABLE rfd_revision_label;
+DROP TABLE label;
@@ -0,0 +1,26 @@
+CREATE TABLE label (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL,
+  description VARCHAR,
+  color VARCHAR,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ
+);
+
+-- Label names are compared case-insensitively
+CREATE UNIQUE INDEX label_name_unique ON label (lower(name));
+
+CREATE TABLE rfd_revision_label (
+  id UUID PRIMARY KEY,
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  label_id UUID REFERENCES label (id) NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ,
+
+  CONSTRAINT rfd_revision_label_unique UNIQUE (rfd_revision_id, label_id)
+);
+
+CREATE INDEX rfd_revision_label_label_id ON rfd_revision_label (label_id);
@@ -0,0 +1,13 @@
+CREATE TABLE rfd_state_transition (
+  id UUID PRIMARY KEY,
+  rfd_id UUID REFERENCES rfd (id) NOT NULL,
+  api_user_id UUID REFERENCES api_user (id) NOT NULL,
+  from_state VARCHAR,
+  to_state VARCHAR NOT NULL,
+  reason VARCHAR,
+  commit_sha VARCHAR,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX rfd_state_transition_rfd_id ON rfd_state_transition (rfd_id);
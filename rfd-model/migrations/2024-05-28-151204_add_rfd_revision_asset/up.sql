@@ -0,0 +1,16 @@
+CREATE TABLE rfd_revision_asset (
+  id UUID PRIMARY KEY,
+  rfd_id UUID REFERENCES rfd (id) NOT NULL,
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  path VARCHAR NOT NULL,
+  object_name VARCHAR NOT NULL,
+  sha VARCHAR NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ,
+
+  CONSTRAINT rfd_revision_asset_path_unique UNIQUE (rfd_revision_id, path)
+);
+
+CREATE INDEX rfd_revision_asset_rfd_id ON rfd_revision_asset (rfd_id);
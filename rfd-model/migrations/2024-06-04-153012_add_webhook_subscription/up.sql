@@ -0,0 +1,31 @@
+CREATE TYPE RFD_WEBHOOK_EVENT as ENUM('created', 'state_changed', 'content_updated', 'visibility_changed');
+
+CREATE TABLE webhook_subscription (
+  id UUID PRIMARY KEY,
+  url VARCHAR NOT NULL,
+  secret VARCHAR NOT NULL,
+  events RFD_WEBHOOK_EVENT[] NOT NULL,
+  rfds INTEGER[],
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ
+);
+
+CREATE TABLE webhook_delivery (
+  id UUID PRIMARY KEY,
+  webhook_subscription_id UUID REFERENCES webhook_subscription (id) NOT NULL,
+  event RFD_WEBHOOK_EVENT NOT NULL,
+  payload JSONB NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  response_status INTEGER,
+  last_error VARCHAR,
+  next_attempt_at TIMESTAMPTZ,
+  delivered_at TIMESTAMPTZ,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX webhook_delivery_subscription ON webhook_delivery (webhook_subscription_id);
+CREATE INDEX webhook_delivery_pending ON webhook_delivery (next_attempt_at) WHERE next_attempt_at IS NOT NULL;
@@ -0,0 +1,7 @@
+CREATE TABLE rfd_reservation (
+  id UUID PRIMARY KEY,
+  rfd_number INTEGER NOT NULL UNIQUE,
+  api_user_id UUID REFERENCES api_user (id) NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,3 @@
+-- This migration is irreversible. The original casing of provider emails is discarded when they
+-- are lowercased, so reverting it leaves the lowercased emails in place
+SELECT 1;
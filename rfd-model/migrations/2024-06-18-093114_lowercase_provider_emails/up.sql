@@ -0,0 +1,4 @@
+UPDATE api_user_provider
+SET emails = ARRAY(
+  SELECT lower(email) FROM unnest(emails) WITH ORDINALITY AS e(email, idx) ORDER BY idx
+);
@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::fmt::Debug;
+
 use chrono::{DateTime, Utc};
 use diesel::{Insertable, Queryable};
 use serde::{Deserialize, Serialize};
@@ -11,11 +13,13 @@ use w_api_permissions::Permissions;
 
 use crate::{
     schema::{
-        access_groups, api_key, api_user, api_user_access_token, api_user_provider, job,
+        access_groups, api_key, api_user, api_user_access_token, api_user_provider, job, label,
         link_request, login_attempt, mapper, oauth_client, oauth_client_redirect_uri,
-        oauth_client_secret, rfd, rfd_pdf, rfd_revision,
+        oauth_client_secret, rfd, rfd_author, rfd_pdf, rfd_reservation, rfd_revision,
+        rfd_revision_asset, rfd_revision_label, rfd_state_transition, webhook_delivery,
+        webhook_subscription,
     },
-    schema_ext::{ContentFormat, LoginAttemptState, PdfSource, Visibility},
+    schema_ext::{ContentFormat, LoginAttemptState, PdfSource, Visibility, WebhookEvent},
 };
 
 #[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
@@ -64,6 +68,78 @@ pub struct RfdPdfModel {
     pub external_id: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_author)]
+pub struct RfdAuthorModel {
+    pub id: Uuid,
+    pub rfd_revision_id: Uuid,
+    pub position: i32,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub api_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = label)]
+pub struct LabelModel {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_revision_label)]
+pub struct RfdRevisionLabelModel {
+    pub id: Uuid,
+    pub rfd_revision_id: Uuid,
+    pub label_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_revision_asset)]
+pub struct RfdRevisionAssetModel {
+    pub id: Uuid,
+    pub rfd_id: Uuid,
+    pub rfd_revision_id: Uuid,
+    pub path: String,
+    pub object_name: String,
+    pub sha: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_reservation)]
+pub struct RfdReservationModel {
+    pub id: Uuid,
+    pub rfd_number: i32,
+    pub api_user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_state_transition)]
+pub struct RfdStateTransitionModel {
+    pub id: Uuid,
+    pub rfd_id: Uuid,
+    pub api_user_id: Uuid,
+    pub from_state: Option<String>,
+    pub to_state: String,
+    pub reason: Option<String>,
+    pub commit_sha: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
 #[diesel(table_name = job)]
 pub struct JobModel {
@@ -214,3 +290,47 @@ pub struct LinkRequestModel {
     pub expires_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
 }
+
+#[derive(Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = webhook_subscription)]
+pub struct WebhookSubscriptionModel {
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<Option<WebhookEvent>>,
+    pub rfds: Option<Vec<Option<i32>>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+// The signing secret of a subscription is left out so that it never ends up in logs
+impl Debug for WebhookSubscriptionModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSubscriptionModel")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("rfds", &self.rfds)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .field("deleted_at", &self.deleted_at)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = webhook_delivery)]
+pub struct WebhookDeliveryModel {
+    pub id: Uuid,
+    pub webhook_subscription_id: Uuid,
+    pub event: WebhookEvent,
+    pub payload: Value,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
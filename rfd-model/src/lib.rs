@@ -4,20 +4,24 @@
 
 use std::{
     collections::{BTreeMap, BTreeSet},
-    fmt::Display,
+    fmt::{Debug, Display},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
 };
 
 use chrono::{DateTime, Utc};
 use db::{
-    AccessGroupModel, JobModel, LinkRequestModel, LoginAttemptModel, MapperModel,
-    OAuthClientRedirectUriModel, OAuthClientSecretModel, RfdModel, RfdPdfModel, RfdRevisionModel,
+    AccessGroupModel, JobModel, LabelModel, LinkRequestModel, LoginAttemptModel, MapperModel,
+    OAuthClientRedirectUriModel, OAuthClientSecretModel, RfdAuthorModel, RfdModel, RfdPdfModel,
+    RfdReservationModel, RfdRevisionAssetModel, RfdRevisionLabelModel, RfdRevisionModel,
+    RfdStateTransitionModel, WebhookDeliveryModel, WebhookSubscriptionModel,
 };
 use partial_struct::partial;
-use schema_ext::{ContentFormat, LoginAttemptState, PdfSource, Visibility};
+use schema_ext::{ContentFormat, LoginAttemptState, PdfSource, Visibility, WebhookEvent};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
+use tokio::net::lookup_host;
 use uuid::Uuid;
 use w_api_permissions::Permissions;
 
@@ -169,6 +173,180 @@ impl From<RfdPdfModel> for RfdPdf {
     }
 }
 
+#[partial(NewRfdAuthor)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdAuthor {
+    pub id: Uuid,
+    pub rfd_revision_id: Uuid,
+    pub position: i32,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub api_user_id: Option<Uuid>,
+    #[partial(NewRfdAuthor(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewRfdAuthor(skip))]
+    pub updated_at: DateTime<Utc>,
+    #[partial(NewRfdAuthor(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<RfdAuthorModel> for RfdAuthor {
+    fn from(value: RfdAuthorModel) -> Self {
+        Self {
+            id: value.id,
+            rfd_revision_id: value.rfd_revision_id,
+            position: value.position,
+            name: value.name,
+            email: value.email,
+            api_user_id: value.api_user_id,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[partial(NewLabel)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Label {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    #[partial(NewLabel(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewLabel(skip))]
+    pub updated_at: DateTime<Utc>,
+    #[partial(NewLabel(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<LabelModel> for Label {
+    fn from(value: LabelModel) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            color: value.color,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[partial(NewRfdRevisionLabel)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdRevisionLabel {
+    pub id: Uuid,
+    pub rfd_revision_id: Uuid,
+    pub label_id: Uuid,
+    #[partial(NewRfdRevisionLabel(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewRfdRevisionLabel(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<RfdRevisionLabelModel> for RfdRevisionLabel {
+    fn from(value: RfdRevisionLabelModel) -> Self {
+        Self {
+            id: value.id,
+            rfd_revision_id: value.rfd_revision_id,
+            label_id: value.label_id,
+            created_at: value.created_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+/// A static asset (such as an image) that was copied to storage for a specific RFD revision
+#[partial(NewRfdRevisionAsset)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdRevisionAsset {
+    pub id: Uuid,
+    pub rfd_id: Uuid,
+    pub rfd_revision_id: Uuid,
+    /// Path of the asset relative to the RFD directory
+    pub path: String,
+    /// Name of the versioned storage object that holds the asset
+    pub object_name: String,
+    pub sha: FileSha,
+    #[partial(NewRfdRevisionAsset(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewRfdRevisionAsset(skip))]
+    pub updated_at: DateTime<Utc>,
+    #[partial(NewRfdRevisionAsset(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<RfdRevisionAssetModel> for RfdRevisionAsset {
+    fn from(value: RfdRevisionAssetModel) -> Self {
+        Self {
+            id: value.id,
+            rfd_id: value.rfd_id,
+            rfd_revision_id: value.rfd_revision_id,
+            path: value.path,
+            object_name: value.object_name,
+            sha: value.sha.into(),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+/// A claim on a RFD number. Reservations are held in the database so that concurrent callers can
+/// not be handed the same number
+#[partial(NewRfdReservation)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdReservation {
+    pub id: Uuid,
+    pub rfd_number: i32,
+    pub api_user_id: Uuid,
+    #[partial(NewRfdReservation(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdReservationModel> for RfdReservation {
+    fn from(value: RfdReservationModel) -> Self {
+        Self {
+            id: value.id,
+            rfd_number: value.rfd_number,
+            api_user_id: value.api_user_id,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[partial(NewRfdStateTransition)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdStateTransition {
+    pub id: Uuid,
+    pub rfd_id: Uuid,
+    pub api_user_id: Uuid,
+    pub from_state: Option<String>,
+    pub to_state: String,
+    pub reason: Option<String>,
+    pub commit: Option<CommitSha>,
+    #[partial(NewRfdStateTransition(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdStateTransitionModel> for RfdStateTransition {
+    fn from(value: RfdStateTransitionModel) -> Self {
+        Self {
+            id: value.id,
+            rfd_id: value.rfd_id,
+            api_user_id: value.api_user_id,
+            from_state: value.from_state,
+            to_state: value.to_state,
+            reason: value.reason,
+            commit: value.commit_sha.map(|sha| sha.into()),
+            created_at: value.created_at,
+        }
+    }
+}
+
 #[partial(NewJob)]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Job {
@@ -531,3 +709,230 @@ impl From<LinkRequestModel> for LinkRequest {
         }
     }
 }
+
+#[partial(NewWebhookSubscription)]
+#[derive(Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub url: String,
+    /// Shared secret used to sign the body of each delivery
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    /// RFDs to deliver events for. Events for every RFD are delivered when this is not set
+    pub rfds: Option<Vec<i32>>,
+    #[partial(NewWebhookSubscription(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewWebhookSubscription(skip))]
+    pub updated_at: DateTime<Utc>,
+    #[partial(NewWebhookSubscription(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookSubscription {
+    pub fn matches(&self, event: &WebhookEvent, rfd_number: i32) -> bool {
+        self.events.contains(event)
+            && self
+                .rfds
+                .as_ref()
+                .map(|rfds| rfds.contains(&rfd_number))
+                .unwrap_or(true)
+    }
+
+    /// Check if deliveries may be sent to the given address. Subscriptions are not allowed to
+    /// target loopback, link-local, private, or otherwise non-global addresses
+    pub fn accepts_address(ip: &IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(ip) => is_global_v4(ip),
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(ip) => is_global_v4(&ip),
+                None => is_global_v6(ip),
+            },
+        }
+    }
+
+    /// Check the host of a subscription url without resolving it. Literal addresses must be
+    /// accepted addresses, and names that always refer to the local machine are rejected
+    pub fn accepts_host(host: &str) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+
+        match host.parse::<IpAddr>() {
+            Ok(ip) => Self::accepts_address(&ip),
+            Err(_) => {
+                let host = host.to_lowercase();
+                host != "localhost" && !host.ends_with(".localhost")
+            }
+        }
+    }
+
+    /// Resolve the host of a subscription url. A host is only accepted if every address that it
+    /// resolves to is an accepted address. Hosts must be resolved again each time a delivery is
+    /// sent, as the addresses that a name resolves to may change
+    pub async fn resolve_host(host: &str, port: u16) -> Result<Vec<SocketAddr>, WebhookHostError> {
+        if !Self::accepts_host(host) {
+            return Err(WebhookHostError::NotAccepted);
+        }
+
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let addresses = lookup_host((host, port)).await?.collect::<Vec<_>>();
+
+        if addresses.is_empty() {
+            Err(WebhookHostError::Unresolved)
+        } else if addresses
+            .iter()
+            .all(|address| Self::accepts_address(&address.ip()))
+        {
+            Ok(addresses)
+        } else {
+            Err(WebhookHostError::NotAccepted)
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookHostError {
+    #[error("Host is, or resolves to, an address that may not receive deliveries")]
+    NotAccepted,
+    #[error("Failed to resolve host: {0}")]
+    Resolve(#[from] std::io::Error),
+    #[error("Host does not resolve to any address")]
+    Unresolved,
+}
+
+fn is_global_v4(ip: &Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "This network" (0.0.0.0/8)
+        || a == 0
+        // Shared address space (100.64.0.0/10)
+        || (a == 100 && (b & 0xc0) == 64)
+        // Benchmarking (198.18.0.0/15)
+        || (a == 198 && (b & 0xfe) == 18))
+}
+
+fn is_global_v6(ip: &Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    let prefix = segments[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // IPv4-compatible addresses (::/96)
+        || segments[..6] == [0; 6]
+        // NAT64 addresses (64:ff9b::/96 and 64:ff9b:1::/48), which are translated to an IPv4
+        // address that is not checked
+        || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+        || segments[..3] == [0x64, 0xff9b, 1]
+        // Unique local addresses (fc00::/7)
+        || (prefix & 0xfe00) == 0xfc00
+        // Link-local addresses (fe80::/10)
+        || (prefix & 0xffc0) == 0xfe80)
+}
+
+// Subscriptions are logged while deliveries are queued and attempted. The signing secret is left
+// out so that it never ends up in logs
+impl Debug for WebhookSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSubscription")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("rfds", &self.rfds)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .field("deleted_at", &self.deleted_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for NewWebhookSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewWebhookSubscription")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("rfds", &self.rfds)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<WebhookSubscriptionModel> for WebhookSubscription {
+    fn from(value: WebhookSubscriptionModel) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            secret: value.secret,
+            events: value.events.into_iter().flatten().collect(),
+            rfds: value.rfds.map(|rfds| rfds.into_iter().flatten().collect()),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+/// A single event queued for delivery to a webhook subscription. Deliveries double as the log of
+/// attempts that have been made to send the event
+#[partial(NewWebhookDelivery)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_subscription_id: Uuid,
+    pub event: WebhookEvent,
+    pub payload: Value,
+    #[partial(NewWebhookDelivery(skip))]
+    pub attempts: i32,
+    /// HTTP status returned by the most recent attempt
+    #[partial(NewWebhookDelivery(skip))]
+    pub response_status: Option<i32>,
+    #[partial(NewWebhookDelivery(skip))]
+    pub last_error: Option<String>,
+    /// When the next attempt is due. This is cleared once the event is delivered or the delivery
+    /// has run out of attempts
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    #[partial(NewWebhookDelivery(skip))]
+    pub delivered_at: Option<DateTime<Utc>>,
+    #[partial(NewWebhookDelivery(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewWebhookDelivery(skip))]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryModel> for WebhookDelivery {
+    fn from(value: WebhookDeliveryModel) -> Self {
+        Self {
+            id: value.id,
+            webhook_subscription_id: value.webhook_subscription_id,
+            event: value.event,
+            payload: value.payload,
+            attempts: value.attempts,
+            response_status: value.response_status,
+            last_error: value.last_error,
+            next_attempt_at: value.next_attempt_at,
+            delivered_at: value.delivered_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Body of a webhook delivery describing a change to a RFD
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdEvent {
+    pub id: Uuid,
+    pub event: WebhookEvent,
+    pub occurred_at: DateTime<Utc>,
+    pub rfd_number: i32,
+    pub title: String,
+    pub state: Option<String>,
+    pub visibility: Visibility,
+    pub commit: CommitSha,
+    pub previous_state: Option<String>,
+    pub previous_visibility: Option<Visibility>,
+}
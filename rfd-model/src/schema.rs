@@ -20,6 +20,10 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "rfd_visibility"))]
     pub struct RfdVisibility;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "rfd_webhook_event"))]
+    pub struct RfdWebhookEvent;
 }
 
 diesel::table! {
@@ -97,6 +101,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    label (id) {
+        id -> Uuid,
+        name -> Varchar,
+        description -> Nullable<Varchar>,
+        color -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     link_request (id) {
         id -> Uuid,
@@ -191,6 +207,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_author (id) {
+        id -> Uuid,
+        rfd_revision_id -> Uuid,
+        position -> Int4,
+        name -> Nullable<Varchar>,
+        email -> Nullable<Varchar>,
+        api_user_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::RfdPdfSource;
@@ -208,6 +238,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_reservation (id) {
+        id -> Uuid,
+        rfd_number -> Int4,
+        api_user_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::RfdContentFormat;
@@ -231,14 +270,96 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_revision_asset (id) {
+        id -> Uuid,
+        rfd_id -> Uuid,
+        rfd_revision_id -> Uuid,
+        path -> Varchar,
+        object_name -> Varchar,
+        sha -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    rfd_revision_label (id) {
+        id -> Uuid,
+        rfd_revision_id -> Uuid,
+        label_id -> Uuid,
+        created_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    rfd_state_transition (id) {
+        id -> Uuid,
+        rfd_id -> Uuid,
+        api_user_id -> Uuid,
+        from_state -> Nullable<Varchar>,
+        to_state -> Varchar,
+        reason -> Nullable<Varchar>,
+        commit_sha -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdWebhookEvent;
+
+    webhook_delivery (id) {
+        id -> Uuid,
+        webhook_subscription_id -> Uuid,
+        event -> RfdWebhookEvent,
+        payload -> Jsonb,
+        attempts -> Int4,
+        response_status -> Nullable<Int4>,
+        last_error -> Nullable<Varchar>,
+        next_attempt_at -> Nullable<Timestamptz>,
+        delivered_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdWebhookEvent;
+
+    webhook_subscription (id) {
+        id -> Uuid,
+        url -> Varchar,
+        secret -> Varchar,
+        events -> Array<Nullable<RfdWebhookEvent>>,
+        rfds -> Nullable<Array<Nullable<Int4>>>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::joinable!(api_key -> api_user (api_user_id));
 diesel::joinable!(api_user_access_token -> api_user (api_user_id));
 diesel::joinable!(api_user_provider -> api_user (api_user_id));
 diesel::joinable!(oauth_client_redirect_uri -> oauth_client (oauth_client_id));
 diesel::joinable!(oauth_client_secret -> oauth_client (oauth_client_id));
+diesel::joinable!(rfd_author -> api_user (api_user_id));
+diesel::joinable!(rfd_author -> rfd_revision (rfd_revision_id));
 diesel::joinable!(rfd_pdf -> rfd (rfd_id));
 diesel::joinable!(rfd_pdf -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_reservation -> api_user (api_user_id));
 diesel::joinable!(rfd_revision -> rfd (rfd_id));
+diesel::joinable!(rfd_revision_asset -> rfd (rfd_id));
+diesel::joinable!(rfd_revision_asset -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_revision_label -> label (label_id));
+diesel::joinable!(rfd_revision_label -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_state_transition -> api_user (api_user_id));
+diesel::joinable!(rfd_state_transition -> rfd (rfd_id));
+diesel::joinable!(webhook_delivery -> webhook_subscription (webhook_subscription_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     access_groups,
@@ -247,6 +368,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     api_user_access_token,
     api_user_provider,
     job,
+    label,
     link_request,
     login_attempt,
     mapper,
@@ -254,6 +376,13 @@ diesel::allow_tables_to_appear_in_same_query!(
     oauth_client_redirect_uri,
     oauth_client_secret,
     rfd,
+    rfd_author,
     rfd_pdf,
+    rfd_reservation,
     rfd_revision,
+    rfd_revision_asset,
+    rfd_revision_label,
+    rfd_state_transition,
+    webhook_delivery,
+    webhook_subscription,
 );
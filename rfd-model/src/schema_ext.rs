@@ -17,7 +17,9 @@ use std::{
     io::Write,
 };
 
-use crate::schema::sql_types::{AttemptState, RfdContentFormat, RfdPdfSource, RfdVisibility};
+use crate::schema::sql_types::{
+    AttemptState, RfdContentFormat, RfdPdfSource, RfdVisibility, RfdWebhookEvent,
+};
 
 macro_rules! sql_conversion {
     (
@@ -153,3 +155,34 @@ impl Display for Visibility {
         }
     }
 }
+
+#[derive(
+    Debug, PartialEq, Eq, Clone, FromSqlRow, AsExpression, Serialize, Deserialize, JsonSchema,
+)]
+#[diesel(sql_type = RfdWebhookEvent)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    Created,
+    StateChanged,
+    ContentUpdated,
+    VisibilityChanged,
+}
+
+sql_conversion! {
+    RfdWebhookEvent => WebhookEvent,
+    Created => b"created",
+    StateChanged => b"state_changed",
+    ContentUpdated => b"content_updated",
+    VisibilityChanged => b"visibility_changed",
+}
+
+impl Display for WebhookEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEvent::Created => write!(f, "created"),
+            WebhookEvent::StateChanged => write!(f, "state_changed"),
+            WebhookEvent::ContentUpdated => write!(f, "content_updated"),
+            WebhookEvent::VisibilityChanged => write!(f, "visibility_changed"),
+        }
+    }
+}
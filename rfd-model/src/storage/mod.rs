@@ -7,6 +7,7 @@ use std::fmt::Debug;
 pub use async_bb8_diesel::{ConnectionError, PoolError};
 use async_trait::async_trait;
 use bb8::RunError;
+use chrono::{DateTime, Utc};
 pub use diesel::result::Error as DbError;
 #[cfg(feature = "mock")]
 use mockall::automock;
@@ -16,15 +17,22 @@ use w_api_permissions::Permission;
 
 use crate::{
     schema_ext::{LoginAttemptState, PdfSource},
-    AccessGroup, AccessToken, ApiKey, ApiUser, ApiUserProvider, Job, LinkRequest, LoginAttempt,
-    Mapper, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser, NewApiUserProvider, NewJob,
-    NewLinkRequest, NewLoginAttempt, NewMapper, NewOAuthClient, NewOAuthClientRedirectUri,
-    NewOAuthClientSecret, NewRfd, NewRfdPdf, NewRfdRevision, OAuthClient, OAuthClientRedirectUri,
-    OAuthClientSecret, Rfd, RfdPdf, RfdRevision,
+    AccessGroup, AccessToken, ApiKey, ApiUser, ApiUserProvider, Job, Label, LinkRequest,
+    LoginAttempt, Mapper, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser,
+    NewApiUserProvider, NewJob, NewLabel, NewLinkRequest, NewLoginAttempt, NewMapper,
+    NewOAuthClient, NewOAuthClientRedirectUri, NewOAuthClientSecret, NewRfd, NewRfdAuthor,
+    NewRfdPdf, NewRfdReservation, NewRfdRevision, NewRfdRevisionAsset, NewRfdRevisionLabel,
+    NewRfdStateTransition, NewWebhookDelivery, NewWebhookSubscription, OAuthClient,
+    OAuthClientRedirectUri, OAuthClientSecret, Rfd, RfdAuthor, RfdEvent, RfdPdf, RfdReservation,
+    RfdRevision, RfdRevisionAsset, RfdRevisionLabel, RfdStateTransition, WebhookDelivery,
+    WebhookSubscription,
 };
 
 pub mod postgres;
 
+/// Postgres channel that RFD change events are published to
+pub static RFD_EVENT_CHANNEL: &str = "rfd_events";
+
 #[derive(Debug, Error)]
 pub enum StoreError {
     #[error("Connection failure: {0}")]
@@ -75,6 +83,7 @@ pub struct RfdFilter {
     pub id: Option<Vec<Uuid>>,
     pub rfd_number: Option<Vec<i32>>,
     pub public: Option<bool>,
+    pub updated_after: Option<DateTime<Utc>>,
     pub deleted: bool,
 }
 
@@ -94,6 +103,11 @@ impl RfdFilter {
         self
     }
 
+    pub fn updated_after(mut self, updated_after: Option<DateTime<Utc>>) -> Self {
+        self.updated_after = updated_after;
+        self
+    }
+
     pub fn deleted(mut self, deleted: bool) -> Self {
         self.deleted = deleted;
         self
@@ -119,8 +133,10 @@ pub trait RfdStore {
 #[derive(Debug, Default)]
 pub struct RfdRevisionFilter {
     pub id: Option<Vec<Uuid>>,
+    pub exclude_id: Option<Vec<Uuid>>,
     pub rfd: Option<Vec<Uuid>>,
     pub sha: Option<Vec<String>>,
+    pub commit: Option<Vec<String>>,
     pub deleted: bool,
 }
 
@@ -130,6 +146,11 @@ impl RfdRevisionFilter {
         self
     }
 
+    pub fn exclude_id(mut self, exclude_id: Option<Vec<Uuid>>) -> Self {
+        self.exclude_id = exclude_id;
+        self
+    }
+
     pub fn rfd(mut self, rfd: Option<Vec<Uuid>>) -> Self {
         self.rfd = rfd;
         self
@@ -140,6 +161,11 @@ impl RfdRevisionFilter {
         self
     }
 
+    pub fn commit(mut self, commit: Option<Vec<String>>) -> Self {
+        self.commit = commit;
+        self
+    }
+
     pub fn deleted(mut self, deleted: bool) -> Self {
         self.deleted = deleted;
         self
@@ -226,6 +252,226 @@ pub trait RfdPdfStore {
     async fn delete(&self, id: &Uuid) -> Result<Option<RfdPdf>, StoreError>;
 }
 
+#[derive(Debug, Default)]
+pub struct RfdRevisionAssetFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub rfd: Option<Vec<Uuid>>,
+    pub rfd_revision: Option<Vec<Uuid>>,
+    pub object_name: Option<Vec<String>>,
+    pub deleted: bool,
+}
+
+impl RfdRevisionAssetFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn rfd(mut self, rfd: Option<Vec<Uuid>>) -> Self {
+        self.rfd = rfd;
+        self
+    }
+
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<Uuid>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn object_name(mut self, object_name: Option<Vec<String>>) -> Self {
+        self.object_name = object_name;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdRevisionAssetStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdRevisionAsset>, StoreError>;
+    async fn list(
+        &self,
+        filter: RfdRevisionAssetFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionAsset>, StoreError>;
+    async fn upsert(&self, new_asset: NewRfdRevisionAsset) -> Result<RfdRevisionAsset, StoreError>;
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdRevisionAsset>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdAuthorFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub rfd_revision: Option<Vec<Uuid>>,
+    pub api_user: Option<Vec<Uuid>>,
+    pub email: Option<Vec<String>>,
+    pub deleted: bool,
+}
+
+impl RfdAuthorFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<Uuid>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn api_user(mut self, api_user: Option<Vec<Uuid>>) -> Self {
+        self.api_user = api_user;
+        self
+    }
+
+    pub fn email(mut self, email: Option<Vec<String>>) -> Self {
+        self.email = email;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdAuthorStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdAuthor>, StoreError>;
+    async fn list(
+        &self,
+        filter: RfdAuthorFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdAuthor>, StoreError>;
+    async fn upsert(&self, new_author: NewRfdAuthor) -> Result<RfdAuthor, StoreError>;
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdAuthor>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct LabelFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub name: Option<Vec<String>>,
+    pub deleted: bool,
+}
+
+impl LabelFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn name(mut self, name: Option<Vec<String>>) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait LabelStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<Label>, StoreError>;
+    async fn list(
+        &self,
+        filter: LabelFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<Label>, StoreError>;
+    async fn upsert(&self, new_label: NewLabel) -> Result<Label, StoreError>;
+    async fn delete(&self, id: &Uuid) -> Result<Option<Label>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdRevisionLabelFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub rfd_revision: Option<Vec<Uuid>>,
+    pub label: Option<Vec<Uuid>>,
+    pub deleted: bool,
+}
+
+impl RfdRevisionLabelFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<Uuid>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn label(mut self, label: Option<Vec<Uuid>>) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdRevisionLabelStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdRevisionLabel>, StoreError>;
+    async fn list(
+        &self,
+        filter: RfdRevisionLabelFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError>;
+    async fn upsert(
+        &self,
+        new_revision_label: NewRfdRevisionLabel,
+    ) -> Result<RfdRevisionLabel, StoreError>;
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdRevisionLabel>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdStateTransitionFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub rfd: Option<Vec<Uuid>>,
+    pub api_user: Option<Vec<Uuid>>,
+}
+
+impl RfdStateTransitionFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn rfd(mut self, rfd: Option<Vec<Uuid>>) -> Self {
+        self.rfd = rfd;
+        self
+    }
+
+    pub fn api_user(mut self, api_user: Option<Vec<Uuid>>) -> Self {
+        self.api_user = api_user;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdStateTransitionStore {
+    async fn get(&self, id: &Uuid) -> Result<Option<RfdStateTransition>, StoreError>;
+    async fn list(
+        &self,
+        filter: RfdStateTransitionFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdStateTransition>, StoreError>;
+    async fn upsert(
+        &self,
+        new_transition: NewRfdStateTransition,
+    ) -> Result<RfdStateTransition, StoreError>;
+}
+
 #[derive(Debug, Default)]
 pub struct JobFilter {
     pub id: Option<Vec<i32>>,
@@ -270,6 +516,168 @@ pub trait JobStore {
     async fn complete(&self, id: i32) -> Result<Option<Job>, StoreError>;
 }
 
+#[derive(Debug, Default)]
+pub struct WebhookSubscriptionFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub deleted: bool,
+}
+
+impl WebhookSubscriptionFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait WebhookSubscriptionStore {
+    async fn get(
+        &self,
+        id: &Uuid,
+        deleted: bool,
+    ) -> Result<Option<WebhookSubscription>, StoreError>;
+    async fn list(
+        &self,
+        filter: WebhookSubscriptionFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscription>, StoreError>;
+    async fn upsert(
+        &self,
+        new_subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, StoreError>;
+    async fn delete(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct WebhookDeliveryFilter {
+    pub id: Option<Vec<Uuid>>,
+    pub webhook_subscription: Option<Vec<Uuid>>,
+    pub due_before: Option<DateTime<Utc>>,
+}
+
+impl WebhookDeliveryFilter {
+    pub fn id(mut self, id: Option<Vec<Uuid>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn webhook_subscription(mut self, webhook_subscription: Option<Vec<Uuid>>) -> Self {
+        self.webhook_subscription = webhook_subscription;
+        self
+    }
+
+    pub fn due_before(mut self, due_before: Option<DateTime<Utc>>) -> Self {
+        self.due_before = due_before;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait WebhookDeliveryStore {
+    async fn get(&self, id: &Uuid) -> Result<Option<WebhookDelivery>, StoreError>;
+    async fn list(
+        &self,
+        filter: WebhookDeliveryFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookDelivery>, StoreError>;
+    async fn upsert(&self, new_delivery: NewWebhookDelivery)
+        -> Result<WebhookDelivery, StoreError>;
+    /// Claim a due delivery for an attempt. The next attempt is moved to `retry_at` so that other
+    /// workers will not pick up the delivery while it is in flight. Returns `None` if the delivery
+    /// is no longer due
+    async fn start(
+        &self,
+        id: &Uuid,
+        retry_at: DateTime<Utc>,
+    ) -> Result<Option<WebhookDelivery>, StoreError>;
+    /// Record the outcome of an attempt. A `next_attempt_at` of `None` ends the delivery
+    async fn complete(
+        &self,
+        id: &Uuid,
+        delivered: bool,
+        response_status: Option<i32>,
+        error: Option<String>,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<WebhookDelivery>, StoreError>;
+}
+
+// Upper bound on the number of subscriptions that an event is matched against
+static WEBHOOK_SUBSCRIPTION_LIMIT: i64 = 1000;
+
+/// Queue events for delivery to each webhook subscription that they match
+pub async fn queue_webhook_deliveries<S>(
+    storage: &S,
+    events: &[RfdEvent],
+) -> Result<Vec<WebhookDelivery>, StoreError>
+where
+    S: WebhookSubscriptionStore + WebhookDeliveryStore + Sync + ?Sized,
+{
+    let subscriptions = WebhookSubscriptionStore::list(
+        storage,
+        WebhookSubscriptionFilter::default(),
+        &ListPagination::default().limit(WEBHOOK_SUBSCRIPTION_LIMIT),
+    )
+    .await?;
+
+    let mut deliveries = vec![];
+
+    for event in events {
+        // Events only hold plain data and can always be serialized
+        let payload = serde_json::to_value(event).unwrap();
+
+        for subscription in subscriptions
+            .iter()
+            .filter(|subscription| subscription.matches(&event.event, event.rfd_number))
+        {
+            deliveries.push(
+                WebhookDeliveryStore::upsert(
+                    storage,
+                    NewWebhookDelivery {
+                        id: Uuid::new_v4(),
+                        webhook_subscription_id: subscription.id,
+                        event: event.event.clone(),
+                        payload: payload.clone(),
+                        next_attempt_at: Some(event.occurred_at),
+                    },
+                )
+                .await?,
+            );
+        }
+    }
+
+    tracing::info!(count = deliveries.len(), "Queued webhook deliveries");
+
+    Ok(deliveries)
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdReservationStore {
+    /// Reserve a RFD number that is higher than every number that has already been reserved, and
+    /// that is no lower than the number of the new reservation
+    async fn reserve(
+        &self,
+        new_reservation: NewRfdReservation,
+    ) -> Result<RfdReservation, StoreError>;
+    /// Release a reservation so that its number can be reserved again
+    async fn release(&self, id: &Uuid) -> Result<Option<RfdReservation>, StoreError>;
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdEventStore {
+    /// Publish an event to the listeners of [`RFD_EVENT_CHANNEL`]. Events are not persisted, and
+    /// only listeners that are connected at the time of the call will receive them
+    async fn notify(&self, event: &RfdEvent) -> Result<(), StoreError>;
+}
+
 #[derive(Debug, Default)]
 pub struct ApiUserFilter {
     pub id: Option<Vec<Uuid>>,
@@ -5,14 +5,19 @@
 use async_bb8_diesel::{AsyncRunQueryDsl, ConnectionError, ConnectionManager};
 use async_trait::async_trait;
 use bb8::Pool;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use diesel::{
+    delete,
+    dsl::max,
     insert_into,
     pg::PgConnection,
     query_dsl::QueryDsl,
+    sql_function, sql_query,
+    sql_types::{Nullable, Text},
     update,
     upsert::{excluded, on_constraint},
-    ExpressionMethods, OptionalExtension as OptionalExtension2, PgArrayExpressionMethods,
+    ExpressionMethods, NullableExpressionMethods, OptionalExtension as OptionalExtension2,
+    PgArrayExpressionMethods,
 };
 use std::{
     collections::{BTreeMap, BTreeSet},
@@ -26,35 +31,48 @@ use w_api_permissions::Permission;
 use crate::{
     db::{
         AccessGroupModel, ApiKeyModel, ApiUserAccessTokenModel, ApiUserModel, ApiUserProviderModel,
-        JobModel, LinkRequestModel, LoginAttemptModel, MapperModel, OAuthClientModel,
-        OAuthClientRedirectUriModel, OAuthClientSecretModel, RfdModel, RfdPdfModel,
-        RfdRevisionModel,
+        JobModel, LabelModel, LinkRequestModel, LoginAttemptModel, MapperModel, OAuthClientModel,
+        OAuthClientRedirectUriModel, OAuthClientSecretModel, RfdAuthorModel, RfdModel, RfdPdfModel,
+        RfdReservationModel, RfdRevisionAssetModel, RfdRevisionLabelModel, RfdRevisionModel,
+        RfdStateTransitionModel, WebhookDeliveryModel, WebhookSubscriptionModel,
     },
     schema::{
-        access_groups, api_key, api_user, api_user_access_token, api_user_provider, job,
+        access_groups, api_key, api_user, api_user_access_token, api_user_provider, job, label,
         link_request, login_attempt, mapper, oauth_client, oauth_client_redirect_uri,
-        oauth_client_secret, rfd, rfd_pdf, rfd_revision,
+        oauth_client_secret, rfd, rfd_author, rfd_pdf, rfd_reservation, rfd_revision,
+        rfd_revision_asset, rfd_revision_label, rfd_state_transition, webhook_delivery,
+        webhook_subscription,
     },
     schema_ext::Visibility,
     storage::{LinkRequestFilter, LinkRequestStore, StoreError},
-    AccessGroup, AccessToken, ApiKey, ApiUser, ApiUserProvider, Job, LinkRequest, LoginAttempt,
-    Mapper, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser, NewApiUserProvider, NewJob,
-    NewLinkRequest, NewLoginAttempt, NewMapper, NewOAuthClient, NewOAuthClientRedirectUri,
-    NewOAuthClientSecret, NewRfd, NewRfdPdf, NewRfdRevision, OAuthClient, OAuthClientRedirectUri,
-    OAuthClientSecret, Rfd, RfdPdf, RfdRevision,
+    AccessGroup, AccessToken, ApiKey, ApiUser, ApiUserProvider, Job, Label, LinkRequest,
+    LoginAttempt, Mapper, NewAccessGroup, NewAccessToken, NewApiKey, NewApiUser,
+    NewApiUserProvider, NewJob, NewLabel, NewLinkRequest, NewLoginAttempt, NewMapper,
+    NewOAuthClient, NewOAuthClientRedirectUri, NewOAuthClientSecret, NewRfd, NewRfdAuthor,
+    NewRfdPdf, NewRfdReservation, NewRfdRevision, NewRfdRevisionAsset, NewRfdRevisionLabel,
+    NewRfdStateTransition, NewWebhookDelivery, NewWebhookSubscription, OAuthClient,
+    OAuthClientRedirectUri, OAuthClientSecret, Rfd, RfdAuthor, RfdEvent, RfdPdf, RfdReservation,
+    RfdRevision, RfdRevisionAsset, RfdRevisionLabel, RfdStateTransition, WebhookDelivery,
+    WebhookSubscription,
 };
 
 use super::{
     AccessGroupFilter, AccessGroupStore, AccessTokenFilter, AccessTokenStore, ApiKeyFilter,
     ApiKeyStore, ApiUserFilter, ApiUserProviderFilter, ApiUserProviderStore, ApiUserStore,
-    JobFilter, JobStore, ListPagination, LoginAttemptFilter, LoginAttemptStore, MapperFilter,
-    MapperStore, OAuthClientFilter, OAuthClientRedirectUriStore, OAuthClientSecretStore,
-    OAuthClientStore, RfdFilter, RfdPdfFilter, RfdPdfStore, RfdRevisionFilter, RfdRevisionStore,
-    RfdStore,
+    JobFilter, JobStore, LabelFilter, LabelStore, ListPagination, LoginAttemptFilter,
+    LoginAttemptStore, MapperFilter, MapperStore, OAuthClientFilter, OAuthClientRedirectUriStore,
+    OAuthClientSecretStore, OAuthClientStore, RfdAuthorFilter, RfdAuthorStore, RfdEventStore,
+    RfdFilter, RfdPdfFilter, RfdPdfStore, RfdReservationStore, RfdRevisionAssetFilter,
+    RfdRevisionAssetStore, RfdRevisionFilter, RfdRevisionLabelFilter, RfdRevisionLabelStore,
+    RfdRevisionStore, RfdStateTransitionFilter, RfdStateTransitionStore, RfdStore,
+    WebhookDeliveryFilter, WebhookDeliveryStore, WebhookSubscriptionFilter,
+    WebhookSubscriptionStore, RFD_EVENT_CHANNEL,
 };
 
 pub type DbPool = Pool<ConnectionManager<PgConnection>>;
 
+sql_function!(fn lower(value: Nullable<Text>) -> Nullable<Text>);
+
 pub struct PostgresStore {
     pool: DbPool,
 }
@@ -109,6 +127,7 @@ impl RfdStore for PostgresStore {
             id,
             rfd_number,
             public,
+            updated_after,
             deleted,
         } = filter;
 
@@ -128,6 +147,10 @@ impl RfdStore for PostgresStore {
             );
         }
 
+        if let Some(updated_after) = updated_after {
+            query = query.filter(rfd::updated_at.gt(updated_after));
+        }
+
         if !deleted {
             query = query.filter(rfd::deleted_at.is_null());
         }
@@ -202,8 +225,10 @@ impl RfdRevisionStore for PostgresStore {
 
         let RfdRevisionFilter {
             id,
+            exclude_id,
             rfd,
             sha,
+            commit,
             deleted,
         } = filter;
 
@@ -211,6 +236,10 @@ impl RfdRevisionStore for PostgresStore {
             query = query.filter(rfd_revision::id.eq_any(id));
         }
 
+        if let Some(exclude_id) = exclude_id {
+            query = query.filter(rfd_revision::id.ne_all(exclude_id));
+        }
+
         if let Some(rfd) = rfd {
             query = query.filter(rfd_revision::rfd_id.eq_any(rfd));
         }
@@ -219,6 +248,10 @@ impl RfdRevisionStore for PostgresStore {
             query = query.filter(rfd_revision::sha.eq_any(sha));
         }
 
+        if let Some(commit) = commit {
+            query = query.filter(rfd_revision::commit_sha.eq_any(commit));
+        }
+
         if !deleted {
             query = query.filter(rfd_revision::deleted_at.is_null());
         }
@@ -250,8 +283,10 @@ impl RfdRevisionStore for PostgresStore {
 
         let RfdRevisionFilter {
             id,
+            exclude_id,
             rfd,
             sha,
+            commit,
             deleted,
         } = filter;
 
@@ -259,6 +294,10 @@ impl RfdRevisionStore for PostgresStore {
             query = query.filter(rfd_revision::id.eq_any(id));
         }
 
+        if let Some(exclude_id) = exclude_id {
+            query = query.filter(rfd_revision::id.ne_all(exclude_id));
+        }
+
         if let Some(rfd) = rfd {
             query = query.filter(rfd_revision::rfd_id.eq_any(rfd));
         }
@@ -267,6 +306,10 @@ impl RfdRevisionStore for PostgresStore {
             query = query.filter(rfd_revision::sha.eq_any(sha));
         }
 
+        if let Some(commit) = commit {
+            query = query.filter(rfd_revision::commit_sha.eq_any(commit));
+        }
+
         if !deleted {
             query = query.filter(rfd_revision::deleted_at.is_null());
         }
@@ -436,6 +479,456 @@ impl RfdPdfStore for PostgresStore {
     }
 }
 
+#[async_trait]
+impl RfdRevisionAssetStore for PostgresStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdRevisionAsset>, StoreError> {
+        let asset = RfdRevisionAssetStore::list(
+            self,
+            RfdRevisionAssetFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(asset.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: RfdRevisionAssetFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionAsset>, StoreError> {
+        let mut query = rfd_revision_asset::dsl::rfd_revision_asset.into_boxed();
+
+        tracing::trace!(?filter, "Lookup RFD revision assets");
+
+        let RfdRevisionAssetFilter {
+            id,
+            rfd,
+            rfd_revision,
+            object_name,
+            deleted,
+        } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(rfd_revision_asset::id.eq_any(id));
+        }
+
+        if let Some(rfd) = rfd {
+            query = query.filter(rfd_revision_asset::rfd_id.eq_any(rfd));
+        }
+
+        if let Some(rfd_revision) = rfd_revision {
+            query = query.filter(rfd_revision_asset::rfd_revision_id.eq_any(rfd_revision));
+        }
+
+        if let Some(object_name) = object_name {
+            query = query.filter(rfd_revision_asset::object_name.eq_any(object_name));
+        }
+
+        if !deleted {
+            query = query.filter(rfd_revision_asset::deleted_at.is_null());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(rfd_revision_asset::created_at.desc())
+            .get_results_async::<RfdRevisionAssetModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results.into_iter().map(|asset| asset.into()).collect())
+    }
+
+    async fn upsert(&self, new_asset: NewRfdRevisionAsset) -> Result<RfdRevisionAsset, StoreError> {
+        let asset: RfdRevisionAssetModel = insert_into(rfd_revision_asset::dsl::rfd_revision_asset)
+            .values((
+                rfd_revision_asset::id.eq(new_asset.id),
+                rfd_revision_asset::rfd_id.eq(new_asset.rfd_id),
+                rfd_revision_asset::rfd_revision_id.eq(new_asset.rfd_revision_id),
+                rfd_revision_asset::path.eq(new_asset.path.clone()),
+                rfd_revision_asset::object_name.eq(new_asset.object_name.clone()),
+                rfd_revision_asset::sha.eq(String::from(new_asset.sha.clone())),
+            ))
+            .on_conflict(on_constraint("rfd_revision_asset_path_unique"))
+            .do_update()
+            .set((
+                rfd_revision_asset::object_name.eq(excluded(rfd_revision_asset::object_name)),
+                rfd_revision_asset::sha.eq(excluded(rfd_revision_asset::sha)),
+                rfd_revision_asset::updated_at.eq(Utc::now()),
+                rfd_revision_asset::deleted_at.eq(None::<DateTime<Utc>>),
+            ))
+            .get_result_async(&*self.pool.get().await?)
+            .await?;
+
+        Ok(asset.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdRevisionAsset>, StoreError> {
+        let _ = update(rfd_revision_asset::dsl::rfd_revision_asset)
+            .filter(rfd_revision_asset::id.eq(*id))
+            .set(rfd_revision_asset::deleted_at.eq(Utc::now()))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        RfdRevisionAssetStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl RfdAuthorStore for PostgresStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdAuthor>, StoreError> {
+        let author = RfdAuthorStore::list(
+            self,
+            RfdAuthorFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(author.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: RfdAuthorFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdAuthor>, StoreError> {
+        let mut query = rfd_author::dsl::rfd_author.into_boxed();
+
+        tracing::trace!(?filter, "Lookup RFD authors");
+
+        let RfdAuthorFilter {
+            id,
+            rfd_revision,
+            api_user,
+            email,
+            deleted,
+        } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(rfd_author::id.eq_any(id));
+        }
+
+        if let Some(rfd_revision) = rfd_revision {
+            query = query.filter(rfd_author::rfd_revision_id.eq_any(rfd_revision));
+        }
+
+        if let Some(api_user) = api_user {
+            query = query.filter(rfd_author::api_user_id.eq_any(api_user));
+        }
+
+        // Emails are compared case insensitively
+        if let Some(email) = email {
+            query = query.filter(
+                lower(rfd_author::email).eq_any(
+                    email
+                        .into_iter()
+                        .map(|email| email.to_lowercase())
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+
+        if !deleted {
+            query = query.filter(rfd_author::deleted_at.is_null());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order((
+                rfd_author::rfd_revision_id.asc(),
+                rfd_author::position.asc(),
+            ))
+            .get_results_async::<RfdAuthorModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results.into_iter().map(|author| author.into()).collect())
+    }
+
+    async fn upsert(&self, new_author: NewRfdAuthor) -> Result<RfdAuthor, StoreError> {
+        let author: RfdAuthorModel = insert_into(rfd_author::dsl::rfd_author)
+            .values((
+                rfd_author::id.eq(new_author.id),
+                rfd_author::rfd_revision_id.eq(new_author.rfd_revision_id),
+                rfd_author::position.eq(new_author.position),
+                rfd_author::name.eq(new_author.name.clone()),
+                rfd_author::email.eq(new_author.email.clone()),
+                rfd_author::api_user_id.eq(new_author.api_user_id),
+            ))
+            .on_conflict(on_constraint("rfd_author_revision_position_unique"))
+            .do_update()
+            .set((
+                rfd_author::name.eq(excluded(rfd_author::name)),
+                rfd_author::email.eq(excluded(rfd_author::email)),
+                rfd_author::api_user_id.eq(excluded(rfd_author::api_user_id)),
+                rfd_author::updated_at.eq(Utc::now()),
+                rfd_author::deleted_at.eq(None::<DateTime<Utc>>),
+            ))
+            .get_result_async(&*self.pool.get().await?)
+            .await?;
+
+        Ok(author.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdAuthor>, StoreError> {
+        let _ = update(rfd_author::dsl::rfd_author)
+            .filter(rfd_author::id.eq(*id))
+            .set(rfd_author::deleted_at.eq(Utc::now()))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        RfdAuthorStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl LabelStore for PostgresStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<Label>, StoreError> {
+        let label = LabelStore::list(
+            self,
+            LabelFilter::default().id(Some(vec![*id])).deleted(deleted),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(label.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: LabelFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<Label>, StoreError> {
+        let mut query = label::dsl::label.into_boxed();
+
+        tracing::trace!(?filter, "Lookup labels");
+
+        let LabelFilter { id, name, deleted } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(label::id.eq_any(id));
+        }
+
+        // Names are compared case insensitively
+        if let Some(name) = name {
+            query = query.filter(
+                lower(label::name.nullable()).eq_any(
+                    name.into_iter()
+                        .map(|name| name.to_lowercase())
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+
+        if !deleted {
+            query = query.filter(label::deleted_at.is_null());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(label::name.asc())
+            .get_results_async::<LabelModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results.into_iter().map(|label| label.into()).collect())
+    }
+
+    async fn upsert(&self, new_label: NewLabel) -> Result<Label, StoreError> {
+        let label: LabelModel = insert_into(label::dsl::label)
+            .values((
+                label::id.eq(new_label.id),
+                label::name.eq(new_label.name.clone()),
+                label::description.eq(new_label.description.clone()),
+                label::color.eq(new_label.color.clone()),
+            ))
+            .on_conflict(label::id)
+            .do_update()
+            .set((
+                label::name.eq(excluded(label::name)),
+                label::description.eq(excluded(label::description)),
+                label::color.eq(excluded(label::color)),
+                label::updated_at.eq(Utc::now()),
+                label::deleted_at.eq(None::<DateTime<Utc>>),
+            ))
+            .get_result_async(&*self.pool.get().await?)
+            .await?;
+
+        Ok(label.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<Option<Label>, StoreError> {
+        let _ = update(label::dsl::label)
+            .filter(label::id.eq(*id))
+            .set(label::deleted_at.eq(Utc::now()))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        LabelStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl RfdRevisionLabelStore for PostgresStore {
+    async fn get(&self, id: &Uuid, deleted: bool) -> Result<Option<RfdRevisionLabel>, StoreError> {
+        let revision_label = RfdRevisionLabelStore::list(
+            self,
+            RfdRevisionLabelFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(revision_label.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: RfdRevisionLabelFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError> {
+        let mut query = rfd_revision_label::dsl::rfd_revision_label.into_boxed();
+
+        tracing::trace!(?filter, "Lookup RFD revision labels");
+
+        let RfdRevisionLabelFilter {
+            id,
+            rfd_revision,
+            label,
+            deleted,
+        } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(rfd_revision_label::id.eq_any(id));
+        }
+
+        if let Some(rfd_revision) = rfd_revision {
+            query = query.filter(rfd_revision_label::rfd_revision_id.eq_any(rfd_revision));
+        }
+
+        if let Some(label) = label {
+            query = query.filter(rfd_revision_label::label_id.eq_any(label));
+        }
+
+        if !deleted {
+            query = query.filter(rfd_revision_label::deleted_at.is_null());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(rfd_revision_label::created_at.asc())
+            .get_results_async::<RfdRevisionLabelModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|revision_label| revision_label.into())
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        new_revision_label: NewRfdRevisionLabel,
+    ) -> Result<RfdRevisionLabel, StoreError> {
+        let revision_label: RfdRevisionLabelModel =
+            insert_into(rfd_revision_label::dsl::rfd_revision_label)
+                .values((
+                    rfd_revision_label::id.eq(new_revision_label.id),
+                    rfd_revision_label::rfd_revision_id.eq(new_revision_label.rfd_revision_id),
+                    rfd_revision_label::label_id.eq(new_revision_label.label_id),
+                ))
+                .on_conflict(on_constraint("rfd_revision_label_unique"))
+                .do_update()
+                .set(rfd_revision_label::deleted_at.eq(None::<DateTime<Utc>>))
+                .get_result_async(&*self.pool.get().await?)
+                .await?;
+
+        Ok(revision_label.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<Option<RfdRevisionLabel>, StoreError> {
+        let _ = update(rfd_revision_label::dsl::rfd_revision_label)
+            .filter(rfd_revision_label::id.eq(*id))
+            .set(rfd_revision_label::deleted_at.eq(Utc::now()))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        RfdRevisionLabelStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl RfdStateTransitionStore for PostgresStore {
+    async fn get(&self, id: &Uuid) -> Result<Option<RfdStateTransition>, StoreError> {
+        let transition = RfdStateTransitionStore::list(
+            self,
+            RfdStateTransitionFilter::default().id(Some(vec![*id])),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(transition.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: RfdStateTransitionFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdStateTransition>, StoreError> {
+        let mut query = rfd_state_transition::dsl::rfd_state_transition.into_boxed();
+
+        tracing::trace!(?filter, "Lookup RFD state transitions");
+
+        let RfdStateTransitionFilter { id, rfd, api_user } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(rfd_state_transition::id.eq_any(id));
+        }
+
+        if let Some(rfd) = rfd {
+            query = query.filter(rfd_state_transition::rfd_id.eq_any(rfd));
+        }
+
+        if let Some(api_user) = api_user {
+            query = query.filter(rfd_state_transition::api_user_id.eq_any(api_user));
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(rfd_state_transition::created_at.asc())
+            .get_results_async::<RfdStateTransitionModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|transition| transition.into())
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        new_transition: NewRfdStateTransition,
+    ) -> Result<RfdStateTransition, StoreError> {
+        let transition: RfdStateTransitionModel =
+            insert_into(rfd_state_transition::dsl::rfd_state_transition)
+                .values((
+                    rfd_state_transition::id.eq(new_transition.id),
+                    rfd_state_transition::rfd_id.eq(new_transition.rfd_id),
+                    rfd_state_transition::api_user_id.eq(new_transition.api_user_id),
+                    rfd_state_transition::from_state.eq(new_transition.from_state),
+                    rfd_state_transition::to_state.eq(new_transition.to_state),
+                    rfd_state_transition::reason.eq(new_transition.reason),
+                    rfd_state_transition::commit_sha.eq(new_transition.commit.map(String::from)),
+                ))
+                .get_result_async(&*self.pool.get().await?)
+                .await?;
+
+        Ok(transition.into())
+    }
+}
+
 #[async_trait]
 impl JobStore for PostgresStore {
     async fn get(&self, id: i32) -> Result<Option<Job>, StoreError> {
@@ -534,6 +1027,299 @@ impl JobStore for PostgresStore {
     }
 }
 
+#[async_trait]
+impl WebhookSubscriptionStore for PostgresStore {
+    async fn get(
+        &self,
+        id: &Uuid,
+        deleted: bool,
+    ) -> Result<Option<WebhookSubscription>, StoreError> {
+        let subscription = WebhookSubscriptionStore::list(
+            self,
+            WebhookSubscriptionFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(subscription.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: WebhookSubscriptionFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscription>, StoreError> {
+        let mut query = webhook_subscription::dsl::webhook_subscription.into_boxed();
+
+        tracing::trace!(?filter, "Lookup webhook subscriptions");
+
+        let WebhookSubscriptionFilter { id, deleted } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(webhook_subscription::id.eq_any(id));
+        }
+
+        if !deleted {
+            query = query.filter(webhook_subscription::deleted_at.is_null());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(webhook_subscription::created_at.asc())
+            .get_results_async::<WebhookSubscriptionModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|subscription| subscription.into())
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        new_subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, StoreError> {
+        let subscription: WebhookSubscriptionModel =
+            insert_into(webhook_subscription::dsl::webhook_subscription)
+                .values((
+                    webhook_subscription::id.eq(new_subscription.id),
+                    webhook_subscription::url.eq(new_subscription.url.clone()),
+                    webhook_subscription::secret.eq(new_subscription.secret.clone()),
+                    webhook_subscription::events.eq(new_subscription
+                        .events
+                        .iter()
+                        .cloned()
+                        .map(Some)
+                        .collect::<Vec<_>>()),
+                    webhook_subscription::rfds.eq(new_subscription
+                        .rfds
+                        .as_ref()
+                        .map(|rfds| rfds.iter().cloned().map(Some).collect::<Vec<_>>())),
+                ))
+                .on_conflict(webhook_subscription::id)
+                .do_update()
+                .set((
+                    webhook_subscription::url.eq(excluded(webhook_subscription::url)),
+                    webhook_subscription::secret.eq(excluded(webhook_subscription::secret)),
+                    webhook_subscription::events.eq(excluded(webhook_subscription::events)),
+                    webhook_subscription::rfds.eq(excluded(webhook_subscription::rfds)),
+                    webhook_subscription::updated_at.eq(Utc::now()),
+                ))
+                .get_result_async(&*self.pool.get().await?)
+                .await?;
+
+        Ok(subscription.into())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<Option<WebhookSubscription>, StoreError> {
+        let _ = update(webhook_subscription::dsl::webhook_subscription)
+            .filter(webhook_subscription::id.eq(*id))
+            .set(webhook_subscription::deleted_at.eq(Utc::now()))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        WebhookSubscriptionStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryStore for PostgresStore {
+    async fn get(&self, id: &Uuid) -> Result<Option<WebhookDelivery>, StoreError> {
+        let delivery = WebhookDeliveryStore::list(
+            self,
+            WebhookDeliveryFilter::default().id(Some(vec![*id])),
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(delivery.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filter: WebhookDeliveryFilter,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookDelivery>, StoreError> {
+        let mut query = webhook_delivery::dsl::webhook_delivery.into_boxed();
+
+        tracing::trace!(?filter, "Lookup webhook deliveries");
+
+        let WebhookDeliveryFilter {
+            id,
+            webhook_subscription,
+            due_before,
+        } = filter;
+
+        if let Some(id) = id {
+            query = query.filter(webhook_delivery::id.eq_any(id));
+        }
+
+        if let Some(webhook_subscription) = webhook_subscription {
+            query = query
+                .filter(webhook_delivery::webhook_subscription_id.eq_any(webhook_subscription));
+        }
+
+        // Due deliveries are listed in the order that they became due so that the longest waiting
+        // deliveries are attempted first. Otherwise the newest deliveries are listed first
+        if let Some(due_before) = due_before {
+            query = query
+                .filter(webhook_delivery::next_attempt_at.le(due_before))
+                .order(webhook_delivery::next_attempt_at.asc());
+        } else {
+            query = query.order(webhook_delivery::created_at.desc());
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .get_results_async::<WebhookDeliveryModel>(&*self.pool.get().await?)
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|delivery| delivery.into())
+            .collect())
+    }
+
+    async fn upsert(
+        &self,
+        new_delivery: NewWebhookDelivery,
+    ) -> Result<WebhookDelivery, StoreError> {
+        let delivery: WebhookDeliveryModel = insert_into(webhook_delivery::dsl::webhook_delivery)
+            .values((
+                webhook_delivery::id.eq(new_delivery.id),
+                webhook_delivery::webhook_subscription_id.eq(new_delivery.webhook_subscription_id),
+                webhook_delivery::event.eq(new_delivery.event.clone()),
+                webhook_delivery::payload.eq(new_delivery.payload.clone()),
+                webhook_delivery::next_attempt_at.eq(new_delivery.next_attempt_at),
+            ))
+            .on_conflict(webhook_delivery::id)
+            .do_update()
+            .set((
+                webhook_delivery::next_attempt_at.eq(excluded(webhook_delivery::next_attempt_at)),
+                webhook_delivery::updated_at.eq(Utc::now()),
+            ))
+            .get_result_async(&*self.pool.get().await?)
+            .await?;
+
+        Ok(delivery.into())
+    }
+
+    async fn start(
+        &self,
+        id: &Uuid,
+        retry_at: DateTime<Utc>,
+    ) -> Result<Option<WebhookDelivery>, StoreError> {
+        let delivery = update(webhook_delivery::dsl::webhook_delivery)
+            .filter(webhook_delivery::id.eq(*id))
+            .filter(webhook_delivery::next_attempt_at.le(Utc::now()))
+            .set((
+                webhook_delivery::attempts.eq(webhook_delivery::attempts + 1),
+                webhook_delivery::next_attempt_at.eq(retry_at),
+                webhook_delivery::updated_at.eq(Utc::now()),
+            ))
+            .get_result_async::<WebhookDeliveryModel>(&*self.pool.get().await?)
+            .await
+            .optional()?;
+
+        Ok(delivery.map(|delivery| delivery.into()))
+    }
+
+    async fn complete(
+        &self,
+        id: &Uuid,
+        delivered: bool,
+        response_status: Option<i32>,
+        error: Option<String>,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<WebhookDelivery>, StoreError> {
+        let _ = update(webhook_delivery::dsl::webhook_delivery)
+            .filter(webhook_delivery::id.eq(*id))
+            .set((
+                webhook_delivery::response_status.eq(response_status),
+                webhook_delivery::last_error.eq(error),
+                webhook_delivery::next_attempt_at.eq(next_attempt_at),
+                webhook_delivery::delivered_at.eq(delivered.then(Utc::now)),
+                webhook_delivery::updated_at.eq(Utc::now()),
+            ))
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        WebhookDeliveryStore::get(self, id).await
+    }
+}
+
+// Number of times a reservation is attempted before giving up. An attempt only fails when a
+// concurrent reservation claims the same number first
+static RESERVATION_ATTEMPTS: usize = 5;
+
+#[async_trait]
+impl RfdReservationStore for PostgresStore {
+    async fn reserve(
+        &self,
+        new_reservation: NewRfdReservation,
+    ) -> Result<RfdReservation, StoreError> {
+        for _ in 0..RESERVATION_ATTEMPTS {
+            let max_reserved = rfd_reservation::dsl::rfd_reservation
+                .select(max(rfd_reservation::rfd_number))
+                .get_result_async::<Option<i32>>(&*self.pool.get().await?)
+                .await?;
+            let rfd_number = new_reservation
+                .rfd_number
+                .max(max_reserved.unwrap_or(0) + 1);
+
+            // The unique constraint on the number rejects the insert if a concurrent reservation
+            // has claimed the same number since the maximum was read
+            let reservation = insert_into(rfd_reservation::dsl::rfd_reservation)
+                .values((
+                    rfd_reservation::id.eq(new_reservation.id),
+                    rfd_reservation::rfd_number.eq(rfd_number),
+                    rfd_reservation::api_user_id.eq(new_reservation.api_user_id),
+                ))
+                .on_conflict_do_nothing()
+                .get_result_async::<RfdReservationModel>(&*self.pool.get().await?)
+                .await
+                .optional()?;
+
+            match reservation {
+                Some(reservation) => return Ok(reservation.into()),
+                None => tracing::info!(rfd_number, "RFD number was reserved concurrently"),
+            }
+        }
+
+        Err(StoreError::InvariantFailed(
+            "Failed to find an unreserved RFD number".to_string(),
+        ))
+    }
+
+    async fn release(&self, id: &Uuid) -> Result<Option<RfdReservation>, StoreError> {
+        let reservation = delete(rfd_reservation::dsl::rfd_reservation)
+            .filter(rfd_reservation::id.eq(*id))
+            .get_result_async::<RfdReservationModel>(&*self.pool.get().await?)
+            .await
+            .optional()?;
+
+        Ok(reservation.map(|reservation| reservation.into()))
+    }
+}
+
+#[async_trait]
+impl RfdEventStore for PostgresStore {
+    async fn notify(&self, event: &RfdEvent) -> Result<(), StoreError> {
+        // Events only hold plain data and can always be serialized
+        let payload = serde_json::to_string(event).unwrap();
+
+        let _ = sql_query("SELECT pg_notify($1, $2)")
+            .bind::<Text, _>(RFD_EVENT_CHANNEL.to_string())
+            .bind::<Text, _>(payload)
+            .execute_async(&*self.pool.get().await?)
+            .await?;
+
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl<T> ApiUserStore<T> for PostgresStore
 where
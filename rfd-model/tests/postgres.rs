@@ -15,9 +15,9 @@ use diesel_migrations::{embed_migrations, EmbeddedMigrations};
 use rfd_model::{
     storage::{
         postgres::PostgresStore, ApiKeyFilter, ApiKeyStore, ApiUserFilter, ApiUserStore,
-        ListPagination, RfdRevisionFilter, RfdRevisionStore,
+        ListPagination, RfdReservationStore, RfdRevisionFilter, RfdRevisionStore,
     },
-    NewApiKey, NewApiUser,
+    NewApiKey, NewApiUser, NewRfdReservation,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -369,3 +369,49 @@ async fn test_rfd_list() {
     .await
     .unwrap();
 }
+
+#[tokio::test]
+async fn test_rfd_reservation() {
+    let db = TestDb::new("test_rfd_reservation");
+    let store = PostgresStore::new(&db.url()).await.unwrap();
+
+    let api_user = ApiUserStore::<TestPermission>::upsert(
+        &store,
+        NewApiUser {
+            id: Uuid::new_v4(),
+            permissions: vec![].into(),
+            groups: BTreeSet::new(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let reserve = |rfd_number: i32| {
+        RfdReservationStore::reserve(
+            &store,
+            NewRfdReservation {
+                id: Uuid::new_v4(),
+                rfd_number,
+                api_user_id: api_user.id,
+            },
+        )
+    };
+
+    // Concurrent reservations for the same number must each be handed a distinct number
+    let (first, second) = tokio::join!(reserve(10), reserve(10));
+    let mut numbers = vec![first.unwrap().rfd_number, second.unwrap().rfd_number];
+    numbers.sort();
+    assert_eq!(vec![10, 11], numbers);
+
+    // A minimum that is higher than every reserved number is used as is
+    let third = reserve(20).await.unwrap();
+    assert_eq!(20, third.rfd_number);
+
+    // Releasing the latest reservation allows its number to be handed out again
+    let released = RfdReservationStore::release(&store, &third.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(third.id, released.id);
+    assert_eq!(20, reserve(20).await.unwrap().rfd_number);
+}
@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use base64::DecodeError;
 use octorust::Client;
 use rfd_data::{
-    content::{RfdAsciidoc, RfdContent, RfdDocument, RfdMarkdown},
+    content::{RfdAsciidoc, RfdContent, RfdDocument, RfdHeader, RfdMarkdown},
     RfdNumber,
 };
 use rfd_github::{GitHubError, GitHubRfdLocation};
@@ -131,7 +131,7 @@ impl<'a> RenderableRfd<'a> {
         number: &RfdNumber,
         location: &GitHubRfdLocation,
     ) -> Result<(), RfdContentError> {
-        let dir = number.repo_path();
+        let dir = location.number_format.repo_path(number);
         let storage_path = self.tmp_path()?;
 
         let images = location.get_images(client, number).await?;
@@ -189,18 +189,10 @@ impl<'a> RfdDocument for RenderableRfd<'a> {
         RfdDocument::get_state(&self.content)
     }
 
-    fn update_state(&mut self, value: &str) {
-        RfdDocument::update_state(&mut self.content, value)
-    }
-
     fn get_discussion(&self) -> Option<&str> {
         RfdDocument::get_discussion(&self.content)
     }
 
-    fn update_discussion(&mut self, value: &str) {
-        RfdDocument::update_discussion(&mut self.content, value)
-    }
-
     fn get_authors(&self) -> Option<&str> {
         RfdDocument::get_authors(&self.content)
     }
@@ -209,8 +201,12 @@ impl<'a> RfdDocument for RenderableRfd<'a> {
         RfdDocument::get_labels(&self.content)
     }
 
-    fn update_labels(&mut self, value: &str) {
-        RfdDocument::update_labels(&mut self.content, value)
+    fn get_header(&self) -> RfdHeader {
+        RfdDocument::get_header(&self.content)
+    }
+
+    fn update_header(&mut self, header: &RfdHeader) {
+        RfdDocument::update_header(&mut self.content, header)
     }
 
     fn header(&self) -> Option<&str> {
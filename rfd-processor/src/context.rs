@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{io::Cursor, time::Duration};
+use std::{io::Cursor, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use google_drive3::{api::File, DriveHub};
@@ -15,7 +15,7 @@ use octorust::{
     http_cache::FileBasedCache,
     Client as GitHubClient, ClientError,
 };
-use reqwest::Error as ReqwestError;
+use reqwest::{redirect::Policy, Error as ReqwestError};
 use rfd_github::{GitHubError, GitHubRfdRepo};
 use rfd_model::{schema_ext::PdfSource, storage::postgres::PostgresStore};
 use rsa::{
@@ -31,9 +31,13 @@ use crate::{
     search::RfdSearchIndex,
     updater::{BoxedAction, RfdUpdateMode, RfdUpdaterError},
     util::{gdrive_client, GDriveError},
+    webhook::WebhookResolver,
     AppConfig, GitHubAuthConfig, PdfStorageConfig, SearchConfig, StaticStorageConfig,
 };
 
+// Amount of time a webhook endpoint has to respond before the delivery attempt is failed
+static WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+
 pub struct Database {
     pub storage: PostgresStore,
 }
@@ -72,6 +76,8 @@ pub enum ContextError {
 pub struct Context {
     pub processor: ProcessorCtx,
     pub scanner: ScannerCtx,
+    pub search_sync: SearchSyncCtx,
+    pub webhook_delivery: WebhookDeliveryCtx,
     pub db: Database,
     pub github: GitHubCtx,
     pub actions: Vec<BoxedAction>,
@@ -131,6 +137,7 @@ impl Context {
             config.source.repo.clone(),
             config.source.path.clone(),
             config.source.default_branch.clone(),
+            config.source.number_format.clone(),
         )
         .await?;
 
@@ -145,6 +152,25 @@ impl Context {
                 enabled: config.scanner_enabled,
                 interval: Duration::from_secs(config.scanner_interval),
             },
+            search_sync: SearchSyncCtx {
+                enabled: config.search_sync_enabled,
+                interval: Duration::from_secs(config.search_sync_interval),
+                consistency_interval: Duration::from_secs(config.search_consistency_interval),
+            },
+            webhook_delivery: WebhookDeliveryCtx {
+                enabled: config.webhook_delivery_enabled,
+                interval: Duration::from_secs(config.webhook_delivery_interval),
+                max_attempts: config.webhook_delivery_max_attempts,
+                // Redirects are not followed, as they would bypass the checks that are made against
+                // the addresses of an endpoint
+                client: reqwest::Client::builder()
+                    .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+                    .https_only(true)
+                    .redirect(Policy::none())
+                    .dns_resolver(Arc::new(WebhookResolver))
+                    .build()
+                    .map_err(ContextError::ClientConstruction)?,
+            },
             db,
             github: GitHubCtx {
                 client: github_client,
@@ -174,6 +200,19 @@ pub struct ScannerCtx {
     pub interval: Duration,
 }
 
+pub struct SearchSyncCtx {
+    pub enabled: bool,
+    pub interval: Duration,
+    pub consistency_interval: Duration,
+}
+
+pub struct WebhookDeliveryCtx {
+    pub enabled: bool,
+    pub interval: Duration,
+    pub max_attempts: i32,
+    pub client: reqwest::Client,
+}
+
 pub struct GitHubCtx {
     pub client: GitHubClient,
     pub repository: GitHubRfdRepo,
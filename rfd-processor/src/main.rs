@@ -2,8 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use clap::{Parser, Subcommand};
 use config::{Config, ConfigError, Environment, File};
 use processor::{processor, JobError};
+use rfd_data::RfdNumberFormat;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
@@ -15,6 +17,8 @@ use updater::RfdUpdateMode;
 use crate::{
     context::{Context, Database},
     scanner::{scanner, ScannerError},
+    search_sync::{configure_indexes, prune, reindex, search_sync, SearchSyncError},
+    webhook::{webhook_delivery, WebhookDeliveryError},
 };
 
 mod content;
@@ -25,8 +29,30 @@ mod processor;
 mod rfd;
 mod scanner;
 mod search;
+mod search_sync;
 mod updater;
 mod util;
+mod webhook;
+
+#[derive(Debug, Parser)]
+#[command(name = "rfd-processor")]
+struct Args {
+    /// Path to a configuration file to load in addition to the default locations
+    config: Option<String>,
+    /// One-off operation to run instead of starting the scanner and processor tasks
+    #[command(subcommand)]
+    operation: Option<Operation>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Operation {
+    /// Create or update the settings of each configured search index
+    ConfigureSearch,
+    /// Rebuild each configured search index into a new index and swap it into place
+    ReindexSearch,
+    /// Delete search documents that belong to RFDs that no longer exist
+    PruneSearch,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
@@ -37,6 +63,12 @@ pub struct AppConfig {
     pub processor_update_mode: RfdUpdateMode,
     pub scanner_enabled: bool,
     pub scanner_interval: u64,
+    pub search_sync_enabled: bool,
+    pub search_sync_interval: u64,
+    pub search_consistency_interval: u64,
+    pub webhook_delivery_enabled: bool,
+    pub webhook_delivery_interval: u64,
+    pub webhook_delivery_max_attempts: i32,
     pub database_url: String,
     pub actions: Vec<String>,
     pub auth: AuthConfig,
@@ -55,6 +87,12 @@ pub enum AppError {
     Job(#[source] JobError),
     #[error("Scanner task failed")]
     Scanner(#[source] ScannerError),
+    #[error("Search sync task failed")]
+    SearchSync(#[source] SearchSyncError),
+    #[error("Search operation failed")]
+    SearchOperation(#[source] SearchSyncError),
+    #[error("Webhook delivery task failed")]
+    WebhookDelivery(#[source] WebhookDeliveryError),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -81,6 +119,8 @@ pub struct GitHubSourceRepo {
     pub repo: String,
     pub path: String,
     pub default_branch: String,
+    #[serde(default)]
+    pub number_format: RfdNumberFormat,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -120,11 +160,9 @@ impl AppConfig {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut args = std::env::args();
-    let _ = args.next();
-    let config_path = args.next();
+    let args = Args::parse();
 
-    let config = AppConfig::new(config_path.map(|path| vec![path]))?;
+    let config = AppConfig::new(args.config.map(|path| vec![path]))?;
 
     let (writer, _guard) = if let Some(log_directory) = &config.log_directory {
         let file_appender = tracing_appender::rolling::daily(log_directory, "rfd-processor.log");
@@ -143,6 +181,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let ctx = Arc::new(Context::new(Database::new(&config.database_url).await, &config).await?);
 
+    if let Some(operation) = args.operation {
+        tracing::info!(?operation, "Running operation");
+
+        let result = match operation {
+            Operation::ConfigureSearch => configure_indexes(&ctx).await,
+            Operation::ReindexSearch => reindex(&ctx).await,
+            Operation::PruneSearch => prune(&ctx).await,
+        };
+
+        return Ok(result.map_err(AppError::SearchOperation)?);
+    }
+
     let scanner_ctx = ctx.clone();
     let scanner_handle = tokio::spawn(async move {
         scanner(scanner_ctx).await?;
@@ -155,6 +205,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok::<_, JobError>(())
     });
 
+    let search_sync_ctx = ctx.clone();
+    let search_sync_handle = tokio::spawn(async move {
+        search_sync(search_sync_ctx).await?;
+        Ok::<_, SearchSyncError>(())
+    });
+
+    let webhook_delivery_ctx = ctx.clone();
+    let webhook_delivery_handle = tokio::spawn(async move {
+        webhook_delivery(webhook_delivery_ctx).await?;
+        Ok::<_, WebhookDeliveryError>(())
+    });
+
     // Tasks should run for the lifetime of the program. If any of them complete for any reason
     // then the entire application should exit
     let error = select! {
@@ -166,6 +228,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             tracing::info!(?value, "Scanner task exited");
             value?.map_err(AppError::Scanner)
         }
+        value = search_sync_handle => {
+            tracing::info!(?value, "Search sync task exited");
+            value?.map_err(AppError::SearchSync)
+        }
+        value = webhook_delivery_handle => {
+            tracing::info!(?value, "Webhook delivery task exited");
+            value?.map_err(AppError::WebhookDelivery)
+        }
     };
 
     Ok(error?)
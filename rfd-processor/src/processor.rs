@@ -52,6 +52,7 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
                                 branch: job.branch.clone(),
                                 commit: job.sha.clone(),
                                 default_branch: ctx.github.repository.default_branch.clone(),
+                                number_format: ctx.github.repository.number_format.clone(),
                             };
 
                             let update = GitHubRfdUpdate {
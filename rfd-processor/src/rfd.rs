@@ -6,21 +6,29 @@ use std::sync::Mutex;
 
 use chrono::{DateTime, Utc};
 use octorust::{Client, ClientError};
-use rfd_data::{content::RfdDocument, RfdNumber};
+use rfd_data::{content::RfdDocument, RfdAuthor, RfdNumber, RfdNumberFormat, RfdState};
 use rfd_github::{GitHubError, GitHubRfdReadme, GitHubRfdUpdate};
 use rfd_model::{
     schema_ext::{ContentFormat, Visibility},
     storage::{
-        ListPagination, RfdFilter, RfdPdfFilter, RfdPdfStore, RfdRevisionFilter, RfdRevisionStore,
-        RfdStore, StoreError,
+        ApiUserProviderFilter, ApiUserProviderStore, LabelFilter, LabelStore, ListPagination,
+        RfdAuthorFilter, RfdAuthorStore, RfdFilter, RfdPdfFilter, RfdPdfStore, RfdRevisionFilter,
+        RfdRevisionLabelFilter, RfdRevisionLabelStore, RfdRevisionStore, RfdStore, StoreError,
     },
-    CommitSha, FileSha, NewRfd, NewRfdRevision, Rfd, RfdRevision,
+    CommitSha, FileSha, NewRfd, NewRfdAuthor, NewRfdRevision, NewRfdRevisionLabel, Rfd,
+    RfdRevision,
 };
 use thiserror::Error;
 use uuid::Uuid;
 
 use crate::content::RenderableRfd;
 
+// Upper bound on the number of authors that are tracked for a single revision
+static AUTHOR_LIMIT: i64 = 100;
+
+// Upper bound on the number of labels that are read when matching a revision against the taxonomy
+static LABEL_LIMIT: i64 = 1000;
+
 #[derive(Debug)]
 pub struct PersistedRfd {
     pub number: RfdNumber,
@@ -97,7 +105,12 @@ impl PersistedRfd {
 
     pub async fn upsert<S>(&self, storage: &S) -> Result<(), RfdError>
     where
-        S: RfdStore + RfdRevisionStore,
+        S: RfdStore
+            + RfdRevisionStore
+            + RfdAuthorStore
+            + ApiUserProviderStore
+            + LabelStore
+            + RfdRevisionLabelStore,
     {
         let should_update = *self.needs_update.lock().unwrap();
 
@@ -106,6 +119,8 @@ impl PersistedRfd {
 
             RfdStore::upsert(storage, self.rfd.clone().into()).await?;
             RfdRevisionStore::upsert(storage, self.revision.clone().into()).await?;
+            upsert_authors(storage, &self.revision).await?;
+            upsert_labels(storage, &self.revision).await?;
 
             tracing::info!("Updated persisted RFD and revision");
 
@@ -128,7 +143,9 @@ impl PersistedRfd {
         let new_discussion_url = new_discussion_url.to_string();
 
         let mut content = self.content();
-        content.update_discussion(&new_discussion_url);
+        let mut header = content.get_header();
+        header.discussion = Some(new_discussion_url.clone());
+        content.update_header(&header);
 
         self.revision.content = content.into_inner_content();
         self.revision.discussion = Some(new_discussion_url);
@@ -138,6 +155,14 @@ impl PersistedRfd {
         Ok(())
     }
 
+    /// The parsed state of this RFD. Returns `None` if the RFD does not have a recognized state
+    pub fn state(&self) -> Option<RfdState> {
+        self.revision
+            .state
+            .as_deref()
+            .and_then(|state| RfdState::try_from(state).ok())
+    }
+
     pub fn is_state(&self, state: &str) -> bool {
         self.revision
             .state
@@ -146,14 +171,14 @@ impl PersistedRfd {
             .unwrap_or(false)
     }
 
-    pub fn update_state(&mut self, new_state: impl ToString) -> Result<(), RfdError> {
-        let new_state = new_state.to_string();
-
+    pub fn update_state(&mut self, new_state: RfdState) -> Result<(), RfdError> {
         let mut content = self.content();
-        content.update_state(&new_state);
+        let mut header = content.get_header();
+        header.state = Some(new_state.clone());
+        content.update_header(&header);
 
         self.revision.content = content.into_inner_content();
-        self.revision.state = Some(new_state);
+        self.revision.state = Some(new_state.to_string());
 
         *self.needs_update.lock().unwrap() = true;
 
@@ -161,7 +186,7 @@ impl PersistedRfd {
     }
 
     pub fn get_pdf_filename(&self) -> String {
-        let mut filename = format!("RFD {}", self.number.as_number_string());
+        let mut filename = format!("RFD {}", RfdNumberFormat::default().format(&self.number));
 
         if !self.revision.title.trim().is_empty() {
             tracing::trace!(?filename, title = ?self.revision.title, "Add title to pdf filename");
@@ -325,7 +350,13 @@ impl RemoteRfd {
 
     pub async fn upsert<S>(self, storage: &S) -> Result<PersistedRfd, RemoteRfdError>
     where
-        S: RfdStore + RfdRevisionStore + RfdPdfStore,
+        S: RfdStore
+            + RfdRevisionStore
+            + RfdPdfStore
+            + RfdAuthorStore
+            + ApiUserProviderStore
+            + LabelStore
+            + RfdRevisionLabelStore,
     {
         let number = self.number;
         let payload = self.into_payload()?;
@@ -402,6 +433,9 @@ impl RemoteRfd {
         )
         .await?;
 
+        upsert_authors(storage, &revision).await?;
+        upsert_labels(storage, &revision).await?;
+
         let mut existing_pdf = RfdPdfStore::list(
             storage,
             RfdPdfFilter::default().rfd(Some(vec![rfd.id])),
@@ -417,3 +451,138 @@ impl RemoteRfd {
         ))
     }
 }
+
+/// Store the structured form of a revision's authors line. Authors are matched against the emails
+/// of known user providers so that RFDs can be looked up by the user that wrote them. Provider
+/// emails are stored lowercased, and so author emails are lowercased before matching
+async fn upsert_authors<S>(storage: &S, revision: &RfdRevision) -> Result<(), StoreError>
+where
+    S: RfdAuthorStore + ApiUserProviderStore,
+{
+    let authors = revision
+        .authors
+        .as_deref()
+        .map(RfdAuthor::parse_list)
+        .unwrap_or_default();
+
+    let existing = RfdAuthorStore::list(
+        storage,
+        RfdAuthorFilter::default().rfd_revision(Some(vec![revision.id])),
+        &ListPagination::default().limit(AUTHOR_LIMIT),
+    )
+    .await?;
+
+    for (position, author) in authors.iter().enumerate() {
+        let position = position as i32;
+
+        let api_user_id = match &author.email {
+            Some(email) => ApiUserProviderStore::list(
+                storage,
+                ApiUserProviderFilter::default().email(Some(vec![email.to_lowercase()])),
+                &ListPagination::latest(),
+            )
+            .await?
+            .into_iter()
+            .next()
+            .map(|provider| provider.api_user_id),
+            None => None,
+        };
+
+        let id = existing
+            .iter()
+            .find(|existing| existing.position == position)
+            .map(|existing| existing.id)
+            .unwrap_or_else(Uuid::new_v4);
+
+        RfdAuthorStore::upsert(
+            storage,
+            NewRfdAuthor {
+                id,
+                rfd_revision_id: revision.id,
+                position,
+                name: author.name.clone(),
+                email: author.email.clone(),
+                api_user_id,
+            },
+        )
+        .await?;
+    }
+
+    // Remove any authors that were dropped from the authors line since the revision was last stored
+    for stale in existing
+        .iter()
+        .filter(|existing| existing.position as usize >= authors.len())
+    {
+        RfdAuthorStore::delete(storage, &stale.id).await?;
+    }
+
+    Ok(())
+}
+
+/// Link a revision to the labels in the label taxonomy that its labels line references. Labels are
+/// matched case-insensitively, and labels that are not part of the taxonomy are left unlinked
+async fn upsert_labels<S>(storage: &S, revision: &RfdRevision) -> Result<(), StoreError>
+where
+    S: LabelStore + RfdRevisionLabelStore,
+{
+    let names = revision
+        .labels
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+
+    let taxonomy = if names.is_empty() {
+        vec![]
+    } else {
+        LabelStore::list(
+            storage,
+            LabelFilter::default().name(Some(names.clone())),
+            &ListPagination::default().limit(LABEL_LIMIT),
+        )
+        .await?
+    };
+
+    let mut label_ids = vec![];
+    for name in &names {
+        match taxonomy
+            .iter()
+            .find(|label| label.name.to_lowercase() == *name)
+        {
+            Some(label) => label_ids.push(label.id),
+            None => tracing::debug!(?name, "Label is not part of the label taxonomy"),
+        }
+    }
+
+    let existing = RfdRevisionLabelStore::list(
+        storage,
+        RfdRevisionLabelFilter::default().rfd_revision(Some(vec![revision.id])),
+        &ListPagination::default().limit(LABEL_LIMIT),
+    )
+    .await?;
+
+    for label_id in &label_ids {
+        if !existing.iter().any(|link| link.label_id == *label_id) {
+            RfdRevisionLabelStore::upsert(
+                storage,
+                NewRfdRevisionLabel {
+                    id: Uuid::new_v4(),
+                    rfd_revision_id: revision.id,
+                    label_id: *label_id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    for stale in existing
+        .iter()
+        .filter(|link| !label_ids.contains(&link.label_id))
+    {
+        RfdRevisionLabelStore::delete(storage, &stale.id).await?;
+    }
+
+    Ok(())
+}
@@ -2,18 +2,26 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use chrono::Utc;
 use hmac::{Hmac, Mac};
 use md5::Md5;
 use meilisearch_sdk::{
+    client::SwapIndexes,
+    documents::DocumentsQuery,
     errors::{Error as MeiliError, ErrorCode},
     indexes::Index,
     settings::Settings,
+    task_info::TaskInfo,
     Client,
 };
 use parse_rfd::{parse, ParsedDoc, ParserError, Section};
-use rfd_data::RfdNumber;
+use rfd_data::{RfdNumber, RfdNumberFormat};
 use serde::{Deserialize, Serialize};
-use std::{cmp::min, collections::HashMap};
+use std::{
+    cmp::min,
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 use thiserror::Error;
 use tracing::instrument;
 
@@ -25,6 +33,16 @@ pub enum SearchError {
     Parse(#[from] ParserError),
 }
 
+// Upper bound on the number of section documents that a single RFD is expected to produce
+static MAX_DOCUMENTS_PER_RFD: usize = 1000;
+
+// Page size to use when walking all of the documents in an index
+static DOCUMENT_PAGE_SIZE: usize = 1000;
+
+// Maximum amount of time to wait on a single search task (i.e. a settings update or a document
+// batch) to be processed before giving up on an index operation
+static TASK_TIMEOUT: Duration = Duration::from_secs(300);
+
 #[derive(Debug)]
 pub struct RfdSearchIndex {
     client: Client,
@@ -68,11 +86,10 @@ impl RfdSearchIndex {
             {
                 tracing::info!(
                     ?err,
-                    "Failed to find index during deletion lookup. Creating index and filters"
+                    "Failed to find index during deletion lookup. Creating index and settings"
                 );
 
-                let settings = Settings::new().with_filterable_attributes(["rfd_number"]);
-                index.set_settings(&settings).await?;
+                index.set_settings(&Self::settings()).await?;
             }
             Err(err) => {
                 return Err(err)?;
@@ -91,6 +108,135 @@ impl RfdSearchIndex {
         Ok(())
     }
 
+    /// The settings that every managed RFD index is expected to have
+    pub fn settings() -> Settings {
+        Settings::new()
+            .with_filterable_attributes(["rfd_number", "public"])
+            .with_sortable_attributes(["rfd_number", "level"])
+            .with_searchable_attributes([
+                "hierarchy_lvl0",
+                "hierarchy_lvl1",
+                "hierarchy_lvl2",
+                "hierarchy_lvl3",
+                "hierarchy_lvl4",
+                "hierarchy_lvl5",
+                "name",
+                "content",
+            ])
+            .with_ranking_rules([
+                "words",
+                "typo",
+                "proximity",
+                "attribute",
+                "sort",
+                "exactness",
+                "level:asc",
+            ])
+    }
+
+    /// Create the index if it does not exist yet and apply the managed settings to it
+    #[instrument(skip(self), fields(index = ?self.index), err(Debug))]
+    pub async fn configure(&self) -> Result<(), SearchError> {
+        tracing::info!("Applying index settings");
+
+        let task = self
+            .client
+            .index(&self.index)
+            .set_settings(&Self::settings())
+            .await?;
+        self.wait_for_task(task).await
+    }
+
+    /// Build a fresh index from the given documents and atomically swap it in place of the
+    /// current index. The previous contents are deleted once the swap has completed
+    #[instrument(skip(self, documents), fields(index = ?self.index, count = documents.len()), err(Debug))]
+    pub async fn reindex(&self, documents: &[IndexDocument]) -> Result<(), SearchError> {
+        let staging_uid = format!("{}_{}", self.index, Utc::now().format("%Y%m%d%H%M%S"));
+        let staging = self.client.index(&staging_uid);
+
+        tracing::info!(?staging_uid, "Building staging index");
+
+        self.wait_for_task(staging.set_settings(&Self::settings()).await?)
+            .await?;
+
+        for batch in documents.chunks(DOCUMENT_PAGE_SIZE) {
+            self.wait_for_task(staging.add_documents(batch, Some("objectID")).await?)
+                .await?;
+        }
+
+        // Swapping requires that both indexes exist. On a first run there may not be a live index
+        // to swap out yet
+        match self.client.get_index(&self.index).await {
+            Ok(_) => (),
+            Err(MeiliError::Meilisearch(err)) if err.error_code == ErrorCode::IndexNotFound => {
+                tracing::info!("Live index does not exist. Creating it before swapping");
+                self.wait_for_task(
+                    self.client
+                        .create_index(&self.index, Some("objectID"))
+                        .await?,
+                )
+                .await?;
+            }
+            Err(err) => return Err(err)?,
+        }
+
+        tracing::info!(?staging_uid, "Swapping staging index into place");
+
+        self.wait_for_task(
+            self.client
+                .swap_indexes([&SwapIndexes {
+                    indexes: (self.index.clone(), staging_uid.clone()),
+                }])
+                .await?,
+        )
+        .await?;
+
+        // After the swap the staging index holds the previous contents of the live index
+        self.wait_for_task(self.client.index(&staging_uid).delete().await?)
+            .await?;
+
+        tracing::info!("Completed reindex");
+
+        Ok(())
+    }
+
+    /// Delete all of the documents that belong to RFDs outside of the given set of RFD numbers
+    #[instrument(skip(self, rfd_numbers), fields(index = ?self.index), err(Debug))]
+    pub async fn prune(&self, rfd_numbers: &HashSet<i32>) -> Result<(), SearchError> {
+        let stale = self
+            .list_document_visibility()
+            .await?
+            .into_iter()
+            .filter(|document| !rfd_numbers.contains(&document.rfd_number))
+            .map(|document| document.object_id)
+            .collect::<Vec<_>>();
+
+        if !stale.is_empty() {
+            tracing::info!(count = stale.len(), "Deleting documents for unknown RFDs");
+
+            let task = self
+                .client
+                .index(&self.index)
+                .delete_documents(&stale)
+                .await?;
+            self.wait_for_task(task).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn wait_for_task(&self, task: TaskInfo) -> Result<(), SearchError> {
+        let task = task
+            .wait_for_completion(&self.client, None, Some(TASK_TIMEOUT))
+            .await?;
+
+        if task.is_failure() {
+            Err(MeiliError::Meilisearch(task.unwrap_failure()))?
+        } else {
+            Ok(())
+        }
+    }
+
     #[instrument(skip(self, index))]
     pub async fn find_rfd_ids(
         &self,
@@ -100,6 +246,7 @@ impl RfdSearchIndex {
         let mut query = index.search();
         let filter = format!("rfd_number = {}", rfd_number);
         query.with_array_filter(vec![&filter]);
+        query.with_limit(MAX_DOCUMENTS_PER_RFD);
 
         tracing::trace!(?filter, "Search for existing RFDs");
 
@@ -112,6 +259,71 @@ impl RfdSearchIndex {
             .collect::<Vec<_>>())
     }
 
+    /// Update the public flag on all of the documents that belong to an RFD without reparsing or
+    /// reindexing its content
+    #[instrument(skip(self), fields(index = ?self.index), err(Debug))]
+    pub async fn update_rfd_visibility(
+        &self,
+        rfd_number: &RfdNumber,
+        public: bool,
+    ) -> Result<(), SearchError> {
+        let index = self.client.index(&self.index);
+
+        let updates = self
+            .find_rfd_ids(&index, rfd_number)
+            .await?
+            .into_iter()
+            .map(|object_id| VisibilityUpdate { object_id, public })
+            .collect::<Vec<_>>();
+
+        self.update_visibility(&updates).await
+    }
+
+    /// Apply a batch of partial visibility updates to existing documents
+    #[instrument(skip(self, updates), fields(index = ?self.index, count = updates.len()), err(Debug))]
+    pub async fn update_visibility(&self, updates: &[VisibilityUpdate]) -> Result<(), SearchError> {
+        if !updates.is_empty() {
+            tracing::info!("Updating visibility of indexed documents");
+
+            self.client
+                .index(&self.index)
+                .add_or_update(updates, Some("objectID"))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve the RFD number and public flag of every document stored in the index
+    #[instrument(skip(self), fields(index = ?self.index), err(Debug))]
+    pub async fn list_document_visibility(&self) -> Result<Vec<IndexedVisibility>, SearchError> {
+        let index = self.client.index(&self.index);
+
+        let mut documents = vec![];
+        let mut offset = 0;
+
+        loop {
+            let page = DocumentsQuery::new(&index)
+                .with_fields(["objectID", "rfd_number", "public"])
+                .with_offset(offset)
+                .with_limit(DOCUMENT_PAGE_SIZE)
+                .execute::<IndexedVisibility>()
+                .await?;
+
+            let count = page.results.len();
+            documents.extend(page.results);
+            offset += count;
+
+            if count < DOCUMENT_PAGE_SIZE {
+                break;
+            }
+        }
+
+        tracing::debug!(count = documents.len(), "Retrieved document visibility");
+
+        Ok(documents)
+    }
+
     #[instrument(skip(content), err(Debug))]
     pub fn parse_document(
         rfd_number: &RfdNumber,
@@ -131,6 +343,22 @@ pub struct RfdId {
     object_id: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IndexedVisibility {
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    pub rfd_number: i32,
+    #[serde(default)]
+    pub public: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VisibilityUpdate {
+    #[serde(rename = "objectID")]
+    pub object_id: String,
+    pub public: bool,
+}
+
 type HmacMd5 = Hmac<Md5>;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -176,10 +404,12 @@ impl IndexDocument {
 
         // The hash here is only intended to enforce uniqueness amongst documents. md5 and the
         // statically defined key is being used to maintain backward compatibility with previous
-        // implementations. None of the key, the ids, nor hash are required to be kept secret
+        // implementations. None of the key, the ids, nor hash are required to be kept secret. The
+        // number is always hashed in the default format so that ids do not change when the format
+        // of the source repository is reconfigured
         let mut mac = HmacMd5::new_from_slice("dsflkajsdf".as_bytes())
             .expect("Statically defined key should always be valid");
-        mac.update(rfd_number.as_number_string().as_bytes());
+        mac.update(RfdNumberFormat::default().format(&rfd_number).as_bytes());
         mac.update(section.section_id.as_bytes());
         let object_id = hex::encode(&mac.finalize().into_bytes()[..]);
 
@@ -0,0 +1,277 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Duration, Utc};
+use rfd_data::RfdNumber;
+use rfd_model::{
+    schema_ext::Visibility,
+    storage::{
+        ListPagination, RfdFilter, RfdRevisionFilter, RfdRevisionStore, RfdStore, StoreError,
+    },
+    Rfd,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use thiserror::Error;
+use tokio::time::{interval, Instant};
+use tracing::instrument;
+
+use crate::{
+    context::Context,
+    search::{RfdSearchIndex, SearchError, VisibilityUpdate},
+    updater::CopyImagesToStorage,
+};
+
+// Number of RFDs to read from the database at a time
+static RFD_PAGE_SIZE: i64 = 100;
+
+// Amount of time that consecutive sync windows overlap by. Visibility updates are idempotent, so
+// re-checking a handful of RFDs is preferred over missing an update that landed mid-sync
+static SYNC_OVERLAP_SECONDS: i64 = 30;
+
+#[derive(Debug, Error)]
+pub enum SearchSyncError {
+    #[error(transparent)]
+    Search(#[from] SearchError),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+pub async fn search_sync(ctx: Arc<Context>) -> Result<(), SearchSyncError> {
+    let mut interval = interval(ctx.search_sync.interval);
+    interval.tick().await;
+
+    let mut last_sync: Option<DateTime<Utc>> = None;
+    let mut last_check: Option<Instant> = None;
+
+    loop {
+        if ctx.search_sync.enabled {
+            let started_at = Utc::now();
+
+            match (last_sync, last_check) {
+                (Some(since), Some(checked_at))
+                    if checked_at.elapsed() < ctx.search_sync.consistency_interval =>
+                {
+                    sync_updated_rfds(&ctx, since).await?;
+                }
+                _ => {
+                    check_consistency(&ctx).await?;
+                    last_check = Some(Instant::now());
+                }
+            }
+
+            last_sync = Some(started_at - Duration::seconds(SYNC_OVERLAP_SECONDS));
+        }
+
+        interval.tick().await;
+    }
+}
+
+fn is_public(rfd: &Rfd) -> bool {
+    match rfd.visibility {
+        Visibility::Private => false,
+        Visibility::Public => true,
+    }
+}
+
+async fn list_rfds(ctx: &Context, filter: impl Fn() -> RfdFilter) -> Result<Vec<Rfd>, StoreError> {
+    let mut rfds = vec![];
+    let mut offset = 0;
+
+    loop {
+        let page = RfdStore::list(
+            &ctx.db.storage,
+            filter(),
+            &ListPagination::default()
+                .offset(offset)
+                .limit(RFD_PAGE_SIZE),
+        )
+        .await?;
+
+        let count = page.len() as i64;
+        rfds.extend(page);
+        offset += count;
+
+        if count < RFD_PAGE_SIZE {
+            break;
+        }
+    }
+
+    Ok(rfds)
+}
+
+// Static storage is publicly readable, so any assets that were copied for a RFD while it was public
+// are removed once it is no longer public
+async fn remove_private_assets(ctx: &Context, rfds: &[Rfd]) {
+    for rfd in rfds.iter().filter(|rfd| !is_public(rfd)) {
+        if let Err(err) = CopyImagesToStorage::remove_all(ctx, rfd.id, &rfd.rfd_number.into()).await
+        {
+            tracing::error!(
+                ?err,
+                rfd_number = rfd.rfd_number,
+                "Failed to remove assets of non-public RFD from storage"
+            );
+        }
+    }
+}
+
+/// Push the current visibility of any RFD that has been modified since the last sync out to the
+/// search indexes and static storage
+#[instrument(skip(ctx), err(Debug))]
+async fn sync_updated_rfds(ctx: &Context, since: DateTime<Utc>) -> Result<(), SearchSyncError> {
+    let rfds = list_rfds(ctx, || RfdFilter::default().updated_after(Some(since))).await?;
+
+    tracing::debug!(count = rfds.len(), "Found updated RFDs to sync");
+
+    remove_private_assets(ctx, &rfds).await;
+
+    for rfd in rfds {
+        for (i, index) in ctx.search.indexes.iter().enumerate() {
+            if let Err(err) = index
+                .update_rfd_visibility(&rfd.rfd_number.into(), is_public(&rfd))
+                .await
+            {
+                tracing::error!(
+                    ?err,
+                    rfd_number = rfd.rfd_number,
+                    search_index = i,
+                    "Failed to update RFD visibility in search index"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compare the public flag of every indexed document against the visibility stored in the
+/// database, and correct any documents that have drifted. Stored assets of every RFD that is not
+/// public are removed as well
+#[instrument(skip(ctx), err(Debug))]
+async fn check_consistency(ctx: &Context) -> Result<(), SearchSyncError> {
+    let rfds = list_rfds(ctx, RfdFilter::default).await?;
+
+    remove_private_assets(ctx, &rfds).await;
+
+    let visibility = rfds
+        .into_iter()
+        .map(|rfd| (rfd.rfd_number, is_public(&rfd)))
+        .collect::<HashMap<_, _>>();
+
+    for (i, index) in ctx.search.indexes.iter().enumerate() {
+        let documents = match index.list_document_visibility().await {
+            Ok(documents) => documents,
+            Err(err) => {
+                tracing::error!(
+                    ?err,
+                    search_index = i,
+                    "Failed to read search index documents"
+                );
+                continue;
+            }
+        };
+
+        // Documents that belong to RFDs that are unknown to the database are left alone. They are
+        // not the responsibility of the visibility check
+        let updates = documents
+            .into_iter()
+            .filter_map(|document| match visibility.get(&document.rfd_number) {
+                Some(public) if *public != document.public => Some(VisibilityUpdate {
+                    object_id: document.object_id,
+                    public: *public,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if !updates.is_empty() {
+            tracing::warn!(
+                count = updates.len(),
+                search_index = i,
+                "Found search documents with stale visibility"
+            );
+
+            if let Err(err) = index.update_visibility(&updates).await {
+                tracing::error!(
+                    ?err,
+                    search_index = i,
+                    "Failed to correct document visibility"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply the managed index settings to every configured search index
+#[instrument(skip(ctx), err(Debug))]
+pub async fn configure_indexes(ctx: &Context) -> Result<(), SearchSyncError> {
+    for index in &ctx.search.indexes {
+        index.configure().await?;
+    }
+
+    Ok(())
+}
+
+/// Rebuild every configured search index from the latest revision of each RFD in the database
+#[instrument(skip(ctx), err(Debug))]
+pub async fn reindex(ctx: &Context) -> Result<(), SearchSyncError> {
+    let mut documents = vec![];
+
+    for rfd in list_rfds(ctx, RfdFilter::default).await? {
+        let revision = RfdRevisionStore::list(
+            &ctx.db.storage,
+            RfdRevisionFilter::default().rfd(Some(vec![rfd.id])),
+            &ListPagination::latest(),
+        )
+        .await?
+        .into_iter()
+        .next();
+
+        if let Some(revision) = revision {
+            let number = RfdNumber::from(rfd.rfd_number);
+
+            match RfdSearchIndex::parse_document(&number, &revision.content) {
+                Ok(mut parsed) => {
+                    for document in parsed.iter_mut() {
+                        document.public = is_public(&rfd);
+                    }
+
+                    documents.extend(parsed);
+                }
+                Err(err) => {
+                    // A single unparseable RFD should not block rebuilding the rest of the index
+                    tracing::warn!(?err, rfd_number = rfd.rfd_number, "Failed to parse RFD");
+                }
+            }
+        }
+    }
+
+    tracing::info!(count = documents.len(), "Parsed RFDs to reindex");
+
+    for index in &ctx.search.indexes {
+        index.reindex(&documents).await?;
+    }
+
+    Ok(())
+}
+
+/// Remove documents from every configured search index for RFDs that no longer exist
+#[instrument(skip(ctx), err(Debug))]
+pub async fn prune(ctx: &Context) -> Result<(), SearchSyncError> {
+    let rfd_numbers = list_rfds(ctx, RfdFilter::default)
+        .await?
+        .into_iter()
+        .map(|rfd| rfd.rfd_number)
+        .collect::<HashSet<_>>();
+
+    for index in &ctx.search.indexes {
+        index.prune(&rfd_numbers).await?;
+    }
+
+    Ok(())
+}
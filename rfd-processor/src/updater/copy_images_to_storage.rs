@@ -2,31 +2,266 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 use google_storage1::api::Object;
+use rfd_data::RfdNumber;
+use rfd_model::{
+    schema_ext::Visibility,
+    storage::{
+        ListPagination, RfdRevisionAssetFilter, RfdRevisionAssetStore, RfdRevisionFilter,
+        RfdRevisionStore, StoreError,
+    },
+    NewRfdRevisionAsset, RfdRevisionAsset,
+};
+use thiserror::Error;
 use tracing::instrument;
+use uuid::Uuid;
 
-use crate::{rfd::PersistedRfd, util::decode_base64};
+use crate::{context::Context, rfd::PersistedRfd, util::decode_base64};
 
 use super::{
     RfdUpdateAction, RfdUpdateActionContext, RfdUpdateActionErr, RfdUpdateActionResponse,
     RfdUpdateMode,
 };
 
+// Number of asset records to read per page when collecting garbage
+static ASSET_PAGE_SIZE: i64 = 1_000;
+
+#[derive(Debug, Error)]
+pub enum AssetCleanupError {
+    #[error("Failed to communicate with storage bucket {0}")]
+    Bucket(#[from] google_storage1::Error),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+/// Copies the images of a RFD to the configured storage buckets. Each image is written to a
+/// prefix that is specific to the commit of the revision being processed, as well as to the
+/// `latest` prefix which always reflects the most recently processed revision. The buckets are
+/// publicly readable, so the images of RFDs that are not public are never copied and are instead
+/// served by the API
 #[derive(Debug)]
 pub struct CopyImagesToStorage;
 
+impl CopyImagesToStorage {
+    // Read the full asset manifest of a RFD. Every page is read before any record is acted upon
+    // so that the records that are read are not shifted by changes made during cleanup
+    async fn list_assets(ctx: &Context, rfd_id: Uuid) -> Result<Vec<RfdRevisionAsset>, StoreError> {
+        let mut assets = vec![];
+        let mut offset = 0;
+
+        loop {
+            let page = RfdRevisionAssetStore::list(
+                &ctx.db.storage,
+                RfdRevisionAssetFilter::default().rfd(Some(vec![rfd_id])),
+                &ListPagination::default()
+                    .offset(offset)
+                    .limit(ASSET_PAGE_SIZE),
+            )
+            .await?;
+
+            let count = page.len() as i64;
+            assets.extend(page);
+            offset += count;
+
+            if count < ASSET_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(assets)
+    }
+
+    // Remove versioned objects that are no longer referenced by the asset manifest of any live
+    // revision of the RFD. Objects under the latest prefix are never removed
+    #[instrument(skip(ctx, new))]
+    async fn remove_unreferenced(
+        ctx: &Context,
+        number: &RfdNumber,
+        new: &PersistedRfd,
+    ) -> Result<(), AssetCleanupError> {
+        let assets = Self::list_assets(ctx, new.rfd.id).await?;
+
+        let revision_ids = assets
+            .iter()
+            .map(|asset| asset.rfd_revision_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let live_revisions = RfdRevisionStore::list(
+            &ctx.db.storage,
+            RfdRevisionFilter::default().id(Some(revision_ids.clone())),
+            &ListPagination::default().limit(revision_ids.len() as i64),
+        )
+        .await?
+        .into_iter()
+        .map(|revision| revision.id)
+        .collect::<HashSet<_>>();
+
+        let mut referenced = HashSet::new();
+        for asset in assets {
+            if live_revisions.contains(&asset.rfd_revision_id) {
+                referenced.insert(asset.object_name);
+            } else {
+                // The revision has been deleted, so its manifest no longer holds on to its assets
+                RfdRevisionAssetStore::delete(&ctx.db.storage, &asset.id).await?;
+            }
+        }
+
+        let prefix = format!("rfd/{}/", number);
+        let latest_prefix = format!("rfd/{}/latest/", number);
+
+        for location in &ctx.assets.locations {
+            let mut page_token: Option<String> = None;
+
+            loop {
+                let mut request = ctx
+                    .assets
+                    .client
+                    .objects()
+                    .list(&location.bucket)
+                    .prefix(&prefix);
+
+                if let Some(token) = &page_token {
+                    request = request.page_token(token);
+                }
+
+                let (_, objects) = request.doit().await?;
+
+                for name in objects
+                    .items
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|object| object.name)
+                {
+                    if !name.starts_with(&latest_prefix)
+                        && !referenced.contains(&name)
+                        && !Self::is_recorded(ctx, &name).await?
+                    {
+                        tracing::info!(bucket = ?location.bucket, ?name, "Removing unreferenced asset");
+
+                        ctx.assets
+                            .client
+                            .objects()
+                            .delete(&location.bucket, &name)
+                            .doit()
+                            .await?;
+                    }
+                }
+
+                page_token = objects.next_page_token;
+                if page_token.is_none() {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove every stored object of a RFD along with its asset manifest. This is used to withdraw
+    /// the assets of a RFD that is no longer public
+    #[instrument(skip(ctx))]
+    pub async fn remove_all(
+        ctx: &Context,
+        rfd_id: Uuid,
+        number: &RfdNumber,
+    ) -> Result<(), AssetCleanupError> {
+        // The manifest is cleared first so that revisions of the RFD no longer reference the
+        // commit specific objects that are about to be removed
+        for asset in Self::list_assets(ctx, rfd_id).await? {
+            RfdRevisionAssetStore::delete(&ctx.db.storage, &asset.id).await?;
+        }
+
+        let prefix = format!("rfd/{}/", number);
+
+        for location in &ctx.assets.locations {
+            let mut page_token: Option<String> = None;
+
+            loop {
+                let mut request = ctx
+                    .assets
+                    .client
+                    .objects()
+                    .list(&location.bucket)
+                    .prefix(&prefix);
+
+                if let Some(token) = &page_token {
+                    request = request.page_token(token);
+                }
+
+                let (_, objects) = request.doit().await?;
+
+                for name in objects
+                    .items
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|object| object.name)
+                {
+                    tracing::info!(bucket = ?location.bucket, ?name, "Removing asset of non-public RFD");
+
+                    ctx.assets
+                        .client
+                        .objects()
+                        .delete(&location.bucket, &name)
+                        .doit()
+                        .await?;
+                }
+
+                page_token = objects.next_page_token;
+                if page_token.is_none() {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Check the manifest for an object immediately before it is removed. Records are written
+    // before their objects are uploaded, so this catches objects that were recorded after the
+    // manifest was read
+    async fn is_recorded(ctx: &Context, object_name: &str) -> Result<bool, StoreError> {
+        let assets = RfdRevisionAssetStore::list(
+            &ctx.db.storage,
+            RfdRevisionAssetFilter::default().object_name(Some(vec![object_name.to_string()])),
+            &ListPagination::latest(),
+        )
+        .await?;
+
+        Ok(!assets.is_empty())
+    }
+}
+
 #[async_trait]
 impl RfdUpdateAction for CopyImagesToStorage {
-    #[instrument(skip(self, ctx, _new), err(Debug))]
+    #[instrument(skip(self, ctx, new), err(Debug))]
     async fn run(
         &self,
         ctx: &mut RfdUpdateActionContext,
-        _new: &mut PersistedRfd,
+        new: &mut PersistedRfd,
         mode: RfdUpdateMode,
     ) -> Result<RfdUpdateActionResponse, RfdUpdateActionErr> {
         let RfdUpdateActionContext { ctx, update, .. } = ctx;
 
+        if new.rfd.visibility != Visibility::Public {
+            tracing::info!("Skipping copy of images for non-public RFD");
+
+            // Any copies that were made while the RFD was public must no longer be served
+            if mode == RfdUpdateMode::Write {
+                if let Err(err) = Self::remove_all(ctx, new.rfd.id, &update.number).await {
+                    tracing::warn!(
+                        ?err,
+                        "Failed to remove assets of non-public RFD from storage"
+                    );
+                }
+            }
+
+            return Ok(RfdUpdateActionResponse::default());
+        }
+
         let images = update
             .location
             .get_images(&ctx.github.client, &update.number)
@@ -34,43 +269,78 @@ impl RfdUpdateAction for CopyImagesToStorage {
             .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
 
         for image in images {
-            let sub_path = image
-                .path
-                .replace(&format!("rfd/{}/", update.number.as_number_string()), "");
-            let object_name = format!("rfd/{}/latest/{}", update.number, sub_path);
-            let mime_type = mime_guess::from_path(&object_name).first_or_octet_stream();
+            let sub_path = image.path.replace(
+                &format!(
+                    "{}/",
+                    update.location.number_format.directory(&update.number)
+                ),
+                "",
+            );
+            let latest_name = format!("rfd/{}/latest/{}", update.number, sub_path);
+            let versioned_name = format!(
+                "rfd/{}/{}/{}",
+                update.number, new.revision.commit.0, sub_path
+            );
+            let mime_type = mime_guess::from_path(&sub_path).first_or_octet_stream();
             let data = decode_base64(&image.content)
                 .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
 
             tracing::info!(
-                ?object_name,
+                ?versioned_name,
                 ?mime_type,
                 size = data.len(),
                 "Writing file to storage buckets"
             );
 
+            if mode == RfdUpdateMode::Write {
+                // The manifest entry is recorded before the upload so that a concurrent cleanup
+                // never considers the object to be unreferenced
+                RfdRevisionAssetStore::upsert(
+                    &ctx.db.storage,
+                    NewRfdRevisionAsset {
+                        id: Uuid::new_v4(),
+                        rfd_id: new.rfd.id,
+                        rfd_revision_id: new.revision.id,
+                        path: sub_path.clone(),
+                        object_name: versioned_name.clone(),
+                        sha: image.sha.clone().into(),
+                    },
+                )
+                .await
+                .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
+            }
+
             let cursor = std::io::Cursor::new(data);
 
             for location in &ctx.assets.locations {
-                tracing::info!(bucket = ?location.bucket, ?object_name, "Writing to location");
+                for object_name in [&versioned_name, &latest_name] {
+                    tracing::info!(bucket = ?location.bucket, ?object_name, "Writing to location");
 
-                if mode == RfdUpdateMode::Write {
-                    // TODO: Move implementation to a trait and abstract over different storage systems
-                    if let Err(err) = ctx
-                        .assets
-                        .client
-                        .objects()
-                        .insert(Object::default(), &location.bucket)
-                        .name(&object_name)
-                        .upload(cursor.clone(), mime_type.clone())
-                        .await
-                    {
-                        tracing::error!(?err, "Failed to upload static file to GCP");
+                    if mode == RfdUpdateMode::Write {
+                        // TODO: Move implementation to a trait and abstract over different storage systems
+                        if let Err(err) = ctx
+                            .assets
+                            .client
+                            .objects()
+                            .insert(Object::default(), &location.bucket)
+                            .name(object_name)
+                            .upload(cursor.clone(), mime_type.clone())
+                            .await
+                        {
+                            tracing::error!(?err, "Failed to upload static file to GCP");
+                        }
                     }
                 }
             }
         }
 
+        if mode == RfdUpdateMode::Write {
+            // Failing to clean up old assets does not affect the current revision
+            if let Err(err) = Self::remove_unreferenced(ctx, &update.number, new).await {
+                tracing::warn!(?err, "Failed to remove unreferenced assets from storage");
+            }
+        }
+
         Ok(RfdUpdateActionResponse::default())
     }
 }
@@ -3,6 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use async_trait::async_trait;
+use rfd_data::transition::RfdStateContext;
 use tracing::instrument;
 
 use crate::rfd::PersistedRfd;
@@ -29,10 +30,16 @@ impl RfdUpdateAction for EnsureRfdOnDefaultIsInValidState {
         // If a RFD exists on the default branch then it should be in either the published or
         // abandoned state
         if update.location.branch == update.location.default_branch {
-            if !new.is_state("published")
-                && !new.is_state("committed")
-                && !new.is_state("abandoned")
-            {
+            let context = RfdStateContext {
+                has_open_pr: false,
+                on_default_branch: true,
+            };
+            let valid = new
+                .state()
+                .map(|state| state.check_location(&context).is_ok())
+                .unwrap_or(false);
+
+            if !valid {
                 tracing::warn!(state = ?new.revision.state, "RFD on the default branch is in an invalid state. It needs to be updated to either published or abandoned");
             } else {
                 tracing::debug!(
@@ -3,6 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use async_trait::async_trait;
+use rfd_data::{transition::RfdStateContext, RfdState};
 use std::cmp::Ordering;
 use tracing::instrument;
 
@@ -36,8 +37,9 @@ impl RfdUpdateAction for EnsureRfdWithPullRequestIsInValidState {
         // branch that we are working on
         match open_prs.count().cmp(&1) {
             Ordering::Equal => {
-                // If there is a pull request open for this branch, then check to ensure that it is in one
-                // of these valid states:
+                // If there is a pull request open for this branch, then check to ensure that it is in
+                // a state that is valid for an open pull request. Any RFD that has moved past
+                // prediscussion may have an open pull request:
                 //   * published  - A RFD may be in this state if it had previously been published and an
                 //                  an update is being made, Or the RFD may be in the process of being
                 //                  published
@@ -52,13 +54,17 @@ impl RfdUpdateAction for EnsureRfdWithPullRequestIsInValidState {
                 //                  initial thoughts on an idea
                 //   * abandoned  - A RFD may be in this state if it had previously been abandoned or is in
                 //                  the process of being abandoned
-                if !new.is_state("discussion")
-                    && !new.is_state("published")
-                    && !new.is_state("committed")
-                    && !new.is_state("ideation")
-                    && !new.is_state("abandoned")
-                {
-                    new.update_state("discussion")
+                let context = RfdStateContext {
+                    has_open_pr: true,
+                    on_default_branch: false,
+                };
+                let valid = new
+                    .state()
+                    .map(|state| state.check_location(&context).is_ok())
+                    .unwrap_or(false);
+
+                if !valid {
+                    new.update_state(RfdState::Discussion)
                         .map_err(|err| RfdUpdateActionErr::Stop(Box::new(err)))?;
                     requires_source_commit = true;
                 } else {
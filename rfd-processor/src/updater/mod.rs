@@ -10,6 +10,7 @@ use rfd_data::content::RfdDocument;
 use rfd_github::{GitHubError, GitHubRfdUpdate};
 use rfd_model::storage::StoreError;
 use serde::Deserialize;
+use tap::TapFallible;
 use thiserror::Error;
 use tracing::instrument;
 use uuid::Uuid;
@@ -17,11 +18,13 @@ use uuid::Uuid;
 use crate::{
     context::Context,
     rfd::{FetchRemoteRfdError, PersistedRfd, RemoteRfd, RemoteRfdError, RfdError},
+    webhook::emit_rfd_events,
 };
 
+pub use self::copy_images_to_storage::CopyImagesToStorage;
+
 use self::{
-    copy_images_to_storage::CopyImagesToStorage, create_pull_request::CreatePullRequest,
-    ensure_default_state::EnsureRfdOnDefaultIsInValidState,
+    create_pull_request::CreatePullRequest, ensure_default_state::EnsureRfdOnDefaultIsInValidState,
     ensure_pr_state::EnsureRfdWithPullRequestIsInValidState,
     update_discussion_url::UpdateDiscussionUrl, update_pdfs::UpdatePdfs,
     update_pull_request::UpdatePullRequest, update_search_index::UpdateSearch,
@@ -67,11 +70,11 @@ impl Validate for GitHubRfdUpdate {
         // a RFD update is only valid in one of two cases:
         //  `1. The update is occurring on the default branch. In this case it does not matter what
         //      RFD is being updated, the update is always considered valid
-        //   2. The update is occurring on a RFD branch with a name of the pattern \d\d\d\d . In
-        //      this case, the update is only valid if the number of the RFD being updated matches
-        //      the branch the update is occurring on.
+        //   2. The update is occurring on a RFD branch, named according to the configured number
+        //      format. In this case, the update is only valid if the number of the RFD being
+        //      updated matches the branch the update is occurring on.
         self.location.branch == self.location.default_branch
-            || self.location.branch == self.number.as_number_string()
+            || self.location.branch == self.location.number_format.branch(&self.number)
     }
 }
 
@@ -200,6 +203,12 @@ impl<'a> RfdUpdater<'a> {
             .await
             .map_err(RfdUpdaterError::RfdUpdate)?;
 
+        // The revision has been fully persisted, so a failure to queue webhook deliveries should
+        // not fail the update
+        let _ = emit_rfd_events(ctx, existing.as_ref(), &persisted)
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to queue webhook events for RFD update"));
+
         tracing::info!(id = ?persisted.rfd.id, number = ?persisted.rfd.rfd_number, "Update for RFD completed");
 
         Ok(())
@@ -0,0 +1,399 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    Url,
+};
+use rfd_model::{
+    schema_ext::WebhookEvent,
+    storage::{
+        queue_webhook_deliveries, ListPagination, RfdEventStore, StoreError, WebhookDeliveryFilter,
+        WebhookDeliveryStore, WebhookSubscriptionStore,
+    },
+    RfdEvent, WebhookDelivery, WebhookSubscription,
+};
+use sha2::Sha256;
+use std::sync::Arc;
+use tap::TapFallible;
+use thiserror::Error;
+use tokio::time::interval;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{context::Context, rfd::PersistedRfd};
+
+// Number of due deliveries to attempt on each tick
+static DELIVERY_BATCH_SIZE: i64 = 50;
+
+// Delay before the first retry of a failed delivery. Each following retry doubles the delay
+static RETRY_BASE_SECONDS: i64 = 30;
+
+// Longest delay between two attempts of a delivery
+static RETRY_MAX_SECONDS: i64 = 3600;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Error)]
+pub enum WebhookDeliveryError {
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+/// Compute the events that describe the change from the previously stored revision of a RFD to
+/// the newly persisted revision
+pub fn rfd_events(
+    previous: Option<&PersistedRfd>,
+    new: &PersistedRfd,
+    occurred_at: DateTime<Utc>,
+) -> Vec<RfdEvent> {
+    let event = |event: WebhookEvent| RfdEvent {
+        id: Uuid::new_v4(),
+        event,
+        occurred_at,
+        rfd_number: new.rfd.rfd_number,
+        title: new.revision.title.clone(),
+        state: new.revision.state.clone(),
+        visibility: new.rfd.visibility.clone(),
+        commit: new.revision.commit.clone(),
+        previous_state: previous.and_then(|previous| previous.revision.state.clone()),
+        previous_visibility: previous.map(|previous| previous.rfd.visibility.clone()),
+    };
+
+    match previous {
+        None => vec![event(WebhookEvent::Created)],
+        Some(previous) => {
+            let mut events = vec![];
+
+            if previous.revision.state != new.revision.state {
+                events.push(event(WebhookEvent::StateChanged));
+            }
+
+            if previous.revision.content != new.revision.content {
+                events.push(event(WebhookEvent::ContentUpdated));
+            }
+
+            if previous.rfd.visibility != new.rfd.visibility {
+                events.push(event(WebhookEvent::VisibilityChanged));
+            }
+
+            events
+        }
+    }
+}
+
+/// Queue the events produced by persisting a new revision for delivery to each matching webhook
+/// subscription, and publish them to live listeners
+#[instrument(skip(ctx, previous, new), fields(number = new.rfd.rfd_number, revision = ?new.revision.id))]
+pub async fn emit_rfd_events(
+    ctx: &Context,
+    previous: Option<&PersistedRfd>,
+    new: &PersistedRfd,
+) -> Result<Vec<WebhookDelivery>, StoreError> {
+    let events = rfd_events(previous, new, Utc::now());
+
+    if events.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let deliveries = queue_webhook_deliveries(&ctx.db.storage, &events).await;
+
+    // Live listeners are a best effort notification and are notified even when the webhook
+    // deliveries could not be queued
+    for event in &events {
+        let _ = RfdEventStore::notify(&ctx.db.storage, event).await.tap_err(
+            |err| tracing::warn!(?err, event = %event.event, "Failed to publish RFD event"),
+        );
+    }
+
+    deliveries
+}
+
+pub async fn webhook_delivery(ctx: Arc<Context>) -> Result<(), WebhookDeliveryError> {
+    let mut interval = interval(ctx.webhook_delivery.interval);
+    interval.tick().await;
+
+    loop {
+        if ctx.webhook_delivery.enabled {
+            let deliveries = WebhookDeliveryStore::list(
+                &ctx.db.storage,
+                WebhookDeliveryFilter::default().due_before(Some(Utc::now())),
+                &ListPagination::default().limit(DELIVERY_BATCH_SIZE),
+            )
+            .await?;
+
+            for delivery in deliveries {
+                attempt_delivery(&ctx, delivery).await?;
+            }
+        }
+
+        interval.tick().await;
+    }
+}
+
+#[instrument(skip(ctx, delivery), fields(id = ?delivery.id, subscription = ?delivery.webhook_subscription_id, event = %delivery.event))]
+async fn attempt_delivery(
+    ctx: &Context,
+    delivery: WebhookDelivery,
+) -> Result<(), WebhookDeliveryError> {
+    // Claim the delivery before sending it. If the processor stops mid-attempt, the delivery will
+    // be picked up again once the retry delay has passed
+    let delivery = match WebhookDeliveryStore::start(
+        &ctx.db.storage,
+        &delivery.id,
+        Utc::now() + retry_delay(delivery.attempts + 1),
+    )
+    .await?
+    {
+        Some(delivery) => delivery,
+        None => {
+            tracing::info!("Delivery is no longer due. Was it claimed by a different task?");
+            return Ok(());
+        }
+    };
+
+    let subscription =
+        WebhookSubscriptionStore::get(&ctx.db.storage, &delivery.webhook_subscription_id, false)
+            .await?;
+
+    let Some(subscription) = subscription else {
+        tracing::info!("Dropping delivery for deleted subscription");
+        WebhookDeliveryStore::complete(
+            &ctx.db.storage,
+            &delivery.id,
+            false,
+            None,
+            Some("Subscription has been deleted".to_string()),
+            None,
+        )
+        .await?;
+        return Ok(());
+    };
+
+    // Subscriptions are validated when they are stored, but are checked again in case they were
+    // stored before the current rules were in place. Hosts that are names are checked by the
+    // resolver of the delivery client
+    if !is_allowed_url(&subscription.url) {
+        tracing::warn!(url = ?subscription.url, "Dropping delivery for disallowed endpoint");
+        WebhookDeliveryStore::complete(
+            &ctx.db.storage,
+            &delivery.id,
+            false,
+            None,
+            Some("Subscription url must be a https url with a public host".to_string()),
+            None,
+        )
+        .await?;
+        return Ok(());
+    }
+
+    // Payloads were serialized from events when they were queued
+    let body = serde_json::to_vec(&delivery.payload).unwrap();
+
+    let result = ctx
+        .webhook_delivery
+        .client
+        .post(&subscription.url)
+        .header("Content-Type", "application/json")
+        .header("X-RFD-Event", delivery.event.to_string())
+        .header("X-RFD-Delivery", delivery.id.to_string())
+        .header(
+            "X-RFD-Signature-256",
+            format!("sha256={}", sign(&subscription.secret, &body)),
+        )
+        .body(body)
+        .send()
+        .await;
+
+    let (status, error) = match result {
+        Ok(response) if response.status().is_success() => {
+            (Some(response.status().as_u16() as i32), None)
+        }
+        Ok(response) => (
+            Some(response.status().as_u16() as i32),
+            Some(format!("Endpoint responded with {}", response.status())),
+        ),
+        Err(err) => (None, Some(err.to_string())),
+    };
+
+    let delivered = error.is_none();
+    let next_attempt_at = if delivered || delivery.attempts >= ctx.webhook_delivery.max_attempts {
+        None
+    } else {
+        Some(Utc::now() + retry_delay(delivery.attempts))
+    };
+
+    if delivered {
+        tracing::info!(?status, "Delivered webhook event");
+    } else {
+        tracing::warn!(
+            ?status,
+            ?error,
+            attempts = delivery.attempts,
+            ?next_attempt_at,
+            "Failed to deliver webhook event"
+        );
+    }
+
+    WebhookDeliveryStore::complete(
+        &ctx.db.storage,
+        &delivery.id,
+        delivered,
+        status,
+        error,
+        next_attempt_at,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Check that a subscription url is a https url whose host, if it is a literal address, is an
+/// address that may receive deliveries
+fn is_allowed_url(url: &str) -> bool {
+    Url::parse(url)
+        .ok()
+        .filter(|url| url.scheme() == "https")
+        .and_then(|url| url.host_str().map(WebhookSubscription::accepts_host))
+        .unwrap_or(false)
+}
+
+/// Resolver used by the webhook delivery client. Names that resolve to an address that a
+/// subscription is not allowed to target are rejected, so that a name that is changed to point at
+/// an internal address after the subscription was validated can not be delivered to
+pub struct WebhookResolver;
+
+impl Resolve for WebhookResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(resolve_public_addresses(name))
+    }
+}
+
+async fn resolve_public_addresses(
+    name: Name,
+) -> Result<Addrs, Box<dyn std::error::Error + Send + Sync>> {
+    let addresses = WebhookSubscription::resolve_host(name.as_str(), 0).await?;
+    let addresses: Addrs = Box::new(addresses.into_iter());
+    Ok(addresses)
+}
+
+/// Delay to wait after the given (1-indexed) attempt before attempting the delivery again
+fn retry_delay(attempt: i32) -> Duration {
+    let exponent = attempt.clamp(1, 16) as u32 - 1;
+    Duration::seconds((RETRY_BASE_SECONDS * 2i64.pow(exponent)).min(RETRY_MAX_SECONDS))
+}
+
+/// Hex encoded HMAC-SHA256 of a delivery body keyed by the subscription secret
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use rfd_model::{
+        schema_ext::{ContentFormat, Visibility, WebhookEvent},
+        Rfd, RfdRevision,
+    };
+    use uuid::Uuid;
+
+    use crate::rfd::PersistedRfd;
+
+    use super::{is_allowed_url, retry_delay, rfd_events, sign};
+
+    fn persisted(state: &str, content: &str, visibility: Visibility) -> PersistedRfd {
+        let rfd_id = Uuid::new_v4();
+
+        PersistedRfd::new(
+            123.into(),
+            Rfd {
+                id: rfd_id,
+                rfd_number: 123,
+                link: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+                visibility,
+            },
+            RfdRevision {
+                id: Uuid::new_v4(),
+                rfd_id,
+                title: "Webhooks".to_string(),
+                state: Some(state.to_string()),
+                discussion: None,
+                authors: None,
+                labels: None,
+                content: content.to_string(),
+                content_format: ContentFormat::Asciidoc,
+                sha: String::new().into(),
+                commit: String::new().into(),
+                committed_at: Utc::now(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn test_events_from_revisions() {
+        let first = persisted("prediscussion", "a", Visibility::Private);
+        let events = rfd_events(None, &first, Utc::now());
+        assert_eq!(1, events.len());
+        assert_eq!(WebhookEvent::Created, events[0].event);
+
+        let unchanged = persisted("prediscussion", "a", Visibility::Private);
+        assert!(rfd_events(Some(&first), &unchanged, Utc::now()).is_empty());
+
+        let moved = persisted("discussion", "b", Visibility::Public);
+        let events = rfd_events(Some(&first), &moved, Utc::now())
+            .into_iter()
+            .map(|event| event.event)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                WebhookEvent::StateChanged,
+                WebhookEvent::ContentUpdated,
+                WebhookEvent::VisibilityChanged
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn test_rejects_disallowed_urls() {
+        assert!(is_allowed_url("https://example.com/hook"));
+        assert!(is_allowed_url("https://93.184.216.34/hook"));
+
+        assert!(!is_allowed_url("http://example.com/hook"));
+        assert!(!is_allowed_url("https://127.0.0.1:8080/hook"));
+        assert!(!is_allowed_url("https://169.254.169.254/latest/meta-data"));
+        assert!(!is_allowed_url("https://[::1]/hook"));
+        assert!(!is_allowed_url("https://[64:ff9b::7f00:1]/hook"));
+        assert!(!is_allowed_url("https://localhost/hook"));
+        assert!(!is_allowed_url("/hook"));
+    }
+
+    #[test]
+    fn test_retry_delay_is_bounded() {
+        assert_eq!(Duration::seconds(30), retry_delay(1));
+        assert_eq!(Duration::seconds(120), retry_delay(3));
+        assert_eq!(Duration::seconds(3600), retry_delay(50));
+    }
+
+    #[test]
+    fn test_signs_body() {
+        // Test vector from RFC 4231 (test case 2)
+        assert_eq!(
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            sign("Jefe", b"what do ya want for nothing?")
+        );
+    }
+}
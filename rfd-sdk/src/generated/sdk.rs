@@ -2978,6 +2978,83 @@ pub mod types {
         }
     }
 
+    /// CreateWebhookSubscription
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "events",
+    ///    "secret",
+    ///    "url"
+    ///  ],
+    ///  "properties": {
+    ///    "events": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/WebhookEvent"
+    ///      }
+
+    ///    },
+    ///    "rfds": {
+    ///      "description": "RFDs to deliver events for. Events for every RFD
+    /// are delivered when this is omitted",
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "int32"
+    ///      }
+
+    ///    },
+    ///    "secret": {
+    ///      "description": "Shared secret used to compute the
+    /// X-RFD-Signature-256 header of each delivery",
+    ///      "type": "string"
+    ///    },
+    ///    "url": {
+    ///      "description": "Https endpoint that events are POSTed to. The
+    /// endpoint must resolve to a public address",
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct CreateWebhookSubscription {
+        pub events: Vec<WebhookEvent>,
+        /// RFDs to deliver events for. Events for every RFD are delivered when
+        /// this is omitted
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub rfds: Vec<i32>,
+        /// Shared secret used to compute the X-RFD-Signature-256 header of each
+        /// delivery
+        pub secret: String,
+        /// Https endpoint that events are POSTed to. The endpoint must resolve
+        /// to a public address
+        pub url: String,
+    }
+
+    impl From<&CreateWebhookSubscription> for CreateWebhookSubscription {
+        fn from(value: &CreateWebhookSubscription) -> Self {
+            value.clone()
+        }
+    }
+
+    impl CreateWebhookSubscription {
+        pub fn builder() -> builder::CreateWebhookSubscription {
+            Default::default()
+        }
+    }
+
     /// Error information from a response.
     ///
     /// <details><summary>JSON schema</summary>
@@ -3027,6 +3104,45 @@ pub mod types {
         }
     }
 
+    /// FeedTokenCreateParams
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "expires_at"
+    ///  ],
+    ///  "properties": {
+    ///    "expires_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct FeedTokenCreateParams {
+        pub expires_at: chrono::DateTime<chrono::offset::Utc>,
+    }
+
+    impl From<&FeedTokenCreateParams> for FeedTokenCreateParams {
+        fn from(value: &FeedTokenCreateParams) -> Self {
+            value.clone()
+        }
+    }
+
+    impl FeedTokenCreateParams {
+        pub fn builder() -> builder::FeedTokenCreateParams {
+            Default::default()
+        }
+    }
+
     /// FileSha
     ///
     /// <details><summary>JSON schema</summary>
@@ -3192,6 +3308,7 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
+    ///    "author_list",
     ///    "commit",
     ///    "committed_at",
     ///    "content",
@@ -3204,6 +3321,13 @@ pub mod types {
     ///    "visibility"
     ///  ],
     ///  "properties": {
+    ///    "author_list": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/RfdAuthorEntry"
+    ///      }
+
+    ///    },
     ///    "authors": {
     ///      "type": [
     ///        "string",
@@ -3280,6 +3404,7 @@ pub mod types {
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
     pub struct FullRfd {
+        pub author_list: Vec<RfdAuthorEntry>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub authors: Option<String>,
         pub commit: CommitSha,
@@ -3808,6 +3933,56 @@ pub mod types {
         }
     }
 
+    /// InitialFeedTokenResponse
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "id",
+    ///    "token"
+    ///  ],
+    ///  "properties": {
+    ///    "created_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "token": {
+    ///      "$ref": "#/components/schemas/SecretString"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct InitialFeedTokenResponse {
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        pub id: uuid::Uuid,
+        pub token: SecretString,
+    }
+
+    impl From<&InitialFeedTokenResponse> for InitialFeedTokenResponse {
+        fn from(value: &InitialFeedTokenResponse) -> Self {
+            value.clone()
+        }
+    }
+
+    impl InitialFeedTokenResponse {
+        pub fn builder() -> builder::InitialFeedTokenResponse {
+            Default::default()
+        }
+    }
+
     /// InitialOAuthClientSecretResponse
     ///
     /// <details><summary>JSON schema</summary>
@@ -3959,6 +4134,158 @@ pub mod types {
         }
     }
 
+    /// Label
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "id",
+    ///    "name",
+    ///    "updated_at"
+    ///  ],
+    ///  "properties": {
+    ///    "color": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "created_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "deleted_at": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
+    ///    "description": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "name": {
+    ///      "type": "string"
+    ///    },
+    ///    "updated_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct Label {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub deleted_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        pub id: uuid::Uuid,
+        pub name: String,
+        pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+    }
+
+    impl From<&Label> for Label {
+        fn from(value: &Label) -> Self {
+            value.clone()
+        }
+    }
+
+    impl Label {
+        pub fn builder() -> builder::Label {
+            Default::default()
+        }
+    }
+
+    /// LabelSummary
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "count",
+    ///    "id",
+    ///    "name"
+    ///  ],
+    ///  "properties": {
+    ///    "color": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "count": {
+    ///      "description": "The number of RFDs visible to the caller whose
+    /// latest revision has this label",
+    ///      "type": "integer",
+    ///      "format": "uint",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "description": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "name": {
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct LabelSummary {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+        /// The number of RFDs visible to the caller whose latest revision has
+        /// this label
+        pub count: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        pub id: uuid::Uuid,
+        pub name: String,
+    }
+
+    impl From<&LabelSummary> for LabelSummary {
+        fn from(value: &LabelSummary) -> Self {
+            value.clone()
+        }
+    }
+
+    impl LabelSummary {
+        pub fn builder() -> builder::LabelSummary {
+            Default::default()
+        }
+    }
+
     /// ListRfd
     ///
     /// <details><summary>JSON schema</summary>
@@ -3967,6 +4294,7 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
+    ///    "author_list",
     ///    "commit",
     ///    "committed_at",
     ///    "format",
@@ -3977,6 +4305,13 @@ pub mod types {
     ///    "visibility"
     ///  ],
     ///  "properties": {
+    ///    "author_list": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/RfdAuthorEntry"
+    ///      }
+
+    ///    },
     ///    "authors": {
     ///      "type": [
     ///        "string",
@@ -4043,6 +4378,7 @@ pub mod types {
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
     pub struct ListRfd {
+        pub author_list: Vec<RfdAuthorEntry>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub authors: Option<String>,
         pub commit: CommitSha,
@@ -5017,12 +5353,40 @@ pub mod types {
     ///        "null"
     ///      ]
     ///    },
-    ///    "title": {
-    ///      "description": "Title of the RFD",
-    ///      "type": "string"
-    ///    }
-
-    ///  }
+    ///    "fields": {
+    ///      "description": "Values for additional fields defined by the
+    /// template",
+    ///      "default": {},
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "string"
+    ///      }
+
+    ///    },
+    ///    "format": {
+    ///      "description": "Format to write the RFD in. Defaults to AsciiDoc",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/ContentFormat"
+    ///        }
+
+    ///      ]
+    ///    },
+    ///    "template": {
+    ///      "description": "Optional name of the template to create the RFD
+    /// from. Defaults to the \"new\" template when contents are supplied and
+    /// the \"placeholder\" template otherwise",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "title": {
+    ///      "description": "Title of the RFD",
+    ///      "type": "string"
+    ///    }
+
+    ///  }
 
     /// }
 
@@ -5033,6 +5397,17 @@ pub mod types {
         /// Optional contents of the RFD
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub content: Option<String>,
+        /// Values for additional fields defined by the template
+        #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+        pub fields: std::collections::HashMap<String, String>,
+        /// Format to write the RFD in. Defaults to AsciiDoc
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub format: Option<ContentFormat>,
+        /// Optional name of the template to create the RFD from. Defaults to
+        /// the "new" template when contents are supplied and the "placeholder"
+        /// template otherwise
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub template: Option<String>,
         /// Title of the RFD
         pub title: String,
     }
@@ -5167,6 +5542,121 @@ pub mod types {
         }
     }
 
+    /// RfdAssetUploadBody
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content",
+    ///    "path"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "description": "Base64 encoded contents of the asset",
+    ///      "type": "string"
+    ///    },
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made
+    /// against. If the RFD has been updated since this commit, the update will
+    /// be rejected. May also be supplied as an If-Match header",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "message": {
+    ///      "description": "Optional Git commit message to send with this
+    /// update (recommended)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "path": {
+    ///      "description": "Path to store the asset at, relative to the RFD
+    /// directory (examples: diagram.svg or images/diagram.svg)",
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdAssetUploadBody {
+        /// Base64 encoded contents of the asset
+        pub content: String,
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub expected_commit: Option<String>,
+        /// Optional Git commit message to send with this update (recommended)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>,
+        /// Path to store the asset at, relative to the RFD directory
+        /// (examples: diagram.svg or images/diagram.svg)
+        pub path: String,
+    }
+
+    impl From<&RfdAssetUploadBody> for RfdAssetUploadBody {
+        fn from(value: &RfdAssetUploadBody) -> Self {
+            value.clone()
+        }
+    }
+
+    impl RfdAssetUploadBody {
+        pub fn builder() -> builder::RfdAssetUploadBody {
+            Default::default()
+        }
+    }
+
+    /// RfdAssetUploadResponse
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "path"
+    ///  ],
+    ///  "properties": {
+    ///    "path": {
+    ///      "description": "Path to use when referencing the asset from the
+    /// RFD document",
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdAssetUploadResponse {
+        /// Path to use when referencing the asset from the RFD document
+        pub path: String,
+    }
+
+    impl From<&RfdAssetUploadResponse> for RfdAssetUploadResponse {
+        fn from(value: &RfdAssetUploadResponse) -> Self {
+            value.clone()
+        }
+    }
+
+    impl RfdAssetUploadResponse {
+        pub fn builder() -> builder::RfdAssetUploadResponse {
+            Default::default()
+        }
+    }
+
     /// RfdAttr
     ///
     /// <details><summary>JSON schema</summary>
@@ -5339,6 +5829,15 @@ pub mod types {
     ///    "value"
     ///  ],
     ///  "properties": {
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made
+    /// against. If the RFD has been updated since this commit, the update will
+    /// be rejected. May also be supplied as an If-Match header",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
     ///    "message": {
     ///      "description": "Optional Git commit message to send with this
     /// update (recommended)",
@@ -5361,6 +5860,11 @@ pub mod types {
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
     pub struct RfdAttrValue {
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub expected_commit: Option<String>,
         /// Optional Git commit message to send with this update (recommended)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub message: Option<String>,
@@ -5380,7 +5884,125 @@ pub mod types {
         }
     }
 
-    /// RfdState
+    /// RfdAuthorEntry
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "api_user_id": {
+    ///      "description": "The user that this author has been matched to, if
+    /// any",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "uuid"
+    ///    },
+    ///    "email": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "name": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdAuthorEntry {
+        /// The user that this author has been matched to, if any
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub api_user_id: Option<uuid::Uuid>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub email: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+    }
+
+    impl From<&RfdAuthorEntry> for RfdAuthorEntry {
+        fn from(value: &RfdAuthorEntry) -> Self {
+            value.clone()
+        }
+    }
+
+    impl RfdAuthorEntry {
+        pub fn builder() -> builder::RfdAuthorEntry {
+            Default::default()
+        }
+    }
+
+    /// RfdBatchBody
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "numbers"
+    ///  ],
+    ///  "properties": {
+    ///    "fields": {
+    ///      "description": "The fields to return for each RFD. Summaries
+    /// contain the fields that are returned when listing RFDs, while full RFDs
+    /// also include their content. Defaults to summaries",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/RfdBatchFields"
+    ///        }
+    ///      ]
+    ///    },
+    ///    "numbers": {
+    ///      "description": "The RFD numbers to fetch (examples: 1 or 123)",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "int32"
+    ///      }
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdBatchBody {
+        /// The fields to return for each RFD. Summaries contain the fields that
+        /// are returned when listing RFDs, while full RFDs also include their
+        /// content. Defaults to summaries
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub fields: Option<RfdBatchFields>,
+        /// The RFD numbers to fetch (examples: 1 or 123)
+        pub numbers: Vec<i32>,
+    }
+
+    impl From<&RfdBatchBody> for RfdBatchBody {
+        fn from(value: &RfdBatchBody) -> Self {
+            value.clone()
+        }
+    }
+
+    impl RfdBatchBody {
+        pub fn builder() -> builder::RfdBatchBody {
+            Default::default()
+        }
+    }
+
+    /// RfdBatchFields
     ///
     /// <details><summary>JSON schema</summary>
     ///
@@ -5388,12 +6010,8 @@ pub mod types {
     /// {
     ///  "type": "string",
     ///  "enum": [
-    ///    "abandoned",
-    ///    "committed",
-    ///    "discussion",
-    ///    "ideation",
-    ///    "prediscussion",
-    ///    "published"
+    ///    "summary",
+    ///    "full"
     ///  ]
     /// }
 
@@ -5412,128 +6030,137 @@ pub mod types {
         Serialize,
         schemars :: JsonSchema,
     )]
-    pub enum RfdState {
-        #[serde(rename = "abandoned")]
-        Abandoned,
-        #[serde(rename = "committed")]
-        Committed,
-        #[serde(rename = "discussion")]
-        Discussion,
-        #[serde(rename = "ideation")]
-        Ideation,
-        #[serde(rename = "prediscussion")]
-        Prediscussion,
-        #[serde(rename = "published")]
-        Published,
+    pub enum RfdBatchFields {
+        #[serde(rename = "summary")]
+        Summary,
+        #[serde(rename = "full")]
+        Full,
     }
 
-    impl From<&RfdState> for RfdState {
-        fn from(value: &RfdState) -> Self {
+    impl From<&RfdBatchFields> for RfdBatchFields {
+        fn from(value: &RfdBatchFields) -> Self {
             value.clone()
         }
     }
 
-    impl ToString for RfdState {
+    impl ToString for RfdBatchFields {
         fn to_string(&self) -> String {
             match *self {
-                Self::Abandoned => "abandoned".to_string(),
-                Self::Committed => "committed".to_string(),
-                Self::Discussion => "discussion".to_string(),
-                Self::Ideation => "ideation".to_string(),
-                Self::Prediscussion => "prediscussion".to_string(),
-                Self::Published => "published".to_string(),
+                Self::Summary => "summary".to_string(),
+                Self::Full => "full".to_string(),
             }
         }
     }
 
-    impl std::str::FromStr for RfdState {
+    impl std::str::FromStr for RfdBatchFields {
         type Err = self::error::ConversionError;
         fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
             match value {
-                "abandoned" => Ok(Self::Abandoned),
-                "committed" => Ok(Self::Committed),
-                "discussion" => Ok(Self::Discussion),
-                "ideation" => Ok(Self::Ideation),
-                "prediscussion" => Ok(Self::Prediscussion),
-                "published" => Ok(Self::Published),
+                "summary" => Ok(Self::Summary),
+                "full" => Ok(Self::Full),
                 _ => Err("invalid value".into()),
             }
         }
     }
 
-    impl std::convert::TryFrom<&str> for RfdState {
+    impl std::convert::TryFrom<&str> for RfdBatchFields {
         type Error = self::error::ConversionError;
         fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
             value.parse()
         }
     }
 
-    impl std::convert::TryFrom<&String> for RfdState {
+    impl std::convert::TryFrom<&String> for RfdBatchFields {
         type Error = self::error::ConversionError;
         fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
             value.parse()
         }
     }
 
-    impl std::convert::TryFrom<String> for RfdState {
+    impl std::convert::TryFrom<String> for RfdBatchFields {
         type Error = self::error::ConversionError;
         fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
             value.parse()
         }
     }
 
-    /// RfdUpdateBody
+    /// RfdBatchResponse
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     /// {
-    ///  "type": "object",
-    ///  "required": [
-    ///    "document"
-    ///  ],
-    ///  "properties": {
-    ///    "document": {
-    ///      "description": "Full Asciidoc document to store for this RFD",
-    ///      "type": "string"
-    ///    },
-    ///    "message": {
-    ///      "description": "Optional Git commit message to send with this
-    /// update (recommended)",
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ]
-    ///    }
-
-    ///  }
-
-    /// }
+    ///  "oneOf": [
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "fields",
+    ///        "rfds"
+    ///      ],
+    ///      "properties": {
+    ///        "fields": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "summary"
+    ///          ]
+    ///        },
+    ///        "rfds": {
+    ///          "type": "array",
+    ///          "items": {
+    ///            "$ref": "#/components/schemas/ListRfd"
+    ///          }
+
+    ///        }
+
+    ///      }
+
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "fields",
+    ///        "rfds"
+    ///      ],
+    ///      "properties": {
+    ///        "fields": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "full"
+    ///          ]
+    ///        },
+    ///        "rfds": {
+    ///          "type": "array",
+    ///          "items": {
+    ///            "$ref": "#/components/schemas/FullRfd"
+    ///          }
+
+    ///        }
+
+    ///      }
+
+    ///    }
+
+    ///  ]
+    /// }
 
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
-    pub struct RfdUpdateBody {
-        /// Full Asciidoc document to store for this RFD
-        pub document: String,
-        /// Optional Git commit message to send with this update (recommended)
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub message: Option<String>,
+    #[serde(tag = "fields", content = "rfds")]
+    pub enum RfdBatchResponse {
+        #[serde(rename = "summary")]
+        Summary(Vec<ListRfd>),
+        #[serde(rename = "full")]
+        Full(Vec<FullRfd>),
     }
 
-    impl From<&RfdUpdateBody> for RfdUpdateBody {
-        fn from(value: &RfdUpdateBody) -> Self {
+    impl From<&RfdBatchResponse> for RfdBatchResponse {
+        fn from(value: &RfdBatchResponse) -> Self {
             value.clone()
         }
     }
 
-    impl RfdUpdateBody {
-        pub fn builder() -> builder::RfdUpdateBody {
-            Default::default()
-        }
-    }
-
-    /// RfdUpdateContentBody
+    /// RfdRevertBody
     ///
     /// <details><summary>JSON schema</summary>
     ///
@@ -5541,16 +6168,18 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
-    ///    "content"
+    ///    "commit"
     ///  ],
     ///  "properties": {
-    ///    "content": {
-    ///      "description": "Asciidoc content to store for this RFD",
+    ///    "commit": {
+    ///      "description": "Commit from the history of the RFD to restore the
+    /// document to",
     ///      "type": "string"
     ///    },
-    ///    "message": {
-    ///      "description": "Optional Git commit message to send with this
-    /// update (recommended)",
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made
+    /// against. If the RFD has been updated since this commit, the update will
+    /// be rejected. May also be supplied as an If-Match header",
     ///      "type": [
     ///        "string",
     ///        "null"
@@ -5564,27 +6193,29 @@ pub mod types {
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
-    pub struct RfdUpdateContentBody {
-        /// Asciidoc content to store for this RFD
-        pub content: String,
-        /// Optional Git commit message to send with this update (recommended)
+    pub struct RfdRevertBody {
+        /// Commit from the history of the RFD to restore the document to
+        pub commit: String,
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub message: Option<String>,
+        pub expected_commit: Option<String>,
     }
 
-    impl From<&RfdUpdateContentBody> for RfdUpdateContentBody {
-        fn from(value: &RfdUpdateContentBody) -> Self {
+    impl From<&RfdRevertBody> for RfdRevertBody {
+        fn from(value: &RfdRevertBody) -> Self {
             value.clone()
         }
     }
 
-    impl RfdUpdateContentBody {
-        pub fn builder() -> builder::RfdUpdateContentBody {
+    impl RfdRevertBody {
+        pub fn builder() -> builder::RfdRevertBody {
             Default::default()
         }
     }
 
-    /// RfdVisibility
+    /// RfdSection
     ///
     /// <details><summary>JSON schema</summary>
     ///
@@ -5592,11 +6223,35 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
-    ///    "visibility"
+    ///    "anchor",
+    ///    "level",
+    ///    "name",
+    ///    "parents"
     ///  ],
     ///  "properties": {
-    ///    "visibility": {
-    ///      "$ref": "#/components/schemas/Visibility"
+    ///    "anchor": {
+    ///      "description": "Anchor that identifies this section within the
+    /// document",
+    ///      "type": "string"
+    ///    },
+    ///    "level": {
+    ///      "description": "Nesting depth of the section, where top level
+    /// sections are level 1",
+    ///      "type": "integer",
+    ///      "format": "uint",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "name": {
+    ///      "type": "string"
+    ///    },
+    ///    "parents": {
+    ///      "description": "Names of the sections that this section is nested
+    /// within",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+
     ///    }
 
     ///  }
@@ -5606,23 +6261,29 @@ pub mod types {
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
-    pub struct RfdVisibility {
-        pub visibility: Visibility,
+    pub struct RfdSection {
+        /// Anchor that identifies this section within the document
+        pub anchor: String,
+        /// Nesting depth of the section, where top level sections are level 1
+        pub level: u32,
+        pub name: String,
+        /// Names of the sections that this section is nested within
+        pub parents: Vec<String>,
     }
 
-    impl From<&RfdVisibility> for RfdVisibility {
-        fn from(value: &RfdVisibility) -> Self {
+    impl From<&RfdSection> for RfdSection {
+        fn from(value: &RfdSection) -> Self {
             value.clone()
         }
     }
 
-    impl RfdVisibility {
-        pub fn builder() -> builder::RfdVisibility {
+    impl RfdSection {
+        pub fn builder() -> builder::RfdSection {
             Default::default()
         }
     }
 
-    /// SearchResultHit
+    /// RfdSectionContent
     ///
     /// <details><summary>JSON schema</summary>
     ///
@@ -5630,65 +6291,41 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
+    ///    "anchor",
     ///    "content",
-    ///    "hierarchy",
-    ///    "hierarchy_radio",
-    ///    "object_id",
-    ///    "rfd_number"
+    ///    "level",
+    ///    "name",
+    ///    "parents"
     ///  ],
     ///  "properties": {
     ///    "anchor": {
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ]
+    ///      "description": "Anchor that identifies this section within the
+    /// document",
+    ///      "type": "string"
     ///    },
     ///    "content": {
+    ///      "description": "Source of the section body, excluding its heading
+    /// and any nested sections",
     ///      "type": "string"
     ///    },
-    ///    "formatted": {
-    ///      "allOf": [
-    ///        {
-    ///          "$ref": "#/components/schemas/FormattedSearchResultHit"
-    ///        }
-
-    ///      ]
+    ///    "level": {
+    ///      "description": "Nesting depth of the section, where top level
+    /// sections are level 1",
+    ///      "type": "integer",
+    ///      "format": "uint",
+    ///      "minimum": 0.0
     ///    },
-    ///    "hierarchy": {
-    ///      "type": "array",
-    ///      "items": {
-    ///        "type": [
-    ///          "string",
-    ///          "null"
-    ///        ]
-    ///      },
-    ///      "maxItems": 6,
-    ///      "minItems": 6
+    ///    "name": {
+    ///      "type": "string"
     ///    },
-    ///    "hierarchy_radio": {
+    ///    "parents": {
+    ///      "description": "Names of the sections that this section is nested
+    /// within",
     ///      "type": "array",
     ///      "items": {
-    ///        "type": [
-    ///          "string",
-    ///          "null"
-    ///        ]
-    ///      },
-    ///      "maxItems": 6,
-    ///      "minItems": 6
-    ///    },
-    ///    "object_id": {
-    ///      "type": "string"
-    ///    },
-    ///    "rfd_number": {
-    ///      "type": "integer",
-    ///      "format": "uint64",
-    ///      "minimum": 0.0
-    ///    },
-    ///    "url": {
-    ///      "type": [
-    ///        "string",
-    ///        "null"
-    ///      ]
+    ///        "type": "string"
+    ///      }
+
     ///    }
 
     ///  }
@@ -5698,33 +6335,32 @@ pub mod types {
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
-    pub struct SearchResultHit {
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub anchor: Option<String>,
+    pub struct RfdSectionContent {
+        /// Anchor that identifies this section within the document
+        pub anchor: String,
+        /// Source of the section body, excluding its heading and any nested
+        /// sections
         pub content: String,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub formatted: Option<FormattedSearchResultHit>,
-        pub hierarchy: [Option<String>; 6usize],
-        pub hierarchy_radio: [Option<String>; 6usize],
-        pub object_id: String,
-        pub rfd_number: u64,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub url: Option<String>,
+        /// Nesting depth of the section, where top level sections are level 1
+        pub level: u32,
+        pub name: String,
+        /// Names of the sections that this section is nested within
+        pub parents: Vec<String>,
     }
 
-    impl From<&SearchResultHit> for SearchResultHit {
-        fn from(value: &SearchResultHit) -> Self {
+    impl From<&RfdSectionContent> for RfdSectionContent {
+        fn from(value: &RfdSectionContent) -> Self {
             value.clone()
         }
     }
 
-    impl SearchResultHit {
-        pub fn builder() -> builder::SearchResultHit {
+    impl RfdSectionContent {
+        pub fn builder() -> builder::RfdSectionContent {
             Default::default()
         }
     }
 
-    /// SearchResults
+    /// RfdSectionUpdateBody
     ///
     /// <details><summary>JSON schema</summary>
     ///
@@ -5732,35 +6368,30 @@ pub mod types {
     /// {
     ///  "type": "object",
     ///  "required": [
-    ///    "hits",
-    ///    "query"
+    ///    "content"
     ///  ],
     ///  "properties": {
-    ///    "hits": {
-    ///      "type": "array",
-    ///      "items": {
-    ///        "$ref": "#/components/schemas/SearchResultHit"
-    ///      }
-
+    ///    "content": {
+    ///      "description": "Content to replace the body of the section with.
+    /// The section heading and any nested sections are left unchanged",
+    ///      "type": "string"
     ///    },
-    ///    "limit": {
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made against.
+    /// If the RFD has been updated since this commit, the update will be
+    /// rejected. May also be supplied as an If-Match header",
     ///      "type": [
-    ///        "integer",
+    ///        "string",
     ///        "null"
-    ///      ],
-    ///      "format": "uint",
-    ///      "minimum": 0.0
+    ///      ]
     ///    },
-    ///    "offset": {
+    ///    "message": {
+    ///      "description": "Optional Git commit message to send with this
+    /// update (recommended)",
     ///      "type": [
-    ///        "integer",
+    ///        "string",
     ///        "null"
-    ///      ],
-    ///      "format": "uint",
-    ///      "minimum": 0.0
-    ///    },
-    ///    "query": {
-    ///      "type": "string"
+    ///      ]
     ///    }
 
     ///  }
@@ -5770,40 +6401,54 @@ pub mod types {
     /// ```
     /// </details>
     #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
-    pub struct SearchResults {
-        pub hits: Vec<SearchResultHit>,
+    pub struct RfdSectionUpdateBody {
+        /// Content to replace the body of the section with. The section heading
+        /// and any nested sections are left unchanged
+        pub content: String,
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub limit: Option<u32>,
+        pub expected_commit: Option<String>,
+        /// Optional Git commit message to send with this update (recommended)
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub offset: Option<u32>,
-        pub query: String,
+        pub message: Option<String>,
     }
 
-    impl From<&SearchResults> for SearchResults {
-        fn from(value: &SearchResults) -> Self {
+    impl From<&RfdSectionUpdateBody> for RfdSectionUpdateBody {
+        fn from(value: &RfdSectionUpdateBody) -> Self {
             value.clone()
         }
     }
 
-    impl SearchResults {
-        pub fn builder() -> builder::SearchResults {
+    impl RfdSectionUpdateBody {
+        pub fn builder() -> builder::RfdSectionUpdateBody {
             Default::default()
         }
     }
 
-    /// SecretString
+    /// RfdState
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     /// {
-    ///  "type": "string"
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "abandoned",
+    ///    "committed",
+    ///    "discussion",
+    ///    "ideation",
+    ///    "prediscussion",
+    ///    "published"
+    ///  ]
     /// }
 
     /// ```
     /// </details>
     #[derive(
         Clone,
+        Copy,
         Debug,
         Deserialize,
         Eq,
@@ -5814,1249 +6459,5892 @@ pub mod types {
         Serialize,
         schemars :: JsonSchema,
     )]
-    pub struct SecretString(pub String);
-    impl std::ops::Deref for SecretString {
-        type Target = String;
-        fn deref(&self) -> &String {
-            &self.0
-        }
+    pub enum RfdState {
+        #[serde(rename = "abandoned")]
+        Abandoned,
+        #[serde(rename = "committed")]
+        Committed,
+        #[serde(rename = "discussion")]
+        Discussion,
+        #[serde(rename = "ideation")]
+        Ideation,
+        #[serde(rename = "prediscussion")]
+        Prediscussion,
+        #[serde(rename = "published")]
+        Published,
     }
 
-    impl From<SecretString> for String {
-        fn from(value: SecretString) -> Self {
-            value.0
+    impl From<&RfdState> for RfdState {
+        fn from(value: &RfdState) -> Self {
+            value.clone()
         }
     }
 
-    impl From<&SecretString> for SecretString {
-        fn from(value: &SecretString) -> Self {
-            value.clone()
+    impl ToString for RfdState {
+        fn to_string(&self) -> String {
+            match *self {
+                Self::Abandoned => "abandoned".to_string(),
+                Self::Committed => "committed".to_string(),
+                Self::Discussion => "discussion".to_string(),
+                Self::Ideation => "ideation".to_string(),
+                Self::Prediscussion => "prediscussion".to_string(),
+                Self::Published => "published".to_string(),
+            }
         }
     }
 
-    impl From<String> for SecretString {
-        fn from(value: String) -> Self {
-            Self(value)
+    impl std::str::FromStr for RfdState {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+            match value {
+                "abandoned" => Ok(Self::Abandoned),
+                "committed" => Ok(Self::Committed),
+                "discussion" => Ok(Self::Discussion),
+                "ideation" => Ok(Self::Ideation),
+                "prediscussion" => Ok(Self::Prediscussion),
+                "published" => Ok(Self::Published),
+                _ => Err("invalid value".into()),
+            }
         }
     }
 
-    impl std::str::FromStr for SecretString {
-        type Err = std::convert::Infallible;
-        fn from_str(value: &str) -> Result<Self, Self::Err> {
-            Ok(Self(value.to_string()))
+    impl std::convert::TryFrom<&str> for RfdState {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
+            value.parse()
         }
     }
 
-    impl ToString for SecretString {
-        fn to_string(&self) -> String {
-            self.0.to_string()
+    impl std::convert::TryFrom<&String> for RfdState {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
         }
     }
 
-    /// Visibility
+    impl std::convert::TryFrom<String> for RfdState {
+        type Error = self::error::ConversionError;
+        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    /// RfdStateTransition
     ///
     /// <details><summary>JSON schema</summary>
     ///
     /// ```json
     /// {
-    ///  "type": "string",
-    ///  "enum": [
-    ///    "public",
-    ///    "private"
-    ///  ]
+    ///  "type": "object",
+    ///  "required": [
+    ///    "api_user_id",
+    ///    "created_at",
+    ///    "id",
+    ///    "rfd_id",
+    ///    "to_state"
+    ///  ],
+    ///  "properties": {
+    ///    "api_user_id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "commit": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/CommitSha"
+    ///        }
+
+    ///      ]
+    ///    },
+    ///    "created_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "from_state": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "reason": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "rfd_id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "to_state": {
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
     /// }
 
     /// ```
     /// </details>
-    #[derive(
-        Clone,
-        Copy,
-        Debug,
-        Deserialize,
-        Eq,
-        Hash,
-        Ord,
-        PartialEq,
-        PartialOrd,
-        Serialize,
-        schemars :: JsonSchema,
-    )]
-    pub enum Visibility {
-        #[serde(rename = "public")]
-        Public,
-        #[serde(rename = "private")]
-        Private,
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdStateTransition {
+        pub api_user_id: uuid::Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub commit: Option<CommitSha>,
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub from_state: Option<String>,
+        pub id: uuid::Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        pub rfd_id: uuid::Uuid,
+        pub to_state: String,
     }
 
-    impl From<&Visibility> for Visibility {
-        fn from(value: &Visibility) -> Self {
+    impl From<&RfdStateTransition> for RfdStateTransition {
+        fn from(value: &RfdStateTransition) -> Self {
             value.clone()
         }
     }
 
-    impl ToString for Visibility {
-        fn to_string(&self) -> String {
-            match *self {
-                Self::Public => "public".to_string(),
-                Self::Private => "private".to_string(),
-            }
+    impl RfdStateTransition {
+        pub fn builder() -> builder::RfdStateTransition {
+            Default::default()
         }
     }
 
-    impl std::str::FromStr for Visibility {
-        type Err = self::error::ConversionError;
-        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
-            match value {
-                "public" => Ok(Self::Public),
-                "private" => Ok(Self::Private),
-                _ => Err("invalid value".into()),
-            }
-        }
+    /// RfdTemplateSummary
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "formats",
+    ///    "name",
+    ///    "optional_fields",
+    ///    "required_fields"
+    ///  ],
+    ///  "properties": {
+    ///    "description": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "formats": {
+    ///      "description": "Formats that the template can be rendered as",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/ContentFormat"
+    ///      }
+
+    ///    },
+    ///    "name": {
+    ///      "type": "string"
+    ///    },
+    ///    "optional_fields": {
+    ///      "description": "Fields that may be supplied, along with their
+    /// default values",
+    ///      "type": "object",
+    ///      "additionalProperties": {
+    ///        "type": "string"
+    ///      }
+
+    ///    },
+    ///    "required_fields": {
+    ///      "description": "Fields that must be supplied when creating a RFD
+    /// from this template",
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": "string"
+    ///      }
+
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdTemplateSummary {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Formats that the template can be rendered as
+        pub formats: Vec<ContentFormat>,
+        pub name: String,
+        /// Fields that may be supplied, along with their default values
+        pub optional_fields: std::collections::HashMap<String, String>,
+        /// Fields that must be supplied when creating a RFD from this template
+        pub required_fields: Vec<String>,
     }
 
-    impl std::convert::TryFrom<&str> for Visibility {
-        type Error = self::error::ConversionError;
-        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
-            value.parse()
+    impl From<&RfdTemplateSummary> for RfdTemplateSummary {
+        fn from(value: &RfdTemplateSummary) -> Self {
+            value.clone()
         }
     }
 
-    impl std::convert::TryFrom<&String> for Visibility {
-        type Error = self::error::ConversionError;
-        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
-            value.parse()
+    impl RfdTemplateSummary {
+        pub fn builder() -> builder::RfdTemplateSummary {
+            Default::default()
         }
     }
 
-    impl std::convert::TryFrom<String> for Visibility {
-        type Error = self::error::ConversionError;
-        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
-            value.parse()
-        }
+    /// A single change to a RFD, derived from comparing a revision to the one
+    /// that preceded it
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "description": "A single change to a RFD, derived from comparing a
+    /// revision to the one that preceded it",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "commit",
+    ///    "committed_at",
+    ///    "event"
+    ///  ],
+    ///  "properties": {
+    ///    "commit": {
+    ///      "$ref": "#/components/schemas/CommitSha"
+    ///    },
+    ///    "committed_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "event": {
+    ///      "$ref": "#/components/schemas/RfdTimelineEventKind"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdTimelineEvent {
+        pub commit: CommitSha,
+        pub committed_at: chrono::DateTime<chrono::offset::Utc>,
+        pub event: RfdTimelineEventKind,
     }
 
-    /// Types for composing complex structures.
-    pub mod builder {
-        #[derive(Clone, Debug)]
-        pub struct AccessGroupForApiPermissionResponse {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            name: Result<String, String>,
-            permissions: Result<super::PermissionsForApiPermissionResponse, String>,
-            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+    impl From<&RfdTimelineEvent> for RfdTimelineEvent {
+        fn from(value: &RfdTimelineEvent) -> Self {
+            value.clone()
         }
+    }
 
-        impl Default for AccessGroupForApiPermissionResponse {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    name: Err("no value supplied for name".to_string()),
-                    permissions: Err("no value supplied for permissions".to_string()),
-                    updated_at: Err("no value supplied for updated_at".to_string()),
-                }
-            }
+    impl RfdTimelineEvent {
+        pub fn builder() -> builder::RfdTimelineEvent {
+            Default::default()
         }
+    }
 
-        impl AccessGroupForApiPermissionResponse {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn name<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.name = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::PermissionsForApiPermissionResponse>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
-            pub fn updated_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.updated_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
-                self
-            }
-        }
+    /// RfdTimelineEventKind
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "oneOf": [
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "title",
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "state": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "title": {
+    ///          "type": "string"
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "created"
+    ///          ]
+    ///        }
 
-        impl std::convert::TryFrom<AccessGroupForApiPermissionResponse>
-            for super::AccessGroupForApiPermissionResponse
-        {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: AccessGroupForApiPermissionResponse,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    id: value.id?,
-                    name: value.name?,
-                    permissions: value.permissions?,
-                    updated_at: value.updated_at?,
-                })
-            }
-        }
+    ///      }
 
-        impl From<super::AccessGroupForApiPermissionResponse> for AccessGroupForApiPermissionResponse {
-            fn from(value: super::AccessGroupForApiPermissionResponse) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    id: Ok(value.id),
-                    name: Ok(value.name),
-                    permissions: Ok(value.permissions),
-                    updated_at: Ok(value.updated_at),
-                }
-            }
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "from",
+    ///        "to",
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "from": {
+    ///          "type": "string"
+    ///        },
+    ///        "to": {
+    ///          "type": "string"
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "title_changed"
+    ///          ]
+    ///        }
 
-        #[derive(Clone, Debug)]
-        pub struct AccessGroupUpdateParams {
-            name: Result<String, String>,
-            permissions: Result<super::PermissionsForApiPermission, String>,
-        }
+    ///      }
 
-        impl Default for AccessGroupUpdateParams {
-            fn default() -> Self {
-                Self {
-                    name: Err("no value supplied for name".to_string()),
-                    permissions: Err("no value supplied for permissions".to_string()),
-                }
-            }
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "from": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "to": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "state_changed"
+    ///          ]
+    ///        }
 
-        impl AccessGroupUpdateParams {
-            pub fn name<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.name = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::PermissionsForApiPermission>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
-        }
+    ///      }
 
-        impl std::convert::TryFrom<AccessGroupUpdateParams> for super::AccessGroupUpdateParams {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: AccessGroupUpdateParams,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    name: value.name?,
-                    permissions: value.permissions?,
-                })
-            }
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "from": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "to": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "labels_changed"
+    ///          ]
+    ///        }
 
-        impl From<super::AccessGroupUpdateParams> for AccessGroupUpdateParams {
-            fn from(value: super::AccessGroupUpdateParams) -> Self {
-                Self {
-                    name: Ok(value.name),
-                    permissions: Ok(value.permissions),
-                }
-            }
-        }
+    ///      }
 
-        #[derive(Clone, Debug)]
-        pub struct AccessTokenExchangeRequest {
-            device_code: Result<String, String>,
-            expires_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            grant_type: Result<String, String>,
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "from": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "to": {
+    ///          "type": [
+    ///            "string",
+    ///            "null"
+    ///          ]
+    ///        },
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "authors_changed"
+    ///          ]
+    ///        }
 
-        impl Default for AccessTokenExchangeRequest {
-            fn default() -> Self {
-                Self {
-                    device_code: Err("no value supplied for device_code".to_string()),
-                    expires_at: Ok(Default::default()),
-                    grant_type: Err("no value supplied for grant_type".to_string()),
-                }
-            }
-        }
+    ///      }
 
-        impl AccessTokenExchangeRequest {
-            pub fn device_code<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.device_code = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for device_code: {}", e));
-                self
-            }
-            pub fn expires_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.expires_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for expires_at: {}", e));
-                self
-            }
-            pub fn grant_type<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.grant_type = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for grant_type: {}", e));
-                self
-            }
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type",
+    ///        "url"
+    ///      ],
+    ///      "properties": {
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "discussion_linked"
+    ///          ]
+    ///        },
+    ///        "url": {
+    ///          "type": "string"
+    ///        }
 
-        impl std::convert::TryFrom<AccessTokenExchangeRequest> for super::AccessTokenExchangeRequest {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: AccessTokenExchangeRequest,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    device_code: value.device_code?,
-                    expires_at: value.expires_at?,
-                    grant_type: value.grant_type?,
-                })
-            }
-        }
+    ///      }
 
-        impl From<super::AccessTokenExchangeRequest> for AccessTokenExchangeRequest {
-            fn from(value: super::AccessTokenExchangeRequest) -> Self {
-                Self {
-                    device_code: Ok(value.device_code),
-                    expires_at: Ok(value.expires_at),
-                    grant_type: Ok(value.grant_type),
-                }
-            }
-        }
+    ///    },
+    ///    {
+    ///      "type": "object",
+    ///      "required": [
+    ///        "type"
+    ///      ],
+    ///      "properties": {
+    ///        "type": {
+    ///          "type": "string",
+    ///          "enum": [
+    ///            "published_to_default_branch"
+    ///          ]
+    ///        }
 
-        #[derive(Clone, Debug)]
-        pub struct AddGroupBody {
-            group_id: Result<uuid::Uuid, String>,
-        }
+    ///      }
 
-        impl Default for AddGroupBody {
-            fn default() -> Self {
-                Self {
-                    group_id: Err("no value supplied for group_id".to_string()),
-                }
-            }
-        }
+    ///    }
 
-        impl AddGroupBody {
-            pub fn group_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.group_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for group_id: {}", e));
-                self
-            }
-        }
+    ///  ]
+    /// }
 
-        impl std::convert::TryFrom<AddGroupBody> for super::AddGroupBody {
-            type Error = super::error::ConversionError;
-            fn try_from(value: AddGroupBody) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    group_id: value.group_id?,
-                })
-            }
-        }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    #[serde(tag = "type")]
+    pub enum RfdTimelineEventKind {
+        #[serde(rename = "created")]
+        Created {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            state: Option<String>,
+            title: String,
+        },
+        #[serde(rename = "title_changed")]
+        TitleChanged { from: String, to: String },
+        #[serde(rename = "state_changed")]
+        StateChanged {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            from: Option<String>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            to: Option<String>,
+        },
+        #[serde(rename = "labels_changed")]
+        LabelsChanged {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            from: Option<String>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            to: Option<String>,
+        },
+        #[serde(rename = "authors_changed")]
+        AuthorsChanged {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            from: Option<String>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            to: Option<String>,
+        },
+        #[serde(rename = "discussion_linked")]
+        DiscussionLinked { url: String },
+        #[serde(rename = "published_to_default_branch")]
+        PublishedToDefaultBranch,
+    }
 
-        impl From<super::AddGroupBody> for AddGroupBody {
-            fn from(value: super::AddGroupBody) -> Self {
-                Self {
-                    group_id: Ok(value.group_id),
-                }
-            }
+    impl From<&RfdTimelineEventKind> for RfdTimelineEventKind {
+        fn from(value: &RfdTimelineEventKind) -> Self {
+            value.clone()
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct AddOAuthClientRedirectBody {
-            redirect_uri: Result<String, String>,
-        }
+    /// RfdTransitionBody
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "state"
+    ///  ],
+    ///  "properties": {
+    ///    "reason": {
+    ///      "description": "Optional explanation for the transition. This is
+    /// included in the Git commit message",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "state": {
+    ///      "description": "State to move the RFD to",
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/RfdState"
+    ///        }
 
-        impl Default for AddOAuthClientRedirectBody {
-            fn default() -> Self {
-                Self {
-                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
-                }
-            }
-        }
+    ///      ]
+    ///    }
 
-        impl AddOAuthClientRedirectBody {
-            pub fn redirect_uri<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.redirect_uri = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for redirect_uri: {}", e)
-                });
-                self
-            }
-        }
+    ///  }
 
-        impl std::convert::TryFrom<AddOAuthClientRedirectBody> for super::AddOAuthClientRedirectBody {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: AddOAuthClientRedirectBody,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    redirect_uri: value.redirect_uri?,
-                })
-            }
-        }
+    /// }
 
-        impl From<super::AddOAuthClientRedirectBody> for AddOAuthClientRedirectBody {
-            fn from(value: super::AddOAuthClientRedirectBody) -> Self {
-                Self {
-                    redirect_uri: Ok(value.redirect_uri),
-                }
-            }
-        }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdTransitionBody {
+        /// Optional explanation for the transition. This is included in the Git
+        /// commit message
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        /// State to move the RFD to
+        pub state: RfdState,
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct ApiKeyCreateParams {
-            expires_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
+    impl From<&RfdTransitionBody> for RfdTransitionBody {
+        fn from(value: &RfdTransitionBody) -> Self {
+            value.clone()
         }
+    }
 
-        impl Default for ApiKeyCreateParams {
-            fn default() -> Self {
-                Self {
-                    expires_at: Err("no value supplied for expires_at".to_string()),
-                    permissions: Ok(Default::default()),
-                }
-            }
+    impl RfdTransitionBody {
+        pub fn builder() -> builder::RfdTransitionBody {
+            Default::default()
         }
+    }
 
-        impl ApiKeyCreateParams {
-            pub fn expires_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.expires_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for expires_at: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
-        }
+    /// RfdUpdateBody
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "document"
+    ///  ],
+    ///  "properties": {
+    ///    "document": {
+    ///      "description": "Full Asciidoc document to store for this RFD",
+    ///      "type": "string"
+    ///    },
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made
+    /// against. If the RFD has been updated since this commit, the update will
+    /// be rejected. May also be supplied as an If-Match header",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "message": {
+    ///      "description": "Optional Git commit message to send with this
+    /// update (recommended)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
 
-        impl std::convert::TryFrom<ApiKeyCreateParams> for super::ApiKeyCreateParams {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ApiKeyCreateParams) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    expires_at: value.expires_at?,
-                    permissions: value.permissions?,
-                })
-            }
-        }
+    ///  }
 
-        impl From<super::ApiKeyCreateParams> for ApiKeyCreateParams {
-            fn from(value: super::ApiKeyCreateParams) -> Self {
-                Self {
-                    expires_at: Ok(value.expires_at),
-                    permissions: Ok(value.permissions),
-                }
-            }
-        }
+    /// }
 
-        #[derive(Clone, Debug)]
-        pub struct ApiKeyResponse {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            id: Result<uuid::Uuid, String>,
-            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
-        }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdUpdateBody {
+        /// Full Asciidoc document to store for this RFD
+        pub document: String,
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub expected_commit: Option<String>,
+        /// Optional Git commit message to send with this update (recommended)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>,
+    }
 
-        impl Default for ApiKeyResponse {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    permissions: Ok(Default::default()),
-                }
-            }
+    impl From<&RfdUpdateBody> for RfdUpdateBody {
+        fn from(value: &RfdUpdateBody) -> Self {
+            value.clone()
         }
+    }
 
-        impl ApiKeyResponse {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
+    impl RfdUpdateBody {
+        pub fn builder() -> builder::RfdUpdateBody {
+            Default::default()
         }
+    }
 
-        impl std::convert::TryFrom<ApiKeyResponse> for super::ApiKeyResponse {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ApiKeyResponse) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    id: value.id?,
-                    permissions: value.permissions?,
-                })
-            }
-        }
+    /// RfdUpdateContentBody
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content"
+    ///  ],
+    ///  "properties": {
+    ///    "content": {
+    ///      "description": "Asciidoc content to store for this RFD",
+    ///      "type": "string"
+    ///    },
+    ///    "expected_commit": {
+    ///      "description": "Optional commit that this update was made
+    /// against. If the RFD has been updated since this commit, the update will
+    /// be rejected. May also be supplied as an If-Match header",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "message": {
+    ///      "description": "Optional Git commit message to send with this
+    /// update (recommended)",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
 
-        impl From<super::ApiKeyResponse> for ApiKeyResponse {
-            fn from(value: super::ApiKeyResponse) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    id: Ok(value.id),
-                    permissions: Ok(value.permissions),
-                }
-            }
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdUpdateContentBody {
+        /// Asciidoc content to store for this RFD
+        pub content: String,
+        /// Optional commit that this update was made against. If the RFD has
+        /// been updated since this commit, the update will be rejected. May
+        /// also be supplied as an If-Match header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub expected_commit: Option<String>,
+        /// Optional Git commit message to send with this update (recommended)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>,
+    }
+
+    impl From<&RfdUpdateContentBody> for RfdUpdateContentBody {
+        fn from(value: &RfdUpdateContentBody) -> Self {
+            value.clone()
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct ApiUserForApiPermissionResponse {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            groups: Result<Vec<uuid::Uuid>, String>,
-            id: Result<uuid::Uuid, String>,
-            permissions: Result<super::PermissionsForApiPermissionResponse, String>,
-            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+    impl RfdUpdateContentBody {
+        pub fn builder() -> builder::RfdUpdateContentBody {
+            Default::default()
         }
+    }
 
-        impl Default for ApiUserForApiPermissionResponse {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    groups: Err("no value supplied for groups".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    permissions: Err("no value supplied for permissions".to_string()),
-                    updated_at: Err("no value supplied for updated_at".to_string()),
-                }
-            }
+    /// RfdVisibility
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "visibility"
+    ///  ],
+    ///  "properties": {
+    ///    "visibility": {
+    ///      "$ref": "#/components/schemas/Visibility"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct RfdVisibility {
+        pub visibility: Visibility,
+    }
+
+    impl From<&RfdVisibility> for RfdVisibility {
+        fn from(value: &RfdVisibility) -> Self {
+            value.clone()
         }
+    }
 
-        impl ApiUserForApiPermissionResponse {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn groups<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<uuid::Uuid>>,
-                T::Error: std::fmt::Display,
-            {
-                self.groups = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for groups: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::PermissionsForApiPermissionResponse>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
-            pub fn updated_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.updated_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
-                self
-            }
+    impl RfdVisibility {
+        pub fn builder() -> builder::RfdVisibility {
+            Default::default()
         }
+    }
 
-        impl std::convert::TryFrom<ApiUserForApiPermissionResponse>
-            for super::ApiUserForApiPermissionResponse
-        {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: ApiUserForApiPermissionResponse,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    groups: value.groups?,
-                    id: value.id?,
-                    permissions: value.permissions?,
-                    updated_at: value.updated_at?,
-                })
-            }
-        }
+    /// SearchResultHit
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "content",
+    ///    "hierarchy",
+    ///    "hierarchy_radio",
+    ///    "object_id",
+    ///    "rfd_number"
+    ///  ],
+    ///  "properties": {
+    ///    "anchor": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "content": {
+    ///      "type": "string"
+    ///    },
+    ///    "formatted": {
+    ///      "allOf": [
+    ///        {
+    ///          "$ref": "#/components/schemas/FormattedSearchResultHit"
+    ///        }
 
-        impl From<super::ApiUserForApiPermissionResponse> for ApiUserForApiPermissionResponse {
-            fn from(value: super::ApiUserForApiPermissionResponse) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    groups: Ok(value.groups),
-                    id: Ok(value.id),
-                    permissions: Ok(value.permissions),
-                    updated_at: Ok(value.updated_at),
-                }
-            }
-        }
+    ///      ]
+    ///    },
+    ///    "hierarchy": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": [
+    ///          "string",
+    ///          "null"
+    ///        ]
+    ///      },
+    ///      "maxItems": 6,
+    ///      "minItems": 6
+    ///    },
+    ///    "hierarchy_radio": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "type": [
+    ///          "string",
+    ///          "null"
+    ///        ]
+    ///      },
+    ///      "maxItems": 6,
+    ///      "minItems": 6
+    ///    },
+    ///    "object_id": {
+    ///      "type": "string"
+    ///    },
+    ///    "rfd_number": {
+    ///      "type": "integer",
+    ///      "format": "uint64",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "url": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
 
-        #[derive(Clone, Debug)]
-        pub struct ApiUserProvider {
-            api_user_id: Result<uuid::Uuid, String>,
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            display_names: Result<Vec<String>, String>,
-            emails: Result<Vec<String>, String>,
-            id: Result<uuid::Uuid, String>,
-            provider: Result<String, String>,
-            provider_id: Result<String, String>,
-            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-        }
+    ///  }
 
-        impl Default for ApiUserProvider {
-            fn default() -> Self {
-                Self {
-                    api_user_id: Err("no value supplied for api_user_id".to_string()),
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    display_names: Err("no value supplied for display_names".to_string()),
-                    emails: Err("no value supplied for emails".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    provider: Err("no value supplied for provider".to_string()),
-                    provider_id: Err("no value supplied for provider_id".to_string()),
-                    updated_at: Err("no value supplied for updated_at".to_string()),
-                }
-            }
-        }
+    /// }
 
-        impl ApiUserProvider {
-            pub fn api_user_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.api_user_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for api_user_id: {}", e));
-                self
-            }
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn display_names<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.display_names = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for display_names: {}", e)
-                });
-                self
-            }
-            pub fn emails<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.emails = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for emails: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn provider<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.provider = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for provider: {}", e));
-                self
-            }
-            pub fn provider_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.provider_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for provider_id: {}", e));
-                self
-            }
-            pub fn updated_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.updated_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
-                self
-            }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct SearchResultHit {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+        pub content: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub formatted: Option<FormattedSearchResultHit>,
+        pub hierarchy: [Option<String>; 6usize],
+        pub hierarchy_radio: [Option<String>; 6usize],
+        pub object_id: String,
+        pub rfd_number: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub url: Option<String>,
+    }
+
+    impl From<&SearchResultHit> for SearchResultHit {
+        fn from(value: &SearchResultHit) -> Self {
+            value.clone()
         }
+    }
 
-        impl std::convert::TryFrom<ApiUserProvider> for super::ApiUserProvider {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ApiUserProvider) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    api_user_id: value.api_user_id?,
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    display_names: value.display_names?,
-                    emails: value.emails?,
-                    id: value.id?,
-                    provider: value.provider?,
-                    provider_id: value.provider_id?,
-                    updated_at: value.updated_at?,
-                })
-            }
+    impl SearchResultHit {
+        pub fn builder() -> builder::SearchResultHit {
+            Default::default()
         }
+    }
 
-        impl From<super::ApiUserProvider> for ApiUserProvider {
-            fn from(value: super::ApiUserProvider) -> Self {
-                Self {
-                    api_user_id: Ok(value.api_user_id),
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    display_names: Ok(value.display_names),
-                    emails: Ok(value.emails),
-                    id: Ok(value.id),
-                    provider: Ok(value.provider),
-                    provider_id: Ok(value.provider_id),
-                    updated_at: Ok(value.updated_at),
-                }
-            }
-        }
+    /// SearchResults
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "hits",
+    ///    "query"
+    ///  ],
+    ///  "properties": {
+    ///    "hits": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/SearchResultHit"
+    ///      }
 
-        #[derive(Clone, Debug)]
-        pub struct ApiUserUpdateParams {
-            groups: Result<Vec<uuid::Uuid>, String>,
-            permissions: Result<super::PermissionsForApiPermission, String>,
-        }
+    ///    },
+    ///    "limit": {
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ],
+    ///      "format": "uint",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "offset": {
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ],
+    ///      "format": "uint",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "query": {
+    ///      "type": "string"
+    ///    }
 
-        impl Default for ApiUserUpdateParams {
-            fn default() -> Self {
-                Self {
-                    groups: Err("no value supplied for groups".to_string()),
-                    permissions: Err("no value supplied for permissions".to_string()),
-                }
-            }
-        }
+    ///  }
 
-        impl ApiUserUpdateParams {
-            pub fn groups<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<uuid::Uuid>>,
-                T::Error: std::fmt::Display,
-            {
-                self.groups = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for groups: {}", e));
-                self
-            }
-            pub fn permissions<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::PermissionsForApiPermission>,
-                T::Error: std::fmt::Display,
-            {
-                self.permissions = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
-                self
-            }
-        }
+    /// }
 
-        impl std::convert::TryFrom<ApiUserUpdateParams> for super::ApiUserUpdateParams {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ApiUserUpdateParams) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    groups: value.groups?,
-                    permissions: value.permissions?,
-                })
-            }
-        }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct SearchResults {
+        pub hits: Vec<SearchResultHit>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub limit: Option<u32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<u32>,
+        pub query: String,
+    }
 
-        impl From<super::ApiUserUpdateParams> for ApiUserUpdateParams {
-            fn from(value: super::ApiUserUpdateParams) -> Self {
-                Self {
-                    groups: Ok(value.groups),
-                    permissions: Ok(value.permissions),
-                }
-            }
+    impl From<&SearchResults> for SearchResults {
+        fn from(value: &SearchResults) -> Self {
+            value.clone()
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct CreateMapper {
-            max_activations: Result<Option<i32>, String>,
-            name: Result<String, String>,
-            rule: Result<super::MappingRules, String>,
+    impl SearchResults {
+        pub fn builder() -> builder::SearchResults {
+            Default::default()
         }
+    }
 
-        impl Default for CreateMapper {
-            fn default() -> Self {
-                Self {
-                    max_activations: Ok(Default::default()),
-                    name: Err("no value supplied for name".to_string()),
-                    rule: Err("no value supplied for rule".to_string()),
-                }
-            }
-        }
+    /// SearchSuggestion
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "rfd_number"
+    ///  ],
+    ///  "properties": {
+    ///    "anchor": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "rfd_number": {
+    ///      "type": "integer",
+    ///      "format": "uint64",
+    ///      "minimum": 0.0
+    ///    },
+    ///    "section": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "title": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
 
-        impl CreateMapper {
-            pub fn max_activations<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<i32>>,
-                T::Error: std::fmt::Display,
-            {
-                self.max_activations = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for max_activations: {}", e)
-                });
-                self
-            }
-            pub fn name<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.name = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
-                self
-            }
-            pub fn rule<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::MappingRules>,
-                T::Error: std::fmt::Display,
-            {
-                self.rule = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for rule: {}", e));
-                self
-            }
-        }
+    ///  }
 
-        impl std::convert::TryFrom<CreateMapper> for super::CreateMapper {
-            type Error = super::error::ConversionError;
-            fn try_from(value: CreateMapper) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    max_activations: value.max_activations?,
-                    name: value.name?,
-                    rule: value.rule?,
-                })
-            }
-        }
+    /// }
 
-        impl From<super::CreateMapper> for CreateMapper {
-            fn from(value: super::CreateMapper) -> Self {
-                Self {
-                    max_activations: Ok(value.max_activations),
-                    name: Ok(value.name),
-                    rule: Ok(value.rule),
-                }
-            }
-        }
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct SearchSuggestion {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+        pub rfd_number: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub section: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub title: Option<String>,
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct Error {
-            error_code: Result<Option<String>, String>,
-            message: Result<String, String>,
-            request_id: Result<String, String>,
+    impl From<&SearchSuggestion> for SearchSuggestion {
+        fn from(value: &SearchSuggestion) -> Self {
+            value.clone()
         }
+    }
 
-        impl Default for Error {
-            fn default() -> Self {
-                Self {
-                    error_code: Ok(Default::default()),
-                    message: Err("no value supplied for message".to_string()),
-                    request_id: Err("no value supplied for request_id".to_string()),
-                }
-            }
+    impl SearchSuggestion {
+        pub fn builder() -> builder::SearchSuggestion {
+            Default::default()
         }
+    }
 
-        impl Error {
-            pub fn error_code<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.error_code = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for error_code: {}", e));
-                self
-            }
-            pub fn message<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.message = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for message: {}", e));
-                self
-            }
-            pub fn request_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.request_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for request_id: {}", e));
-                self
-            }
+    /// SecretString
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "string"
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Serialize,
+        schemars :: JsonSchema,
+    )]
+    pub struct SecretString(pub String);
+    impl std::ops::Deref for SecretString {
+        type Target = String;
+        fn deref(&self) -> &String {
+            &self.0
         }
+    }
 
-        impl std::convert::TryFrom<Error> for super::Error {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Error) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    error_code: value.error_code?,
-                    message: value.message?,
-                    request_id: value.request_id?,
-                })
-            }
+    impl From<SecretString> for String {
+        fn from(value: SecretString) -> Self {
+            value.0
         }
+    }
 
-        impl From<super::Error> for Error {
-            fn from(value: super::Error) -> Self {
-                Self {
-                    error_code: Ok(value.error_code),
-                    message: Ok(value.message),
-                    request_id: Ok(value.request_id),
-                }
-            }
+    impl From<&SecretString> for SecretString {
+        fn from(value: &SecretString) -> Self {
+            value.clone()
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct FormattedSearchResultHit {
-            anchor: Result<Option<String>, String>,
-            content: Result<Option<String>, String>,
-            hierarchy: Result<[Option<String>; 6usize], String>,
-            hierarchy_radio: Result<[Option<String>; 6usize], String>,
-            object_id: Result<String, String>,
-            rfd_number: Result<u64, String>,
-            url: Result<Option<String>, String>,
+    impl From<String> for SecretString {
+        fn from(value: String) -> Self {
+            Self(value)
         }
+    }
 
-        impl Default for FormattedSearchResultHit {
-            fn default() -> Self {
-                Self {
-                    anchor: Ok(Default::default()),
-                    content: Ok(Default::default()),
-                    hierarchy: Err("no value supplied for hierarchy".to_string()),
-                    hierarchy_radio: Err("no value supplied for hierarchy_radio".to_string()),
-                    object_id: Err("no value supplied for object_id".to_string()),
-                    rfd_number: Err("no value supplied for rfd_number".to_string()),
-                    url: Ok(Default::default()),
-                }
-            }
+    impl std::str::FromStr for SecretString {
+        type Err = std::convert::Infallible;
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            Ok(Self(value.to_string()))
         }
+    }
 
-        impl FormattedSearchResultHit {
-            pub fn anchor<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.anchor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
-                self
-            }
-            pub fn content<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.content = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for content: {}", e));
-                self
-            }
-            pub fn hierarchy<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<[Option<String>; 6usize]>,
-                T::Error: std::fmt::Display,
-            {
-                self.hierarchy = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for hierarchy: {}", e));
-                self
-            }
-            pub fn hierarchy_radio<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<[Option<String>; 6usize]>,
-                T::Error: std::fmt::Display,
-            {
-                self.hierarchy_radio = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for hierarchy_radio: {}", e)
-                });
-                self
-            }
-            pub fn object_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.object_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for object_id: {}", e));
-                self
-            }
-            pub fn rfd_number<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<u64>,
-                T::Error: std::fmt::Display,
-            {
-                self.rfd_number = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
-                self
-            }
-            pub fn url<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.url = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for url: {}", e));
-                self
-            }
+    impl ToString for SecretString {
+        fn to_string(&self) -> String {
+            self.0.to_string()
         }
+    }
 
-        impl std::convert::TryFrom<FormattedSearchResultHit> for super::FormattedSearchResultHit {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: FormattedSearchResultHit,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    anchor: value.anchor?,
-                    content: value.content?,
-                    hierarchy: value.hierarchy?,
-                    hierarchy_radio: value.hierarchy_radio?,
-                    object_id: value.object_id?,
-                    rfd_number: value.rfd_number?,
-                    url: value.url?,
-                })
-            }
+    /// UpdateLabel
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "properties": {
+    ///    "color": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "description": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct UpdateLabel {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub color: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+    }
+
+    impl From<&UpdateLabel> for UpdateLabel {
+        fn from(value: &UpdateLabel) -> Self {
+            value.clone()
         }
+    }
 
-        impl From<super::FormattedSearchResultHit> for FormattedSearchResultHit {
-            fn from(value: super::FormattedSearchResultHit) -> Self {
-                Self {
-                    anchor: Ok(value.anchor),
-                    content: Ok(value.content),
-                    hierarchy: Ok(value.hierarchy),
-                    hierarchy_radio: Ok(value.hierarchy_radio),
-                    object_id: Ok(value.object_id),
-                    rfd_number: Ok(value.rfd_number),
-                    url: Ok(value.url),
-                }
-            }
+    impl UpdateLabel {
+        pub fn builder() -> builder::UpdateLabel {
+            Default::default()
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct FullRfd {
-            authors: Result<Option<String>, String>,
-            commit: Result<super::CommitSha, String>,
-            committed_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            content: Result<String, String>,
-            discussion: Result<Option<String>, String>,
-            format: Result<super::ContentFormat, String>,
-            id: Result<uuid::Uuid, String>,
-            labels: Result<Option<String>, String>,
-            link: Result<Option<String>, String>,
-            pdfs: Result<Vec<super::FullRfdPdfEntry>, String>,
-            rfd_number: Result<i32, String>,
-            sha: Result<super::FileSha, String>,
+    /// UpdateWebhookSubscription
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "object",
+    ///  "required": [
+    ///    "events",
+    ///    "url"
+    ///  ],
+    ///  "properties": {
+    ///    "events": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/WebhookEvent"
+    ///      }
+
+    ///    },
+    ///    "rfds": {
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "int32"
+    ///      }
+
+    ///    },
+    ///    "secret": {
+    ///      "description": "Replacement signing secret. The existing secret is
+    /// kept when this is omitted",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "url": {
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct UpdateWebhookSubscription {
+        pub events: Vec<WebhookEvent>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub rfds: Vec<i32>,
+        /// Replacement signing secret. The existing secret is kept when this is
+        /// omitted
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub secret: Option<String>,
+        pub url: String,
+    }
+
+    impl From<&UpdateWebhookSubscription> for UpdateWebhookSubscription {
+        fn from(value: &UpdateWebhookSubscription) -> Self {
+            value.clone()
+        }
+    }
+
+    impl UpdateWebhookSubscription {
+        pub fn builder() -> builder::UpdateWebhookSubscription {
+            Default::default()
+        }
+    }
+
+    /// Visibility
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "public",
+    ///    "private"
+    ///  ]
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        Deserialize,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Serialize,
+        schemars :: JsonSchema,
+    )]
+    pub enum Visibility {
+        #[serde(rename = "public")]
+        Public,
+        #[serde(rename = "private")]
+        Private,
+    }
+
+    impl From<&Visibility> for Visibility {
+        fn from(value: &Visibility) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ToString for Visibility {
+        fn to_string(&self) -> String {
+            match *self {
+                Self::Public => "public".to_string(),
+                Self::Private => "private".to_string(),
+            }
+        }
+    }
+
+    impl std::str::FromStr for Visibility {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+            match value {
+                "public" => Ok(Self::Public),
+                "private" => Ok(Self::Private),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl std::convert::TryFrom<&str> for Visibility {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl std::convert::TryFrom<&String> for Visibility {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl std::convert::TryFrom<String> for Visibility {
+        type Error = self::error::ConversionError;
+        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    /// A single event queued for delivery to a webhook subscription. Deliveries
+    /// double as the log of attempts that have been made to send the event
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "description": "A single event queued for delivery to a webhook
+    /// subscription. Deliveries double as the log of attempts that have been
+    /// made to send the event",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "attempts",
+    ///    "created_at",
+    ///    "event",
+    ///    "id",
+    ///    "payload",
+    ///    "updated_at",
+    ///    "webhook_subscription_id"
+    ///  ],
+    ///  "properties": {
+    ///    "attempts": {
+    ///      "type": "integer",
+    ///      "format": "int32"
+    ///    },
+    ///    "created_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "delivered_at": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
+    ///    "event": {
+    ///      "$ref": "#/components/schemas/WebhookEvent"
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "last_error": {
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ]
+    ///    },
+    ///    "next_attempt_at": {
+    ///      "description": "When the next attempt is due. This is cleared once
+    /// the event is delivered or the delivery has run out of attempts",
+    ///      "type": [
+    ///        "string",
+    ///        "null"
+    ///      ],
+    ///      "format": "date-time"
+    ///    },
+    ///    "payload": {},
+    ///    "response_status": {
+    ///      "description": "HTTP status returned by the most recent attempt",
+    ///      "type": [
+    ///        "integer",
+    ///        "null"
+    ///      ],
+    ///      "format": "int32"
+    ///    },
+    ///    "updated_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "webhook_subscription_id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct WebhookDelivery {
+        pub attempts: i32,
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub delivered_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        pub event: WebhookEvent,
+        pub id: uuid::Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub last_error: Option<String>,
+        /// When the next attempt is due. This is cleared once the event is
+        /// delivered or the delivery has run out of attempts
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub next_attempt_at: Option<chrono::DateTime<chrono::offset::Utc>>,
+        pub payload: serde_json::Value,
+        /// HTTP status returned by the most recent attempt
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub response_status: Option<i32>,
+        pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+        pub webhook_subscription_id: uuid::Uuid,
+    }
+
+    impl From<&WebhookDelivery> for WebhookDelivery {
+        fn from(value: &WebhookDelivery) -> Self {
+            value.clone()
+        }
+    }
+
+    impl WebhookDelivery {
+        pub fn builder() -> builder::WebhookDelivery {
+            Default::default()
+        }
+    }
+
+    /// WebhookEvent
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "type": "string",
+    ///  "enum": [
+    ///    "created",
+    ///    "state_changed",
+    ///    "content_updated",
+    ///    "visibility_changed"
+    ///  ]
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        Deserialize,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Serialize,
+        schemars :: JsonSchema,
+    )]
+    pub enum WebhookEvent {
+        #[serde(rename = "created")]
+        Created,
+        #[serde(rename = "state_changed")]
+        StateChanged,
+        #[serde(rename = "content_updated")]
+        ContentUpdated,
+        #[serde(rename = "visibility_changed")]
+        VisibilityChanged,
+    }
+
+    impl From<&WebhookEvent> for WebhookEvent {
+        fn from(value: &WebhookEvent) -> Self {
+            value.clone()
+        }
+    }
+
+    impl ToString for WebhookEvent {
+        fn to_string(&self) -> String {
+            match *self {
+                Self::Created => "created".to_string(),
+                Self::StateChanged => "state_changed".to_string(),
+                Self::ContentUpdated => "content_updated".to_string(),
+                Self::VisibilityChanged => "visibility_changed".to_string(),
+            }
+        }
+    }
+
+    impl std::str::FromStr for WebhookEvent {
+        type Err = self::error::ConversionError;
+        fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+            match value {
+                "created" => Ok(Self::Created),
+                "state_changed" => Ok(Self::StateChanged),
+                "content_updated" => Ok(Self::ContentUpdated),
+                "visibility_changed" => Ok(Self::VisibilityChanged),
+                _ => Err("invalid value".into()),
+            }
+        }
+    }
+
+    impl std::convert::TryFrom<&str> for WebhookEvent {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &str) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl std::convert::TryFrom<&String> for WebhookEvent {
+        type Error = self::error::ConversionError;
+        fn try_from(value: &String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    impl std::convert::TryFrom<String> for WebhookEvent {
+        type Error = self::error::ConversionError;
+        fn try_from(value: String) -> Result<Self, self::error::ConversionError> {
+            value.parse()
+        }
+    }
+
+    /// A webhook subscription. The signing secret is never returned
+    ///
+    /// <details><summary>JSON schema</summary>
+    ///
+    /// ```json
+    /// {
+    ///  "description": "A webhook subscription. The signing secret is never
+    /// returned",
+    ///  "type": "object",
+    ///  "required": [
+    ///    "created_at",
+    ///    "events",
+    ///    "id",
+    ///    "updated_at",
+    ///    "url"
+    ///  ],
+    ///  "properties": {
+    ///    "created_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "events": {
+    ///      "type": "array",
+    ///      "items": {
+    ///        "$ref": "#/components/schemas/WebhookEvent"
+    ///      }
+
+    ///    },
+    ///    "id": {
+    ///      "type": "string",
+    ///      "format": "uuid"
+    ///    },
+    ///    "rfds": {
+    ///      "type": [
+    ///        "array",
+    ///        "null"
+    ///      ],
+    ///      "items": {
+    ///        "type": "integer",
+    ///        "format": "int32"
+    ///      }
+
+    ///    },
+    ///    "updated_at": {
+    ///      "type": "string",
+    ///      "format": "date-time"
+    ///    },
+    ///    "url": {
+    ///      "type": "string"
+    ///    }
+
+    ///  }
+
+    /// }
+
+    /// ```
+    /// </details>
+    #[derive(Clone, Debug, Deserialize, Serialize, schemars :: JsonSchema)]
+    pub struct WebhookSubscriptionResponse {
+        pub created_at: chrono::DateTime<chrono::offset::Utc>,
+        pub events: Vec<WebhookEvent>,
+        pub id: uuid::Uuid,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub rfds: Vec<i32>,
+        pub updated_at: chrono::DateTime<chrono::offset::Utc>,
+        pub url: String,
+    }
+
+    impl From<&WebhookSubscriptionResponse> for WebhookSubscriptionResponse {
+        fn from(value: &WebhookSubscriptionResponse) -> Self {
+            value.clone()
+        }
+    }
+
+    impl WebhookSubscriptionResponse {
+        pub fn builder() -> builder::WebhookSubscriptionResponse {
+            Default::default()
+        }
+    }
+
+    /// Types for composing complex structures.
+    pub mod builder {
+        #[derive(Clone, Debug)]
+        pub struct AccessGroupForApiPermissionResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            name: Result<String, String>,
+            permissions: Result<super::PermissionsForApiPermissionResponse, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for AccessGroupForApiPermissionResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    permissions: Err("no value supplied for permissions".to_string()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                }
+            }
+        }
+
+        impl AccessGroupForApiPermissionResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::PermissionsForApiPermissionResponse>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<AccessGroupForApiPermissionResponse>
+            for super::AccessGroupForApiPermissionResponse
+        {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: AccessGroupForApiPermissionResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    id: value.id?,
+                    name: value.name?,
+                    permissions: value.permissions?,
+                    updated_at: value.updated_at?,
+                })
+            }
+        }
+
+        impl From<super::AccessGroupForApiPermissionResponse> for AccessGroupForApiPermissionResponse {
+            fn from(value: super::AccessGroupForApiPermissionResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    id: Ok(value.id),
+                    name: Ok(value.name),
+                    permissions: Ok(value.permissions),
+                    updated_at: Ok(value.updated_at),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct AccessGroupUpdateParams {
+            name: Result<String, String>,
+            permissions: Result<super::PermissionsForApiPermission, String>,
+        }
+
+        impl Default for AccessGroupUpdateParams {
+            fn default() -> Self {
+                Self {
+                    name: Err("no value supplied for name".to_string()),
+                    permissions: Err("no value supplied for permissions".to_string()),
+                }
+            }
+        }
+
+        impl AccessGroupUpdateParams {
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::PermissionsForApiPermission>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<AccessGroupUpdateParams> for super::AccessGroupUpdateParams {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: AccessGroupUpdateParams,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    name: value.name?,
+                    permissions: value.permissions?,
+                })
+            }
+        }
+
+        impl From<super::AccessGroupUpdateParams> for AccessGroupUpdateParams {
+            fn from(value: super::AccessGroupUpdateParams) -> Self {
+                Self {
+                    name: Ok(value.name),
+                    permissions: Ok(value.permissions),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct AccessTokenExchangeRequest {
+            device_code: Result<String, String>,
+            expires_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            grant_type: Result<String, String>,
+        }
+
+        impl Default for AccessTokenExchangeRequest {
+            fn default() -> Self {
+                Self {
+                    device_code: Err("no value supplied for device_code".to_string()),
+                    expires_at: Ok(Default::default()),
+                    grant_type: Err("no value supplied for grant_type".to_string()),
+                }
+            }
+        }
+
+        impl AccessTokenExchangeRequest {
+            pub fn device_code<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.device_code = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for device_code: {}", e));
+                self
+            }
+            pub fn expires_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expires_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for expires_at: {}", e));
+                self
+            }
+            pub fn grant_type<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.grant_type = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for grant_type: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<AccessTokenExchangeRequest> for super::AccessTokenExchangeRequest {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: AccessTokenExchangeRequest,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    device_code: value.device_code?,
+                    expires_at: value.expires_at?,
+                    grant_type: value.grant_type?,
+                })
+            }
+        }
+
+        impl From<super::AccessTokenExchangeRequest> for AccessTokenExchangeRequest {
+            fn from(value: super::AccessTokenExchangeRequest) -> Self {
+                Self {
+                    device_code: Ok(value.device_code),
+                    expires_at: Ok(value.expires_at),
+                    grant_type: Ok(value.grant_type),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct AddGroupBody {
+            group_id: Result<uuid::Uuid, String>,
+        }
+
+        impl Default for AddGroupBody {
+            fn default() -> Self {
+                Self {
+                    group_id: Err("no value supplied for group_id".to_string()),
+                }
+            }
+        }
+
+        impl AddGroupBody {
+            pub fn group_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.group_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for group_id: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<AddGroupBody> for super::AddGroupBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: AddGroupBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    group_id: value.group_id?,
+                })
+            }
+        }
+
+        impl From<super::AddGroupBody> for AddGroupBody {
+            fn from(value: super::AddGroupBody) -> Self {
+                Self {
+                    group_id: Ok(value.group_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct AddOAuthClientRedirectBody {
+            redirect_uri: Result<String, String>,
+        }
+
+        impl Default for AddOAuthClientRedirectBody {
+            fn default() -> Self {
+                Self {
+                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
+                }
+            }
+        }
+
+        impl AddOAuthClientRedirectBody {
+            pub fn redirect_uri<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.redirect_uri = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for redirect_uri: {}", e)
+                });
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<AddOAuthClientRedirectBody> for super::AddOAuthClientRedirectBody {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: AddOAuthClientRedirectBody,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    redirect_uri: value.redirect_uri?,
+                })
+            }
+        }
+
+        impl From<super::AddOAuthClientRedirectBody> for AddOAuthClientRedirectBody {
+            fn from(value: super::AddOAuthClientRedirectBody) -> Self {
+                Self {
+                    redirect_uri: Ok(value.redirect_uri),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ApiKeyCreateParams {
+            expires_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
+        }
+
+        impl Default for ApiKeyCreateParams {
+            fn default() -> Self {
+                Self {
+                    expires_at: Err("no value supplied for expires_at".to_string()),
+                    permissions: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ApiKeyCreateParams {
+            pub fn expires_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expires_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for expires_at: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ApiKeyCreateParams> for super::ApiKeyCreateParams {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ApiKeyCreateParams) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    expires_at: value.expires_at?,
+                    permissions: value.permissions?,
+                })
+            }
+        }
+
+        impl From<super::ApiKeyCreateParams> for ApiKeyCreateParams {
+            fn from(value: super::ApiKeyCreateParams) -> Self {
+                Self {
+                    expires_at: Ok(value.expires_at),
+                    permissions: Ok(value.permissions),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ApiKeyResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            id: Result<uuid::Uuid, String>,
+            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
+        }
+
+        impl Default for ApiKeyResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    permissions: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl ApiKeyResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ApiKeyResponse> for super::ApiKeyResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ApiKeyResponse) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    id: value.id?,
+                    permissions: value.permissions?,
+                })
+            }
+        }
+
+        impl From<super::ApiKeyResponse> for ApiKeyResponse {
+            fn from(value: super::ApiKeyResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    id: Ok(value.id),
+                    permissions: Ok(value.permissions),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ApiUserForApiPermissionResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            groups: Result<Vec<uuid::Uuid>, String>,
+            id: Result<uuid::Uuid, String>,
+            permissions: Result<super::PermissionsForApiPermissionResponse, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for ApiUserForApiPermissionResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    groups: Err("no value supplied for groups".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    permissions: Err("no value supplied for permissions".to_string()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                }
+            }
+        }
+
+        impl ApiUserForApiPermissionResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn groups<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<uuid::Uuid>>,
+                T::Error: std::fmt::Display,
+            {
+                self.groups = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for groups: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::PermissionsForApiPermissionResponse>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ApiUserForApiPermissionResponse>
+            for super::ApiUserForApiPermissionResponse
+        {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: ApiUserForApiPermissionResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    groups: value.groups?,
+                    id: value.id?,
+                    permissions: value.permissions?,
+                    updated_at: value.updated_at?,
+                })
+            }
+        }
+
+        impl From<super::ApiUserForApiPermissionResponse> for ApiUserForApiPermissionResponse {
+            fn from(value: super::ApiUserForApiPermissionResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    groups: Ok(value.groups),
+                    id: Ok(value.id),
+                    permissions: Ok(value.permissions),
+                    updated_at: Ok(value.updated_at),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ApiUserProvider {
+            api_user_id: Result<uuid::Uuid, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            display_names: Result<Vec<String>, String>,
+            emails: Result<Vec<String>, String>,
+            id: Result<uuid::Uuid, String>,
+            provider: Result<String, String>,
+            provider_id: Result<String, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for ApiUserProvider {
+            fn default() -> Self {
+                Self {
+                    api_user_id: Err("no value supplied for api_user_id".to_string()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    display_names: Err("no value supplied for display_names".to_string()),
+                    emails: Err("no value supplied for emails".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    provider: Err("no value supplied for provider".to_string()),
+                    provider_id: Err("no value supplied for provider_id".to_string()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                }
+            }
+        }
+
+        impl ApiUserProvider {
+            pub fn api_user_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.api_user_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for api_user_id: {}", e));
+                self
+            }
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn display_names<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.display_names = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for display_names: {}", e)
+                });
+                self
+            }
+            pub fn emails<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.emails = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for emails: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn provider<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.provider = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for provider: {}", e));
+                self
+            }
+            pub fn provider_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.provider_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for provider_id: {}", e));
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ApiUserProvider> for super::ApiUserProvider {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ApiUserProvider) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    api_user_id: value.api_user_id?,
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    display_names: value.display_names?,
+                    emails: value.emails?,
+                    id: value.id?,
+                    provider: value.provider?,
+                    provider_id: value.provider_id?,
+                    updated_at: value.updated_at?,
+                })
+            }
+        }
+
+        impl From<super::ApiUserProvider> for ApiUserProvider {
+            fn from(value: super::ApiUserProvider) -> Self {
+                Self {
+                    api_user_id: Ok(value.api_user_id),
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    display_names: Ok(value.display_names),
+                    emails: Ok(value.emails),
+                    id: Ok(value.id),
+                    provider: Ok(value.provider),
+                    provider_id: Ok(value.provider_id),
+                    updated_at: Ok(value.updated_at),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ApiUserUpdateParams {
+            groups: Result<Vec<uuid::Uuid>, String>,
+            permissions: Result<super::PermissionsForApiPermission, String>,
+        }
+
+        impl Default for ApiUserUpdateParams {
+            fn default() -> Self {
+                Self {
+                    groups: Err("no value supplied for groups".to_string()),
+                    permissions: Err("no value supplied for permissions".to_string()),
+                }
+            }
+        }
+
+        impl ApiUserUpdateParams {
+            pub fn groups<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<uuid::Uuid>>,
+                T::Error: std::fmt::Display,
+            {
+                self.groups = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for groups: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::PermissionsForApiPermission>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ApiUserUpdateParams> for super::ApiUserUpdateParams {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ApiUserUpdateParams) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    groups: value.groups?,
+                    permissions: value.permissions?,
+                })
+            }
+        }
+
+        impl From<super::ApiUserUpdateParams> for ApiUserUpdateParams {
+            fn from(value: super::ApiUserUpdateParams) -> Self {
+                Self {
+                    groups: Ok(value.groups),
+                    permissions: Ok(value.permissions),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct CreateMapper {
+            max_activations: Result<Option<i32>, String>,
+            name: Result<String, String>,
+            rule: Result<super::MappingRules, String>,
+        }
+
+        impl Default for CreateMapper {
+            fn default() -> Self {
+                Self {
+                    max_activations: Ok(Default::default()),
+                    name: Err("no value supplied for name".to_string()),
+                    rule: Err("no value supplied for rule".to_string()),
+                }
+            }
+        }
+
+        impl CreateMapper {
+            pub fn max_activations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<i32>>,
+                T::Error: std::fmt::Display,
+            {
+                self.max_activations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for max_activations: {}", e)
+                });
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn rule<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::MappingRules>,
+                T::Error: std::fmt::Display,
+            {
+                self.rule = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rule: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<CreateMapper> for super::CreateMapper {
+            type Error = super::error::ConversionError;
+            fn try_from(value: CreateMapper) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    max_activations: value.max_activations?,
+                    name: value.name?,
+                    rule: value.rule?,
+                })
+            }
+        }
+
+        impl From<super::CreateMapper> for CreateMapper {
+            fn from(value: super::CreateMapper) -> Self {
+                Self {
+                    max_activations: Ok(value.max_activations),
+                    name: Ok(value.name),
+                    rule: Ok(value.rule),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct CreateWebhookSubscription {
+            events: Result<Vec<super::WebhookEvent>, String>,
+            rfds: Result<Vec<i32>, String>,
+            secret: Result<String, String>,
+            url: Result<String, String>,
+        }
+
+        impl Default for CreateWebhookSubscription {
+            fn default() -> Self {
+                Self {
+                    events: Err("no value supplied for events".to_string()),
+                    rfds: Ok(Default::default()),
+                    secret: Err("no value supplied for secret".to_string()),
+                    url: Err("no value supplied for url".to_string()),
+                }
+            }
+        }
+
+        impl CreateWebhookSubscription {
+            pub fn events<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::WebhookEvent>>,
+                T::Error: std::fmt::Display,
+            {
+                self.events = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for events: {}", e));
+                self
+            }
+            pub fn rfds<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<i32>>,
+                T::Error: std::fmt::Display,
+            {
+                self.rfds = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rfds: {}", e));
+                self
+            }
+            pub fn secret<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.secret = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for secret: {}", e));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<CreateWebhookSubscription> for super::CreateWebhookSubscription {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: CreateWebhookSubscription,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    events: value.events?,
+                    rfds: value.rfds?,
+                    secret: value.secret?,
+                    url: value.url?,
+                })
+            }
+        }
+
+        impl From<super::CreateWebhookSubscription> for CreateWebhookSubscription {
+            fn from(value: super::CreateWebhookSubscription) -> Self {
+                Self {
+                    events: Ok(value.events),
+                    rfds: Ok(value.rfds),
+                    secret: Ok(value.secret),
+                    url: Ok(value.url),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Error {
+            error_code: Result<Option<String>, String>,
+            message: Result<String, String>,
+            request_id: Result<String, String>,
+        }
+
+        impl Default for Error {
+            fn default() -> Self {
+                Self {
+                    error_code: Ok(Default::default()),
+                    message: Err("no value supplied for message".to_string()),
+                    request_id: Err("no value supplied for request_id".to_string()),
+                }
+            }
+        }
+
+        impl Error {
+            pub fn error_code<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.error_code = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for error_code: {}", e));
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
+                self
+            }
+            pub fn request_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.request_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for request_id: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Error> for super::Error {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Error) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    error_code: value.error_code?,
+                    message: value.message?,
+                    request_id: value.request_id?,
+                })
+            }
+        }
+
+        impl From<super::Error> for Error {
+            fn from(value: super::Error) -> Self {
+                Self {
+                    error_code: Ok(value.error_code),
+                    message: Ok(value.message),
+                    request_id: Ok(value.request_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct FeedTokenCreateParams {
+            expires_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for FeedTokenCreateParams {
+            fn default() -> Self {
+                Self {
+                    expires_at: Err("no value supplied for expires_at".to_string()),
+                }
+            }
+        }
+
+        impl FeedTokenCreateParams {
+            pub fn expires_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expires_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for expires_at: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<FeedTokenCreateParams> for super::FeedTokenCreateParams {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: FeedTokenCreateParams,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    expires_at: value.expires_at?,
+                })
+            }
+        }
+
+        impl From<super::FeedTokenCreateParams> for FeedTokenCreateParams {
+            fn from(value: super::FeedTokenCreateParams) -> Self {
+                Self {
+                    expires_at: Ok(value.expires_at),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct FormattedSearchResultHit {
+            anchor: Result<Option<String>, String>,
+            content: Result<Option<String>, String>,
+            hierarchy: Result<[Option<String>; 6usize], String>,
+            hierarchy_radio: Result<[Option<String>; 6usize], String>,
+            object_id: Result<String, String>,
+            rfd_number: Result<u64, String>,
+            url: Result<Option<String>, String>,
+        }
+
+        impl Default for FormattedSearchResultHit {
+            fn default() -> Self {
+                Self {
+                    anchor: Ok(Default::default()),
+                    content: Ok(Default::default()),
+                    hierarchy: Err("no value supplied for hierarchy".to_string()),
+                    hierarchy_radio: Err("no value supplied for hierarchy_radio".to_string()),
+                    object_id: Err("no value supplied for object_id".to_string()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    url: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl FormattedSearchResultHit {
+            pub fn anchor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.anchor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
+                self
+            }
+            pub fn content<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn hierarchy<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<[Option<String>; 6usize]>,
+                T::Error: std::fmt::Display,
+            {
+                self.hierarchy = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for hierarchy: {}", e));
+                self
+            }
+            pub fn hierarchy_radio<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<[Option<String>; 6usize]>,
+                T::Error: std::fmt::Display,
+            {
+                self.hierarchy_radio = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for hierarchy_radio: {}", e)
+                });
+                self
+            }
+            pub fn object_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.object_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for object_id: {}", e));
+                self
+            }
+            pub fn rfd_number<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u64>,
+                T::Error: std::fmt::Display,
+            {
+                self.rfd_number = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                self
+            }
+            pub fn url<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.url = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<FormattedSearchResultHit> for super::FormattedSearchResultHit {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: FormattedSearchResultHit,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    anchor: value.anchor?,
+                    content: value.content?,
+                    hierarchy: value.hierarchy?,
+                    hierarchy_radio: value.hierarchy_radio?,
+                    object_id: value.object_id?,
+                    rfd_number: value.rfd_number?,
+                    url: value.url?,
+                })
+            }
+        }
+
+        impl From<super::FormattedSearchResultHit> for FormattedSearchResultHit {
+            fn from(value: super::FormattedSearchResultHit) -> Self {
+                Self {
+                    anchor: Ok(value.anchor),
+                    content: Ok(value.content),
+                    hierarchy: Ok(value.hierarchy),
+                    hierarchy_radio: Ok(value.hierarchy_radio),
+                    object_id: Ok(value.object_id),
+                    rfd_number: Ok(value.rfd_number),
+                    url: Ok(value.url),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct FullRfd {
+            author_list: Result<Vec<super::RfdAuthorEntry>, String>,
+            authors: Result<Option<String>, String>,
+            commit: Result<super::CommitSha, String>,
+            committed_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            content: Result<String, String>,
+            discussion: Result<Option<String>, String>,
+            format: Result<super::ContentFormat, String>,
+            id: Result<uuid::Uuid, String>,
+            labels: Result<Option<String>, String>,
+            link: Result<Option<String>, String>,
+            pdfs: Result<Vec<super::FullRfdPdfEntry>, String>,
+            rfd_number: Result<i32, String>,
+            sha: Result<super::FileSha, String>,
+            state: Result<Option<String>, String>,
+            title: Result<String, String>,
+            visibility: Result<super::Visibility, String>,
+        }
+
+        impl Default for FullRfd {
+            fn default() -> Self {
+                Self {
+                    author_list: Err("no value supplied for author_list".to_string()),
+                    authors: Ok(Default::default()),
+                    commit: Err("no value supplied for commit".to_string()),
+                    committed_at: Err("no value supplied for committed_at".to_string()),
+                    content: Err("no value supplied for content".to_string()),
+                    discussion: Ok(Default::default()),
+                    format: Err("no value supplied for format".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    labels: Ok(Default::default()),
+                    link: Ok(Default::default()),
+                    pdfs: Err("no value supplied for pdfs".to_string()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    sha: Err("no value supplied for sha".to_string()),
+                    state: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                    visibility: Err("no value supplied for visibility".to_string()),
+                }
+            }
+        }
+
+        impl FullRfd {
+            pub fn author_list<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::RfdAuthorEntry>>,
+                T::Error: std::fmt::Display,
+            {
+                self.author_list = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for author_list: {}", e));
+                self
+            }
+            pub fn authors<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.authors = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for authors: {}", e));
+                self
+            }
+            pub fn commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::CommitSha>,
+                T::Error: std::fmt::Display,
+            {
+                self.commit = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for commit: {}", e));
+                self
+            }
+            pub fn committed_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.committed_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for committed_at: {}", e)
+                });
+                self
+            }
+            pub fn content<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn discussion<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.discussion = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for discussion: {}", e));
+                self
+            }
+            pub fn format<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ContentFormat>,
+                T::Error: std::fmt::Display,
+            {
+                self.format = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for format: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn labels<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.labels = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for labels: {}", e));
+                self
+            }
+            pub fn link<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.link = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                self
+            }
+            pub fn pdfs<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::FullRfdPdfEntry>>,
+                T::Error: std::fmt::Display,
+            {
+                self.pdfs = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for pdfs: {}", e));
+                self
+            }
+            pub fn rfd_number<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i32>,
+                T::Error: std::fmt::Display,
+            {
+                self.rfd_number = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                self
+            }
+            pub fn sha<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::FileSha>,
+                T::Error: std::fmt::Display,
+            {
+                self.sha = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for sha: {}", e));
+                self
+            }
+            pub fn state<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.state = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for state: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+            pub fn visibility<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::Visibility>,
+                T::Error: std::fmt::Display,
+            {
+                self.visibility = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<FullRfd> for super::FullRfd {
+            type Error = super::error::ConversionError;
+            fn try_from(value: FullRfd) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    author_list: value.author_list?,
+                    authors: value.authors?,
+                    commit: value.commit?,
+                    committed_at: value.committed_at?,
+                    content: value.content?,
+                    discussion: value.discussion?,
+                    format: value.format?,
+                    id: value.id?,
+                    labels: value.labels?,
+                    link: value.link?,
+                    pdfs: value.pdfs?,
+                    rfd_number: value.rfd_number?,
+                    sha: value.sha?,
+                    state: value.state?,
+                    title: value.title?,
+                    visibility: value.visibility?,
+                })
+            }
+        }
+
+        impl From<super::FullRfd> for FullRfd {
+            fn from(value: super::FullRfd) -> Self {
+                Self {
+                    author_list: Ok(value.author_list),
+                    authors: Ok(value.authors),
+                    commit: Ok(value.commit),
+                    committed_at: Ok(value.committed_at),
+                    content: Ok(value.content),
+                    discussion: Ok(value.discussion),
+                    format: Ok(value.format),
+                    id: Ok(value.id),
+                    labels: Ok(value.labels),
+                    link: Ok(value.link),
+                    pdfs: Ok(value.pdfs),
+                    rfd_number: Ok(value.rfd_number),
+                    sha: Ok(value.sha),
+                    state: Ok(value.state),
+                    title: Ok(value.title),
+                    visibility: Ok(value.visibility),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct FullRfdPdfEntry {
+            link: Result<String, String>,
+            source: Result<String, String>,
+        }
+
+        impl Default for FullRfdPdfEntry {
+            fn default() -> Self {
+                Self {
+                    link: Err("no value supplied for link".to_string()),
+                    source: Err("no value supplied for source".to_string()),
+                }
+            }
+        }
+
+        impl FullRfdPdfEntry {
+            pub fn link<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.link = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                self
+            }
+            pub fn source<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.source = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for source: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<FullRfdPdfEntry> for super::FullRfdPdfEntry {
+            type Error = super::error::ConversionError;
+            fn try_from(value: FullRfdPdfEntry) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    link: value.link?,
+                    source: value.source?,
+                })
+            }
+        }
+
+        impl From<super::FullRfdPdfEntry> for FullRfdPdfEntry {
+            fn from(value: super::FullRfdPdfEntry) -> Self {
+                Self {
+                    link: Ok(value.link),
+                    source: Ok(value.source),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GetUserResponse {
+            info: Result<super::ApiUserForApiPermissionResponse, String>,
+            providers: Result<Vec<super::ApiUserProvider>, String>,
+        }
+
+        impl Default for GetUserResponse {
+            fn default() -> Self {
+                Self {
+                    info: Err("no value supplied for info".to_string()),
+                    providers: Err("no value supplied for providers".to_string()),
+                }
+            }
+        }
+
+        impl GetUserResponse {
+            pub fn info<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ApiUserForApiPermissionResponse>,
+                T::Error: std::fmt::Display,
+            {
+                self.info = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for info: {}", e));
+                self
+            }
+            pub fn providers<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ApiUserProvider>>,
+                T::Error: std::fmt::Display,
+            {
+                self.providers = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for providers: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GetUserResponse> for super::GetUserResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GetUserResponse) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    info: value.info?,
+                    providers: value.providers?,
+                })
+            }
+        }
+
+        impl From<super::GetUserResponse> for GetUserResponse {
+            fn from(value: super::GetUserResponse) -> Self {
+                Self {
+                    info: Ok(value.info),
+                    providers: Ok(value.providers),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubCommit {
+            added: Result<Vec<String>, String>,
+            id: Result<String, String>,
+            modified: Result<Vec<String>, String>,
+            removed: Result<Vec<String>, String>,
+            timestamp: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for GitHubCommit {
+            fn default() -> Self {
+                Self {
+                    added: Err("no value supplied for added".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    modified: Err("no value supplied for modified".to_string()),
+                    removed: Err("no value supplied for removed".to_string()),
+                    timestamp: Err("no value supplied for timestamp".to_string()),
+                }
+            }
+        }
+
+        impl GitHubCommit {
+            pub fn added<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.added = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for added: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn modified<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.modified = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for modified: {}", e));
+                self
+            }
+            pub fn removed<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.removed = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for removed: {}", e));
+                self
+            }
+            pub fn timestamp<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.timestamp = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for timestamp: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubCommit> for super::GitHubCommit {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GitHubCommit) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    added: value.added?,
+                    id: value.id?,
+                    modified: value.modified?,
+                    removed: value.removed?,
+                    timestamp: value.timestamp?,
+                })
+            }
+        }
+
+        impl From<super::GitHubCommit> for GitHubCommit {
+            fn from(value: super::GitHubCommit) -> Self {
+                Self {
+                    added: Ok(value.added),
+                    id: Ok(value.id),
+                    modified: Ok(value.modified),
+                    removed: Ok(value.removed),
+                    timestamp: Ok(value.timestamp),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubCommitPayload {
+            commits: Result<Vec<super::GitHubCommit>, String>,
+            head_commit: Result<Option<super::GitHubCommit>, String>,
+            installation: Result<super::GitHubInstallation, String>,
+            ref_: Result<String, String>,
+            repository: Result<super::GitHubRepository, String>,
+            sender: Result<super::GitHubSender, String>,
+        }
+
+        impl Default for GitHubCommitPayload {
+            fn default() -> Self {
+                Self {
+                    commits: Err("no value supplied for commits".to_string()),
+                    head_commit: Ok(Default::default()),
+                    installation: Err("no value supplied for installation".to_string()),
+                    ref_: Err("no value supplied for ref_".to_string()),
+                    repository: Err("no value supplied for repository".to_string()),
+                    sender: Err("no value supplied for sender".to_string()),
+                }
+            }
+        }
+
+        impl GitHubCommitPayload {
+            pub fn commits<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::GitHubCommit>>,
+                T::Error: std::fmt::Display,
+            {
+                self.commits = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for commits: {}", e));
+                self
+            }
+            pub fn head_commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::GitHubCommit>>,
+                T::Error: std::fmt::Display,
+            {
+                self.head_commit = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for head_commit: {}", e));
+                self
+            }
+            pub fn installation<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::GitHubInstallation>,
+                T::Error: std::fmt::Display,
+            {
+                self.installation = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for installation: {}", e)
+                });
+                self
+            }
+            pub fn ref_<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.ref_ = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for ref_: {}", e));
+                self
+            }
+            pub fn repository<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::GitHubRepository>,
+                T::Error: std::fmt::Display,
+            {
+                self.repository = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for repository: {}", e));
+                self
+            }
+            pub fn sender<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::GitHubSender>,
+                T::Error: std::fmt::Display,
+            {
+                self.sender = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for sender: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubCommitPayload> for super::GitHubCommitPayload {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GitHubCommitPayload) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    commits: value.commits?,
+                    head_commit: value.head_commit?,
+                    installation: value.installation?,
+                    ref_: value.ref_?,
+                    repository: value.repository?,
+                    sender: value.sender?,
+                })
+            }
+        }
+
+        impl From<super::GitHubCommitPayload> for GitHubCommitPayload {
+            fn from(value: super::GitHubCommitPayload) -> Self {
+                Self {
+                    commits: Ok(value.commits),
+                    head_commit: Ok(value.head_commit),
+                    installation: Ok(value.installation),
+                    ref_: Ok(value.ref_),
+                    repository: Ok(value.repository),
+                    sender: Ok(value.sender),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubInstallation {
+            id: Result<u64, String>,
+            node_id: Result<String, String>,
+        }
+
+        impl Default for GitHubInstallation {
+            fn default() -> Self {
+                Self {
+                    id: Err("no value supplied for id".to_string()),
+                    node_id: Err("no value supplied for node_id".to_string()),
+                }
+            }
+        }
+
+        impl GitHubInstallation {
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u64>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn node_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.node_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubInstallation> for super::GitHubInstallation {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GitHubInstallation) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    id: value.id?,
+                    node_id: value.node_id?,
+                })
+            }
+        }
+
+        impl From<super::GitHubInstallation> for GitHubInstallation {
+            fn from(value: super::GitHubInstallation) -> Self {
+                Self {
+                    id: Ok(value.id),
+                    node_id: Ok(value.node_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubRepository {
+            default_branch: Result<String, String>,
+            id: Result<u64, String>,
+            name: Result<String, String>,
+            node_id: Result<String, String>,
+            owner: Result<super::GitHubRepositoryOwner, String>,
+        }
+
+        impl Default for GitHubRepository {
+            fn default() -> Self {
+                Self {
+                    default_branch: Err("no value supplied for default_branch".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    node_id: Err("no value supplied for node_id".to_string()),
+                    owner: Err("no value supplied for owner".to_string()),
+                }
+            }
+        }
+
+        impl GitHubRepository {
+            pub fn default_branch<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.default_branch = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for default_branch: {}", e)
+                });
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u64>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn node_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.node_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                self
+            }
+            pub fn owner<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::GitHubRepositoryOwner>,
+                T::Error: std::fmt::Display,
+            {
+                self.owner = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for owner: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubRepository> for super::GitHubRepository {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GitHubRepository) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    default_branch: value.default_branch?,
+                    id: value.id?,
+                    name: value.name?,
+                    node_id: value.node_id?,
+                    owner: value.owner?,
+                })
+            }
+        }
+
+        impl From<super::GitHubRepository> for GitHubRepository {
+            fn from(value: super::GitHubRepository) -> Self {
+                Self {
+                    default_branch: Ok(value.default_branch),
+                    id: Ok(value.id),
+                    name: Ok(value.name),
+                    node_id: Ok(value.node_id),
+                    owner: Ok(value.owner),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubRepositoryOwner {
+            login: Result<String, String>,
+        }
+
+        impl Default for GitHubRepositoryOwner {
+            fn default() -> Self {
+                Self {
+                    login: Err("no value supplied for login".to_string()),
+                }
+            }
+        }
+
+        impl GitHubRepositoryOwner {
+            pub fn login<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.login = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for login: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubRepositoryOwner> for super::GitHubRepositoryOwner {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: GitHubRepositoryOwner,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    login: value.login?,
+                })
+            }
+        }
+
+        impl From<super::GitHubRepositoryOwner> for GitHubRepositoryOwner {
+            fn from(value: super::GitHubRepositoryOwner) -> Self {
+                Self {
+                    login: Ok(value.login),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct GitHubSender {
+            id: Result<u64, String>,
+            login: Result<String, String>,
+            node_id: Result<String, String>,
+            type_: Result<String, String>,
+        }
+
+        impl Default for GitHubSender {
+            fn default() -> Self {
+                Self {
+                    id: Err("no value supplied for id".to_string()),
+                    login: Err("no value supplied for login".to_string()),
+                    node_id: Err("no value supplied for node_id".to_string()),
+                    type_: Err("no value supplied for type_".to_string()),
+                }
+            }
+        }
+
+        impl GitHubSender {
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u64>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn login<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.login = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for login: {}", e));
+                self
+            }
+            pub fn node_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.node_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                self
+            }
+            pub fn type_<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.type_ = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for type_: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<GitHubSender> for super::GitHubSender {
+            type Error = super::error::ConversionError;
+            fn try_from(value: GitHubSender) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    id: value.id?,
+                    login: value.login?,
+                    node_id: value.node_id?,
+                    type_: value.type_?,
+                })
+            }
+        }
+
+        impl From<super::GitHubSender> for GitHubSender {
+            fn from(value: super::GitHubSender) -> Self {
+                Self {
+                    id: Ok(value.id),
+                    login: Ok(value.login),
+                    node_id: Ok(value.node_id),
+                    type_: Ok(value.type_),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct InitialApiKeyResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            id: Result<uuid::Uuid, String>,
+            key: Result<super::SecretString, String>,
+            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
+        }
+
+        impl Default for InitialApiKeyResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    key: Err("no value supplied for key".to_string()),
+                    permissions: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl InitialApiKeyResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::SecretString>,
+                T::Error: std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {}", e));
+                self
+            }
+            pub fn permissions<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
+                T::Error: std::fmt::Display,
+            {
+                self.permissions = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<InitialApiKeyResponse> for super::InitialApiKeyResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: InitialApiKeyResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    id: value.id?,
+                    key: value.key?,
+                    permissions: value.permissions?,
+                })
+            }
+        }
+
+        impl From<super::InitialApiKeyResponse> for InitialApiKeyResponse {
+            fn from(value: super::InitialApiKeyResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    id: Ok(value.id),
+                    key: Ok(value.key),
+                    permissions: Ok(value.permissions),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct InitialFeedTokenResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            id: Result<uuid::Uuid, String>,
+            token: Result<super::SecretString, String>,
+        }
+
+        impl Default for InitialFeedTokenResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    token: Err("no value supplied for token".to_string()),
+                }
+            }
+        }
+
+        impl InitialFeedTokenResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn token<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::SecretString>,
+                T::Error: std::fmt::Display,
+            {
+                self.token = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for token: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<InitialFeedTokenResponse> for super::InitialFeedTokenResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: InitialFeedTokenResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    id: value.id?,
+                    token: value.token?,
+                })
+            }
+        }
+
+        impl From<super::InitialFeedTokenResponse> for InitialFeedTokenResponse {
+            fn from(value: super::InitialFeedTokenResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    id: Ok(value.id),
+                    token: Ok(value.token),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct InitialOAuthClientSecretResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            id: Result<uuid::Uuid, String>,
+            key: Result<super::SecretString, String>,
+        }
+
+        impl Default for InitialOAuthClientSecretResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    key: Err("no value supplied for key".to_string()),
+                }
+            }
+        }
+
+        impl InitialOAuthClientSecretResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn key<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::SecretString>,
+                T::Error: std::fmt::Display,
+            {
+                self.key = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for key: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<InitialOAuthClientSecretResponse>
+            for super::InitialOAuthClientSecretResponse
+        {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: InitialOAuthClientSecretResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    id: value.id?,
+                    key: value.key?,
+                })
+            }
+        }
+
+        impl From<super::InitialOAuthClientSecretResponse> for InitialOAuthClientSecretResponse {
+            fn from(value: super::InitialOAuthClientSecretResponse) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    id: Ok(value.id),
+                    key: Ok(value.key),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Jwk {
+            e: Result<String, String>,
+            kid: Result<String, String>,
+            kty: Result<String, String>,
+            n: Result<String, String>,
+            use_: Result<String, String>,
+        }
+
+        impl Default for Jwk {
+            fn default() -> Self {
+                Self {
+                    e: Err("no value supplied for e".to_string()),
+                    kid: Err("no value supplied for kid".to_string()),
+                    kty: Err("no value supplied for kty".to_string()),
+                    n: Err("no value supplied for n".to_string()),
+                    use_: Err("no value supplied for use_".to_string()),
+                }
+            }
+        }
+
+        impl Jwk {
+            pub fn e<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.e = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for e: {}", e));
+                self
+            }
+            pub fn kid<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.kid = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for kid: {}", e));
+                self
+            }
+            pub fn kty<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.kty = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for kty: {}", e));
+                self
+            }
+            pub fn n<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.n = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for n: {}", e));
+                self
+            }
+            pub fn use_<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.use_ = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for use_: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Jwk> for super::Jwk {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Jwk) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    e: value.e?,
+                    kid: value.kid?,
+                    kty: value.kty?,
+                    n: value.n?,
+                    use_: value.use_?,
+                })
+            }
+        }
+
+        impl From<super::Jwk> for Jwk {
+            fn from(value: super::Jwk) -> Self {
+                Self {
+                    e: Ok(value.e),
+                    kid: Ok(value.kid),
+                    kty: Ok(value.kty),
+                    n: Ok(value.n),
+                    use_: Ok(value.use_),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Jwks {
+            keys: Result<Vec<super::Jwk>, String>,
+        }
+
+        impl Default for Jwks {
+            fn default() -> Self {
+                Self {
+                    keys: Err("no value supplied for keys".to_string()),
+                }
+            }
+        }
+
+        impl Jwks {
+            pub fn keys<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::Jwk>>,
+                T::Error: std::fmt::Display,
+            {
+                self.keys = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for keys: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Jwks> for super::Jwks {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Jwks) -> Result<Self, super::error::ConversionError> {
+                Ok(Self { keys: value.keys? })
+            }
+        }
+
+        impl From<super::Jwks> for Jwks {
+            fn from(value: super::Jwks) -> Self {
+                Self {
+                    keys: Ok(value.keys),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Label {
+            color: Result<Option<String>, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            description: Result<Option<String>, String>,
+            id: Result<uuid::Uuid, String>,
+            name: Result<String, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        }
+
+        impl Default for Label {
+            fn default() -> Self {
+                Self {
+                    color: Ok(Default::default()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    description: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                }
+            }
+        }
+
+        impl Label {
+            pub fn color<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.color = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for color: {}", e));
+                self
+            }
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Label> for super::Label {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Label) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    color: value.color?,
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    description: value.description?,
+                    id: value.id?,
+                    name: value.name?,
+                    updated_at: value.updated_at?,
+                })
+            }
+        }
+
+        impl From<super::Label> for Label {
+            fn from(value: super::Label) -> Self {
+                Self {
+                    color: Ok(value.color),
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    description: Ok(value.description),
+                    id: Ok(value.id),
+                    name: Ok(value.name),
+                    updated_at: Ok(value.updated_at),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct LabelSummary {
+            color: Result<Option<String>, String>,
+            count: Result<u32, String>,
+            description: Result<Option<String>, String>,
+            id: Result<uuid::Uuid, String>,
+            name: Result<String, String>,
+        }
+
+        impl Default for LabelSummary {
+            fn default() -> Self {
+                Self {
+                    color: Ok(Default::default()),
+                    count: Err("no value supplied for count".to_string()),
+                    description: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                }
+            }
+        }
+
+        impl LabelSummary {
+            pub fn color<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.color = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for color: {}", e));
+                self
+            }
+            pub fn count<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u32>,
+                T::Error: std::fmt::Display,
+            {
+                self.count = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for count: {}", e));
+                self
+            }
+            pub fn description<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.description = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<LabelSummary> for super::LabelSummary {
+            type Error = super::error::ConversionError;
+            fn try_from(value: LabelSummary) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    color: value.color?,
+                    count: value.count?,
+                    description: value.description?,
+                    id: value.id?,
+                    name: value.name?,
+                })
+            }
+        }
+
+        impl From<super::LabelSummary> for LabelSummary {
+            fn from(value: super::LabelSummary) -> Self {
+                Self {
+                    color: Ok(value.color),
+                    count: Ok(value.count),
+                    description: Ok(value.description),
+                    id: Ok(value.id),
+                    name: Ok(value.name),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ListRfd {
+            author_list: Result<Vec<super::RfdAuthorEntry>, String>,
+            authors: Result<Option<String>, String>,
+            commit: Result<super::CommitSha, String>,
+            committed_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            discussion: Result<Option<String>, String>,
+            format: Result<super::ContentFormat, String>,
+            id: Result<uuid::Uuid, String>,
+            labels: Result<Option<String>, String>,
+            link: Result<Option<String>, String>,
+            rfd_number: Result<i32, String>,
+            sha: Result<super::FileSha, String>,
             state: Result<Option<String>, String>,
             title: Result<String, String>,
             visibility: Result<super::Visibility, String>,
         }
 
-        impl Default for FullRfd {
+        impl Default for ListRfd {
+            fn default() -> Self {
+                Self {
+                    author_list: Err("no value supplied for author_list".to_string()),
+                    authors: Ok(Default::default()),
+                    commit: Err("no value supplied for commit".to_string()),
+                    committed_at: Err("no value supplied for committed_at".to_string()),
+                    discussion: Ok(Default::default()),
+                    format: Err("no value supplied for format".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    labels: Ok(Default::default()),
+                    link: Ok(Default::default()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    sha: Err("no value supplied for sha".to_string()),
+                    state: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                    visibility: Err("no value supplied for visibility".to_string()),
+                }
+            }
+        }
+
+        impl ListRfd {
+            pub fn author_list<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::RfdAuthorEntry>>,
+                T::Error: std::fmt::Display,
+            {
+                self.author_list = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for author_list: {}", e));
+                self
+            }
+            pub fn authors<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.authors = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for authors: {}", e));
+                self
+            }
+            pub fn commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::CommitSha>,
+                T::Error: std::fmt::Display,
+            {
+                self.commit = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for commit: {}", e));
+                self
+            }
+            pub fn committed_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.committed_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for committed_at: {}", e)
+                });
+                self
+            }
+            pub fn discussion<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.discussion = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for discussion: {}", e));
+                self
+            }
+            pub fn format<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::ContentFormat>,
+                T::Error: std::fmt::Display,
+            {
+                self.format = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for format: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn labels<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.labels = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for labels: {}", e));
+                self
+            }
+            pub fn link<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.link = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                self
+            }
+            pub fn rfd_number<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i32>,
+                T::Error: std::fmt::Display,
+            {
+                self.rfd_number = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                self
+            }
+            pub fn sha<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::FileSha>,
+                T::Error: std::fmt::Display,
+            {
+                self.sha = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for sha: {}", e));
+                self
+            }
+            pub fn state<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.state = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for state: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+            pub fn visibility<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::Visibility>,
+                T::Error: std::fmt::Display,
+            {
+                self.visibility = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ListRfd> for super::ListRfd {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ListRfd) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    author_list: value.author_list?,
+                    authors: value.authors?,
+                    commit: value.commit?,
+                    committed_at: value.committed_at?,
+                    discussion: value.discussion?,
+                    format: value.format?,
+                    id: value.id?,
+                    labels: value.labels?,
+                    link: value.link?,
+                    rfd_number: value.rfd_number?,
+                    sha: value.sha?,
+                    state: value.state?,
+                    title: value.title?,
+                    visibility: value.visibility?,
+                })
+            }
+        }
+
+        impl From<super::ListRfd> for ListRfd {
+            fn from(value: super::ListRfd) -> Self {
+                Self {
+                    author_list: Ok(value.author_list),
+                    authors: Ok(value.authors),
+                    commit: Ok(value.commit),
+                    committed_at: Ok(value.committed_at),
+                    discussion: Ok(value.discussion),
+                    format: Ok(value.format),
+                    id: Ok(value.id),
+                    labels: Ok(value.labels),
+                    link: Ok(value.link),
+                    rfd_number: Ok(value.rfd_number),
+                    sha: Ok(value.sha),
+                    state: Ok(value.state),
+                    title: Ok(value.title),
+                    visibility: Ok(value.visibility),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct LocalLogin {
+            email: Result<String, String>,
+            external_id: Result<String, String>,
+        }
+
+        impl Default for LocalLogin {
+            fn default() -> Self {
+                Self {
+                    email: Err("no value supplied for email".to_string()),
+                    external_id: Err("no value supplied for external_id".to_string()),
+                }
+            }
+        }
+
+        impl LocalLogin {
+            pub fn email<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.email = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for email: {}", e));
+                self
+            }
+            pub fn external_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.external_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for external_id: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<LocalLogin> for super::LocalLogin {
+            type Error = super::error::ConversionError;
+            fn try_from(value: LocalLogin) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    email: value.email?,
+                    external_id: value.external_id?,
+                })
+            }
+        }
+
+        impl From<super::LocalLogin> for LocalLogin {
+            fn from(value: super::LocalLogin) -> Self {
+                Self {
+                    email: Ok(value.email),
+                    external_id: Ok(value.external_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Mapper {
+            activations: Result<Option<i32>, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            depleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            max_activations: Result<Option<i32>, String>,
+            name: Result<String, String>,
+            rule: Result<serde_json::Value, String>,
+        }
+
+        impl Default for Mapper {
+            fn default() -> Self {
+                Self {
+                    activations: Ok(Default::default()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    depleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    max_activations: Ok(Default::default()),
+                    name: Err("no value supplied for name".to_string()),
+                    rule: Err("no value supplied for rule".to_string()),
+                }
+            }
+        }
+
+        impl Mapper {
+            pub fn activations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<i32>>,
+                T::Error: std::fmt::Display,
+            {
+                self.activations = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for activations: {}", e));
+                self
+            }
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn depleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.depleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for depleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn max_activations<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<i32>>,
+                T::Error: std::fmt::Display,
+            {
+                self.max_activations = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for max_activations: {}", e)
+                });
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn rule<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<serde_json::Value>,
+                T::Error: std::fmt::Display,
+            {
+                self.rule = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rule: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Mapper> for super::Mapper {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Mapper) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    activations: value.activations?,
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    depleted_at: value.depleted_at?,
+                    id: value.id?,
+                    max_activations: value.max_activations?,
+                    name: value.name?,
+                    rule: value.rule?,
+                })
+            }
+        }
+
+        impl From<super::Mapper> for Mapper {
+            fn from(value: super::Mapper) -> Self {
+                Self {
+                    activations: Ok(value.activations),
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    depleted_at: Ok(value.depleted_at),
+                    id: Ok(value.id),
+                    max_activations: Ok(value.max_activations),
+                    name: Ok(value.name),
+                    rule: Ok(value.rule),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthAuthzCodeExchangeBody {
+            client_id: Result<uuid::Uuid, String>,
+            client_secret: Result<super::SecretString, String>,
+            code: Result<String, String>,
+            grant_type: Result<String, String>,
+            pkce_verifier: Result<Option<String>, String>,
+            redirect_uri: Result<String, String>,
+        }
+
+        impl Default for OAuthAuthzCodeExchangeBody {
+            fn default() -> Self {
+                Self {
+                    client_id: Err("no value supplied for client_id".to_string()),
+                    client_secret: Err("no value supplied for client_secret".to_string()),
+                    code: Err("no value supplied for code".to_string()),
+                    grant_type: Err("no value supplied for grant_type".to_string()),
+                    pkce_verifier: Ok(Default::default()),
+                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
+                }
+            }
+        }
+
+        impl OAuthAuthzCodeExchangeBody {
+            pub fn client_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.client_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for client_id: {}", e));
+                self
+            }
+            pub fn client_secret<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::SecretString>,
+                T::Error: std::fmt::Display,
+            {
+                self.client_secret = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for client_secret: {}", e)
+                });
+                self
+            }
+            pub fn code<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.code = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for code: {}", e));
+                self
+            }
+            pub fn grant_type<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.grant_type = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for grant_type: {}", e));
+                self
+            }
+            pub fn pkce_verifier<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.pkce_verifier = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for pkce_verifier: {}", e)
+                });
+                self
+            }
+            pub fn redirect_uri<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.redirect_uri = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for redirect_uri: {}", e)
+                });
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthAuthzCodeExchangeBody> for super::OAuthAuthzCodeExchangeBody {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: OAuthAuthzCodeExchangeBody,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    client_id: value.client_id?,
+                    client_secret: value.client_secret?,
+                    code: value.code?,
+                    grant_type: value.grant_type?,
+                    pkce_verifier: value.pkce_verifier?,
+                    redirect_uri: value.redirect_uri?,
+                })
+            }
+        }
+
+        impl From<super::OAuthAuthzCodeExchangeBody> for OAuthAuthzCodeExchangeBody {
+            fn from(value: super::OAuthAuthzCodeExchangeBody) -> Self {
+                Self {
+                    client_id: Ok(value.client_id),
+                    client_secret: Ok(value.client_secret),
+                    code: Ok(value.code),
+                    grant_type: Ok(value.grant_type),
+                    pkce_verifier: Ok(value.pkce_verifier),
+                    redirect_uri: Ok(value.redirect_uri),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthAuthzCodeExchangeResponse {
+            access_token: Result<String, String>,
+            expires_in: Result<i64, String>,
+            token_type: Result<String, String>,
+        }
+
+        impl Default for OAuthAuthzCodeExchangeResponse {
+            fn default() -> Self {
+                Self {
+                    access_token: Err("no value supplied for access_token".to_string()),
+                    expires_in: Err("no value supplied for expires_in".to_string()),
+                    token_type: Err("no value supplied for token_type".to_string()),
+                }
+            }
+        }
+
+        impl OAuthAuthzCodeExchangeResponse {
+            pub fn access_token<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.access_token = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for access_token: {}", e)
+                });
+                self
+            }
+            pub fn expires_in<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i64>,
+                T::Error: std::fmt::Display,
+            {
+                self.expires_in = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for expires_in: {}", e));
+                self
+            }
+            pub fn token_type<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.token_type = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for token_type: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthAuthzCodeExchangeResponse>
+            for super::OAuthAuthzCodeExchangeResponse
+        {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: OAuthAuthzCodeExchangeResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    access_token: value.access_token?,
+                    expires_in: value.expires_in?,
+                    token_type: value.token_type?,
+                })
+            }
+        }
+
+        impl From<super::OAuthAuthzCodeExchangeResponse> for OAuthAuthzCodeExchangeResponse {
+            fn from(value: super::OAuthAuthzCodeExchangeResponse) -> Self {
+                Self {
+                    access_token: Ok(value.access_token),
+                    expires_in: Ok(value.expires_in),
+                    token_type: Ok(value.token_type),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthClient {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            redirect_uris: Result<Vec<super::OAuthClientRedirectUri>, String>,
+            secrets: Result<Vec<super::OAuthClientSecret>, String>,
+        }
+
+        impl Default for OAuthClient {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    redirect_uris: Err("no value supplied for redirect_uris".to_string()),
+                    secrets: Err("no value supplied for secrets".to_string()),
+                }
+            }
+        }
+
+        impl OAuthClient {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn redirect_uris<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::OAuthClientRedirectUri>>,
+                T::Error: std::fmt::Display,
+            {
+                self.redirect_uris = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for redirect_uris: {}", e)
+                });
+                self
+            }
+            pub fn secrets<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::OAuthClientSecret>>,
+                T::Error: std::fmt::Display,
+            {
+                self.secrets = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for secrets: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthClient> for super::OAuthClient {
+            type Error = super::error::ConversionError;
+            fn try_from(value: OAuthClient) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    id: value.id?,
+                    redirect_uris: value.redirect_uris?,
+                    secrets: value.secrets?,
+                })
+            }
+        }
+
+        impl From<super::OAuthClient> for OAuthClient {
+            fn from(value: super::OAuthClient) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    id: Ok(value.id),
+                    redirect_uris: Ok(value.redirect_uris),
+                    secrets: Ok(value.secrets),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthClientRedirectUri {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            oauth_client_id: Result<uuid::Uuid, String>,
+            redirect_uri: Result<String, String>,
+        }
+
+        impl Default for OAuthClientRedirectUri {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    oauth_client_id: Err("no value supplied for oauth_client_id".to_string()),
+                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
+                }
+            }
+        }
+
+        impl OAuthClientRedirectUri {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn oauth_client_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.oauth_client_id = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for oauth_client_id: {}", e)
+                });
+                self
+            }
+            pub fn redirect_uri<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.redirect_uri = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for redirect_uri: {}", e)
+                });
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthClientRedirectUri> for super::OAuthClientRedirectUri {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: OAuthClientRedirectUri,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    id: value.id?,
+                    oauth_client_id: value.oauth_client_id?,
+                    redirect_uri: value.redirect_uri?,
+                })
+            }
+        }
+
+        impl From<super::OAuthClientRedirectUri> for OAuthClientRedirectUri {
+            fn from(value: super::OAuthClientRedirectUri) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    id: Ok(value.id),
+                    oauth_client_id: Ok(value.oauth_client_id),
+                    redirect_uri: Ok(value.redirect_uri),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthClientSecret {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            oauth_client_id: Result<uuid::Uuid, String>,
+            secret_signature: Result<String, String>,
+        }
+
+        impl Default for OAuthClientSecret {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    oauth_client_id: Err("no value supplied for oauth_client_id".to_string()),
+                    secret_signature: Err("no value supplied for secret_signature".to_string()),
+                }
+            }
+        }
+
+        impl OAuthClientSecret {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn oauth_client_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.oauth_client_id = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for oauth_client_id: {}", e)
+                });
+                self
+            }
+            pub fn secret_signature<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.secret_signature = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for secret_signature: {}",
+                        e
+                    )
+                });
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthClientSecret> for super::OAuthClientSecret {
+            type Error = super::error::ConversionError;
+            fn try_from(value: OAuthClientSecret) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    id: value.id?,
+                    oauth_client_id: value.oauth_client_id?,
+                    secret_signature: value.secret_signature?,
+                })
+            }
+        }
+
+        impl From<super::OAuthClientSecret> for OAuthClientSecret {
+            fn from(value: super::OAuthClientSecret) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    id: Ok(value.id),
+                    oauth_client_id: Ok(value.oauth_client_id),
+                    secret_signature: Ok(value.secret_signature),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OAuthProviderInfo {
+            auth_url_endpoint: Result<String, String>,
+            client_id: Result<String, String>,
+            device_code_endpoint: Result<String, String>,
+            provider: Result<super::OAuthProviderName, String>,
+            scopes: Result<Vec<String>, String>,
+            token_endpoint: Result<String, String>,
+        }
+
+        impl Default for OAuthProviderInfo {
+            fn default() -> Self {
+                Self {
+                    auth_url_endpoint: Err("no value supplied for auth_url_endpoint".to_string()),
+                    client_id: Err("no value supplied for client_id".to_string()),
+                    device_code_endpoint: Err(
+                        "no value supplied for device_code_endpoint".to_string()
+                    ),
+                    provider: Err("no value supplied for provider".to_string()),
+                    scopes: Err("no value supplied for scopes".to_string()),
+                    token_endpoint: Err("no value supplied for token_endpoint".to_string()),
+                }
+            }
+        }
+
+        impl OAuthProviderInfo {
+            pub fn auth_url_endpoint<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.auth_url_endpoint = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for auth_url_endpoint: {}",
+                        e
+                    )
+                });
+                self
+            }
+            pub fn client_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.client_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for client_id: {}", e));
+                self
+            }
+            pub fn device_code_endpoint<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.device_code_endpoint = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for device_code_endpoint: {}",
+                        e
+                    )
+                });
+                self
+            }
+            pub fn provider<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::OAuthProviderName>,
+                T::Error: std::fmt::Display,
+            {
+                self.provider = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for provider: {}", e));
+                self
+            }
+            pub fn scopes<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.scopes = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for scopes: {}", e));
+                self
+            }
+            pub fn token_endpoint<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.token_endpoint = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for token_endpoint: {}", e)
+                });
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OAuthProviderInfo> for super::OAuthProviderInfo {
+            type Error = super::error::ConversionError;
+            fn try_from(value: OAuthProviderInfo) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    auth_url_endpoint: value.auth_url_endpoint?,
+                    client_id: value.client_id?,
+                    device_code_endpoint: value.device_code_endpoint?,
+                    provider: value.provider?,
+                    scopes: value.scopes?,
+                    token_endpoint: value.token_endpoint?,
+                })
+            }
+        }
+
+        impl From<super::OAuthProviderInfo> for OAuthProviderInfo {
+            fn from(value: super::OAuthProviderInfo) -> Self {
+                Self {
+                    auth_url_endpoint: Ok(value.auth_url_endpoint),
+                    client_id: Ok(value.client_id),
+                    device_code_endpoint: Ok(value.device_code_endpoint),
+                    provider: Ok(value.provider),
+                    scopes: Ok(value.scopes),
+                    token_endpoint: Ok(value.token_endpoint),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct OpenIdConfiguration {
+            jwks_uri: Result<String, String>,
+        }
+
+        impl Default for OpenIdConfiguration {
+            fn default() -> Self {
+                Self {
+                    jwks_uri: Err("no value supplied for jwks_uri".to_string()),
+                }
+            }
+        }
+
+        impl OpenIdConfiguration {
+            pub fn jwks_uri<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.jwks_uri = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for jwks_uri: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<OpenIdConfiguration> for super::OpenIdConfiguration {
+            type Error = super::error::ConversionError;
+            fn try_from(value: OpenIdConfiguration) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    jwks_uri: value.jwks_uri?,
+                })
+            }
+        }
+
+        impl From<super::OpenIdConfiguration> for OpenIdConfiguration {
+            fn from(value: super::OpenIdConfiguration) -> Self {
+                Self {
+                    jwks_uri: Ok(value.jwks_uri),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ReserveRfdBody {
+            content: Result<Option<String>, String>,
+            fields: Result<std::collections::HashMap<String, String>, String>,
+            format: Result<Option<super::ContentFormat>, String>,
+            template: Result<Option<String>, String>,
+            title: Result<String, String>,
+        }
+
+        impl Default for ReserveRfdBody {
+            fn default() -> Self {
+                Self {
+                    content: Ok(Default::default()),
+                    fields: Ok(Default::default()),
+                    format: Ok(Default::default()),
+                    template: Ok(Default::default()),
+                    title: Err("no value supplied for title".to_string()),
+                }
+            }
+        }
+
+        impl ReserveRfdBody {
+            pub fn content<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn fields<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<std::collections::HashMap<String, String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.fields = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for fields: {}", e));
+                self
+            }
+            pub fn format<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::ContentFormat>>,
+                T::Error: std::fmt::Display,
+            {
+                self.format = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for format: {}", e));
+                self
+            }
+            pub fn template<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.template = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for template: {}", e));
+                self
+            }
+            pub fn title<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.title = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ReserveRfdBody> for super::ReserveRfdBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ReserveRfdBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    content: value.content?,
+                    fields: value.fields?,
+                    format: value.format?,
+                    template: value.template?,
+                    title: value.title?,
+                })
+            }
+        }
+
+        impl From<super::ReserveRfdBody> for ReserveRfdBody {
+            fn from(value: super::ReserveRfdBody) -> Self {
+                Self {
+                    content: Ok(value.content),
+                    fields: Ok(value.fields),
+                    format: Ok(value.format),
+                    template: Ok(value.template),
+                    title: Ok(value.title),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct ReserveRfdResponse {
+            number: Result<i32, String>,
+        }
+
+        impl Default for ReserveRfdResponse {
+            fn default() -> Self {
+                Self {
+                    number: Err("no value supplied for number".to_string()),
+                }
+            }
+        }
+
+        impl ReserveRfdResponse {
+            pub fn number<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i32>,
+                T::Error: std::fmt::Display,
+            {
+                self.number = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for number: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<ReserveRfdResponse> for super::ReserveRfdResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(value: ReserveRfdResponse) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    number: value.number?,
+                })
+            }
+        }
+
+        impl From<super::ReserveRfdResponse> for ReserveRfdResponse {
+            fn from(value: super::ReserveRfdResponse) -> Self {
+                Self {
+                    number: Ok(value.number),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Rfd {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            id: Result<uuid::Uuid, String>,
+            link: Result<Option<String>, String>,
+            rfd_number: Result<i32, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            visibility: Result<super::Visibility, String>,
+        }
+
+        impl Default for Rfd {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    deleted_at: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    link: Ok(Default::default()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                    visibility: Err("no value supplied for visibility".to_string()),
+                }
+            }
+        }
+
+        impl Rfd {
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
+            }
+            pub fn deleted_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.deleted_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
+                self
+            }
+            pub fn id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<uuid::Uuid>,
+                T::Error: std::fmt::Display,
+            {
+                self.id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self
+            }
+            pub fn link<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.link = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                self
+            }
+            pub fn rfd_number<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<i32>,
+                T::Error: std::fmt::Display,
+            {
+                self.rfd_number = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
+                self
+            }
+            pub fn visibility<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::Visibility>,
+                T::Error: std::fmt::Display,
+            {
+                self.visibility = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<Rfd> for super::Rfd {
+            type Error = super::error::ConversionError;
+            fn try_from(value: Rfd) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    created_at: value.created_at?,
+                    deleted_at: value.deleted_at?,
+                    id: value.id?,
+                    link: value.link?,
+                    rfd_number: value.rfd_number?,
+                    updated_at: value.updated_at?,
+                    visibility: value.visibility?,
+                })
+            }
+        }
+
+        impl From<super::Rfd> for Rfd {
+            fn from(value: super::Rfd) -> Self {
+                Self {
+                    created_at: Ok(value.created_at),
+                    deleted_at: Ok(value.deleted_at),
+                    id: Ok(value.id),
+                    link: Ok(value.link),
+                    rfd_number: Ok(value.rfd_number),
+                    updated_at: Ok(value.updated_at),
+                    visibility: Ok(value.visibility),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdAssetUploadBody {
+            content: Result<String, String>,
+            expected_commit: Result<Option<String>, String>,
+            message: Result<Option<String>, String>,
+            path: Result<String, String>,
+        }
+
+        impl Default for RfdAssetUploadBody {
+            fn default() -> Self {
+                Self {
+                    content: Err("no value supplied for content".to_string()),
+                    expected_commit: Ok(Default::default()),
+                    message: Ok(Default::default()),
+                    path: Err("no value supplied for path".to_string()),
+                }
+            }
+        }
+
+        impl RfdAssetUploadBody {
+            pub fn content<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn expected_commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
+                });
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
+                self
+            }
+            pub fn path<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.path = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for path: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdAssetUploadBody> for super::RfdAssetUploadBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdAssetUploadBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    content: value.content?,
+                    expected_commit: value.expected_commit?,
+                    message: value.message?,
+                    path: value.path?,
+                })
+            }
+        }
+
+        impl From<super::RfdAssetUploadBody> for RfdAssetUploadBody {
+            fn from(value: super::RfdAssetUploadBody) -> Self {
+                Self {
+                    content: Ok(value.content),
+                    expected_commit: Ok(value.expected_commit),
+                    message: Ok(value.message),
+                    path: Ok(value.path),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdAssetUploadResponse {
+            path: Result<String, String>,
+        }
+
+        impl Default for RfdAssetUploadResponse {
+            fn default() -> Self {
+                Self {
+                    path: Err("no value supplied for path".to_string()),
+                }
+            }
+        }
+
+        impl RfdAssetUploadResponse {
+            pub fn path<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.path = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for path: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdAssetUploadResponse> for super::RfdAssetUploadResponse {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: RfdAssetUploadResponse,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self { path: value.path? })
+            }
+        }
+
+        impl From<super::RfdAssetUploadResponse> for RfdAssetUploadResponse {
+            fn from(value: super::RfdAssetUploadResponse) -> Self {
+                Self {
+                    path: Ok(value.path),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdAttrValue {
+            expected_commit: Result<Option<String>, String>,
+            message: Result<Option<String>, String>,
+            value: Result<String, String>,
+        }
+
+        impl Default for RfdAttrValue {
+            fn default() -> Self {
+                Self {
+                    expected_commit: Ok(Default::default()),
+                    message: Ok(Default::default()),
+                    value: Err("no value supplied for value".to_string()),
+                }
+            }
+        }
+
+        impl RfdAttrValue {
+            pub fn expected_commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
+                });
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
+                self
+            }
+            pub fn value<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.value = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for value: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdAttrValue> for super::RfdAttrValue {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdAttrValue) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    expected_commit: value.expected_commit?,
+                    message: value.message?,
+                    value: value.value?,
+                })
+            }
+        }
+
+        impl From<super::RfdAttrValue> for RfdAttrValue {
+            fn from(value: super::RfdAttrValue) -> Self {
+                Self {
+                    expected_commit: Ok(value.expected_commit),
+                    message: Ok(value.message),
+                    value: Ok(value.value),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdAuthorEntry {
+            api_user_id: Result<Option<uuid::Uuid>, String>,
+            email: Result<Option<String>, String>,
+            name: Result<Option<String>, String>,
+        }
+
+        impl Default for RfdAuthorEntry {
+            fn default() -> Self {
+                Self {
+                    api_user_id: Ok(Default::default()),
+                    email: Ok(Default::default()),
+                    name: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl RfdAuthorEntry {
+            pub fn api_user_id<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<uuid::Uuid>>,
+                T::Error: std::fmt::Display,
+            {
+                self.api_user_id = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for api_user_id: {}", e));
+                self
+            }
+            pub fn email<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.email = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for email: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdAuthorEntry> for super::RfdAuthorEntry {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdAuthorEntry) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    api_user_id: value.api_user_id?,
+                    email: value.email?,
+                    name: value.name?,
+                })
+            }
+        }
+
+        impl From<super::RfdAuthorEntry> for RfdAuthorEntry {
+            fn from(value: super::RfdAuthorEntry) -> Self {
+                Self {
+                    api_user_id: Ok(value.api_user_id),
+                    email: Ok(value.email),
+                    name: Ok(value.name),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdBatchBody {
+            fields: Result<Option<super::RfdBatchFields>, String>,
+            numbers: Result<Vec<i32>, String>,
+        }
+
+        impl Default for RfdBatchBody {
             fn default() -> Self {
                 Self {
-                    authors: Ok(Default::default()),
-                    commit: Err("no value supplied for commit".to_string()),
-                    committed_at: Err("no value supplied for committed_at".to_string()),
-                    content: Err("no value supplied for content".to_string()),
-                    discussion: Ok(Default::default()),
-                    format: Err("no value supplied for format".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    labels: Ok(Default::default()),
-                    link: Ok(Default::default()),
-                    pdfs: Err("no value supplied for pdfs".to_string()),
-                    rfd_number: Err("no value supplied for rfd_number".to_string()),
-                    sha: Err("no value supplied for sha".to_string()),
-                    state: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                    visibility: Err("no value supplied for visibility".to_string()),
+                    fields: Ok(Default::default()),
+                    numbers: Err("no value supplied for numbers".to_string()),
                 }
             }
         }
 
-        impl FullRfd {
-            pub fn authors<T>(mut self, value: T) -> Self
+        impl RfdBatchBody {
+            pub fn fields<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<Option<super::RfdBatchFields>>,
                 T::Error: std::fmt::Display,
             {
-                self.authors = value
+                self.fields = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for authors: {}", e));
+                    .map_err(|e| format!("error converting supplied value for fields: {}", e));
+                self
+            }
+            pub fn numbers<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<i32>>,
+                T::Error: std::fmt::Display,
+            {
+                self.numbers = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for numbers: {}", e));
                 self
             }
+        }
+
+        impl std::convert::TryFrom<RfdBatchBody> for super::RfdBatchBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdBatchBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    fields: value.fields?,
+                    numbers: value.numbers?,
+                })
+            }
+        }
+
+        impl From<super::RfdBatchBody> for RfdBatchBody {
+            fn from(value: super::RfdBatchBody) -> Self {
+                Self {
+                    fields: Ok(value.fields),
+                    numbers: Ok(value.numbers),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdRevertBody {
+            commit: Result<String, String>,
+            expected_commit: Result<Option<String>, String>,
+        }
+
+        impl Default for RfdRevertBody {
+            fn default() -> Self {
+                Self {
+                    commit: Err("no value supplied for commit".to_string()),
+                    expected_commit: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl RfdRevertBody {
             pub fn commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::CommitSha>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
                 self.commit = value
@@ -7064,16 +12352,154 @@ pub mod types {
                     .map_err(|e| format!("error converting supplied value for commit: {}", e));
                 self
             }
-            pub fn committed_at<T>(mut self, value: T) -> Self
+            pub fn expected_commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.committed_at = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for committed_at: {}", e)
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
                 });
                 self
             }
+        }
+
+        impl std::convert::TryFrom<RfdRevertBody> for super::RfdRevertBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdRevertBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    commit: value.commit?,
+                    expected_commit: value.expected_commit?,
+                })
+            }
+        }
+
+        impl From<super::RfdRevertBody> for RfdRevertBody {
+            fn from(value: super::RfdRevertBody) -> Self {
+                Self {
+                    commit: Ok(value.commit),
+                    expected_commit: Ok(value.expected_commit),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdSection {
+            anchor: Result<String, String>,
+            level: Result<u32, String>,
+            name: Result<String, String>,
+            parents: Result<Vec<String>, String>,
+        }
+
+        impl Default for RfdSection {
+            fn default() -> Self {
+                Self {
+                    anchor: Err("no value supplied for anchor".to_string()),
+                    level: Err("no value supplied for level".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    parents: Err("no value supplied for parents".to_string()),
+                }
+            }
+        }
+
+        impl RfdSection {
+            pub fn anchor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.anchor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
+                self
+            }
+            pub fn level<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<u32>,
+                T::Error: std::fmt::Display,
+            {
+                self.level = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for level: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.name = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn parents<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.parents = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for parents: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdSection> for super::RfdSection {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdSection) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    anchor: value.anchor?,
+                    level: value.level?,
+                    name: value.name?,
+                    parents: value.parents?,
+                })
+            }
+        }
+
+        impl From<super::RfdSection> for RfdSection {
+            fn from(value: super::RfdSection) -> Self {
+                Self {
+                    anchor: Ok(value.anchor),
+                    level: Ok(value.level),
+                    name: Ok(value.name),
+                    parents: Ok(value.parents),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdSectionContent {
+            anchor: Result<String, String>,
+            content: Result<String, String>,
+            level: Result<u32, String>,
+            name: Result<String, String>,
+            parents: Result<Vec<String>, String>,
+        }
+
+        impl Default for RfdSectionContent {
+            fn default() -> Self {
+                Self {
+                    anchor: Err("no value supplied for anchor".to_string()),
+                    content: Err("no value supplied for content".to_string()),
+                    level: Err("no value supplied for level".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    parents: Err("no value supplied for parents".to_string()),
+                }
+            }
+        }
+
+        impl RfdSectionContent {
+            pub fn anchor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.anchor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
+                self
+            }
             pub fn content<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
@@ -7084,3612 +12510,4283 @@ pub mod types {
                     .map_err(|e| format!("error converting supplied value for content: {}", e));
                 self
             }
-            pub fn discussion<T>(mut self, value: T) -> Self
+            pub fn level<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<u32>,
                 T::Error: std::fmt::Display,
             {
-                self.discussion = value
+                self.level = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for discussion: {}", e));
+                    .map_err(|e| format!("error converting supplied value for level: {}", e));
                 self
             }
-            pub fn format<T>(mut self, value: T) -> Self
+            pub fn name<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::ContentFormat>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.format = value
+                self.name = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for format: {}", e));
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn parents<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.parents = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for parents: {}", e));
+                self
+            }
+        }
+
+        impl std::convert::TryFrom<RfdSectionContent> for super::RfdSectionContent {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdSectionContent) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    anchor: value.anchor?,
+                    content: value.content?,
+                    level: value.level?,
+                    name: value.name?,
+                    parents: value.parents?,
+                })
+            }
+        }
+
+        impl From<super::RfdSectionContent> for RfdSectionContent {
+            fn from(value: super::RfdSectionContent) -> Self {
+                Self {
+                    anchor: Ok(value.anchor),
+                    content: Ok(value.content),
+                    level: Ok(value.level),
+                    name: Ok(value.name),
+                    parents: Ok(value.parents),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdSectionUpdateBody {
+            content: Result<String, String>,
+            expected_commit: Result<Option<String>, String>,
+            message: Result<Option<String>, String>,
+        }
+
+        impl Default for RfdSectionUpdateBody {
+            fn default() -> Self {
+                Self {
+                    content: Err("no value supplied for content".to_string()),
+                    expected_commit: Ok(Default::default()),
+                    message: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl RfdSectionUpdateBody {
+            pub fn content<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<String>,
+                T::Error: std::fmt::Display,
+            {
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn expected_commit<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
+                });
+                self
+            }
+            pub fn message<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
+        }
+
+        impl std::convert::TryFrom<RfdSectionUpdateBody> for super::RfdSectionUpdateBody {
+            type Error = super::error::ConversionError;
+            fn try_from(
+                value: RfdSectionUpdateBody,
+            ) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    content: value.content?,
+                    expected_commit: value.expected_commit?,
+                    message: value.message?,
+                })
+            }
+        }
+
+        impl From<super::RfdSectionUpdateBody> for RfdSectionUpdateBody {
+            fn from(value: super::RfdSectionUpdateBody) -> Self {
+                Self {
+                    content: Ok(value.content),
+                    expected_commit: Ok(value.expected_commit),
+                    message: Ok(value.message),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdStateTransition {
+            api_user_id: Result<uuid::Uuid, String>,
+            commit: Result<Option<super::CommitSha>, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            from_state: Result<Option<String>, String>,
+            id: Result<uuid::Uuid, String>,
+            reason: Result<Option<String>, String>,
+            rfd_id: Result<uuid::Uuid, String>,
+            to_state: Result<String, String>,
+        }
+
+        impl Default for RfdStateTransition {
+            fn default() -> Self {
+                Self {
+                    api_user_id: Err("no value supplied for api_user_id".to_string()),
+                    commit: Ok(Default::default()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    from_state: Ok(Default::default()),
+                    id: Err("no value supplied for id".to_string()),
+                    reason: Ok(Default::default()),
+                    rfd_id: Err("no value supplied for rfd_id".to_string()),
+                    to_state: Err("no value supplied for to_state".to_string()),
+                }
             }
-            pub fn labels<T>(mut self, value: T) -> Self
+        }
+
+        impl RfdStateTransition {
+            pub fn api_user_id<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.labels = value
+                self.api_user_id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for labels: {}", e));
+                    .map_err(|e| format!("error converting supplied value for api_user_id: {}", e));
                 self
             }
-            pub fn link<T>(mut self, value: T) -> Self
+            pub fn commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<Option<super::CommitSha>>,
                 T::Error: std::fmt::Display,
             {
-                self.link = value
+                self.commit = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                    .map_err(|e| format!("error converting supplied value for commit: {}", e));
                 self
             }
-            pub fn pdfs<T>(mut self, value: T) -> Self
+            pub fn created_at<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::FullRfdPdfEntry>>,
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
                 T::Error: std::fmt::Display,
             {
-                self.pdfs = value
+                self.created_at = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for pdfs: {}", e));
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
                 self
             }
-            pub fn rfd_number<T>(mut self, value: T) -> Self
+            pub fn from_state<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<i32>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.rfd_number = value
+                self.from_state = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                    .map_err(|e| format!("error converting supplied value for from_state: {}", e));
                 self
             }
-            pub fn sha<T>(mut self, value: T) -> Self
+            pub fn id<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::FileSha>,
+                T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.sha = value
+                self.id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for sha: {}", e));
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
                 self
             }
-            pub fn state<T>(mut self, value: T) -> Self
+            pub fn reason<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.state = value
+                self.reason = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for state: {}", e));
+                    .map_err(|e| format!("error converting supplied value for reason: {}", e));
                 self
             }
-            pub fn title<T>(mut self, value: T) -> Self
+            pub fn rfd_id<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.title = value
+                self.rfd_id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
+                    .map_err(|e| format!("error converting supplied value for rfd_id: {}", e));
                 self
             }
-            pub fn visibility<T>(mut self, value: T) -> Self
+            pub fn to_state<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::Visibility>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.visibility = value
+                self.to_state = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                    .map_err(|e| format!("error converting supplied value for to_state: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<FullRfd> for super::FullRfd {
+        impl std::convert::TryFrom<RfdStateTransition> for super::RfdStateTransition {
             type Error = super::error::ConversionError;
-            fn try_from(value: FullRfd) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: RfdStateTransition) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    authors: value.authors?,
+                    api_user_id: value.api_user_id?,
                     commit: value.commit?,
-                    committed_at: value.committed_at?,
-                    content: value.content?,
-                    discussion: value.discussion?,
-                    format: value.format?,
+                    created_at: value.created_at?,
+                    from_state: value.from_state?,
                     id: value.id?,
-                    labels: value.labels?,
-                    link: value.link?,
-                    pdfs: value.pdfs?,
-                    rfd_number: value.rfd_number?,
-                    sha: value.sha?,
-                    state: value.state?,
-                    title: value.title?,
-                    visibility: value.visibility?,
+                    reason: value.reason?,
+                    rfd_id: value.rfd_id?,
+                    to_state: value.to_state?,
                 })
             }
         }
 
-        impl From<super::FullRfd> for FullRfd {
-            fn from(value: super::FullRfd) -> Self {
+        impl From<super::RfdStateTransition> for RfdStateTransition {
+            fn from(value: super::RfdStateTransition) -> Self {
                 Self {
-                    authors: Ok(value.authors),
+                    api_user_id: Ok(value.api_user_id),
                     commit: Ok(value.commit),
-                    committed_at: Ok(value.committed_at),
-                    content: Ok(value.content),
-                    discussion: Ok(value.discussion),
-                    format: Ok(value.format),
+                    created_at: Ok(value.created_at),
+                    from_state: Ok(value.from_state),
                     id: Ok(value.id),
-                    labels: Ok(value.labels),
-                    link: Ok(value.link),
-                    pdfs: Ok(value.pdfs),
-                    rfd_number: Ok(value.rfd_number),
-                    sha: Ok(value.sha),
-                    state: Ok(value.state),
-                    title: Ok(value.title),
-                    visibility: Ok(value.visibility),
+                    reason: Ok(value.reason),
+                    rfd_id: Ok(value.rfd_id),
+                    to_state: Ok(value.to_state),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct FullRfdPdfEntry {
-            link: Result<String, String>,
-            source: Result<String, String>,
+        pub struct RfdTemplateSummary {
+            description: Result<Option<String>, String>,
+            formats: Result<Vec<super::ContentFormat>, String>,
+            name: Result<String, String>,
+            optional_fields: Result<std::collections::HashMap<String, String>, String>,
+            required_fields: Result<Vec<String>, String>,
         }
 
-        impl Default for FullRfdPdfEntry {
+        impl Default for RfdTemplateSummary {
             fn default() -> Self {
                 Self {
-                    link: Err("no value supplied for link".to_string()),
-                    source: Err("no value supplied for source".to_string()),
+                    description: Ok(Default::default()),
+                    formats: Err("no value supplied for formats".to_string()),
+                    name: Err("no value supplied for name".to_string()),
+                    optional_fields: Err("no value supplied for optional_fields".to_string()),
+                    required_fields: Err("no value supplied for required_fields".to_string()),
                 }
             }
         }
 
-        impl FullRfdPdfEntry {
-            pub fn link<T>(mut self, value: T) -> Self
+        impl RfdTemplateSummary {
+            pub fn description<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.link = value
+                self.description = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
                 self
             }
-            pub fn source<T>(mut self, value: T) -> Self
+            pub fn formats<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<super::ContentFormat>>,
+                T::Error: std::fmt::Display,
+            {
+                self.formats = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for formats: {}", e));
+                self
+            }
+            pub fn name<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.source = value
+                self.name = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for source: {}", e));
+                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self
+            }
+            pub fn optional_fields<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<std::collections::HashMap<String, String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.optional_fields = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for optional_fields: {}", e)
+                });
+                self
+            }
+            pub fn required_fields<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Vec<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.required_fields = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for required_fields: {}", e)
+                });
                 self
             }
         }
 
-        impl std::convert::TryFrom<FullRfdPdfEntry> for super::FullRfdPdfEntry {
+        impl std::convert::TryFrom<RfdTemplateSummary> for super::RfdTemplateSummary {
             type Error = super::error::ConversionError;
-            fn try_from(value: FullRfdPdfEntry) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: RfdTemplateSummary) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    link: value.link?,
-                    source: value.source?,
+                    description: value.description?,
+                    formats: value.formats?,
+                    name: value.name?,
+                    optional_fields: value.optional_fields?,
+                    required_fields: value.required_fields?,
                 })
             }
         }
 
-        impl From<super::FullRfdPdfEntry> for FullRfdPdfEntry {
-            fn from(value: super::FullRfdPdfEntry) -> Self {
+        impl From<super::RfdTemplateSummary> for RfdTemplateSummary {
+            fn from(value: super::RfdTemplateSummary) -> Self {
                 Self {
-                    link: Ok(value.link),
-                    source: Ok(value.source),
+                    description: Ok(value.description),
+                    formats: Ok(value.formats),
+                    name: Ok(value.name),
+                    optional_fields: Ok(value.optional_fields),
+                    required_fields: Ok(value.required_fields),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GetUserResponse {
-            info: Result<super::ApiUserForApiPermissionResponse, String>,
-            providers: Result<Vec<super::ApiUserProvider>, String>,
+        pub struct RfdTimelineEvent {
+            commit: Result<super::CommitSha, String>,
+            committed_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            event: Result<super::RfdTimelineEventKind, String>,
         }
 
-        impl Default for GetUserResponse {
+        impl Default for RfdTimelineEvent {
             fn default() -> Self {
                 Self {
-                    info: Err("no value supplied for info".to_string()),
-                    providers: Err("no value supplied for providers".to_string()),
+                    commit: Err("no value supplied for commit".to_string()),
+                    committed_at: Err("no value supplied for committed_at".to_string()),
+                    event: Err("no value supplied for event".to_string()),
                 }
             }
         }
 
-        impl GetUserResponse {
-            pub fn info<T>(mut self, value: T) -> Self
+        impl RfdTimelineEvent {
+            pub fn commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::ApiUserForApiPermissionResponse>,
+                T: std::convert::TryInto<super::CommitSha>,
                 T::Error: std::fmt::Display,
             {
-                self.info = value
+                self.commit = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for info: {}", e));
+                    .map_err(|e| format!("error converting supplied value for commit: {}", e));
                 self
             }
-            pub fn providers<T>(mut self, value: T) -> Self
+            pub fn committed_at<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::ApiUserProvider>>,
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
                 T::Error: std::fmt::Display,
             {
-                self.providers = value
+                self.committed_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for committed_at: {}", e)
+                });
+                self
+            }
+            pub fn event<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::RfdTimelineEventKind>,
+                T::Error: std::fmt::Display,
+            {
+                self.event = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for providers: {}", e));
+                    .map_err(|e| format!("error converting supplied value for event: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GetUserResponse> for super::GetUserResponse {
+        impl std::convert::TryFrom<RfdTimelineEvent> for super::RfdTimelineEvent {
             type Error = super::error::ConversionError;
-            fn try_from(value: GetUserResponse) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: RfdTimelineEvent) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    info: value.info?,
-                    providers: value.providers?,
+                    commit: value.commit?,
+                    committed_at: value.committed_at?,
+                    event: value.event?,
                 })
             }
         }
 
-        impl From<super::GetUserResponse> for GetUserResponse {
-            fn from(value: super::GetUserResponse) -> Self {
+        impl From<super::RfdTimelineEvent> for RfdTimelineEvent {
+            fn from(value: super::RfdTimelineEvent) -> Self {
                 Self {
-                    info: Ok(value.info),
-                    providers: Ok(value.providers),
+                    commit: Ok(value.commit),
+                    committed_at: Ok(value.committed_at),
+                    event: Ok(value.event),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GitHubCommit {
-            added: Result<Vec<String>, String>,
-            id: Result<String, String>,
-            modified: Result<Vec<String>, String>,
-            removed: Result<Vec<String>, String>,
-            timestamp: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+        pub struct RfdTransitionBody {
+            reason: Result<Option<String>, String>,
+            state: Result<super::RfdState, String>,
         }
 
-        impl Default for GitHubCommit {
+        impl Default for RfdTransitionBody {
             fn default() -> Self {
                 Self {
-                    added: Err("no value supplied for added".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    modified: Err("no value supplied for modified".to_string()),
-                    removed: Err("no value supplied for removed".to_string()),
-                    timestamp: Err("no value supplied for timestamp".to_string()),
+                    reason: Ok(Default::default()),
+                    state: Err("no value supplied for state".to_string()),
                 }
             }
         }
 
-        impl GitHubCommit {
-            pub fn added<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.added = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for added: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn modified<T>(mut self, value: T) -> Self
+        impl RfdTransitionBody {
+            pub fn reason<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.modified = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for modified: {}", e));
-                self
-            }
-            pub fn removed<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.removed = value
+                self.reason = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for removed: {}", e));
+                    .map_err(|e| format!("error converting supplied value for reason: {}", e));
                 self
             }
-            pub fn timestamp<T>(mut self, value: T) -> Self
+            pub fn state<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<super::RfdState>,
                 T::Error: std::fmt::Display,
             {
-                self.timestamp = value
+                self.state = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for timestamp: {}", e));
+                    .map_err(|e| format!("error converting supplied value for state: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GitHubCommit> for super::GitHubCommit {
+        impl std::convert::TryFrom<RfdTransitionBody> for super::RfdTransitionBody {
             type Error = super::error::ConversionError;
-            fn try_from(value: GitHubCommit) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: RfdTransitionBody) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    added: value.added?,
-                    id: value.id?,
-                    modified: value.modified?,
-                    removed: value.removed?,
-                    timestamp: value.timestamp?,
+                    reason: value.reason?,
+                    state: value.state?,
                 })
             }
         }
 
-        impl From<super::GitHubCommit> for GitHubCommit {
-            fn from(value: super::GitHubCommit) -> Self {
+        impl From<super::RfdTransitionBody> for RfdTransitionBody {
+            fn from(value: super::RfdTransitionBody) -> Self {
                 Self {
-                    added: Ok(value.added),
-                    id: Ok(value.id),
-                    modified: Ok(value.modified),
-                    removed: Ok(value.removed),
-                    timestamp: Ok(value.timestamp),
+                    reason: Ok(value.reason),
+                    state: Ok(value.state),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GitHubCommitPayload {
-            commits: Result<Vec<super::GitHubCommit>, String>,
-            head_commit: Result<Option<super::GitHubCommit>, String>,
-            installation: Result<super::GitHubInstallation, String>,
-            ref_: Result<String, String>,
-            repository: Result<super::GitHubRepository, String>,
-            sender: Result<super::GitHubSender, String>,
+        pub struct RfdUpdateBody {
+            document: Result<String, String>,
+            expected_commit: Result<Option<String>, String>,
+            message: Result<Option<String>, String>,
         }
 
-        impl Default for GitHubCommitPayload {
+        impl Default for RfdUpdateBody {
             fn default() -> Self {
                 Self {
-                    commits: Err("no value supplied for commits".to_string()),
-                    head_commit: Ok(Default::default()),
-                    installation: Err("no value supplied for installation".to_string()),
-                    ref_: Err("no value supplied for ref_".to_string()),
-                    repository: Err("no value supplied for repository".to_string()),
-                    sender: Err("no value supplied for sender".to_string()),
+                    document: Err("no value supplied for document".to_string()),
+                    expected_commit: Ok(Default::default()),
+                    message: Ok(Default::default()),
                 }
             }
         }
 
-        impl GitHubCommitPayload {
-            pub fn commits<T>(mut self, value: T) -> Self
+        impl RfdUpdateBody {
+            pub fn document<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::GitHubCommit>>,
+                T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.commits = value
+                self.document = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for commits: {}", e));
+                    .map_err(|e| format!("error converting supplied value for document: {}", e));
                 self
             }
-            pub fn head_commit<T>(mut self, value: T) -> Self
+            pub fn expected_commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<super::GitHubCommit>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.head_commit = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for head_commit: {}", e));
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
+                });
                 self
             }
-            pub fn installation<T>(mut self, value: T) -> Self
+            pub fn message<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::GitHubInstallation>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.installation = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for installation: {}", e)
-                });
+                self.message = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
                 self
             }
-            pub fn ref_<T>(mut self, value: T) -> Self
+        }
+
+        impl std::convert::TryFrom<RfdUpdateBody> for super::RfdUpdateBody {
+            type Error = super::error::ConversionError;
+            fn try_from(value: RfdUpdateBody) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    document: value.document?,
+                    expected_commit: value.expected_commit?,
+                    message: value.message?,
+                })
+            }
+        }
+
+        impl From<super::RfdUpdateBody> for RfdUpdateBody {
+            fn from(value: super::RfdUpdateBody) -> Self {
+                Self {
+                    document: Ok(value.document),
+                    expected_commit: Ok(value.expected_commit),
+                    message: Ok(value.message),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RfdUpdateContentBody {
+            content: Result<String, String>,
+            expected_commit: Result<Option<String>, String>,
+            message: Result<Option<String>, String>,
+        }
+
+        impl Default for RfdUpdateContentBody {
+            fn default() -> Self {
+                Self {
+                    content: Err("no value supplied for content".to_string()),
+                    expected_commit: Ok(Default::default()),
+                    message: Ok(Default::default()),
+                }
+            }
+        }
+
+        impl RfdUpdateContentBody {
+            pub fn content<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.ref_ = value
+                self.content = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for ref_: {}", e));
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
                 self
             }
-            pub fn repository<T>(mut self, value: T) -> Self
+            pub fn expected_commit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::GitHubRepository>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.repository = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for repository: {}", e));
+                self.expected_commit = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for expected_commit: {}", e)
+                });
                 self
             }
-            pub fn sender<T>(mut self, value: T) -> Self
+            pub fn message<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::GitHubSender>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.sender = value
+                self.message = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for sender: {}", e));
+                    .map_err(|e| format!("error converting supplied value for message: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GitHubCommitPayload> for super::GitHubCommitPayload {
+        impl std::convert::TryFrom<RfdUpdateContentBody> for super::RfdUpdateContentBody {
             type Error = super::error::ConversionError;
-            fn try_from(value: GitHubCommitPayload) -> Result<Self, super::error::ConversionError> {
+            fn try_from(
+                value: RfdUpdateContentBody,
+            ) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    commits: value.commits?,
-                    head_commit: value.head_commit?,
-                    installation: value.installation?,
-                    ref_: value.ref_?,
-                    repository: value.repository?,
-                    sender: value.sender?,
+                    content: value.content?,
+                    expected_commit: value.expected_commit?,
+                    message: value.message?,
                 })
             }
         }
 
-        impl From<super::GitHubCommitPayload> for GitHubCommitPayload {
-            fn from(value: super::GitHubCommitPayload) -> Self {
+        impl From<super::RfdUpdateContentBody> for RfdUpdateContentBody {
+            fn from(value: super::RfdUpdateContentBody) -> Self {
                 Self {
-                    commits: Ok(value.commits),
-                    head_commit: Ok(value.head_commit),
-                    installation: Ok(value.installation),
-                    ref_: Ok(value.ref_),
-                    repository: Ok(value.repository),
-                    sender: Ok(value.sender),
+                    content: Ok(value.content),
+                    expected_commit: Ok(value.expected_commit),
+                    message: Ok(value.message),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GitHubInstallation {
-            id: Result<u64, String>,
-            node_id: Result<String, String>,
+        pub struct RfdVisibility {
+            visibility: Result<super::Visibility, String>,
         }
 
-        impl Default for GitHubInstallation {
+        impl Default for RfdVisibility {
             fn default() -> Self {
                 Self {
-                    id: Err("no value supplied for id".to_string()),
-                    node_id: Err("no value supplied for node_id".to_string()),
+                    visibility: Err("no value supplied for visibility".to_string()),
                 }
             }
         }
 
-        impl GitHubInstallation {
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<u64>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn node_id<T>(mut self, value: T) -> Self
+        impl RfdVisibility {
+            pub fn visibility<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<super::Visibility>,
                 T::Error: std::fmt::Display,
             {
-                self.node_id = value
+                self.visibility = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GitHubInstallation> for super::GitHubInstallation {
+        impl std::convert::TryFrom<RfdVisibility> for super::RfdVisibility {
             type Error = super::error::ConversionError;
-            fn try_from(value: GitHubInstallation) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: RfdVisibility) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    id: value.id?,
-                    node_id: value.node_id?,
+                    visibility: value.visibility?,
                 })
             }
         }
 
-        impl From<super::GitHubInstallation> for GitHubInstallation {
-            fn from(value: super::GitHubInstallation) -> Self {
+        impl From<super::RfdVisibility> for RfdVisibility {
+            fn from(value: super::RfdVisibility) -> Self {
                 Self {
-                    id: Ok(value.id),
-                    node_id: Ok(value.node_id),
+                    visibility: Ok(value.visibility),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GitHubRepository {
-            default_branch: Result<String, String>,
-            id: Result<u64, String>,
-            name: Result<String, String>,
-            node_id: Result<String, String>,
-            owner: Result<super::GitHubRepositoryOwner, String>,
+        pub struct SearchResultHit {
+            anchor: Result<Option<String>, String>,
+            content: Result<String, String>,
+            formatted: Result<Option<super::FormattedSearchResultHit>, String>,
+            hierarchy: Result<[Option<String>; 6usize], String>,
+            hierarchy_radio: Result<[Option<String>; 6usize], String>,
+            object_id: Result<String, String>,
+            rfd_number: Result<u64, String>,
+            url: Result<Option<String>, String>,
         }
 
-        impl Default for GitHubRepository {
+        impl Default for SearchResultHit {
             fn default() -> Self {
                 Self {
-                    default_branch: Err("no value supplied for default_branch".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    name: Err("no value supplied for name".to_string()),
-                    node_id: Err("no value supplied for node_id".to_string()),
-                    owner: Err("no value supplied for owner".to_string()),
+                    anchor: Ok(Default::default()),
+                    content: Err("no value supplied for content".to_string()),
+                    formatted: Ok(Default::default()),
+                    hierarchy: Err("no value supplied for hierarchy".to_string()),
+                    hierarchy_radio: Err("no value supplied for hierarchy_radio".to_string()),
+                    object_id: Err("no value supplied for object_id".to_string()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    url: Ok(Default::default()),
                 }
             }
         }
 
-        impl GitHubRepository {
-            pub fn default_branch<T>(mut self, value: T) -> Self
+        impl SearchResultHit {
+            pub fn anchor<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<String>>,
+                T::Error: std::fmt::Display,
+            {
+                self.anchor = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
+                self
+            }
+            pub fn content<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.default_branch = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for default_branch: {}", e)
-                });
+                self.content = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for content: {}", e));
+                self
+            }
+            pub fn formatted<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<super::FormattedSearchResultHit>>,
+                T::Error: std::fmt::Display,
+            {
+                self.formatted = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for formatted: {}", e));
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
+            pub fn hierarchy<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<u64>,
+                T: std::convert::TryInto<[Option<String>; 6usize]>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
+                self.hierarchy = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for hierarchy: {}", e));
                 self
             }
-            pub fn name<T>(mut self, value: T) -> Self
+            pub fn hierarchy_radio<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<[Option<String>; 6usize]>,
                 T::Error: std::fmt::Display,
             {
-                self.name = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
+                self.hierarchy_radio = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for hierarchy_radio: {}", e)
+                });
                 self
             }
-            pub fn node_id<T>(mut self, value: T) -> Self
+            pub fn object_id<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.node_id = value
+                self.object_id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for object_id: {}", e));
                 self
             }
-            pub fn owner<T>(mut self, value: T) -> Self
+            pub fn rfd_number<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::GitHubRepositoryOwner>,
+                T: std::convert::TryInto<u64>,
                 T::Error: std::fmt::Display,
             {
-                self.owner = value
+                self.rfd_number = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for owner: {}", e));
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
                 self
             }
-        }
-
-        impl std::convert::TryFrom<GitHubRepository> for super::GitHubRepository {
-            type Error = super::error::ConversionError;
-            fn try_from(value: GitHubRepository) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    default_branch: value.default_branch?,
-                    id: value.id?,
-                    name: value.name?,
-                    node_id: value.node_id?,
-                    owner: value.owner?,
-                })
-            }
-        }
-
-        impl From<super::GitHubRepository> for GitHubRepository {
-            fn from(value: super::GitHubRepository) -> Self {
-                Self {
-                    default_branch: Ok(value.default_branch),
-                    id: Ok(value.id),
-                    name: Ok(value.name),
-                    node_id: Ok(value.node_id),
-                    owner: Ok(value.owner),
-                }
-            }
-        }
-
-        #[derive(Clone, Debug)]
-        pub struct GitHubRepositoryOwner {
-            login: Result<String, String>,
-        }
-
-        impl Default for GitHubRepositoryOwner {
-            fn default() -> Self {
-                Self {
-                    login: Err("no value supplied for login".to_string()),
-                }
-            }
-        }
-
-        impl GitHubRepositoryOwner {
-            pub fn login<T>(mut self, value: T) -> Self
+            pub fn url<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.login = value
+                self.url = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for login: {}", e));
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GitHubRepositoryOwner> for super::GitHubRepositoryOwner {
+        impl std::convert::TryFrom<SearchResultHit> for super::SearchResultHit {
             type Error = super::error::ConversionError;
-            fn try_from(
-                value: GitHubRepositoryOwner,
-            ) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: SearchResultHit) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    login: value.login?,
+                    anchor: value.anchor?,
+                    content: value.content?,
+                    formatted: value.formatted?,
+                    hierarchy: value.hierarchy?,
+                    hierarchy_radio: value.hierarchy_radio?,
+                    object_id: value.object_id?,
+                    rfd_number: value.rfd_number?,
+                    url: value.url?,
                 })
             }
         }
 
-        impl From<super::GitHubRepositoryOwner> for GitHubRepositoryOwner {
-            fn from(value: super::GitHubRepositoryOwner) -> Self {
+        impl From<super::SearchResultHit> for SearchResultHit {
+            fn from(value: super::SearchResultHit) -> Self {
                 Self {
-                    login: Ok(value.login),
+                    anchor: Ok(value.anchor),
+                    content: Ok(value.content),
+                    formatted: Ok(value.formatted),
+                    hierarchy: Ok(value.hierarchy),
+                    hierarchy_radio: Ok(value.hierarchy_radio),
+                    object_id: Ok(value.object_id),
+                    rfd_number: Ok(value.rfd_number),
+                    url: Ok(value.url),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct GitHubSender {
-            id: Result<u64, String>,
-            login: Result<String, String>,
-            node_id: Result<String, String>,
-            type_: Result<String, String>,
+        pub struct SearchResults {
+            hits: Result<Vec<super::SearchResultHit>, String>,
+            limit: Result<Option<u32>, String>,
+            offset: Result<Option<u32>, String>,
+            query: Result<String, String>,
         }
 
-        impl Default for GitHubSender {
+        impl Default for SearchResults {
             fn default() -> Self {
                 Self {
-                    id: Err("no value supplied for id".to_string()),
-                    login: Err("no value supplied for login".to_string()),
-                    node_id: Err("no value supplied for node_id".to_string()),
-                    type_: Err("no value supplied for type_".to_string()),
+                    hits: Err("no value supplied for hits".to_string()),
+                    limit: Ok(Default::default()),
+                    offset: Ok(Default::default()),
+                    query: Err("no value supplied for query".to_string()),
                 }
             }
         }
 
-        impl GitHubSender {
-            pub fn id<T>(mut self, value: T) -> Self
+        impl SearchResults {
+            pub fn hits<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<u64>,
+                T: std::convert::TryInto<Vec<super::SearchResultHit>>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
+                self.hits = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for hits: {}", e));
                 self
             }
-            pub fn login<T>(mut self, value: T) -> Self
+            pub fn limit<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<u32>>,
                 T::Error: std::fmt::Display,
             {
-                self.login = value
+                self.limit = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for login: {}", e));
+                    .map_err(|e| format!("error converting supplied value for limit: {}", e));
                 self
             }
-            pub fn node_id<T>(mut self, value: T) -> Self
+            pub fn offset<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<u32>>,
                 T::Error: std::fmt::Display,
             {
-                self.node_id = value
+                self.offset = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for node_id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for offset: {}", e));
                 self
             }
-            pub fn type_<T>(mut self, value: T) -> Self
+            pub fn query<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.type_ = value
+                self.query = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for type_: {}", e));
+                    .map_err(|e| format!("error converting supplied value for query: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<GitHubSender> for super::GitHubSender {
+        impl std::convert::TryFrom<SearchResults> for super::SearchResults {
             type Error = super::error::ConversionError;
-            fn try_from(value: GitHubSender) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: SearchResults) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    id: value.id?,
-                    login: value.login?,
-                    node_id: value.node_id?,
-                    type_: value.type_?,
+                    hits: value.hits?,
+                    limit: value.limit?,
+                    offset: value.offset?,
+                    query: value.query?,
                 })
             }
         }
 
-        impl From<super::GitHubSender> for GitHubSender {
-            fn from(value: super::GitHubSender) -> Self {
+        impl From<super::SearchResults> for SearchResults {
+            fn from(value: super::SearchResults) -> Self {
                 Self {
-                    id: Ok(value.id),
-                    login: Ok(value.login),
-                    node_id: Ok(value.node_id),
-                    type_: Ok(value.type_),
+                    hits: Ok(value.hits),
+                    limit: Ok(value.limit),
+                    offset: Ok(value.offset),
+                    query: Ok(value.query),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct InitialApiKeyResponse {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            id: Result<uuid::Uuid, String>,
-            key: Result<super::SecretString, String>,
-            permissions: Result<Option<super::PermissionsForApiPermissionResponse>, String>,
+        pub struct SearchSuggestion {
+            anchor: Result<Option<String>, String>,
+            rfd_number: Result<u64, String>,
+            section: Result<Option<String>, String>,
+            title: Result<Option<String>, String>,
         }
 
-        impl Default for InitialApiKeyResponse {
+        impl Default for SearchSuggestion {
             fn default() -> Self {
                 Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    key: Err("no value supplied for key".to_string()),
-                    permissions: Ok(Default::default()),
+                    anchor: Ok(Default::default()),
+                    rfd_number: Err("no value supplied for rfd_number".to_string()),
+                    section: Ok(Default::default()),
+                    title: Ok(Default::default()),
                 }
             }
         }
 
-        impl InitialApiKeyResponse {
-            pub fn created_at<T>(mut self, value: T) -> Self
+        impl SearchSuggestion {
+            pub fn anchor<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.created_at = value
+                self.anchor = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
+            pub fn rfd_number<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<uuid::Uuid>,
+                T: std::convert::TryInto<u64>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
+                self.rfd_number = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
                 self
             }
-            pub fn key<T>(mut self, value: T) -> Self
+            pub fn section<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::SecretString>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.key = value
+                self.section = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for key: {}", e));
+                    .map_err(|e| format!("error converting supplied value for section: {}", e));
                 self
             }
-            pub fn permissions<T>(mut self, value: T) -> Self
+            pub fn title<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<super::PermissionsForApiPermissionResponse>>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.permissions = value
+                self.title = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for permissions: {}", e));
+                    .map_err(|e| format!("error converting supplied value for title: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<InitialApiKeyResponse> for super::InitialApiKeyResponse {
+        impl std::convert::TryFrom<SearchSuggestion> for super::SearchSuggestion {
             type Error = super::error::ConversionError;
-            fn try_from(
-                value: InitialApiKeyResponse,
-            ) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: SearchSuggestion) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    created_at: value.created_at?,
-                    id: value.id?,
-                    key: value.key?,
-                    permissions: value.permissions?,
+                    anchor: value.anchor?,
+                    rfd_number: value.rfd_number?,
+                    section: value.section?,
+                    title: value.title?,
                 })
             }
         }
 
-        impl From<super::InitialApiKeyResponse> for InitialApiKeyResponse {
-            fn from(value: super::InitialApiKeyResponse) -> Self {
+        impl From<super::SearchSuggestion> for SearchSuggestion {
+            fn from(value: super::SearchSuggestion) -> Self {
                 Self {
-                    created_at: Ok(value.created_at),
-                    id: Ok(value.id),
-                    key: Ok(value.key),
-                    permissions: Ok(value.permissions),
+                    anchor: Ok(value.anchor),
+                    rfd_number: Ok(value.rfd_number),
+                    section: Ok(value.section),
+                    title: Ok(value.title),
                 }
             }
         }
 
-        #[derive(Clone, Debug)]
-        pub struct InitialOAuthClientSecretResponse {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            id: Result<uuid::Uuid, String>,
-            key: Result<super::SecretString, String>,
-        }
-
-        impl Default for InitialOAuthClientSecretResponse {
+        #[derive(Clone, Debug)]
+        pub struct UpdateLabel {
+            color: Result<Option<String>, String>,
+            description: Result<Option<String>, String>,
+        }
+
+        impl Default for UpdateLabel {
             fn default() -> Self {
                 Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    key: Err("no value supplied for key".to_string()),
+                    color: Ok(Default::default()),
+                    description: Ok(Default::default()),
                 }
             }
         }
 
-        impl InitialOAuthClientSecretResponse {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
+        impl UpdateLabel {
+            pub fn color<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<uuid::Uuid>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
+                self.color = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                    .map_err(|e| format!("error converting supplied value for color: {}", e));
                 self
             }
-            pub fn key<T>(mut self, value: T) -> Self
+            pub fn description<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::SecretString>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.key = value
+                self.description = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for key: {}", e));
+                    .map_err(|e| format!("error converting supplied value for description: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<InitialOAuthClientSecretResponse>
-            for super::InitialOAuthClientSecretResponse
-        {
+        impl std::convert::TryFrom<UpdateLabel> for super::UpdateLabel {
             type Error = super::error::ConversionError;
-            fn try_from(
-                value: InitialOAuthClientSecretResponse,
-            ) -> Result<Self, super::error::ConversionError> {
+            fn try_from(value: UpdateLabel) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    created_at: value.created_at?,
-                    id: value.id?,
-                    key: value.key?,
+                    color: value.color?,
+                    description: value.description?,
                 })
             }
         }
 
-        impl From<super::InitialOAuthClientSecretResponse> for InitialOAuthClientSecretResponse {
-            fn from(value: super::InitialOAuthClientSecretResponse) -> Self {
+        impl From<super::UpdateLabel> for UpdateLabel {
+            fn from(value: super::UpdateLabel) -> Self {
                 Self {
-                    created_at: Ok(value.created_at),
-                    id: Ok(value.id),
-                    key: Ok(value.key),
+                    color: Ok(value.color),
+                    description: Ok(value.description),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct Jwk {
-            e: Result<String, String>,
-            kid: Result<String, String>,
-            kty: Result<String, String>,
-            n: Result<String, String>,
-            use_: Result<String, String>,
+        pub struct UpdateWebhookSubscription {
+            events: Result<Vec<super::WebhookEvent>, String>,
+            rfds: Result<Vec<i32>, String>,
+            secret: Result<Option<String>, String>,
+            url: Result<String, String>,
         }
 
-        impl Default for Jwk {
+        impl Default for UpdateWebhookSubscription {
             fn default() -> Self {
                 Self {
-                    e: Err("no value supplied for e".to_string()),
-                    kid: Err("no value supplied for kid".to_string()),
-                    kty: Err("no value supplied for kty".to_string()),
-                    n: Err("no value supplied for n".to_string()),
-                    use_: Err("no value supplied for use_".to_string()),
+                    events: Err("no value supplied for events".to_string()),
+                    rfds: Ok(Default::default()),
+                    secret: Ok(Default::default()),
+                    url: Err("no value supplied for url".to_string()),
                 }
             }
         }
 
-        impl Jwk {
-            pub fn e<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.e = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for e: {}", e));
-                self
-            }
-            pub fn kid<T>(mut self, value: T) -> Self
+        impl UpdateWebhookSubscription {
+            pub fn events<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Vec<super::WebhookEvent>>,
                 T::Error: std::fmt::Display,
             {
-                self.kid = value
+                self.events = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for kid: {}", e));
+                    .map_err(|e| format!("error converting supplied value for events: {}", e));
                 self
             }
-            pub fn kty<T>(mut self, value: T) -> Self
+            pub fn rfds<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Vec<i32>>,
                 T::Error: std::fmt::Display,
             {
-                self.kty = value
+                self.rfds = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for kty: {}", e));
+                    .map_err(|e| format!("error converting supplied value for rfds: {}", e));
                 self
             }
-            pub fn n<T>(mut self, value: T) -> Self
+            pub fn secret<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<String>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.n = value
+                self.secret = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for n: {}", e));
+                    .map_err(|e| format!("error converting supplied value for secret: {}", e));
                 self
             }
-            pub fn use_<T>(mut self, value: T) -> Self
+            pub fn url<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.use_ = value
+                self.url = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for use_: {}", e));
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<Jwk> for super::Jwk {
+        impl std::convert::TryFrom<UpdateWebhookSubscription> for super::UpdateWebhookSubscription {
             type Error = super::error::ConversionError;
-            fn try_from(value: Jwk) -> Result<Self, super::error::ConversionError> {
+            fn try_from(
+                value: UpdateWebhookSubscription,
+            ) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    e: value.e?,
-                    kid: value.kid?,
-                    kty: value.kty?,
-                    n: value.n?,
-                    use_: value.use_?,
+                    events: value.events?,
+                    rfds: value.rfds?,
+                    secret: value.secret?,
+                    url: value.url?,
                 })
             }
         }
 
-        impl From<super::Jwk> for Jwk {
-            fn from(value: super::Jwk) -> Self {
+        impl From<super::UpdateWebhookSubscription> for UpdateWebhookSubscription {
+            fn from(value: super::UpdateWebhookSubscription) -> Self {
                 Self {
-                    e: Ok(value.e),
-                    kid: Ok(value.kid),
-                    kty: Ok(value.kty),
-                    n: Ok(value.n),
-                    use_: Ok(value.use_),
+                    events: Ok(value.events),
+                    rfds: Ok(value.rfds),
+                    secret: Ok(value.secret),
+                    url: Ok(value.url),
                 }
             }
         }
 
         #[derive(Clone, Debug)]
-        pub struct Jwks {
-            keys: Result<Vec<super::Jwk>, String>,
+        pub struct WebhookDelivery {
+            attempts: Result<i32, String>,
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            delivered_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            event: Result<super::WebhookEvent, String>,
+            id: Result<uuid::Uuid, String>,
+            last_error: Result<Option<String>, String>,
+            next_attempt_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
+            payload: Result<serde_json::Value, String>,
+            response_status: Result<Option<i32>, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            webhook_subscription_id: Result<uuid::Uuid, String>,
         }
 
-        impl Default for Jwks {
+        impl Default for WebhookDelivery {
             fn default() -> Self {
                 Self {
-                    keys: Err("no value supplied for keys".to_string()),
+                    attempts: Err("no value supplied for attempts".to_string()),
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    delivered_at: Ok(Default::default()),
+                    event: Err("no value supplied for event".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    last_error: Ok(Default::default()),
+                    next_attempt_at: Ok(Default::default()),
+                    payload: Err("no value supplied for payload".to_string()),
+                    response_status: Ok(Default::default()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                    webhook_subscription_id: Err(
+                        "no value supplied for webhook_subscription_id".to_string()
+                    ),
                 }
             }
         }
 
-        impl Jwks {
-            pub fn keys<T>(mut self, value: T) -> Self
+        impl WebhookDelivery {
+            pub fn attempts<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Vec<super::Jwk>>,
+                T: std::convert::TryInto<i32>,
                 T::Error: std::fmt::Display,
             {
-                self.keys = value
+                self.attempts = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for keys: {}", e));
+                    .map_err(|e| format!("error converting supplied value for attempts: {}", e));
                 self
             }
-        }
-
-        impl std::convert::TryFrom<Jwks> for super::Jwks {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Jwks) -> Result<Self, super::error::ConversionError> {
-                Ok(Self { keys: value.keys? })
+            pub fn created_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.created_at = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
+                self
             }
-        }
-
-        impl From<super::Jwks> for Jwks {
-            fn from(value: super::Jwks) -> Self {
-                Self {
-                    keys: Ok(value.keys),
-                }
+            pub fn delivered_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
+                T::Error: std::fmt::Display,
+            {
+                self.delivered_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for delivered_at: {}", e)
+                });
+                self
             }
-        }
-
-        #[derive(Clone, Debug)]
-        pub struct ListRfd {
-            authors: Result<Option<String>, String>,
-            commit: Result<super::CommitSha, String>,
-            committed_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            discussion: Result<Option<String>, String>,
-            format: Result<super::ContentFormat, String>,
-            id: Result<uuid::Uuid, String>,
-            labels: Result<Option<String>, String>,
-            link: Result<Option<String>, String>,
-            rfd_number: Result<i32, String>,
-            sha: Result<super::FileSha, String>,
-            state: Result<Option<String>, String>,
-            title: Result<String, String>,
-            visibility: Result<super::Visibility, String>,
-        }
-
-        impl Default for ListRfd {
-            fn default() -> Self {
-                Self {
-                    authors: Ok(Default::default()),
-                    commit: Err("no value supplied for commit".to_string()),
-                    committed_at: Err("no value supplied for committed_at".to_string()),
-                    discussion: Ok(Default::default()),
-                    format: Err("no value supplied for format".to_string()),
-                    id: Err("no value supplied for id".to_string()),
-                    labels: Ok(Default::default()),
-                    link: Ok(Default::default()),
-                    rfd_number: Err("no value supplied for rfd_number".to_string()),
-                    sha: Err("no value supplied for sha".to_string()),
-                    state: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                    visibility: Err("no value supplied for visibility".to_string()),
-                }
+            pub fn event<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<super::WebhookEvent>,
+                T::Error: std::fmt::Display,
+            {
+                self.event = value
+                    .try_into()
+                    .map_err(|e| format!("error converting supplied value for event: {}", e));
+                self
             }
-        }
-
-        impl ListRfd {
-            pub fn authors<T>(mut self, value: T) -> Self
+            pub fn id<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.authors = value
+                self.id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for authors: {}", e));
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
                 self
             }
-            pub fn commit<T>(mut self, value: T) -> Self
+            pub fn last_error<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::CommitSha>,
+                T: std::convert::TryInto<Option<String>>,
                 T::Error: std::fmt::Display,
             {
-                self.commit = value
+                self.last_error = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for commit: {}", e));
+                    .map_err(|e| format!("error converting supplied value for last_error: {}", e));
                 self
             }
-            pub fn committed_at<T>(mut self, value: T) -> Self
+            pub fn next_attempt_at<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
                 T::Error: std::fmt::Display,
             {
-                self.committed_at = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for committed_at: {}", e)
+                self.next_attempt_at = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for next_attempt_at: {}", e)
                 });
                 self
             }
-            pub fn discussion<T>(mut self, value: T) -> Self
+            pub fn payload<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<serde_json::Value>,
                 T::Error: std::fmt::Display,
             {
-                self.discussion = value
+                self.payload = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for discussion: {}", e));
+                    .map_err(|e| format!("error converting supplied value for payload: {}", e));
                 self
             }
-            pub fn format<T>(mut self, value: T) -> Self
+            pub fn response_status<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::ContentFormat>,
+                T: std::convert::TryInto<Option<i32>>,
                 T::Error: std::fmt::Display,
             {
-                self.format = value
+                self.response_status = value.try_into().map_err(|e| {
+                    format!("error converting supplied value for response_status: {}", e)
+                });
+                self
+            }
+            pub fn updated_at<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
+                T::Error: std::fmt::Display,
+            {
+                self.updated_at = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for format: {}", e));
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
                 self
             }
-            pub fn id<T>(mut self, value: T) -> Self
+            pub fn webhook_subscription_id<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
+                self.webhook_subscription_id = value.try_into().map_err(|e| {
+                    format!(
+                        "error converting supplied value for webhook_subscription_id: {}",
+                        e
+                    )
+                });
                 self
             }
-            pub fn labels<T>(mut self, value: T) -> Self
+        }
+
+        impl std::convert::TryFrom<WebhookDelivery> for super::WebhookDelivery {
+            type Error = super::error::ConversionError;
+            fn try_from(value: WebhookDelivery) -> Result<Self, super::error::ConversionError> {
+                Ok(Self {
+                    attempts: value.attempts?,
+                    created_at: value.created_at?,
+                    delivered_at: value.delivered_at?,
+                    event: value.event?,
+                    id: value.id?,
+                    last_error: value.last_error?,
+                    next_attempt_at: value.next_attempt_at?,
+                    payload: value.payload?,
+                    response_status: value.response_status?,
+                    updated_at: value.updated_at?,
+                    webhook_subscription_id: value.webhook_subscription_id?,
+                })
+            }
+        }
+
+        impl From<super::WebhookDelivery> for WebhookDelivery {
+            fn from(value: super::WebhookDelivery) -> Self {
+                Self {
+                    attempts: Ok(value.attempts),
+                    created_at: Ok(value.created_at),
+                    delivered_at: Ok(value.delivered_at),
+                    event: Ok(value.event),
+                    id: Ok(value.id),
+                    last_error: Ok(value.last_error),
+                    next_attempt_at: Ok(value.next_attempt_at),
+                    payload: Ok(value.payload),
+                    response_status: Ok(value.response_status),
+                    updated_at: Ok(value.updated_at),
+                    webhook_subscription_id: Ok(value.webhook_subscription_id),
+                }
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct WebhookSubscriptionResponse {
+            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            events: Result<Vec<super::WebhookEvent>, String>,
+            id: Result<uuid::Uuid, String>,
+            rfds: Result<Vec<i32>, String>,
+            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
+            url: Result<String, String>,
+        }
+
+        impl Default for WebhookSubscriptionResponse {
+            fn default() -> Self {
+                Self {
+                    created_at: Err("no value supplied for created_at".to_string()),
+                    events: Err("no value supplied for events".to_string()),
+                    id: Err("no value supplied for id".to_string()),
+                    rfds: Ok(Default::default()),
+                    updated_at: Err("no value supplied for updated_at".to_string()),
+                    url: Err("no value supplied for url".to_string()),
+                }
+            }
+        }
+
+        impl WebhookSubscriptionResponse {
+            pub fn created_at<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
                 T::Error: std::fmt::Display,
             {
-                self.labels = value
+                self.created_at = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for labels: {}", e));
+                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
                 self
             }
-            pub fn link<T>(mut self, value: T) -> Self
+            pub fn events<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<Vec<super::WebhookEvent>>,
                 T::Error: std::fmt::Display,
             {
-                self.link = value
+                self.events = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for link: {}", e));
+                    .map_err(|e| format!("error converting supplied value for events: {}", e));
                 self
             }
-            pub fn rfd_number<T>(mut self, value: T) -> Self
+            pub fn id<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<i32>,
+                T: std::convert::TryInto<uuid::Uuid>,
                 T::Error: std::fmt::Display,
             {
-                self.rfd_number = value
+                self.id = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
+                    .map_err(|e| format!("error converting supplied value for id: {}", e));
                 self
             }
-            pub fn sha<T>(mut self, value: T) -> Self
+            pub fn rfds<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<super::FileSha>,
+                T: std::convert::TryInto<Vec<i32>>,
                 T::Error: std::fmt::Display,
             {
-                self.sha = value
+                self.rfds = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for sha: {}", e));
+                    .map_err(|e| format!("error converting supplied value for rfds: {}", e));
                 self
             }
-            pub fn state<T>(mut self, value: T) -> Self
+            pub fn updated_at<T>(mut self, value: T) -> Self
             where
-                T: std::convert::TryInto<Option<String>>,
+                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
                 T::Error: std::fmt::Display,
             {
-                self.state = value
+                self.updated_at = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for state: {}", e));
+                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
                 self
             }
-            pub fn title<T>(mut self, value: T) -> Self
+            pub fn url<T>(mut self, value: T) -> Self
             where
                 T: std::convert::TryInto<String>,
                 T::Error: std::fmt::Display,
             {
-                self.title = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
-                self
-            }
-            pub fn visibility<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::Visibility>,
-                T::Error: std::fmt::Display,
-            {
-                self.visibility = value
+                self.url = value
                     .try_into()
-                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
+                    .map_err(|e| format!("error converting supplied value for url: {}", e));
                 self
             }
         }
 
-        impl std::convert::TryFrom<ListRfd> for super::ListRfd {
+        impl std::convert::TryFrom<WebhookSubscriptionResponse> for super::WebhookSubscriptionResponse {
             type Error = super::error::ConversionError;
-            fn try_from(value: ListRfd) -> Result<Self, super::error::ConversionError> {
+            fn try_from(
+                value: WebhookSubscriptionResponse,
+            ) -> Result<Self, super::error::ConversionError> {
                 Ok(Self {
-                    authors: value.authors?,
-                    commit: value.commit?,
-                    committed_at: value.committed_at?,
-                    discussion: value.discussion?,
-                    format: value.format?,
+                    created_at: value.created_at?,
+                    events: value.events?,
                     id: value.id?,
-                    labels: value.labels?,
-                    link: value.link?,
-                    rfd_number: value.rfd_number?,
-                    sha: value.sha?,
-                    state: value.state?,
-                    title: value.title?,
-                    visibility: value.visibility?,
+                    rfds: value.rfds?,
+                    updated_at: value.updated_at?,
+                    url: value.url?,
                 })
             }
         }
 
-        impl From<super::ListRfd> for ListRfd {
-            fn from(value: super::ListRfd) -> Self {
+        impl From<super::WebhookSubscriptionResponse> for WebhookSubscriptionResponse {
+            fn from(value: super::WebhookSubscriptionResponse) -> Self {
                 Self {
-                    authors: Ok(value.authors),
-                    commit: Ok(value.commit),
-                    committed_at: Ok(value.committed_at),
-                    discussion: Ok(value.discussion),
-                    format: Ok(value.format),
+                    created_at: Ok(value.created_at),
+                    events: Ok(value.events),
                     id: Ok(value.id),
-                    labels: Ok(value.labels),
-                    link: Ok(value.link),
-                    rfd_number: Ok(value.rfd_number),
-                    sha: Ok(value.sha),
-                    state: Ok(value.state),
-                    title: Ok(value.title),
-                    visibility: Ok(value.visibility),
+                    rfds: Ok(value.rfds),
+                    updated_at: Ok(value.updated_at),
+                    url: Ok(value.url),
                 }
             }
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct LocalLogin {
-            email: Result<String, String>,
-            external_id: Result<String, String>,
+    /// Generation of default values for serde.
+    pub mod defaults {
+        pub(super) fn mapping_rules_default_permissions() -> super::PermissionsForApiPermission {
+            super::PermissionsForApiPermission(vec![])
         }
 
-        impl Default for LocalLogin {
-            fn default() -> Self {
-                Self {
-                    email: Err("no value supplied for email".to_string()),
-                    external_id: Err("no value supplied for external_id".to_string()),
-                }
-            }
+        pub(super) fn mapping_rules_email_address_permissions() -> super::PermissionsForApiPermission
+        {
+            super::PermissionsForApiPermission(vec![])
+        }
+
+        pub(super) fn mapping_rules_email_domain_permissions() -> super::PermissionsForApiPermission
+        {
+            super::PermissionsForApiPermission(vec![])
+        }
+
+        pub(super) fn mapping_rules_github_username_permissions(
+        ) -> super::PermissionsForApiPermission {
+            super::PermissionsForApiPermission(vec![])
         }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Client for RFD API
+///
+/// Programmatic access to RFDs
+///
+/// Version:
+pub struct Client {
+    pub(crate) baseurl: String,
+    pub(crate) client: reqwest::Client,
+}
+
+impl Client {
+    /// Create a new client.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new(baseurl: &str) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let dur = std::time::Duration::from_secs(15);
+            reqwest::ClientBuilder::new()
+                .connect_timeout(dur)
+                .timeout(dur)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let client = reqwest::ClientBuilder::new();
+        Self::new_with_client(baseurl, client.build().unwrap())
+    }
+
+    /// Construct a new client with an existing `reqwest::Client`,
+    /// allowing more control over its configuration.
+    ///
+    /// `baseurl` is the base URL provided to the internal
+    /// `reqwest::Client`, and should include a scheme and hostname,
+    /// as well as port and a path stem if applicable.
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+        Self {
+            baseurl: baseurl.to_string(),
+            client,
+        }
+    }
+
+    /// Get the base URL to which requests are made.
+    pub fn baseurl(&self) -> &String {
+        &self.baseurl
+    }
+
+    /// Get the internal `reqwest::Client` used to make requests.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Get the version of this API.
+    ///
+    /// This string is pulled directly from the source OpenAPI
+    /// document and may be in any format the API selects.
+    pub fn api_version(&self) -> &'static str {
+        ""
+    }
+}
+
+impl Client {
+    /// Sends a `GET` request to `/.well-known/jwks.json`
+    ///
+    /// ```ignore
+    /// let response = client.jwks_json()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn jwks_json(&self) -> builder::JwksJson {
+        builder::JwksJson::new(self)
+    }
+
+    /// Sends a `GET` request to `/.well-known/openid-configuration`
+    ///
+    /// ```ignore
+    /// let response = client.openid_configuration()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn openid_configuration(&self) -> builder::OpenidConfiguration {
+        builder::OpenidConfiguration::new(self)
+    }
+
+    /// Create a new user with a given set of permissions
+    ///
+    /// Sends a `POST` request to `/api-user`
+    ///
+    /// ```ignore
+    /// let response = client.create_api_user()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_api_user(&self) -> builder::CreateApiUser {
+        builder::CreateApiUser::new(self)
+    }
+
+    /// Get user information for a given user id
+    ///
+    /// Sends a `GET` request to `/api-user/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.get_api_user()
+    ///    .identifier(identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_api_user(&self) -> builder::GetApiUser {
+        builder::GetApiUser::new(self)
+    }
+
+    /// Update the permissions assigned to a given user
+    ///
+    /// Sends a `POST` request to `/api-user/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.update_api_user()
+    ///    .identifier(identifier)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn update_api_user(&self) -> builder::UpdateApiUser {
+        builder::UpdateApiUser::new(self)
+    }
 
-        impl LocalLogin {
-            pub fn email<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.email = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for email: {}", e));
-                self
-            }
-            pub fn external_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.external_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for external_id: {}", e));
-                self
-            }
-        }
+    /// Sends a `POST` request to `/api-user/{identifier}/feed-token`
+    ///
+    /// ```ignore
+    /// let response = client.create_api_user_feed_token()
+    ///    .identifier(identifier)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_api_user_feed_token(&self) -> builder::CreateApiUserFeedToken {
+        builder::CreateApiUserFeedToken::new(self)
+    }
 
-        impl std::convert::TryFrom<LocalLogin> for super::LocalLogin {
-            type Error = super::error::ConversionError;
-            fn try_from(value: LocalLogin) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    email: value.email?,
-                    external_id: value.external_id?,
-                })
-            }
-        }
+    /// Sends a `POST` request to `/api-user/{identifier}/group`
+    ///
+    /// ```ignore
+    /// let response = client.add_api_user_to_group()
+    ///    .identifier(identifier)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn add_api_user_to_group(&self) -> builder::AddApiUserToGroup {
+        builder::AddApiUserToGroup::new(self)
+    }
 
-        impl From<super::LocalLogin> for LocalLogin {
-            fn from(value: super::LocalLogin) -> Self {
-                Self {
-                    email: Ok(value.email),
-                    external_id: Ok(value.external_id),
-                }
-            }
-        }
+    /// Sends a `DELETE` request to `/api-user/{identifier}/group/{group_id}`
+    ///
+    /// ```ignore
+    /// let response = client.remove_api_user_from_group()
+    ///    .identifier(identifier)
+    ///    .group_id(group_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn remove_api_user_from_group(&self) -> builder::RemoveApiUserFromGroup {
+        builder::RemoveApiUserFromGroup::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct Mapper {
-            activations: Result<Option<i32>, String>,
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            depleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            max_activations: Result<Option<i32>, String>,
-            name: Result<String, String>,
-            rule: Result<serde_json::Value, String>,
-        }
+    /// List the active and expired API tokens for a given user
+    ///
+    /// Sends a `GET` request to `/api-user/{identifier}/token`
+    ///
+    /// ```ignore
+    /// let response = client.list_api_user_tokens()
+    ///    .identifier(identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn list_api_user_tokens(&self) -> builder::ListApiUserTokens {
+        builder::ListApiUserTokens::new(self)
+    }
 
-        impl Default for Mapper {
-            fn default() -> Self {
-                Self {
-                    activations: Ok(Default::default()),
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    depleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    max_activations: Ok(Default::default()),
-                    name: Err("no value supplied for name".to_string()),
-                    rule: Err("no value supplied for rule".to_string()),
-                }
-            }
-        }
+    /// Sends a `POST` request to `/api-user/{identifier}/token`
+    ///
+    /// ```ignore
+    /// let response = client.create_api_user_token()
+    ///    .identifier(identifier)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_api_user_token(&self) -> builder::CreateApiUserToken {
+        builder::CreateApiUserToken::new(self)
+    }
 
-        impl Mapper {
-            pub fn activations<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<i32>>,
-                T::Error: std::fmt::Display,
-            {
-                self.activations = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for activations: {}", e));
-                self
-            }
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn depleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.depleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for depleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn max_activations<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<i32>>,
-                T::Error: std::fmt::Display,
-            {
-                self.max_activations = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for max_activations: {}", e)
-                });
-                self
-            }
-            pub fn name<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.name = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for name: {}", e));
-                self
-            }
-            pub fn rule<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<serde_json::Value>,
-                T::Error: std::fmt::Display,
-            {
-                self.rule = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for rule: {}", e));
-                self
-            }
-        }
+    /// Sends a `GET` request to
+    /// `/api-user/{identifier}/token/{token_identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.get_api_user_token()
+    ///    .identifier(identifier)
+    ///    .token_identifier(token_identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_api_user_token(&self) -> builder::GetApiUserToken {
+        builder::GetApiUserToken::new(self)
+    }
 
-        impl std::convert::TryFrom<Mapper> for super::Mapper {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Mapper) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    activations: value.activations?,
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    depleted_at: value.depleted_at?,
-                    id: value.id?,
-                    max_activations: value.max_activations?,
-                    name: value.name?,
-                    rule: value.rule?,
-                })
-            }
-        }
+    /// Sends a `DELETE` request to
+    /// `/api-user/{identifier}/token/{token_identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_api_user_token()
+    ///    .identifier(identifier)
+    ///    .token_identifier(token_identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_api_user_token(&self) -> builder::DeleteApiUserToken {
+        builder::DeleteApiUserToken::new(self)
+    }
+
+    /// Sends a `GET` request to `/group`
+    ///
+    /// ```ignore
+    /// let response = client.get_groups()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_groups(&self) -> builder::GetGroups {
+        builder::GetGroups::new(self)
+    }
 
-        impl From<super::Mapper> for Mapper {
-            fn from(value: super::Mapper) -> Self {
-                Self {
-                    activations: Ok(value.activations),
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    depleted_at: Ok(value.depleted_at),
-                    id: Ok(value.id),
-                    max_activations: Ok(value.max_activations),
-                    name: Ok(value.name),
-                    rule: Ok(value.rule),
-                }
-            }
-        }
+    /// Sends a `POST` request to `/group`
+    ///
+    /// ```ignore
+    /// let response = client.create_group()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_group(&self) -> builder::CreateGroup {
+        builder::CreateGroup::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthAuthzCodeExchangeBody {
-            client_id: Result<uuid::Uuid, String>,
-            client_secret: Result<super::SecretString, String>,
-            code: Result<String, String>,
-            grant_type: Result<String, String>,
-            pkce_verifier: Result<Option<String>, String>,
-            redirect_uri: Result<String, String>,
-        }
+    /// Sends a `PUT` request to `/group/{group_id}`
+    ///
+    /// ```ignore
+    /// let response = client.update_group()
+    ///    .group_id(group_id)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn update_group(&self) -> builder::UpdateGroup {
+        builder::UpdateGroup::new(self)
+    }
 
-        impl Default for OAuthAuthzCodeExchangeBody {
-            fn default() -> Self {
-                Self {
-                    client_id: Err("no value supplied for client_id".to_string()),
-                    client_secret: Err("no value supplied for client_secret".to_string()),
-                    code: Err("no value supplied for code".to_string()),
-                    grant_type: Err("no value supplied for grant_type".to_string()),
-                    pkce_verifier: Ok(Default::default()),
-                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
-                }
-            }
-        }
+    /// Sends a `DELETE` request to `/group/{group_id}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_group()
+    ///    .group_id(group_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_group(&self) -> builder::DeleteGroup {
+        builder::DeleteGroup::new(self)
+    }
 
-        impl OAuthAuthzCodeExchangeBody {
-            pub fn client_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.client_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for client_id: {}", e));
-                self
-            }
-            pub fn client_secret<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::SecretString>,
-                T::Error: std::fmt::Display,
-            {
-                self.client_secret = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for client_secret: {}", e)
-                });
-                self
-            }
-            pub fn code<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.code = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for code: {}", e));
-                self
-            }
-            pub fn grant_type<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.grant_type = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for grant_type: {}", e));
-                self
-            }
-            pub fn pkce_verifier<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.pkce_verifier = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for pkce_verifier: {}", e)
-                });
-                self
-            }
-            pub fn redirect_uri<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.redirect_uri = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for redirect_uri: {}", e)
-                });
-                self
-            }
-        }
+    /// List the labels in the label taxonomy along with the number of RFDs
+    /// using each label
+    ///
+    /// Sends a `GET` request to `/label`
+    ///
+    /// ```ignore
+    /// let response = client.get_labels()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_labels(&self) -> builder::GetLabels {
+        builder::GetLabels::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthAuthzCodeExchangeBody> for super::OAuthAuthzCodeExchangeBody {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: OAuthAuthzCodeExchangeBody,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    client_id: value.client_id?,
-                    client_secret: value.client_secret?,
-                    code: value.code?,
-                    grant_type: value.grant_type?,
-                    pkce_verifier: value.pkce_verifier?,
-                    redirect_uri: value.redirect_uri?,
-                })
-            }
-        }
+    /// Add a label to the label taxonomy, or update the description and color
+    /// of an existing label
+    ///
+    /// Sends a `PUT` request to `/label/{name}`
+    ///
+    /// ```ignore
+    /// let response = client.update_label()
+    ///    .name(name)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn update_label(&self) -> builder::UpdateLabel {
+        builder::UpdateLabel::new(self)
+    }
 
-        impl From<super::OAuthAuthzCodeExchangeBody> for OAuthAuthzCodeExchangeBody {
-            fn from(value: super::OAuthAuthzCodeExchangeBody) -> Self {
-                Self {
-                    client_id: Ok(value.client_id),
-                    client_secret: Ok(value.client_secret),
-                    code: Ok(value.code),
-                    grant_type: Ok(value.grant_type),
-                    pkce_verifier: Ok(value.pkce_verifier),
-                    redirect_uri: Ok(value.redirect_uri),
-                }
-            }
-        }
+    /// Remove a label from the label taxonomy
+    ///
+    /// Sends a `DELETE` request to `/label/{name}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_label()
+    ///    .name(name)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_label(&self) -> builder::DeleteLabel {
+        builder::DeleteLabel::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthAuthzCodeExchangeResponse {
-            access_token: Result<String, String>,
-            expires_in: Result<i64, String>,
-            token_type: Result<String, String>,
-        }
+    /// Sends a `POST` request to `/login/local`
+    ///
+    /// ```ignore
+    /// let response = client.local_login()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn local_login(&self) -> builder::LocalLogin {
+        builder::LocalLogin::new(self)
+    }
 
-        impl Default for OAuthAuthzCodeExchangeResponse {
-            fn default() -> Self {
-                Self {
-                    access_token: Err("no value supplied for access_token".to_string()),
-                    expires_in: Err("no value supplied for expires_in".to_string()),
-                    token_type: Err("no value supplied for token_type".to_string()),
-                }
-            }
-        }
+    /// Generate the remote provider login url and redirect the user
+    ///
+    /// Sends a `GET` request to `/login/oauth/{provider}/code/authorize`
+    ///
+    /// ```ignore
+    /// let response = client.authz_code_redirect()
+    ///    .provider(provider)
+    ///    .client_id(client_id)
+    ///    .redirect_uri(redirect_uri)
+    ///    .response_type(response_type)
+    ///    .scope(scope)
+    ///    .state(state)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn authz_code_redirect(&self) -> builder::AuthzCodeRedirect {
+        builder::AuthzCodeRedirect::new(self)
+    }
+
+    /// Handle return calls from a remote OAuth provider
+    ///
+    /// Sends a `GET` request to `/login/oauth/{provider}/code/callback`
+    ///
+    /// ```ignore
+    /// let response = client.authz_code_callback()
+    ///    .provider(provider)
+    ///    .code(code)
+    ///    .error(error)
+    ///    .state(state)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn authz_code_callback(&self) -> builder::AuthzCodeCallback {
+        builder::AuthzCodeCallback::new(self)
+    }
+
+    /// Exchange an authorization code for an access token
+    ///
+    /// Sends a `POST` request to `/login/oauth/{provider}/code/token`
+    ///
+    /// ```ignore
+    /// let response = client.authz_code_exchange()
+    ///    .provider(provider)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn authz_code_exchange(&self) -> builder::AuthzCodeExchange {
+        builder::AuthzCodeExchange::new(self)
+    }
 
-        impl OAuthAuthzCodeExchangeResponse {
-            pub fn access_token<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.access_token = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for access_token: {}", e)
-                });
-                self
-            }
-            pub fn expires_in<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<i64>,
-                T::Error: std::fmt::Display,
-            {
-                self.expires_in = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for expires_in: {}", e));
-                self
-            }
-            pub fn token_type<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.token_type = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for token_type: {}", e));
-                self
-            }
-        }
+    /// Sends a `GET` request to `/login/oauth/{provider}/device`
+    ///
+    /// ```ignore
+    /// let response = client.get_device_provider()
+    ///    .provider(provider)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_device_provider(&self) -> builder::GetDeviceProvider {
+        builder::GetDeviceProvider::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthAuthzCodeExchangeResponse>
-            for super::OAuthAuthzCodeExchangeResponse
-        {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: OAuthAuthzCodeExchangeResponse,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    access_token: value.access_token?,
-                    expires_in: value.expires_in?,
-                    token_type: value.token_type?,
-                })
-            }
-        }
+    /// Sends a `POST` request to `/login/oauth/{provider}/device/exchange`
+    ///
+    /// ```ignore
+    /// let response = client.exchange_device_token()
+    ///    .provider(provider)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn exchange_device_token(&self) -> builder::ExchangeDeviceToken {
+        builder::ExchangeDeviceToken::new(self)
+    }
 
-        impl From<super::OAuthAuthzCodeExchangeResponse> for OAuthAuthzCodeExchangeResponse {
-            fn from(value: super::OAuthAuthzCodeExchangeResponse) -> Self {
-                Self {
-                    access_token: Ok(value.access_token),
-                    expires_in: Ok(value.expires_in),
-                    token_type: Ok(value.token_type),
-                }
-            }
-        }
+    /// Sends a `GET` request to `/mapper`
+    ///
+    /// Arguments:
+    /// - `include_depleted`: Include depleted mappers in the returned results
+    /// ```ignore
+    /// let response = client.get_mappers()
+    ///    .include_depleted(include_depleted)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_mappers(&self) -> builder::GetMappers {
+        builder::GetMappers::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthClient {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            redirect_uris: Result<Vec<super::OAuthClientRedirectUri>, String>,
-            secrets: Result<Vec<super::OAuthClientSecret>, String>,
-        }
+    /// Sends a `POST` request to `/mapper`
+    ///
+    /// ```ignore
+    /// let response = client.create_mapper()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_mapper(&self) -> builder::CreateMapper {
+        builder::CreateMapper::new(self)
+    }
 
-        impl Default for OAuthClient {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    redirect_uris: Err("no value supplied for redirect_uris".to_string()),
-                    secrets: Err("no value supplied for secrets".to_string()),
-                }
-            }
-        }
+    /// Sends a `DELETE` request to `/mapper/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_mapper()
+    ///    .identifier(identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_mapper(&self) -> builder::DeleteMapper {
+        builder::DeleteMapper::new(self)
+    }
 
-        impl OAuthClient {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn redirect_uris<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::OAuthClientRedirectUri>>,
-                T::Error: std::fmt::Display,
-            {
-                self.redirect_uris = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for redirect_uris: {}", e)
-                });
-                self
-            }
-            pub fn secrets<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::OAuthClientSecret>>,
-                T::Error: std::fmt::Display,
-            {
-                self.secrets = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for secrets: {}", e));
-                self
-            }
-        }
+    /// List OAuth clients
+    ///
+    /// Sends a `GET` request to `/oauth/client`
+    ///
+    /// ```ignore
+    /// let response = client.list_oauth_clients()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn list_oauth_clients(&self) -> builder::ListOauthClients {
+        builder::ListOauthClients::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthClient> for super::OAuthClient {
-            type Error = super::error::ConversionError;
-            fn try_from(value: OAuthClient) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    id: value.id?,
-                    redirect_uris: value.redirect_uris?,
-                    secrets: value.secrets?,
-                })
-            }
-        }
+    /// Create a new OAuth Client
+    ///
+    /// Sends a `POST` request to `/oauth/client`
+    ///
+    /// ```ignore
+    /// let response = client.create_oauth_client()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_oauth_client(&self) -> builder::CreateOauthClient {
+        builder::CreateOauthClient::new(self)
+    }
 
-        impl From<super::OAuthClient> for OAuthClient {
-            fn from(value: super::OAuthClient) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    id: Ok(value.id),
-                    redirect_uris: Ok(value.redirect_uris),
-                    secrets: Ok(value.secrets),
-                }
-            }
-        }
+    /// Get an new OAuth Client
+    ///
+    /// Sends a `GET` request to `/oauth/client/{client_id}`
+    ///
+    /// ```ignore
+    /// let response = client.get_oauth_client()
+    ///    .client_id(client_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_oauth_client(&self) -> builder::GetOauthClient {
+        builder::GetOauthClient::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthClientRedirectUri {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            oauth_client_id: Result<uuid::Uuid, String>,
-            redirect_uri: Result<String, String>,
-        }
+    /// Add an OAuth client redirect uri
+    ///
+    /// Sends a `POST` request to `/oauth/client/{client_id}/redirect_uri`
+    ///
+    /// ```ignore
+    /// let response = client.create_oauth_client_redirect_uri()
+    ///    .client_id(client_id)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_oauth_client_redirect_uri(&self) -> builder::CreateOauthClientRedirectUri {
+        builder::CreateOauthClientRedirectUri::new(self)
+    }
 
-        impl Default for OAuthClientRedirectUri {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    oauth_client_id: Err("no value supplied for oauth_client_id".to_string()),
-                    redirect_uri: Err("no value supplied for redirect_uri".to_string()),
-                }
-            }
-        }
+    /// Delete an OAuth client redirect uri
+    ///
+    /// Sends a `DELETE` request to
+    /// `/oauth/client/{client_id}/redirect_uri/{redirect_uri_id}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_oauth_client_redirect_uri()
+    ///    .client_id(client_id)
+    ///    .redirect_uri_id(redirect_uri_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_oauth_client_redirect_uri(&self) -> builder::DeleteOauthClientRedirectUri {
+        builder::DeleteOauthClientRedirectUri::new(self)
+    }
 
-        impl OAuthClientRedirectUri {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn oauth_client_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.oauth_client_id = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for oauth_client_id: {}", e)
-                });
-                self
-            }
-            pub fn redirect_uri<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.redirect_uri = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for redirect_uri: {}", e)
-                });
-                self
-            }
-        }
+    /// Add an OAuth client secret
+    ///
+    /// Sends a `POST` request to `/oauth/client/{client_id}/secret`
+    ///
+    /// ```ignore
+    /// let response = client.create_oauth_client_secret()
+    ///    .client_id(client_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_oauth_client_secret(&self) -> builder::CreateOauthClientSecret {
+        builder::CreateOauthClientSecret::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthClientRedirectUri> for super::OAuthClientRedirectUri {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: OAuthClientRedirectUri,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    id: value.id?,
-                    oauth_client_id: value.oauth_client_id?,
-                    redirect_uri: value.redirect_uri?,
-                })
-            }
-        }
+    /// Delete an OAuth client secret
+    ///
+    /// Sends a `DELETE` request to
+    /// `/oauth/client/{client_id}/secret/{secret_id}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_oauth_client_secret()
+    ///    .client_id(client_id)
+    ///    .secret_id(secret_id)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_oauth_client_secret(&self) -> builder::DeleteOauthClientSecret {
+        builder::DeleteOauthClientSecret::new(self)
+    }
 
-        impl From<super::OAuthClientRedirectUri> for OAuthClientRedirectUri {
-            fn from(value: super::OAuthClientRedirectUri) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    id: Ok(value.id),
-                    oauth_client_id: Ok(value.oauth_client_id),
-                    redirect_uri: Ok(value.redirect_uri),
-                }
-            }
-        }
+    /// List all available RFDs
+    ///
+    /// Sends a `GET` request to `/rfd`
+    ///
+    /// ```ignore
+    /// let response = client.get_rfds()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfds(&self) -> builder::GetRfds {
+        builder::GetRfds::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthClientSecret {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            oauth_client_id: Result<uuid::Uuid, String>,
-            secret_signature: Result<String, String>,
-        }
+    /// Create a new RFD
+    ///
+    /// Sends a `POST` request to `/rfd`
+    ///
+    /// ```ignore
+    /// let response = client.reserve_rfd()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn reserve_rfd(&self) -> builder::ReserveRfd {
+        builder::ReserveRfd::new(self)
+    }
 
-        impl Default for OAuthClientSecret {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    oauth_client_id: Err("no value supplied for oauth_client_id".to_string()),
-                    secret_signature: Err("no value supplied for secret_signature".to_string()),
-                }
-            }
-        }
+    /// Get the latest representation of a RFD
+    ///
+    /// Sends a `GET` request to `/rfd/{number}`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.get_rfd()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd(&self) -> builder::GetRfd {
+        builder::GetRfd::new(self)
+    }
 
-        impl OAuthClientSecret {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn oauth_client_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.oauth_client_id = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for oauth_client_id: {}", e)
-                });
-                self
-            }
-            pub fn secret_signature<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.secret_signature = value.try_into().map_err(|e| {
-                    format!(
-                        "error converting supplied value for secret_signature: {}",
-                        e
-                    )
-                });
-                self
-            }
-        }
+    /// Replace the full document of a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.set_rfd_document()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn set_rfd_document(&self) -> builder::SetRfdDocument {
+        builder::SetRfdDocument::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthClientSecret> for super::OAuthClientSecret {
-            type Error = super::error::ConversionError;
-            fn try_from(value: OAuthClientSecret) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    id: value.id?,
-                    oauth_client_id: value.oauth_client_id?,
-                    secret_signature: value.secret_signature?,
-                })
-            }
-        }
+    /// Upload an image or other attachment to a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/assets`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.upload_rfd_asset()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn upload_rfd_asset(&self) -> builder::UploadRfdAsset {
+        builder::UploadRfdAsset::new(self)
+    }
 
-        impl From<super::OAuthClientSecret> for OAuthClientSecret {
-            fn from(value: super::OAuthClientSecret) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    id: Ok(value.id),
-                    oauth_client_id: Ok(value.oauth_client_id),
-                    secret_signature: Ok(value.secret_signature),
-                }
-            }
-        }
+    /// Get an attribute of a RFD
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/attr/{attr}`
+    ///
+    /// ```ignore
+    /// let response = client.get_rfd_attr()
+    ///    .number(number)
+    ///    .attr(attr)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_attr(&self) -> builder::GetRfdAttr {
+        builder::GetRfdAttr::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OAuthProviderInfo {
-            auth_url_endpoint: Result<String, String>,
-            client_id: Result<String, String>,
-            device_code_endpoint: Result<String, String>,
-            provider: Result<super::OAuthProviderName, String>,
-            scopes: Result<Vec<String>, String>,
-            token_endpoint: Result<String, String>,
-        }
+    /// Set an attribute of a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/attr/{attr}`
+    ///
+    /// ```ignore
+    /// let response = client.set_rfd_attr()
+    ///    .number(number)
+    ///    .attr(attr)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn set_rfd_attr(&self) -> builder::SetRfdAttr {
+        builder::SetRfdAttr::new(self)
+    }
 
-        impl Default for OAuthProviderInfo {
-            fn default() -> Self {
-                Self {
-                    auth_url_endpoint: Err("no value supplied for auth_url_endpoint".to_string()),
-                    client_id: Err("no value supplied for client_id".to_string()),
-                    device_code_endpoint: Err(
-                        "no value supplied for device_code_endpoint".to_string()
-                    ),
-                    provider: Err("no value supplied for provider".to_string()),
-                    scopes: Err("no value supplied for scopes".to_string()),
-                    token_endpoint: Err("no value supplied for token_endpoint".to_string()),
-                }
-            }
-        }
+    /// Replace the contents of a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/content`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.set_rfd_content()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn set_rfd_content(&self) -> builder::SetRfdContent {
+        builder::SetRfdContent::new(self)
+    }
 
-        impl OAuthProviderInfo {
-            pub fn auth_url_endpoint<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.auth_url_endpoint = value.try_into().map_err(|e| {
-                    format!(
-                        "error converting supplied value for auth_url_endpoint: {}",
-                        e
-                    )
-                });
-                self
-            }
-            pub fn client_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.client_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for client_id: {}", e));
-                self
-            }
-            pub fn device_code_endpoint<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.device_code_endpoint = value.try_into().map_err(|e| {
-                    format!(
-                        "error converting supplied value for device_code_endpoint: {}",
-                        e
-                    )
-                });
-                self
-            }
-            pub fn provider<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::OAuthProviderName>,
-                T::Error: std::fmt::Display,
-            {
-                self.provider = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for provider: {}", e));
-                self
-            }
-            pub fn scopes<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.scopes = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for scopes: {}", e));
-                self
-            }
-            pub fn token_endpoint<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.token_endpoint = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for token_endpoint: {}", e)
-                });
-                self
-            }
-        }
+    /// Open a RFD for discussion
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/discuss`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.discuss_rfd()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn discuss_rfd(&self) -> builder::DiscussRfd {
+        builder::DiscussRfd::new(self)
+    }
 
-        impl std::convert::TryFrom<OAuthProviderInfo> for super::OAuthProviderInfo {
-            type Error = super::error::ConversionError;
-            fn try_from(value: OAuthProviderInfo) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    auth_url_endpoint: value.auth_url_endpoint?,
-                    client_id: value.client_id?,
-                    device_code_endpoint: value.device_code_endpoint?,
-                    provider: value.provider?,
-                    scopes: value.scopes?,
-                    token_endpoint: value.token_endpoint?,
-                })
-            }
-        }
+    /// Get a RFD rendered as sanitized HTML
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/html`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `commit`: Render the RFD as of the given commit instead of the latest
+    ///   revision
+    /// ```ignore
+    /// let response = client.get_rfd_html()
+    ///    .number(number)
+    ///    .commit(commit)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_html(&self) -> builder::GetRfdHtml {
+        builder::GetRfdHtml::new(self)
+    }
 
-        impl From<super::OAuthProviderInfo> for OAuthProviderInfo {
-            fn from(value: super::OAuthProviderInfo) -> Self {
-                Self {
-                    auth_url_endpoint: Ok(value.auth_url_endpoint),
-                    client_id: Ok(value.client_id),
-                    device_code_endpoint: Ok(value.device_code_endpoint),
-                    provider: Ok(value.provider),
-                    scopes: Ok(value.scopes),
-                    token_endpoint: Ok(value.token_endpoint),
-                }
-            }
-        }
+    /// Add a label to a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/labels/{label}`
+    ///
+    /// ```ignore
+    /// let response = client.add_rfd_label()
+    ///    .number(number)
+    ///    .label(label)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn add_rfd_label(&self) -> builder::AddRfdLabel {
+        builder::AddRfdLabel::new(self)
+    }
+
+    /// Remove a label from a RFD
+    ///
+    /// Sends a `DELETE` request to `/rfd/{number}/labels/{label}`
+    ///
+    /// ```ignore
+    /// let response = client.remove_rfd_label()
+    ///    .number(number)
+    ///    .label(label)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn remove_rfd_label(&self) -> builder::RemoveRfdLabel {
+        builder::RemoveRfdLabel::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct OpenIdConfiguration {
-            jwks_uri: Result<String, String>,
-        }
+    /// Publish a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/publish`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.publish_rfd()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn publish_rfd(&self) -> builder::PublishRfd {
+        builder::PublishRfd::new(self)
+    }
 
-        impl Default for OpenIdConfiguration {
-            fn default() -> Self {
-                Self {
-                    jwks_uri: Err("no value supplied for jwks_uri".to_string()),
-                }
-            }
-        }
+    /// Restore a RFD to the document stored at a previous commit
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/revert`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.revert_rfd()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn revert_rfd(&self) -> builder::RevertRfd {
+        builder::RevertRfd::new(self)
+    }
 
-        impl OpenIdConfiguration {
-            pub fn jwks_uri<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.jwks_uri = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for jwks_uri: {}", e));
-                self
-            }
-        }
+    /// Get the table of contents of a RFD
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/sections`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.get_rfd_sections()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_sections(&self) -> builder::GetRfdSections {
+        builder::GetRfdSections::new(self)
+    }
 
-        impl std::convert::TryFrom<OpenIdConfiguration> for super::OpenIdConfiguration {
-            type Error = super::error::ConversionError;
-            fn try_from(value: OpenIdConfiguration) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    jwks_uri: value.jwks_uri?,
-                })
-            }
-        }
+    /// Get a single section of a RFD
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/sections/{anchor}`
+    ///
+    /// ```ignore
+    /// let response = client.get_rfd_section()
+    ///    .number(number)
+    ///    .anchor(anchor)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_section(&self) -> builder::GetRfdSection {
+        builder::GetRfdSection::new(self)
+    }
 
-        impl From<super::OpenIdConfiguration> for OpenIdConfiguration {
-            fn from(value: super::OpenIdConfiguration) -> Self {
-                Self {
-                    jwks_uri: Ok(value.jwks_uri),
-                }
-            }
-        }
+    /// Replace the body of a single section of a RFD
+    ///
+    /// Sends a `PUT` request to `/rfd/{number}/sections/{anchor}`
+    ///
+    /// ```ignore
+    /// let response = client.set_rfd_section()
+    ///    .number(number)
+    ///    .anchor(anchor)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn set_rfd_section(&self) -> builder::SetRfdSection {
+        builder::SetRfdSection::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct ReserveRfdBody {
-            content: Result<Option<String>, String>,
-            title: Result<String, String>,
-        }
+    /// Get the lifecycle timeline of a RFD, derived from its revision history
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/timeline`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.get_rfd_timeline()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_timeline(&self) -> builder::GetRfdTimeline {
+        builder::GetRfdTimeline::new(self)
+    }
 
-        impl Default for ReserveRfdBody {
-            fn default() -> Self {
-                Self {
-                    content: Ok(Default::default()),
-                    title: Err("no value supplied for title".to_string()),
-                }
-            }
-        }
+    /// List the state transitions that have been made to a RFD
+    ///
+    /// Sends a `GET` request to `/rfd/{number}/transition`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// ```ignore
+    /// let response = client.get_rfd_transitions()
+    ///    .number(number)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_transitions(&self) -> builder::GetRfdTransitions {
+        builder::GetRfdTransitions::new(self)
+    }
 
-        impl ReserveRfdBody {
-            pub fn content<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.content = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for content: {}", e));
-                self
-            }
-            pub fn title<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.title = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for title: {}", e));
-                self
-            }
-        }
+    /// Move a RFD to a new state
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/transition`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.transition_rfd()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn transition_rfd(&self) -> builder::TransitionRfd {
+        builder::TransitionRfd::new(self)
+    }
 
-        impl std::convert::TryFrom<ReserveRfdBody> for super::ReserveRfdBody {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ReserveRfdBody) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    content: value.content?,
-                    title: value.title?,
-                })
-            }
-        }
+    /// Modify the visibility of a RFD
+    ///
+    /// Sends a `POST` request to `/rfd/{number}/visibility`
+    ///
+    /// Arguments:
+    /// - `number`: The RFD number (examples: 1 or 123)
+    /// - `body`
+    /// ```ignore
+    /// let response = client.update_rfd_visibility()
+    ///    .number(number)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn update_rfd_visibility(&self) -> builder::UpdateRfdVisibility {
+        builder::UpdateRfdVisibility::new(self)
+    }
 
-        impl From<super::ReserveRfdBody> for ReserveRfdBody {
-            fn from(value: super::ReserveRfdBody) -> Self {
-                Self {
-                    content: Ok(value.content),
-                    title: Ok(value.title),
-                }
-            }
-        }
+    /// Get the latest representation of many RFDs at once. RFDs that do not
+    /// exist or that the caller is not allowed to access are omitted from the
+    /// response
+    ///
+    /// Sends a `POST` request to `/rfd-batch`
+    ///
+    /// ```ignore
+    /// let response = client.get_rfd_batch()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_batch(&self) -> builder::GetRfdBatch {
+        builder::GetRfdBatch::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct ReserveRfdResponse {
-            number: Result<i32, String>,
-        }
+    /// Search the RFD index and get a list of results
+    ///
+    /// Sends a `GET` request to `/rfd-search`
+    ///
+    /// ```ignore
+    /// let response = client.search_rfds()
+    ///    .attributes_to_crop(attributes_to_crop)
+    ///    .highlight_post_tag(highlight_post_tag)
+    ///    .highlight_pre_tag(highlight_pre_tag)
+    ///    .limit(limit)
+    ///    .offset(offset)
+    ///    .q(q)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn search_rfds(&self) -> builder::SearchRfds {
+        builder::SearchRfds::new(self)
+    }
 
-        impl Default for ReserveRfdResponse {
-            fn default() -> Self {
-                Self {
-                    number: Err("no value supplied for number".to_string()),
-                }
-            }
-        }
+    /// Get a list of RFDs whose number, title, or section headings match the
+    /// start of a query
+    ///
+    /// Sends a `GET` request to `/rfd-search/suggest`
+    ///
+    /// ```ignore
+    /// let response = client.suggest_rfds()
+    ///    .limit(limit)
+    ///    .q(q)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn suggest_rfds(&self) -> builder::SuggestRfds {
+        builder::SuggestRfds::new(self)
+    }
 
-        impl ReserveRfdResponse {
-            pub fn number<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<i32>,
-                T::Error: std::fmt::Display,
-            {
-                self.number = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for number: {}", e));
-                self
-            }
-        }
+    /// List the templates that can be used to create a new RFD
+    ///
+    /// Sends a `GET` request to `/rfd-templates`
+    ///
+    /// ```ignore
+    /// let response = client.get_rfd_templates()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_rfd_templates(&self) -> builder::GetRfdTemplates {
+        builder::GetRfdTemplates::new(self)
+    }
 
-        impl std::convert::TryFrom<ReserveRfdResponse> for super::ReserveRfdResponse {
-            type Error = super::error::ConversionError;
-            fn try_from(value: ReserveRfdResponse) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    number: value.number?,
-                })
-            }
-        }
+    /// Retrieve the user information of the calling user
+    ///
+    /// Sends a `GET` request to `/self`
+    ///
+    /// ```ignore
+    /// let response = client.get_self()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_self(&self) -> builder::GetSelf {
+        builder::GetSelf::new(self)
+    }
 
-        impl From<super::ReserveRfdResponse> for ReserveRfdResponse {
-            fn from(value: super::ReserveRfdResponse) -> Self {
-                Self {
-                    number: Ok(value.number),
-                }
-            }
-        }
+    /// List the configured webhook subscriptions
+    ///
+    /// Sends a `GET` request to `/webhook-subscription`
+    ///
+    /// ```ignore
+    /// let response = client.get_webhook_subscriptions()
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_webhook_subscriptions(&self) -> builder::GetWebhookSubscriptions {
+        builder::GetWebhookSubscriptions::new(self)
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct Rfd {
-            created_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            deleted_at: Result<Option<chrono::DateTime<chrono::offset::Utc>>, String>,
-            id: Result<uuid::Uuid, String>,
-            link: Result<Option<String>, String>,
-            rfd_number: Result<i32, String>,
-            updated_at: Result<chrono::DateTime<chrono::offset::Utc>, String>,
-            visibility: Result<super::Visibility, String>,
-        }
+    /// Subscribe an external endpoint to RFD events
+    ///
+    /// Sends a `POST` request to `/webhook-subscription`
+    ///
+    /// ```ignore
+    /// let response = client.create_webhook_subscription()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn create_webhook_subscription(&self) -> builder::CreateWebhookSubscription {
+        builder::CreateWebhookSubscription::new(self)
+    }
 
-        impl Default for Rfd {
-            fn default() -> Self {
-                Self {
-                    created_at: Err("no value supplied for created_at".to_string()),
-                    deleted_at: Ok(Default::default()),
-                    id: Err("no value supplied for id".to_string()),
-                    link: Ok(Default::default()),
-                    rfd_number: Err("no value supplied for rfd_number".to_string()),
-                    updated_at: Err("no value supplied for updated_at".to_string()),
-                    visibility: Err("no value supplied for visibility".to_string()),
-                }
-            }
-        }
+    /// Get a webhook subscription
+    ///
+    /// Sends a `GET` request to `/webhook-subscription/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.get_webhook_subscription()
+    ///    .identifier(identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_webhook_subscription(&self) -> builder::GetWebhookSubscription {
+        builder::GetWebhookSubscription::new(self)
+    }
 
-        impl Rfd {
-            pub fn created_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.created_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for created_at: {}", e));
-                self
-            }
-            pub fn deleted_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<chrono::DateTime<chrono::offset::Utc>>>,
-                T::Error: std::fmt::Display,
-            {
-                self.deleted_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for deleted_at: {}", e));
-                self
-            }
-            pub fn id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<uuid::Uuid>,
-                T::Error: std::fmt::Display,
-            {
-                self.id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for id: {}", e));
-                self
-            }
-            pub fn link<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.link = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for link: {}", e));
-                self
-            }
-            pub fn rfd_number<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<i32>,
-                T::Error: std::fmt::Display,
-            {
-                self.rfd_number = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
-                self
-            }
-            pub fn updated_at<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<chrono::DateTime<chrono::offset::Utc>>,
-                T::Error: std::fmt::Display,
-            {
-                self.updated_at = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for updated_at: {}", e));
-                self
-            }
-            pub fn visibility<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::Visibility>,
-                T::Error: std::fmt::Display,
-            {
-                self.visibility = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
-                self
-            }
-        }
+    /// Replace the endpoint, filters, and optionally the secret of a webhook
+    /// subscription
+    ///
+    /// Sends a `PUT` request to `/webhook-subscription/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.update_webhook_subscription()
+    ///    .identifier(identifier)
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn update_webhook_subscription(&self) -> builder::UpdateWebhookSubscription {
+        builder::UpdateWebhookSubscription::new(self)
+    }
 
-        impl std::convert::TryFrom<Rfd> for super::Rfd {
-            type Error = super::error::ConversionError;
-            fn try_from(value: Rfd) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    created_at: value.created_at?,
-                    deleted_at: value.deleted_at?,
-                    id: value.id?,
-                    link: value.link?,
-                    rfd_number: value.rfd_number?,
-                    updated_at: value.updated_at?,
-                    visibility: value.visibility?,
-                })
-            }
-        }
+    /// Delete a webhook subscription. Queued deliveries for the subscription
+    /// are not sent
+    ///
+    /// Sends a `DELETE` request to `/webhook-subscription/{identifier}`
+    ///
+    /// ```ignore
+    /// let response = client.delete_webhook_subscription()
+    ///    .identifier(identifier)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn delete_webhook_subscription(&self) -> builder::DeleteWebhookSubscription {
+        builder::DeleteWebhookSubscription::new(self)
+    }
 
-        impl From<super::Rfd> for Rfd {
-            fn from(value: super::Rfd) -> Self {
-                Self {
-                    created_at: Ok(value.created_at),
-                    deleted_at: Ok(value.deleted_at),
-                    id: Ok(value.id),
-                    link: Ok(value.link),
-                    rfd_number: Ok(value.rfd_number),
-                    updated_at: Ok(value.updated_at),
-                    visibility: Ok(value.visibility),
-                }
-            }
-        }
+    /// List the delivery log of a webhook subscription, newest first
+    ///
+    /// Sends a `GET` request to `/webhook-subscription/{identifier}/delivery`
+    ///
+    /// ```ignore
+    /// let response = client.get_webhook_deliveries()
+    ///    .identifier(identifier)
+    ///    .limit(limit)
+    ///    .offset(offset)
+    ///    .send()
+    ///    .await;
+    /// ```
+    pub fn get_webhook_deliveries(&self) -> builder::GetWebhookDeliveries {
+        builder::GetWebhookDeliveries::new(self)
+    }
+}
 
-        #[derive(Clone, Debug)]
-        pub struct RfdAttrValue {
-            message: Result<Option<String>, String>,
-            value: Result<String, String>,
-        }
+/// Internal endpoints
+pub trait ClientHiddenExt {
+    /// Sends a `POST` request to `/github`
+    ///
+    /// ```ignore
+    /// let response = client.github_webhook()
+    ///    .body(body)
+    ///    .send()
+    ///    .await;
+    /// ```
+    fn github_webhook(&self) -> builder::GithubWebhook;
+}
 
-        impl Default for RfdAttrValue {
-            fn default() -> Self {
-                Self {
-                    message: Ok(Default::default()),
-                    value: Err("no value supplied for value".to_string()),
-                }
-            }
-        }
+impl ClientHiddenExt for Client {
+    fn github_webhook(&self) -> builder::GithubWebhook {
+        builder::GithubWebhook::new(self)
+    }
+}
 
-        impl RfdAttrValue {
-            pub fn message<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.message = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for message: {}", e));
-                self
-            }
-            pub fn value<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.value = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for value: {}", e));
-                self
-            }
-        }
+/// Types for composing operation parameters.
+#[allow(clippy::all)]
+pub mod builder {
+    use super::types;
+    #[allow(unused_imports)]
+    use super::{
+        encode_path, ByteStream, Error, HeaderMap, HeaderValue, RequestBuilderExt, ResponseValue,
+    };
+    /// Builder for [`Client::jwks_json`]
+    ///
+    /// [`Client::jwks_json`]: super::Client::jwks_json
+    #[derive(Debug, Clone)]
+    pub struct JwksJson<'a> {
+        client: &'a super::Client,
+    }
 
-        impl std::convert::TryFrom<RfdAttrValue> for super::RfdAttrValue {
-            type Error = super::error::ConversionError;
-            fn try_from(value: RfdAttrValue) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    message: value.message?,
-                    value: value.value?,
-                })
-            }
+    impl<'a> JwksJson<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
         }
 
-        impl From<super::RfdAttrValue> for RfdAttrValue {
-            fn from(value: super::RfdAttrValue) -> Self {
-                Self {
-                    message: Ok(value.message),
-                    value: Ok(value.value),
-                }
+        /// Sends a `GET` request to `/.well-known/jwks.json`
+        pub async fn send(self) -> Result<ResponseValue<types::Jwks>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/.well-known/jwks.json", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        #[derive(Clone, Debug)]
-        pub struct RfdUpdateBody {
-            document: Result<String, String>,
-            message: Result<Option<String>, String>,
-        }
+    /// Builder for [`Client::openid_configuration`]
+    ///
+    /// [`Client::openid_configuration`]: super::Client::openid_configuration
+    #[derive(Debug, Clone)]
+    pub struct OpenidConfiguration<'a> {
+        client: &'a super::Client,
+    }
 
-        impl Default for RfdUpdateBody {
-            fn default() -> Self {
-                Self {
-                    document: Err("no value supplied for document".to_string()),
-                    message: Ok(Default::default()),
-                }
-            }
+    impl<'a> OpenidConfiguration<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
         }
 
-        impl RfdUpdateBody {
-            pub fn document<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.document = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for document: {}", e));
-                self
-            }
-            pub fn message<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.message = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for message: {}", e));
-                self
+        /// Sends a `GET` request to `/.well-known/openid-configuration`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::OpenIdConfiguration>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/.well-known/openid-configuration", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        impl std::convert::TryFrom<RfdUpdateBody> for super::RfdUpdateBody {
-            type Error = super::error::ConversionError;
-            fn try_from(value: RfdUpdateBody) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    document: value.document?,
-                    message: value.message?,
-                })
-            }
-        }
+    /// Builder for [`Client::create_api_user`]
+    ///
+    /// [`Client::create_api_user`]: super::Client::create_api_user
+    #[derive(Debug, Clone)]
+    pub struct CreateApiUser<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::ApiUserUpdateParams, String>,
+    }
 
-        impl From<super::RfdUpdateBody> for RfdUpdateBody {
-            fn from(value: super::RfdUpdateBody) -> Self {
-                Self {
-                    document: Ok(value.document),
-                    message: Ok(value.message),
-                }
+    impl<'a> CreateApiUser<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(types::builder::ApiUserUpdateParams::default()),
             }
         }
 
-        #[derive(Clone, Debug)]
-        pub struct RfdUpdateContentBody {
-            content: Result<String, String>,
-            message: Result<Option<String>, String>,
-        }
-
-        impl Default for RfdUpdateContentBody {
-            fn default() -> Self {
-                Self {
-                    content: Err("no value supplied for content".to_string()),
-                    message: Ok(Default::default()),
-                }
-            }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::ApiUserUpdateParams>,
+            <V as std::convert::TryInto<types::ApiUserUpdateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ApiUserUpdateParams` for body failed: {}", s));
+            self
         }
 
-        impl RfdUpdateContentBody {
-            pub fn content<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.content = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for content: {}", e));
-                self
-            }
-            pub fn message<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.message = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for message: {}", e));
-                self
-            }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::ApiUserUpdateParams,
+            ) -> types::builder::ApiUserUpdateParams,
+        {
+            self.body = self.body.map(f);
+            self
         }
 
-        impl std::convert::TryFrom<RfdUpdateContentBody> for super::RfdUpdateContentBody {
-            type Error = super::error::ConversionError;
-            fn try_from(
-                value: RfdUpdateContentBody,
-            ) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    content: value.content?,
-                    message: value.message?,
-                })
+        /// Sends a `POST` request to `/api-user`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::ApiUserUpdateParams::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/api-user", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        impl From<super::RfdUpdateContentBody> for RfdUpdateContentBody {
-            fn from(value: super::RfdUpdateContentBody) -> Self {
-                Self {
-                    content: Ok(value.content),
-                    message: Ok(value.message),
-                }
+    /// Builder for [`Client::get_api_user`]
+    ///
+    /// [`Client::get_api_user`]: super::Client::get_api_user
+    #[derive(Debug, Clone)]
+    pub struct GetApiUser<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+    }
+
+    impl<'a> GetApiUser<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
             }
         }
 
-        #[derive(Clone, Debug)]
-        pub struct RfdVisibility {
-            visibility: Result<super::Visibility, String>,
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
         }
 
-        impl Default for RfdVisibility {
-            fn default() -> Self {
-                Self {
-                    visibility: Err("no value supplied for visibility".to_string()),
-                }
+        /// Sends a `GET` request to `/api-user/{identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::GetUserResponse>, Error<types::Error>> {
+            let Self { client, identifier } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        impl RfdVisibility {
-            pub fn visibility<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<super::Visibility>,
-                T::Error: std::fmt::Display,
-            {
-                self.visibility = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for visibility: {}", e));
-                self
-            }
-        }
+    /// Builder for [`Client::update_api_user`]
+    ///
+    /// [`Client::update_api_user`]: super::Client::update_api_user
+    #[derive(Debug, Clone)]
+    pub struct UpdateApiUser<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        body: Result<types::builder::ApiUserUpdateParams, String>,
+    }
 
-        impl std::convert::TryFrom<RfdVisibility> for super::RfdVisibility {
-            type Error = super::error::ConversionError;
-            fn try_from(value: RfdVisibility) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    visibility: value.visibility?,
-                })
+    impl<'a> UpdateApiUser<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                body: Ok(types::builder::ApiUserUpdateParams::default()),
             }
         }
 
-        impl From<super::RfdVisibility> for RfdVisibility {
-            fn from(value: super::RfdVisibility) -> Self {
-                Self {
-                    visibility: Ok(value.visibility),
-                }
-            }
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
         }
 
-        #[derive(Clone, Debug)]
-        pub struct SearchResultHit {
-            anchor: Result<Option<String>, String>,
-            content: Result<String, String>,
-            formatted: Result<Option<super::FormattedSearchResultHit>, String>,
-            hierarchy: Result<[Option<String>; 6usize], String>,
-            hierarchy_radio: Result<[Option<String>; 6usize], String>,
-            object_id: Result<String, String>,
-            rfd_number: Result<u64, String>,
-            url: Result<Option<String>, String>,
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::ApiUserUpdateParams>,
+            <V as std::convert::TryInto<types::ApiUserUpdateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ApiUserUpdateParams` for body failed: {}", s));
+            self
         }
 
-        impl Default for SearchResultHit {
-            fn default() -> Self {
-                Self {
-                    anchor: Ok(Default::default()),
-                    content: Err("no value supplied for content".to_string()),
-                    formatted: Ok(Default::default()),
-                    hierarchy: Err("no value supplied for hierarchy".to_string()),
-                    hierarchy_radio: Err("no value supplied for hierarchy_radio".to_string()),
-                    object_id: Err("no value supplied for object_id".to_string()),
-                    rfd_number: Err("no value supplied for rfd_number".to_string()),
-                    url: Ok(Default::default()),
-                }
-            }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::ApiUserUpdateParams,
+            ) -> types::builder::ApiUserUpdateParams,
+        {
+            self.body = self.body.map(f);
+            self
         }
 
-        impl SearchResultHit {
-            pub fn anchor<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.anchor = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for anchor: {}", e));
-                self
-            }
-            pub fn content<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.content = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for content: {}", e));
-                self
-            }
-            pub fn formatted<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<super::FormattedSearchResultHit>>,
-                T::Error: std::fmt::Display,
-            {
-                self.formatted = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for formatted: {}", e));
-                self
-            }
-            pub fn hierarchy<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<[Option<String>; 6usize]>,
-                T::Error: std::fmt::Display,
-            {
-                self.hierarchy = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for hierarchy: {}", e));
-                self
-            }
-            pub fn hierarchy_radio<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<[Option<String>; 6usize]>,
-                T::Error: std::fmt::Display,
-            {
-                self.hierarchy_radio = value.try_into().map_err(|e| {
-                    format!("error converting supplied value for hierarchy_radio: {}", e)
-                });
-                self
-            }
-            pub fn object_id<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.object_id = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for object_id: {}", e));
-                self
-            }
-            pub fn rfd_number<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<u64>,
-                T::Error: std::fmt::Display,
-            {
-                self.rfd_number = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for rfd_number: {}", e));
-                self
-            }
-            pub fn url<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<String>>,
-                T::Error: std::fmt::Display,
-            {
-                self.url = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for url: {}", e));
-                self
+        /// Sends a `POST` request to `/api-user/{identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                identifier,
+                body,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::ApiUserUpdateParams::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        impl std::convert::TryFrom<SearchResultHit> for super::SearchResultHit {
-            type Error = super::error::ConversionError;
-            fn try_from(value: SearchResultHit) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    anchor: value.anchor?,
-                    content: value.content?,
-                    formatted: value.formatted?,
-                    hierarchy: value.hierarchy?,
-                    hierarchy_radio: value.hierarchy_radio?,
-                    object_id: value.object_id?,
-                    rfd_number: value.rfd_number?,
-                    url: value.url?,
-                })
+    /// Builder for [`Client::create_api_user_feed_token`]
+    ///
+    /// [`Client::create_api_user_feed_token`]: super::Client::create_api_user_feed_token
+    #[derive(Debug, Clone)]
+    pub struct CreateApiUserFeedToken<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        body: Result<types::builder::FeedTokenCreateParams, String>,
+    }
+
+    impl<'a> CreateApiUserFeedToken<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                body: Ok(types::builder::FeedTokenCreateParams::default()),
             }
         }
 
-        impl From<super::SearchResultHit> for SearchResultHit {
-            fn from(value: super::SearchResultHit) -> Self {
-                Self {
-                    anchor: Ok(value.anchor),
-                    content: Ok(value.content),
-                    formatted: Ok(value.formatted),
-                    hierarchy: Ok(value.hierarchy),
-                    hierarchy_radio: Ok(value.hierarchy_radio),
-                    object_id: Ok(value.object_id),
-                    rfd_number: Ok(value.rfd_number),
-                    url: Ok(value.url),
-                }
-            }
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
         }
 
-        #[derive(Clone, Debug)]
-        pub struct SearchResults {
-            hits: Result<Vec<super::SearchResultHit>, String>,
-            limit: Result<Option<u32>, String>,
-            offset: Result<Option<u32>, String>,
-            query: Result<String, String>,
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::FeedTokenCreateParams>,
+            <V as std::convert::TryInto<types::FeedTokenCreateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `FeedTokenCreateParams` for body failed: {}",
+                    s
+                )
+            });
+            self
         }
 
-        impl Default for SearchResults {
-            fn default() -> Self {
-                Self {
-                    hits: Err("no value supplied for hits".to_string()),
-                    limit: Ok(Default::default()),
-                    offset: Ok(Default::default()),
-                    query: Err("no value supplied for query".to_string()),
-                }
-            }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::FeedTokenCreateParams,
+            ) -> types::builder::FeedTokenCreateParams,
+        {
+            self.body = self.body.map(f);
+            self
         }
 
-        impl SearchResults {
-            pub fn hits<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Vec<super::SearchResultHit>>,
-                T::Error: std::fmt::Display,
-            {
-                self.hits = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for hits: {}", e));
-                self
-            }
-            pub fn limit<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<u32>>,
-                T::Error: std::fmt::Display,
-            {
-                self.limit = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for limit: {}", e));
-                self
-            }
-            pub fn offset<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<Option<u32>>,
-                T::Error: std::fmt::Display,
-            {
-                self.offset = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for offset: {}", e));
-                self
+        /// Sends a `POST` request to `/api-user/{identifier}/feed-token`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::InitialFeedTokenResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                identifier,
+                body,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::FeedTokenCreateParams::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/feed-token",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
-            pub fn query<T>(mut self, value: T) -> Self
-            where
-                T: std::convert::TryInto<String>,
-                T::Error: std::fmt::Display,
-            {
-                self.query = value
-                    .try_into()
-                    .map_err(|e| format!("error converting supplied value for query: {}", e));
-                self
+        }
+    }
+
+    /// Builder for [`Client::add_api_user_to_group`]
+    ///
+    /// [`Client::add_api_user_to_group`]: super::Client::add_api_user_to_group
+    #[derive(Debug, Clone)]
+    pub struct AddApiUserToGroup<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        body: Result<types::builder::AddGroupBody, String>,
+    }
+
+    impl<'a> AddApiUserToGroup<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                body: Ok(types::builder::AddGroupBody::default()),
             }
         }
 
-        impl std::convert::TryFrom<SearchResults> for super::SearchResults {
-            type Error = super::error::ConversionError;
-            fn try_from(value: SearchResults) -> Result<Self, super::error::ConversionError> {
-                Ok(Self {
-                    hits: value.hits?,
-                    limit: value.limit?,
-                    offset: value.offset?,
-                    query: value.query?,
-                })
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::AddGroupBody>,
+            <V as std::convert::TryInto<types::AddGroupBody>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `AddGroupBody` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::AddGroupBody) -> types::builder::AddGroupBody,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/api-user/{identifier}/group`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                identifier,
+                body,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::AddGroupBody::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/group",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
+    }
 
-        impl From<super::SearchResults> for SearchResults {
-            fn from(value: super::SearchResults) -> Self {
-                Self {
-                    hits: Ok(value.hits),
-                    limit: Ok(value.limit),
-                    offset: Ok(value.offset),
-                    query: Ok(value.query),
-                }
-            }
-        }
+    /// Builder for [`Client::remove_api_user_from_group`]
+    ///
+    /// [`Client::remove_api_user_from_group`]: super::Client::remove_api_user_from_group
+    #[derive(Debug, Clone)]
+    pub struct RemoveApiUserFromGroup<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        group_id: Result<uuid::Uuid, String>,
     }
 
-    /// Generation of default values for serde.
-    pub mod defaults {
-        pub(super) fn mapping_rules_default_permissions() -> super::PermissionsForApiPermission {
-            super::PermissionsForApiPermission(vec![])
+    impl<'a> RemoveApiUserFromGroup<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                group_id: Err("group_id was not initialized".to_string()),
+            }
         }
 
-        pub(super) fn mapping_rules_email_address_permissions() -> super::PermissionsForApiPermission
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            super::PermissionsForApiPermission(vec![])
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
         }
 
-        pub(super) fn mapping_rules_email_domain_permissions() -> super::PermissionsForApiPermission
+        pub fn group_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            super::PermissionsForApiPermission(vec![])
+            self.group_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
+            self
         }
 
-        pub(super) fn mapping_rules_github_username_permissions(
-        ) -> super::PermissionsForApiPermission {
-            super::PermissionsForApiPermission(vec![])
+        /// Sends a `DELETE` request to
+        /// `/api-user/{identifier}/group/{group_id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                identifier,
+                group_id,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let group_id = group_id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/group/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+                encode_path(&group_id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
         }
     }
-}
-
-#[derive(Clone, Debug)]
-/// Client for RFD API
-///
-/// Programmatic access to RFDs
-///
-/// Version:
-pub struct Client {
-    pub(crate) baseurl: String,
-    pub(crate) client: reqwest::Client,
-}
 
-impl Client {
-    /// Create a new client.
+    /// Builder for [`Client::list_api_user_tokens`]
     ///
-    /// `baseurl` is the base URL provided to the internal
-    /// `reqwest::Client`, and should include a scheme and hostname,
-    /// as well as port and a path stem if applicable.
-    pub fn new(baseurl: &str) -> Self {
-        #[cfg(not(target_arch = "wasm32"))]
-        let client = {
-            let dur = std::time::Duration::from_secs(15);
-            reqwest::ClientBuilder::new()
-                .connect_timeout(dur)
-                .timeout(dur)
-        };
-        #[cfg(target_arch = "wasm32")]
-        let client = reqwest::ClientBuilder::new();
-        Self::new_with_client(baseurl, client.build().unwrap())
+    /// [`Client::list_api_user_tokens`]: super::Client::list_api_user_tokens
+    #[derive(Debug, Clone)]
+    pub struct ListApiUserTokens<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
     }
 
-    /// Construct a new client with an existing `reqwest::Client`,
-    /// allowing more control over its configuration.
-    ///
-    /// `baseurl` is the base URL provided to the internal
-    /// `reqwest::Client`, and should include a scheme and hostname,
-    /// as well as port and a path stem if applicable.
-    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
-        Self {
-            baseurl: baseurl.to_string(),
-            client,
+    impl<'a> ListApiUserTokens<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+            }
         }
-    }
-
-    /// Get the base URL to which requests are made.
-    pub fn baseurl(&self) -> &String {
-        &self.baseurl
-    }
-
-    /// Get the internal `reqwest::Client` used to make requests.
-    pub fn client(&self) -> &reqwest::Client {
-        &self.client
-    }
-
-    /// Get the version of this API.
-    ///
-    /// This string is pulled directly from the source OpenAPI
-    /// document and may be in any format the API selects.
-    pub fn api_version(&self) -> &'static str {
-        ""
-    }
-}
-
-impl Client {
-    /// Sends a `GET` request to `/.well-known/jwks.json`
-    ///
-    /// ```ignore
-    /// let response = client.jwks_json()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn jwks_json(&self) -> builder::JwksJson {
-        builder::JwksJson::new(self)
-    }
-
-    /// Sends a `GET` request to `/.well-known/openid-configuration`
-    ///
-    /// ```ignore
-    /// let response = client.openid_configuration()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn openid_configuration(&self) -> builder::OpenidConfiguration {
-        builder::OpenidConfiguration::new(self)
-    }
-
-    /// Create a new user with a given set of permissions
-    ///
-    /// Sends a `POST` request to `/api-user`
-    ///
-    /// ```ignore
-    /// let response = client.create_api_user()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_api_user(&self) -> builder::CreateApiUser {
-        builder::CreateApiUser::new(self)
-    }
 
-    /// Get user information for a given user id
-    ///
-    /// Sends a `GET` request to `/api-user/{identifier}`
-    ///
-    /// ```ignore
-    /// let response = client.get_api_user()
-    ///    .identifier(identifier)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_api_user(&self) -> builder::GetApiUser {
-        builder::GetApiUser::new(self)
-    }
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
+        }
 
-    /// Update the permissions assigned to a given user
-    ///
-    /// Sends a `POST` request to `/api-user/{identifier}`
-    ///
-    /// ```ignore
-    /// let response = client.update_api_user()
-    ///    .identifier(identifier)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn update_api_user(&self) -> builder::UpdateApiUser {
-        builder::UpdateApiUser::new(self)
+        /// Sends a `GET` request to `/api-user/{identifier}/token`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::ApiKeyResponse>>, Error<types::Error>> {
+            let Self { client, identifier } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/token",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Sends a `POST` request to `/api-user/{identifier}/group`
+    /// Builder for [`Client::create_api_user_token`]
     ///
-    /// ```ignore
-    /// let response = client.add_api_user_to_group()
-    ///    .identifier(identifier)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn add_api_user_to_group(&self) -> builder::AddApiUserToGroup {
-        builder::AddApiUserToGroup::new(self)
+    /// [`Client::create_api_user_token`]: super::Client::create_api_user_token
+    #[derive(Debug, Clone)]
+    pub struct CreateApiUserToken<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        body: Result<types::builder::ApiKeyCreateParams, String>,
     }
 
-    /// Sends a `DELETE` request to `/api-user/{identifier}/group/{group_id}`
-    ///
-    /// ```ignore
-    /// let response = client.remove_api_user_from_group()
-    ///    .identifier(identifier)
-    ///    .group_id(group_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn remove_api_user_from_group(&self) -> builder::RemoveApiUserFromGroup {
-        builder::RemoveApiUserFromGroup::new(self)
-    }
+    impl<'a> CreateApiUserToken<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                body: Ok(types::builder::ApiKeyCreateParams::default()),
+            }
+        }
 
-    /// List the active and expired API tokens for a given user
-    ///
-    /// Sends a `GET` request to `/api-user/{identifier}/token`
-    ///
-    /// ```ignore
-    /// let response = client.list_api_user_tokens()
-    ///    .identifier(identifier)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn list_api_user_tokens(&self) -> builder::ListApiUserTokens {
-        builder::ListApiUserTokens::new(self)
-    }
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
+        }
 
-    /// Sends a `POST` request to `/api-user/{identifier}/token`
-    ///
-    /// ```ignore
-    /// let response = client.create_api_user_token()
-    ///    .identifier(identifier)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_api_user_token(&self) -> builder::CreateApiUserToken {
-        builder::CreateApiUserToken::new(self)
-    }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::ApiKeyCreateParams>,
+            <V as std::convert::TryInto<types::ApiKeyCreateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ApiKeyCreateParams` for body failed: {}", s));
+            self
+        }
 
-    /// Sends a `GET` request to
-    /// `/api-user/{identifier}/token/{token_identifier}`
-    ///
-    /// ```ignore
-    /// let response = client.get_api_user_token()
-    ///    .identifier(identifier)
-    ///    .token_identifier(token_identifier)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_api_user_token(&self) -> builder::GetApiUserToken {
-        builder::GetApiUserToken::new(self)
-    }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::ApiKeyCreateParams,
+            ) -> types::builder::ApiKeyCreateParams,
+        {
+            self.body = self.body.map(f);
+            self
+        }
 
-    /// Sends a `DELETE` request to
-    /// `/api-user/{identifier}/token/{token_identifier}`
-    ///
-    /// ```ignore
-    /// let response = client.delete_api_user_token()
-    ///    .identifier(identifier)
-    ///    .token_identifier(token_identifier)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn delete_api_user_token(&self) -> builder::DeleteApiUserToken {
-        builder::DeleteApiUserToken::new(self)
+        /// Sends a `POST` request to `/api-user/{identifier}/token`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::InitialApiKeyResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                identifier,
+                body,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::ApiKeyCreateParams::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/token",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Sends a `GET` request to `/group`
+    /// Builder for [`Client::get_api_user_token`]
     ///
-    /// ```ignore
-    /// let response = client.get_groups()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_groups(&self) -> builder::GetGroups {
-        builder::GetGroups::new(self)
+    /// [`Client::get_api_user_token`]: super::Client::get_api_user_token
+    #[derive(Debug, Clone)]
+    pub struct GetApiUserToken<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        token_identifier: Result<uuid::Uuid, String>,
     }
 
-    /// Sends a `POST` request to `/group`
-    ///
-    /// ```ignore
-    /// let response = client.create_group()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_group(&self) -> builder::CreateGroup {
-        builder::CreateGroup::new(self)
+    impl<'a> GetApiUserToken<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                token_identifier: Err("token_identifier was not initialized".to_string()),
+            }
+        }
+
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
+        }
+
+        pub fn token_identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.token_identifier = value.try_into().map_err(|_| {
+                "conversion to `uuid :: Uuid` for token_identifier failed".to_string()
+            });
+            self
+        }
+
+        /// Sends a `GET` request to
+        /// `/api-user/{identifier}/token/{token_identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiKeyResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                identifier,
+                token_identifier,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let token_identifier = token_identifier.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/token/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+                encode_path(&token_identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Sends a `PUT` request to `/group/{group_id}`
+    /// Builder for [`Client::delete_api_user_token`]
     ///
-    /// ```ignore
-    /// let response = client.update_group()
-    ///    .group_id(group_id)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn update_group(&self) -> builder::UpdateGroup {
-        builder::UpdateGroup::new(self)
+    /// [`Client::delete_api_user_token`]: super::Client::delete_api_user_token
+    #[derive(Debug, Clone)]
+    pub struct DeleteApiUserToken<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        token_identifier: Result<uuid::Uuid, String>,
     }
 
-    /// Sends a `DELETE` request to `/group/{group_id}`
-    ///
-    /// ```ignore
-    /// let response = client.delete_group()
-    ///    .group_id(group_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn delete_group(&self) -> builder::DeleteGroup {
-        builder::DeleteGroup::new(self)
+    impl<'a> DeleteApiUserToken<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                token_identifier: Err("token_identifier was not initialized".to_string()),
+            }
+        }
+
+        pub fn identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.identifier = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+            self
+        }
+
+        pub fn token_identifier<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.token_identifier = value.try_into().map_err(|_| {
+                "conversion to `uuid :: Uuid` for token_identifier failed".to_string()
+            });
+            self
+        }
+
+        /// Sends a `DELETE` request to
+        /// `/api-user/{identifier}/token/{token_identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::ApiKeyResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                identifier,
+                token_identifier,
+            } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let token_identifier = token_identifier.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/api-user/{}/token/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+                encode_path(&token_identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Sends a `POST` request to `/login/local`
+    /// Builder for [`ClientHiddenExt::github_webhook`]
     ///
-    /// ```ignore
-    /// let response = client.local_login()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn local_login(&self) -> builder::LocalLogin {
-        builder::LocalLogin::new(self)
+    /// [`ClientHiddenExt::github_webhook`]: super::ClientHiddenExt::github_webhook
+    #[derive(Debug, Clone)]
+    pub struct GithubWebhook<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::GitHubCommitPayload, String>,
     }
 
-    /// Generate the remote provider login url and redirect the user
-    ///
-    /// Sends a `GET` request to `/login/oauth/{provider}/code/authorize`
-    ///
-    /// ```ignore
-    /// let response = client.authz_code_redirect()
-    ///    .provider(provider)
-    ///    .client_id(client_id)
-    ///    .redirect_uri(redirect_uri)
-    ///    .response_type(response_type)
-    ///    .scope(scope)
-    ///    .state(state)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn authz_code_redirect(&self) -> builder::AuthzCodeRedirect {
-        builder::AuthzCodeRedirect::new(self)
+    impl<'a> GithubWebhook<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(types::builder::GitHubCommitPayload::default()),
+            }
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::GitHubCommitPayload>,
+            <V as std::convert::TryInto<types::GitHubCommitPayload>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `GitHubCommitPayload` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::GitHubCommitPayload,
+            ) -> types::builder::GitHubCommitPayload,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/github`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::GitHubCommitPayload::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/github", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                202u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Handle return calls from a remote OAuth provider
-    ///
-    /// Sends a `GET` request to `/login/oauth/{provider}/code/callback`
+    /// Builder for [`Client::get_groups`]
     ///
-    /// ```ignore
-    /// let response = client.authz_code_callback()
-    ///    .provider(provider)
-    ///    .code(code)
-    ///    .error(error)
-    ///    .state(state)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn authz_code_callback(&self) -> builder::AuthzCodeCallback {
-        builder::AuthzCodeCallback::new(self)
+    /// [`Client::get_groups`]: super::Client::get_groups
+    #[derive(Debug, Clone)]
+    pub struct GetGroups<'a> {
+        client: &'a super::Client,
     }
 
-    /// Exchange an authorization code for an access token
-    ///
-    /// Sends a `POST` request to `/login/oauth/{provider}/code/token`
-    ///
-    /// ```ignore
-    /// let response = client.authz_code_exchange()
-    ///    .provider(provider)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn authz_code_exchange(&self) -> builder::AuthzCodeExchange {
-        builder::AuthzCodeExchange::new(self)
+    impl<'a> GetGroups<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        /// Sends a `GET` request to `/group`
+        pub async fn send(
+            self,
+        ) -> Result<
+            ResponseValue<Vec<types::AccessGroupForApiPermissionResponse>>,
+            Error<types::Error>,
+        > {
+            let Self { client } = self;
+            let url = format!("{}/group", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Sends a `GET` request to `/login/oauth/{provider}/device`
+    /// Builder for [`Client::create_group`]
     ///
-    /// ```ignore
-    /// let response = client.get_device_provider()
-    ///    .provider(provider)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_device_provider(&self) -> builder::GetDeviceProvider {
-        builder::GetDeviceProvider::new(self)
+    /// [`Client::create_group`]: super::Client::create_group
+    #[derive(Debug, Clone)]
+    pub struct CreateGroup<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::AccessGroupUpdateParams, String>,
     }
 
-    /// Sends a `POST` request to `/login/oauth/{provider}/device/exchange`
-    ///
-    /// ```ignore
-    /// let response = client.exchange_device_token()
-    ///    .provider(provider)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn exchange_device_token(&self) -> builder::ExchangeDeviceToken {
-        builder::ExchangeDeviceToken::new(self)
-    }
+    impl<'a> CreateGroup<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(types::builder::AccessGroupUpdateParams::default()),
+            }
+        }
 
-    /// Sends a `GET` request to `/mapper`
-    ///
-    /// Arguments:
-    /// - `include_depleted`: Include depleted mappers in the returned results
-    /// ```ignore
-    /// let response = client.get_mappers()
-    ///    .include_depleted(include_depleted)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_mappers(&self) -> builder::GetMappers {
-        builder::GetMappers::new(self)
-    }
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::AccessGroupUpdateParams>,
+            <V as std::convert::TryInto<types::AccessGroupUpdateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `AccessGroupUpdateParams` for body failed: {}",
+                    s
+                )
+            });
+            self
+        }
 
-    /// Sends a `POST` request to `/mapper`
-    ///
-    /// ```ignore
-    /// let response = client.create_mapper()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_mapper(&self) -> builder::CreateMapper {
-        builder::CreateMapper::new(self)
-    }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::AccessGroupUpdateParams,
+            ) -> types::builder::AccessGroupUpdateParams,
+        {
+            self.body = self.body.map(f);
+            self
+        }
 
-    /// Sends a `DELETE` request to `/mapper/{identifier}`
-    ///
-    /// ```ignore
-    /// let response = client.delete_mapper()
-    ///    .identifier(identifier)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn delete_mapper(&self) -> builder::DeleteMapper {
-        builder::DeleteMapper::new(self)
+        /// Sends a `POST` request to `/group`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| {
+                    types::AccessGroupUpdateParams::try_from(v).map_err(|e| e.to_string())
+                })
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/group", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// List OAuth clients
-    ///
-    /// Sends a `GET` request to `/oauth/client`
+    /// Builder for [`Client::update_group`]
     ///
-    /// ```ignore
-    /// let response = client.list_oauth_clients()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn list_oauth_clients(&self) -> builder::ListOauthClients {
-        builder::ListOauthClients::new(self)
+    /// [`Client::update_group`]: super::Client::update_group
+    #[derive(Debug, Clone)]
+    pub struct UpdateGroup<'a> {
+        client: &'a super::Client,
+        group_id: Result<uuid::Uuid, String>,
+        body: Result<types::builder::AccessGroupUpdateParams, String>,
     }
 
-    /// Create a new OAuth Client
-    ///
-    /// Sends a `POST` request to `/oauth/client`
-    ///
-    /// ```ignore
-    /// let response = client.create_oauth_client()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_oauth_client(&self) -> builder::CreateOauthClient {
-        builder::CreateOauthClient::new(self)
-    }
+    impl<'a> UpdateGroup<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                group_id: Err("group_id was not initialized".to_string()),
+                body: Ok(types::builder::AccessGroupUpdateParams::default()),
+            }
+        }
 
-    /// Get an new OAuth Client
-    ///
-    /// Sends a `GET` request to `/oauth/client/{client_id}`
-    ///
-    /// ```ignore
-    /// let response = client.get_oauth_client()
-    ///    .client_id(client_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_oauth_client(&self) -> builder::GetOauthClient {
-        builder::GetOauthClient::new(self)
+        pub fn group_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.group_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::AccessGroupUpdateParams>,
+            <V as std::convert::TryInto<types::AccessGroupUpdateParams>>::Error: std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `AccessGroupUpdateParams` for body failed: {}",
+                    s
+                )
+            });
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::AccessGroupUpdateParams,
+            ) -> types::builder::AccessGroupUpdateParams,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `PUT` request to `/group/{group_id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self {
+                client,
+                group_id,
+                body,
+            } = self;
+            let group_id = group_id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| {
+                    types::AccessGroupUpdateParams::try_from(v).map_err(|e| e.to_string())
+                })
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/group/{}",
+                client.baseurl,
+                encode_path(&group_id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .put(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Add an OAuth client redirect uri
-    ///
-    /// Sends a `POST` request to `/oauth/client/{client_id}/redirect_uri`
+    /// Builder for [`Client::delete_group`]
     ///
-    /// ```ignore
-    /// let response = client.create_oauth_client_redirect_uri()
-    ///    .client_id(client_id)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_oauth_client_redirect_uri(&self) -> builder::CreateOauthClientRedirectUri {
-        builder::CreateOauthClientRedirectUri::new(self)
+    /// [`Client::delete_group`]: super::Client::delete_group
+    #[derive(Debug, Clone)]
+    pub struct DeleteGroup<'a> {
+        client: &'a super::Client,
+        group_id: Result<uuid::Uuid, String>,
     }
 
-    /// Delete an OAuth client redirect uri
-    ///
-    /// Sends a `DELETE` request to
-    /// `/oauth/client/{client_id}/redirect_uri/{redirect_uri_id}`
-    ///
-    /// ```ignore
-    /// let response = client.delete_oauth_client_redirect_uri()
-    ///    .client_id(client_id)
-    ///    .redirect_uri_id(redirect_uri_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn delete_oauth_client_redirect_uri(&self) -> builder::DeleteOauthClientRedirectUri {
-        builder::DeleteOauthClientRedirectUri::new(self)
-    }
+    impl<'a> DeleteGroup<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                group_id: Err("group_id was not initialized".to_string()),
+            }
+        }
 
-    /// Add an OAuth client secret
-    ///
-    /// Sends a `POST` request to `/oauth/client/{client_id}/secret`
-    ///
-    /// ```ignore
-    /// let response = client.create_oauth_client_secret()
-    ///    .client_id(client_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn create_oauth_client_secret(&self) -> builder::CreateOauthClientSecret {
-        builder::CreateOauthClientSecret::new(self)
-    }
+        pub fn group_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.group_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
+            self
+        }
 
-    /// Delete an OAuth client secret
-    ///
-    /// Sends a `DELETE` request to
-    /// `/oauth/client/{client_id}/secret/{secret_id}`
-    ///
-    /// ```ignore
-    /// let response = client.delete_oauth_client_secret()
-    ///    .client_id(client_id)
-    ///    .secret_id(secret_id)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn delete_oauth_client_secret(&self) -> builder::DeleteOauthClientSecret {
-        builder::DeleteOauthClientSecret::new(self)
+        /// Sends a `DELETE` request to `/group/{group_id}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
+        {
+            let Self { client, group_id } = self;
+            let group_id = group_id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/group/{}",
+                client.baseurl,
+                encode_path(&group_id.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// List all available RFDs
+    /// Builder for [`Client::get_labels`]
     ///
-    /// Sends a `GET` request to `/rfd`
-    ///
-    /// ```ignore
-    /// let response = client.get_rfds()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_rfds(&self) -> builder::GetRfds {
-        builder::GetRfds::new(self)
+    /// [`Client::get_labels`]: super::Client::get_labels
+    #[derive(Debug, Clone)]
+    pub struct GetLabels<'a> {
+        client: &'a super::Client,
     }
 
-    /// Create a new RFD
-    ///
-    /// Sends a `POST` request to `/rfd`
-    ///
-    /// ```ignore
-    /// let response = client.reserve_rfd()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn reserve_rfd(&self) -> builder::ReserveRfd {
-        builder::ReserveRfd::new(self)
-    }
+    impl<'a> GetLabels<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
 
-    /// Get the latest representation of a RFD
-    ///
-    /// Sends a `GET` request to `/rfd/{number}`
-    ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// ```ignore
-    /// let response = client.get_rfd()
-    ///    .number(number)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_rfd(&self) -> builder::GetRfd {
-        builder::GetRfd::new(self)
+        /// Sends a `GET` request to `/label`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::LabelSummary>>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/label", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Replace the full document of a RFD
-    ///
-    /// Sends a `POST` request to `/rfd/{number}`
+    /// Builder for [`Client::update_label`]
     ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// - `body`
-    /// ```ignore
-    /// let response = client.set_rfd_document()
-    ///    .number(number)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn set_rfd_document(&self) -> builder::SetRfdDocument {
-        builder::SetRfdDocument::new(self)
+    /// [`Client::update_label`]: super::Client::update_label
+    #[derive(Debug, Clone)]
+    pub struct UpdateLabel<'a> {
+        client: &'a super::Client,
+        name: Result<String, String>,
+        body: Result<types::builder::UpdateLabel, String>,
     }
 
-    /// Get an attribute of a RFD
-    ///
-    /// Sends a `GET` request to `/rfd/{number}/attr/{attr}`
-    ///
-    /// ```ignore
-    /// let response = client.get_rfd_attr()
-    ///    .number(number)
-    ///    .attr(attr)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_rfd_attr(&self) -> builder::GetRfdAttr {
-        builder::GetRfdAttr::new(self)
+    impl<'a> UpdateLabel<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                name: Err("name was not initialized".to_string()),
+                body: Ok(types::builder::UpdateLabel::default()),
+            }
+        }
+
+        pub fn name<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for name failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::UpdateLabel>,
+            <V as std::convert::TryInto<types::UpdateLabel>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `UpdateLabel` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::UpdateLabel) -> types::builder::UpdateLabel,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `PUT` request to `/label/{name}`
+        pub async fn send(self) -> Result<ResponseValue<types::Label>, Error<types::Error>> {
+            let Self { client, name, body } = self;
+            let name = name.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::UpdateLabel::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/label/{}",
+                client.baseurl,
+                encode_path(&name.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .put(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Set an attribute of a RFD
-    ///
-    /// Sends a `POST` request to `/rfd/{number}/attr/{attr}`
+    /// Builder for [`Client::delete_label`]
     ///
-    /// ```ignore
-    /// let response = client.set_rfd_attr()
-    ///    .number(number)
-    ///    .attr(attr)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn set_rfd_attr(&self) -> builder::SetRfdAttr {
-        builder::SetRfdAttr::new(self)
+    /// [`Client::delete_label`]: super::Client::delete_label
+    #[derive(Debug, Clone)]
+    pub struct DeleteLabel<'a> {
+        client: &'a super::Client,
+        name: Result<String, String>,
     }
 
-    /// Replace the contents of a RFD
-    ///
-    /// Sends a `POST` request to `/rfd/{number}/content`
-    ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// - `body`
-    /// ```ignore
-    /// let response = client.set_rfd_content()
-    ///    .number(number)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn set_rfd_content(&self) -> builder::SetRfdContent {
-        builder::SetRfdContent::new(self)
-    }
+    impl<'a> DeleteLabel<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                name: Err("name was not initialized".to_string()),
+            }
+        }
 
-    /// Open a RFD for discussion
-    ///
-    /// Sends a `POST` request to `/rfd/{number}/discuss`
-    ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// ```ignore
-    /// let response = client.discuss_rfd()
-    ///    .number(number)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn discuss_rfd(&self) -> builder::DiscussRfd {
-        builder::DiscussRfd::new(self)
-    }
+        pub fn name<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for name failed".to_string());
+            self
+        }
 
-    /// Publish a RFD
-    ///
-    /// Sends a `POST` request to `/rfd/{number}/publish`
-    ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// ```ignore
-    /// let response = client.publish_rfd()
-    ///    .number(number)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn publish_rfd(&self) -> builder::PublishRfd {
-        builder::PublishRfd::new(self)
+        /// Sends a `DELETE` request to `/label/{name}`
+        pub async fn send(self) -> Result<ResponseValue<types::Label>, Error<types::Error>> {
+            let Self { client, name } = self;
+            let name = name.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/label/{}",
+                client.baseurl,
+                encode_path(&name.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
     }
 
-    /// Modify the visibility of a RFD
-    ///
-    /// Sends a `POST` request to `/rfd/{number}/visibility`
+    /// Builder for [`Client::local_login`]
     ///
-    /// Arguments:
-    /// - `number`: The RFD number (examples: 1 or 123)
-    /// - `body`
-    /// ```ignore
-    /// let response = client.update_rfd_visibility()
-    ///    .number(number)
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn update_rfd_visibility(&self) -> builder::UpdateRfdVisibility {
-        builder::UpdateRfdVisibility::new(self)
+    /// [`Client::local_login`]: super::Client::local_login
+    #[derive(Debug, Clone)]
+    pub struct LocalLogin<'a> {
+        client: &'a super::Client,
+        body: Result<types::builder::LocalLogin, String>,
     }
 
-    /// Search the RFD index and get a list of results
-    ///
-    /// Sends a `GET` request to `/rfd-search`
-    ///
-    /// ```ignore
-    /// let response = client.search_rfds()
-    ///    .attributes_to_crop(attributes_to_crop)
-    ///    .highlight_post_tag(highlight_post_tag)
-    ///    .highlight_pre_tag(highlight_pre_tag)
-    ///    .limit(limit)
-    ///    .offset(offset)
-    ///    .q(q)
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn search_rfds(&self) -> builder::SearchRfds {
-        builder::SearchRfds::new(self)
+    impl<'a> LocalLogin<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                body: Ok(types::builder::LocalLogin::default()),
+            }
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::LocalLogin>,
+            <V as std::convert::TryInto<types::LocalLogin>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `LocalLogin` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::LocalLogin) -> types::builder::LocalLogin,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/login/local`
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| types::LocalLogin::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/login/local", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client.client.post(url).json(&body).build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+            }
+        }
     }
 
-    /// Retrieve the user information of the calling user
-    ///
-    /// Sends a `GET` request to `/self`
+    /// Builder for [`Client::authz_code_redirect`]
     ///
-    /// ```ignore
-    /// let response = client.get_self()
-    ///    .send()
-    ///    .await;
-    /// ```
-    pub fn get_self(&self) -> builder::GetSelf {
-        builder::GetSelf::new(self)
+    /// [`Client::authz_code_redirect`]: super::Client::authz_code_redirect
+    #[derive(Debug, Clone)]
+    pub struct AuthzCodeRedirect<'a> {
+        client: &'a super::Client,
+        provider: Result<types::OAuthProviderName, String>,
+        client_id: Result<uuid::Uuid, String>,
+        redirect_uri: Result<String, String>,
+        response_type: Result<String, String>,
+        scope: Result<Option<String>, String>,
+        state: Result<String, String>,
     }
-}
 
-/// Internal endpoints
-pub trait ClientHiddenExt {
-    /// Sends a `POST` request to `/github`
-    ///
-    /// ```ignore
-    /// let response = client.github_webhook()
-    ///    .body(body)
-    ///    .send()
-    ///    .await;
-    /// ```
-    fn github_webhook(&self) -> builder::GithubWebhook;
-}
+    impl<'a> AuthzCodeRedirect<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                provider: Err("provider was not initialized".to_string()),
+                client_id: Err("client_id was not initialized".to_string()),
+                redirect_uri: Err("redirect_uri was not initialized".to_string()),
+                response_type: Err("response_type was not initialized".to_string()),
+                scope: Ok(None),
+                state: Err("state was not initialized".to_string()),
+            }
+        }
+
+        pub fn provider<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::OAuthProviderName>,
+        {
+            self.provider = value
+                .try_into()
+                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+            self
+        }
+
+        pub fn client_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.client_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+            self
+        }
+
+        pub fn redirect_uri<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.redirect_uri = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for redirect_uri failed".to_string());
+            self
+        }
 
-impl ClientHiddenExt for Client {
-    fn github_webhook(&self) -> builder::GithubWebhook {
-        builder::GithubWebhook::new(self)
-    }
-}
+        pub fn response_type<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.response_type = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for response_type failed".to_string());
+            self
+        }
 
-/// Types for composing operation parameters.
-#[allow(clippy::all)]
-pub mod builder {
-    use super::types;
-    #[allow(unused_imports)]
-    use super::{
-        encode_path, ByteStream, Error, HeaderMap, HeaderValue, RequestBuilderExt, ResponseValue,
-    };
-    /// Builder for [`Client::jwks_json`]
-    ///
-    /// [`Client::jwks_json`]: super::Client::jwks_json
-    #[derive(Debug, Clone)]
-    pub struct JwksJson<'a> {
-        client: &'a super::Client,
-    }
+        pub fn scope<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.scope = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for scope failed".to_string());
+            self
+        }
 
-    impl<'a> JwksJson<'a> {
-        pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+        pub fn state<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.state = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for state failed".to_string());
+            self
         }
 
-        /// Sends a `GET` request to `/.well-known/jwks.json`
-        pub async fn send(self) -> Result<ResponseValue<types::Jwks>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/.well-known/jwks.json", client.baseurl,);
+        /// Sends a `GET` request to `/login/oauth/{provider}/code/authorize`
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+            let Self {
+                client,
+                provider,
+                client_id,
+                redirect_uri,
+                response_type,
+                scope,
+                state,
+            } = self;
+            let provider = provider.map_err(Error::InvalidRequest)?;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let redirect_uri = redirect_uri.map_err(Error::InvalidRequest)?;
+            let response_type = response_type.map_err(Error::InvalidRequest)?;
+            let scope = scope.map_err(Error::InvalidRequest)?;
+            let state = state.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/login/oauth/{}/code/authorize",
+                client.baseurl,
+                encode_path(&provider.to_string()),
+            );
+            let mut query = Vec::with_capacity(5usize);
+            query.push(("client_id", client_id.to_string()));
+            query.push(("redirect_uri", redirect_uri.to_string()));
+            query.push(("response_type", response_type.to_string()));
+            if let Some(v) = &scope {
+                query.push(("scope", v.to_string()));
+            }
+            query.push(("state", state.to_string()));
             #[allow(unused_mut)]
-            let mut request = client
-                .client
-                .get(url)
-                .header(
-                    reqwest::header::ACCEPT,
-                    reqwest::header::HeaderValue::from_static("application/json"),
-                )
-                .build()?;
+            let mut request = client.client.get(url).query(&query).build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                400u16..=499u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                500u16..=599u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                _ => Err(Error::UnexpectedResponse(response)),
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
             }
         }
     }
 
-    /// Builder for [`Client::openid_configuration`]
+    /// Builder for [`Client::authz_code_callback`]
     ///
-    /// [`Client::openid_configuration`]: super::Client::openid_configuration
+    /// [`Client::authz_code_callback`]: super::Client::authz_code_callback
     #[derive(Debug, Clone)]
-    pub struct OpenidConfiguration<'a> {
+    pub struct AuthzCodeCallback<'a> {
         client: &'a super::Client,
+        provider: Result<types::OAuthProviderName, String>,
+        code: Result<Option<String>, String>,
+        error: Result<Option<String>, String>,
+        state: Result<Option<String>, String>,
     }
 
-    impl<'a> OpenidConfiguration<'a> {
+    impl<'a> AuthzCodeCallback<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                provider: Err("provider was not initialized".to_string()),
+                code: Ok(None),
+                error: Ok(None),
+                state: Ok(None),
+            }
         }
 
-        /// Sends a `GET` request to `/.well-known/openid-configuration`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::OpenIdConfiguration>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/.well-known/openid-configuration", client.baseurl,);
+        pub fn provider<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::OAuthProviderName>,
+        {
+            self.provider = value
+                .try_into()
+                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+            self
+        }
+
+        pub fn code<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.code = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for code failed".to_string());
+            self
+        }
+
+        pub fn error<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.error = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for error failed".to_string());
+            self
+        }
+
+        pub fn state<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.state = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for state failed".to_string());
+            self
+        }
+
+        /// Sends a `GET` request to `/login/oauth/{provider}/code/callback`
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+            let Self {
+                client,
+                provider,
+                code,
+                error,
+                state,
+            } = self;
+            let provider = provider.map_err(Error::InvalidRequest)?;
+            let code = code.map_err(Error::InvalidRequest)?;
+            let error = error.map_err(Error::InvalidRequest)?;
+            let state = state.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/login/oauth/{}/code/callback",
+                client.baseurl,
+                encode_path(&provider.to_string()),
+            );
+            let mut query = Vec::with_capacity(3usize);
+            if let Some(v) = &code {
+                query.push(("code", v.to_string()));
+            }
+            if let Some(v) = &error {
+                query.push(("error", v.to_string()));
+            }
+            if let Some(v) = &state {
+                query.push(("state", v.to_string()));
+            }
             #[allow(unused_mut)]
-            let mut request = client
-                .client
-                .get(url)
-                .header(
-                    reqwest::header::ACCEPT,
-                    reqwest::header::HeaderValue::from_static("application/json"),
-                )
-                .build()?;
+            let mut request = client.client.get(url).query(&query).build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                200..=299 => Ok(ResponseValue::stream(response)),
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -10701,55 +16798,81 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_api_user`]
+    /// Builder for [`Client::authz_code_exchange`]
     ///
-    /// [`Client::create_api_user`]: super::Client::create_api_user
+    /// [`Client::authz_code_exchange`]: super::Client::authz_code_exchange
     #[derive(Debug, Clone)]
-    pub struct CreateApiUser<'a> {
+    pub struct AuthzCodeExchange<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::ApiUserUpdateParams, String>,
+        provider: Result<types::OAuthProviderName, String>,
+        body: Result<types::builder::OAuthAuthzCodeExchangeBody, String>,
     }
 
-    impl<'a> CreateApiUser<'a> {
+    impl<'a> AuthzCodeExchange<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::ApiUserUpdateParams::default()),
+                provider: Err("provider was not initialized".to_string()),
+                body: Ok(types::builder::OAuthAuthzCodeExchangeBody::default()),
             }
         }
 
-        pub fn body<V>(mut self, value: V) -> Self
+        pub fn provider<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::ApiUserUpdateParams>,
-            <V as std::convert::TryInto<types::ApiUserUpdateParams>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::OAuthProviderName>,
         {
-            self.body = value
+            self.provider = value
                 .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `ApiUserUpdateParams` for body failed: {}", s));
+                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::OAuthAuthzCodeExchangeBody>,
+            <V as std::convert::TryInto<types::OAuthAuthzCodeExchangeBody>>::Error:
+                std::fmt::Display,
+        {
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `OAuthAuthzCodeExchangeBody` for body failed: {}",
+                    s
+                )
+            });
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
             F: std::ops::FnOnce(
-                types::builder::ApiUserUpdateParams,
-            ) -> types::builder::ApiUserUpdateParams,
+                types::builder::OAuthAuthzCodeExchangeBody,
+            ) -> types::builder::OAuthAuthzCodeExchangeBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/api-user`
+        /// Sends a `POST` request to `/login/oauth/{provider}/code/token`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
+        ) -> Result<ResponseValue<types::OAuthAuthzCodeExchangeResponse>, Error<types::Error>>
         {
-            let Self { client, body } = self;
+            let Self {
+                client,
+                provider,
+                body,
+            } = self;
+            let provider = provider.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::ApiUserUpdateParams::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| {
+                    types::OAuthAuthzCodeExchangeBody::try_from(v).map_err(|e| e.to_string())
+                })
                 .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/api-user", client.baseurl,);
+            let url = format!(
+                "{}/login/oauth/{}/code/token",
+                client.baseurl,
+                encode_path(&provider.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -10758,12 +16881,12 @@ pub mod builder {
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
+                .form_urlencoded(&body)?
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -10775,43 +16898,43 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_api_user`]
+    /// Builder for [`Client::get_device_provider`]
     ///
-    /// [`Client::get_api_user`]: super::Client::get_api_user
+    /// [`Client::get_device_provider`]: super::Client::get_device_provider
     #[derive(Debug, Clone)]
-    pub struct GetApiUser<'a> {
+    pub struct GetDeviceProvider<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
+        provider: Result<types::OAuthProviderName, String>,
     }
 
-    impl<'a> GetApiUser<'a> {
+    impl<'a> GetDeviceProvider<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
+                provider: Err("provider was not initialized".to_string()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn provider<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<types::OAuthProviderName>,
         {
-            self.identifier = value
+            self.provider = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/api-user/{identifier}`
+        /// Sends a `GET` request to `/login/oauth/{provider}/device`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::GetUserResponse>, Error<types::Error>> {
-            let Self { client, identifier } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
+        ) -> Result<ResponseValue<types::OAuthProviderInfo>, Error<types::Error>> {
+            let Self { client, provider } = self;
+            let provider = provider.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}",
+                "{}/login/oauth/{}/device",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
+                encode_path(&provider.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
@@ -10837,85 +16960,138 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::update_api_user`]
+    /// Builder for [`Client::exchange_device_token`]
     ///
-    /// [`Client::update_api_user`]: super::Client::update_api_user
+    /// [`Client::exchange_device_token`]: super::Client::exchange_device_token
     #[derive(Debug, Clone)]
-    pub struct UpdateApiUser<'a> {
+    pub struct ExchangeDeviceToken<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
-        body: Result<types::builder::ApiUserUpdateParams, String>,
+        provider: Result<types::OAuthProviderName, String>,
+        body: Result<types::builder::AccessTokenExchangeRequest, String>,
     }
 
-    impl<'a> UpdateApiUser<'a> {
+    impl<'a> ExchangeDeviceToken<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-                body: Ok(types::builder::ApiUserUpdateParams::default()),
+                provider: Err("provider was not initialized".to_string()),
+                body: Ok(types::builder::AccessTokenExchangeRequest::default()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn provider<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<types::OAuthProviderName>,
         {
-            self.identifier = value
+            self.provider = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
             self
         }
 
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::ApiUserUpdateParams>,
-            <V as std::convert::TryInto<types::ApiUserUpdateParams>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::AccessTokenExchangeRequest>,
+            <V as std::convert::TryInto<types::AccessTokenExchangeRequest>>::Error:
+                std::fmt::Display,
         {
-            self.body = value
-                .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `ApiUserUpdateParams` for body failed: {}", s));
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `AccessTokenExchangeRequest` for body failed: {}",
+                    s
+                )
+            });
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
             F: std::ops::FnOnce(
-                types::builder::ApiUserUpdateParams,
-            ) -> types::builder::ApiUserUpdateParams,
+                types::builder::AccessTokenExchangeRequest,
+            ) -> types::builder::AccessTokenExchangeRequest,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/api-user/{identifier}`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
-        {
+        /// Sends a `POST` request to `/login/oauth/{provider}/device/exchange`
+        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
             let Self {
                 client,
-                identifier,
+                provider,
                 body,
             } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let provider = provider.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::ApiUserUpdateParams::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| {
+                    types::AccessTokenExchangeRequest::try_from(v).map_err(|e| e.to_string())
+                })
                 .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}",
+                "{}/login/oauth/{}/device/exchange",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
+                encode_path(&provider.to_string()),
             );
             #[allow(unused_mut)]
+            let mut request = client.client.post(url).form_urlencoded(&body)?.build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200..=299 => Ok(ResponseValue::stream(response)),
+                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+            }
+        }
+    }
+
+    /// Builder for [`Client::get_mappers`]
+    ///
+    /// [`Client::get_mappers`]: super::Client::get_mappers
+    #[derive(Debug, Clone)]
+    pub struct GetMappers<'a> {
+        client: &'a super::Client,
+        include_depleted: Result<Option<bool>, String>,
+    }
+
+    impl<'a> GetMappers<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                include_depleted: Ok(None),
+            }
+        }
+
+        pub fn include_depleted<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<bool>,
+        {
+            self.include_depleted = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `bool` for include_depleted failed".to_string());
+            self
+        }
+
+        /// Sends a `GET` request to `/mapper`
+        pub async fn send(self) -> Result<ResponseValue<Vec<types::Mapper>>, Error<types::Error>> {
+            let Self {
+                client,
+                include_depleted,
+            } = self;
+            let include_depleted = include_depleted.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/mapper", client.baseurl,);
+            let mut query = Vec::with_capacity(1usize);
+            if let Some(v) = &include_depleted {
+                query.push(("include_depleted", v.to_string()));
+            }
+            #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
+                .query(&query)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
@@ -10932,74 +17108,50 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::add_api_user_to_group`]
+    /// Builder for [`Client::create_mapper`]
     ///
-    /// [`Client::add_api_user_to_group`]: super::Client::add_api_user_to_group
+    /// [`Client::create_mapper`]: super::Client::create_mapper
     #[derive(Debug, Clone)]
-    pub struct AddApiUserToGroup<'a> {
+    pub struct CreateMapper<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
-        body: Result<types::builder::AddGroupBody, String>,
+        body: Result<types::builder::CreateMapper, String>,
     }
 
-    impl<'a> AddApiUserToGroup<'a> {
+    impl<'a> CreateMapper<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-                body: Ok(types::builder::AddGroupBody::default()),
+                body: Ok(types::builder::CreateMapper::default()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<uuid::Uuid>,
-        {
-            self.identifier = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
-            self
-        }
-
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::AddGroupBody>,
-            <V as std::convert::TryInto<types::AddGroupBody>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::CreateMapper>,
+            <V as std::convert::TryInto<types::CreateMapper>>::Error: std::fmt::Display,
         {
             self.body = value
                 .try_into()
                 .map(From::from)
-                .map_err(|s| format!("conversion to `AddGroupBody` for body failed: {}", s));
+                .map_err(|s| format!("conversion to `CreateMapper` for body failed: {}", s));
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(types::builder::AddGroupBody) -> types::builder::AddGroupBody,
+            F: std::ops::FnOnce(types::builder::CreateMapper) -> types::builder::CreateMapper,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/api-user/{identifier}/group`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
-        {
-            let Self {
-                client,
-                identifier,
-                body,
-            } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
+        /// Sends a `POST` request to `/mapper`
+        pub async fn send(self) -> Result<ResponseValue<types::Mapper>, Error<types::Error>> {
+            let Self { client, body } = self;
             let body = body
-                .and_then(|v| types::AddGroupBody::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| types::CreateMapper::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/api-user/{}/group",
-                client.baseurl,
-                encode_path(&identifier.to_string()),
-            );
+            let url = format!("{}/mapper", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -11013,7 +17165,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                201u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -11025,22 +17177,20 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::remove_api_user_from_group`]
+    /// Builder for [`Client::delete_mapper`]
     ///
-    /// [`Client::remove_api_user_from_group`]: super::Client::remove_api_user_from_group
+    /// [`Client::delete_mapper`]: super::Client::delete_mapper
     #[derive(Debug, Clone)]
-    pub struct RemoveApiUserFromGroup<'a> {
+    pub struct DeleteMapper<'a> {
         client: &'a super::Client,
         identifier: Result<uuid::Uuid, String>,
-        group_id: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> RemoveApiUserFromGroup<'a> {
+    impl<'a> DeleteMapper<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
                 identifier: Err("identifier was not initialized".to_string()),
-                group_id: Err("group_id was not initialized".to_string()),
             }
         }
 
@@ -11054,34 +17204,14 @@ pub mod builder {
             self
         }
 
-        pub fn group_id<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<uuid::Uuid>,
-        {
-            self.group_id = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
-            self
-        }
-
-        /// Sends a `DELETE` request to
-        /// `/api-user/{identifier}/group/{group_id}`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::ApiUserForApiPermissionResponse>, Error<types::Error>>
-        {
-            let Self {
-                client,
-                identifier,
-                group_id,
-            } = self;
+        /// Sends a `DELETE` request to `/mapper/{identifier}`
+        pub async fn send(self) -> Result<ResponseValue<types::Mapper>, Error<types::Error>> {
+            let Self { client, identifier } = self;
             let identifier = identifier.map_err(Error::InvalidRequest)?;
-            let group_id = group_id.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}/group/{}",
+                "{}/mapper/{}",
                 client.baseurl,
                 encode_path(&identifier.to_string()),
-                encode_path(&group_id.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
@@ -11107,44 +17237,25 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::list_api_user_tokens`]
+    /// Builder for [`Client::list_oauth_clients`]
     ///
-    /// [`Client::list_api_user_tokens`]: super::Client::list_api_user_tokens
+    /// [`Client::list_oauth_clients`]: super::Client::list_oauth_clients
     #[derive(Debug, Clone)]
-    pub struct ListApiUserTokens<'a> {
+    pub struct ListOauthClients<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> ListApiUserTokens<'a> {
+    impl<'a> ListOauthClients<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self {
-                client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-            }
-        }
-
-        pub fn identifier<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<uuid::Uuid>,
-        {
-            self.identifier = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
-            self
+            Self { client: client }
         }
 
-        /// Sends a `GET` request to `/api-user/{identifier}/token`
+        /// Sends a `GET` request to `/oauth/client`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<Vec<types::ApiKeyResponse>>, Error<types::Error>> {
-            let Self { client, identifier } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/api-user/{}/token",
-                client.baseurl,
-                encode_path(&identifier.to_string()),
-            );
+        ) -> Result<ResponseValue<Vec<types::OAuthClient>>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/oauth/client", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -11169,89 +17280,96 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_api_user_token`]
+    /// Builder for [`Client::create_oauth_client`]
     ///
-    /// [`Client::create_api_user_token`]: super::Client::create_api_user_token
+    /// [`Client::create_oauth_client`]: super::Client::create_oauth_client
     #[derive(Debug, Clone)]
-    pub struct CreateApiUserToken<'a> {
+    pub struct CreateOauthClient<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
-        body: Result<types::builder::ApiKeyCreateParams, String>,
     }
 
-    impl<'a> CreateApiUserToken<'a> {
+    impl<'a> CreateOauthClient<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self { client: client }
+        }
+
+        /// Sends a `POST` request to `/oauth/client`
+        pub async fn send(self) -> Result<ResponseValue<types::OAuthClient>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/oauth/client", client.baseurl,);
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                201u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
+        }
+    }
+
+    /// Builder for [`Client::get_oauth_client`]
+    ///
+    /// [`Client::get_oauth_client`]: super::Client::get_oauth_client
+    #[derive(Debug, Clone)]
+    pub struct GetOauthClient<'a> {
+        client: &'a super::Client,
+        client_id: Result<uuid::Uuid, String>,
+    }
+
+    impl<'a> GetOauthClient<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-                body: Ok(types::builder::ApiKeyCreateParams::default()),
+                client_id: Err("client_id was not initialized".to_string()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn client_id<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.identifier = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
-            self
-        }
-
-        pub fn body<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::ApiKeyCreateParams>,
-            <V as std::convert::TryInto<types::ApiKeyCreateParams>>::Error: std::fmt::Display,
-        {
-            self.body = value
+            self.client_id = value
                 .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `ApiKeyCreateParams` for body failed: {}", s));
-            self
-        }
-
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(
-                types::builder::ApiKeyCreateParams,
-            ) -> types::builder::ApiKeyCreateParams,
-        {
-            self.body = self.body.map(f);
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/api-user/{identifier}/token`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::InitialApiKeyResponse>, Error<types::Error>> {
-            let Self {
-                client,
-                identifier,
-                body,
-            } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
-            let body = body
-                .and_then(|v| types::ApiKeyCreateParams::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
+        /// Sends a `GET` request to `/oauth/client/{client_id}`
+        pub async fn send(self) -> Result<ResponseValue<types::OAuthClient>, Error<types::Error>> {
+            let Self { client, client_id } = self;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}/token",
+                "{}/oauth/client/{}",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
+                encode_path(&client_id.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -11263,71 +17381,89 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_api_user_token`]
+    /// Builder for [`Client::create_oauth_client_redirect_uri`]
     ///
-    /// [`Client::get_api_user_token`]: super::Client::get_api_user_token
+    /// [`Client::create_oauth_client_redirect_uri`]: super::Client::create_oauth_client_redirect_uri
     #[derive(Debug, Clone)]
-    pub struct GetApiUserToken<'a> {
+    pub struct CreateOauthClientRedirectUri<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
-        token_identifier: Result<uuid::Uuid, String>,
+        client_id: Result<uuid::Uuid, String>,
+        body: Result<types::builder::AddOAuthClientRedirectBody, String>,
     }
 
-    impl<'a> GetApiUserToken<'a> {
+    impl<'a> CreateOauthClientRedirectUri<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-                token_identifier: Err("token_identifier was not initialized".to_string()),
+                client_id: Err("client_id was not initialized".to_string()),
+                body: Ok(types::builder::AddOAuthClientRedirectBody::default()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn client_id<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.identifier = value
+            self.client_id = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
             self
         }
 
-        pub fn token_identifier<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<types::AddOAuthClientRedirectBody>,
+            <V as std::convert::TryInto<types::AddOAuthClientRedirectBody>>::Error:
+                std::fmt::Display,
         {
-            self.token_identifier = value.try_into().map_err(|_| {
-                "conversion to `uuid :: Uuid` for token_identifier failed".to_string()
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `AddOAuthClientRedirectBody` for body failed: {}",
+                    s
+                )
             });
             self
         }
 
-        /// Sends a `GET` request to
-        /// `/api-user/{identifier}/token/{token_identifier}`
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::AddOAuthClientRedirectBody,
+            ) -> types::builder::AddOAuthClientRedirectBody,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/oauth/client/{client_id}/redirect_uri`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::ApiKeyResponse>, Error<types::Error>> {
+        ) -> Result<ResponseValue<types::OAuthClientRedirectUri>, Error<types::Error>> {
             let Self {
                 client,
-                identifier,
-                token_identifier,
+                client_id,
+                body,
             } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
-            let token_identifier = token_identifier.map_err(Error::InvalidRequest)?;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| {
+                    types::AddOAuthClientRedirectBody::try_from(v).map_err(|e| e.to_string())
+                })
+                .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}/token/{}",
+                "{}/oauth/client/{}/redirect_uri",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
-                encode_path(&token_identifier.to_string()),
+                encode_path(&client_id.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
@@ -11344,62 +17480,62 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::delete_api_user_token`]
+    /// Builder for [`Client::delete_oauth_client_redirect_uri`]
     ///
-    /// [`Client::delete_api_user_token`]: super::Client::delete_api_user_token
+    /// [`Client::delete_oauth_client_redirect_uri`]: super::Client::delete_oauth_client_redirect_uri
     #[derive(Debug, Clone)]
-    pub struct DeleteApiUserToken<'a> {
+    pub struct DeleteOauthClientRedirectUri<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
-        token_identifier: Result<uuid::Uuid, String>,
+        client_id: Result<uuid::Uuid, String>,
+        redirect_uri_id: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> DeleteApiUserToken<'a> {
+    impl<'a> DeleteOauthClientRedirectUri<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
-                token_identifier: Err("token_identifier was not initialized".to_string()),
+                client_id: Err("client_id was not initialized".to_string()),
+                redirect_uri_id: Err("redirect_uri_id was not initialized".to_string()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn client_id<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.identifier = value
+            self.client_id = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
             self
         }
 
-        pub fn token_identifier<V>(mut self, value: V) -> Self
+        pub fn redirect_uri_id<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.token_identifier = value.try_into().map_err(|_| {
-                "conversion to `uuid :: Uuid` for token_identifier failed".to_string()
-            });
+            self.redirect_uri_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for redirect_uri_id failed".to_string());
             self
         }
 
         /// Sends a `DELETE` request to
-        /// `/api-user/{identifier}/token/{token_identifier}`
+        /// `/oauth/client/{client_id}/redirect_uri/{redirect_uri_id}`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::ApiKeyResponse>, Error<types::Error>> {
+        ) -> Result<ResponseValue<types::OAuthClientRedirectUri>, Error<types::Error>> {
             let Self {
                 client,
-                identifier,
-                token_identifier,
+                client_id,
+                redirect_uri_id,
             } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
-            let token_identifier = token_identifier.map_err(Error::InvalidRequest)?;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let redirect_uri_id = redirect_uri_id.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/api-user/{}/token/{}",
+                "{}/oauth/client/{}/redirect_uri/{}",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
-                encode_path(&token_identifier.to_string()),
+                encode_path(&client_id.to_string()),
+                encode_path(&redirect_uri_id.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
@@ -11425,52 +17561,45 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`ClientHiddenExt::github_webhook`]
+    /// Builder for [`Client::create_oauth_client_secret`]
     ///
-    /// [`ClientHiddenExt::github_webhook`]: super::ClientHiddenExt::github_webhook
+    /// [`Client::create_oauth_client_secret`]: super::Client::create_oauth_client_secret
     #[derive(Debug, Clone)]
-    pub struct GithubWebhook<'a> {
+    pub struct CreateOauthClientSecret<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::GitHubCommitPayload, String>,
+        client_id: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> GithubWebhook<'a> {
+    impl<'a> CreateOauthClientSecret<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::GitHubCommitPayload::default()),
+                client_id: Err("client_id was not initialized".to_string()),
             }
         }
 
-        pub fn body<V>(mut self, value: V) -> Self
+        pub fn client_id<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::GitHubCommitPayload>,
-            <V as std::convert::TryInto<types::GitHubCommitPayload>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.body = value
+            self.client_id = value
                 .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `GitHubCommitPayload` for body failed: {}", s));
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
             self
         }
 
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(
-                types::builder::GitHubCommitPayload,
-            ) -> types::builder::GitHubCommitPayload,
+        /// Sends a `POST` request to `/oauth/client/{client_id}/secret`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::InitialOAuthClientSecretResponse>, Error<types::Error>>
         {
-            self.body = self.body.map(f);
-            self
-        }
-
-        /// Sends a `POST` request to `/github`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
-            let Self { client, body } = self;
-            let body = body
-                .and_then(|v| types::GitHubCommitPayload::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/github", client.baseurl,);
+            let Self { client, client_id } = self;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/oauth/client/{}/secret",
+                client.baseurl,
+                encode_path(&client_id.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -11479,12 +17608,11 @@ pub mod builder {
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -11496,32 +17624,67 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_groups`]
+    /// Builder for [`Client::delete_oauth_client_secret`]
     ///
-    /// [`Client::get_groups`]: super::Client::get_groups
+    /// [`Client::delete_oauth_client_secret`]: super::Client::delete_oauth_client_secret
     #[derive(Debug, Clone)]
-    pub struct GetGroups<'a> {
+    pub struct DeleteOauthClientSecret<'a> {
         client: &'a super::Client,
+        client_id: Result<uuid::Uuid, String>,
+        secret_id: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> GetGroups<'a> {
+    impl<'a> DeleteOauthClientSecret<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                client_id: Err("client_id was not initialized".to_string()),
+                secret_id: Err("secret_id was not initialized".to_string()),
+            }
         }
 
-        /// Sends a `GET` request to `/group`
+        pub fn client_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.client_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+            self
+        }
+
+        pub fn secret_id<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<uuid::Uuid>,
+        {
+            self.secret_id = value
+                .try_into()
+                .map_err(|_| "conversion to `uuid :: Uuid` for secret_id failed".to_string());
+            self
+        }
+
+        /// Sends a `DELETE` request to
+        /// `/oauth/client/{client_id}/secret/{secret_id}`
         pub async fn send(
             self,
-        ) -> Result<
-            ResponseValue<Vec<types::AccessGroupForApiPermissionResponse>>,
-            Error<types::Error>,
-        > {
-            let Self { client } = self;
-            let url = format!("{}/group", client.baseurl,);
+        ) -> Result<ResponseValue<types::OAuthClientSecret>, Error<types::Error>> {
+            let Self {
+                client,
+                client_id,
+                secret_id,
+            } = self;
+            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let secret_id = secret_id.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/oauth/client/{}/secret/{}",
+                client.baseurl,
+                encode_path(&client_id.to_string()),
+                encode_path(&secret_id.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .delete(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -11542,73 +17705,36 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_group`]
+    /// Builder for [`Client::get_rfds`]
     ///
-    /// [`Client::create_group`]: super::Client::create_group
+    /// [`Client::get_rfds`]: super::Client::get_rfds
     #[derive(Debug, Clone)]
-    pub struct CreateGroup<'a> {
+    pub struct GetRfds<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::AccessGroupUpdateParams, String>,
     }
 
-    impl<'a> CreateGroup<'a> {
+    impl<'a> GetRfds<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self {
-                client: client,
-                body: Ok(types::builder::AccessGroupUpdateParams::default()),
-            }
-        }
-
-        pub fn body<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::AccessGroupUpdateParams>,
-            <V as std::convert::TryInto<types::AccessGroupUpdateParams>>::Error: std::fmt::Display,
-        {
-            self.body = value.try_into().map(From::from).map_err(|s| {
-                format!(
-                    "conversion to `AccessGroupUpdateParams` for body failed: {}",
-                    s
-                )
-            });
-            self
-        }
-
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(
-                types::builder::AccessGroupUpdateParams,
-            ) -> types::builder::AccessGroupUpdateParams,
-        {
-            self.body = self.body.map(f);
-            self
+            Self { client: client }
         }
 
-        /// Sends a `POST` request to `/group`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
-        {
-            let Self { client, body } = self;
-            let body = body
-                .and_then(|v| {
-                    types::AccessGroupUpdateParams::try_from(v).map_err(|e| e.to_string())
-                })
-                .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/group", client.baseurl,);
+        /// Sends a `GET` request to `/rfd`
+        pub async fn send(self) -> Result<ResponseValue<Vec<types::ListRfd>>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/rfd", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -11620,84 +17746,56 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::update_group`]
+    /// Builder for [`Client::reserve_rfd`]
     ///
-    /// [`Client::update_group`]: super::Client::update_group
+    /// [`Client::reserve_rfd`]: super::Client::reserve_rfd
     #[derive(Debug, Clone)]
-    pub struct UpdateGroup<'a> {
+    pub struct ReserveRfd<'a> {
         client: &'a super::Client,
-        group_id: Result<uuid::Uuid, String>,
-        body: Result<types::builder::AccessGroupUpdateParams, String>,
+        body: Result<types::builder::ReserveRfdBody, String>,
     }
 
-    impl<'a> UpdateGroup<'a> {
+    impl<'a> ReserveRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                group_id: Err("group_id was not initialized".to_string()),
-                body: Ok(types::builder::AccessGroupUpdateParams::default()),
+                body: Ok(types::builder::ReserveRfdBody::default()),
             }
         }
 
-        pub fn group_id<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<uuid::Uuid>,
-        {
-            self.group_id = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
-            self
-        }
-
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::AccessGroupUpdateParams>,
-            <V as std::convert::TryInto<types::AccessGroupUpdateParams>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::ReserveRfdBody>,
+            <V as std::convert::TryInto<types::ReserveRfdBody>>::Error: std::fmt::Display,
         {
-            self.body = value.try_into().map(From::from).map_err(|s| {
-                format!(
-                    "conversion to `AccessGroupUpdateParams` for body failed: {}",
-                    s
-                )
-            });
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `ReserveRfdBody` for body failed: {}", s));
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(
-                types::builder::AccessGroupUpdateParams,
-            ) -> types::builder::AccessGroupUpdateParams,
+            F: std::ops::FnOnce(types::builder::ReserveRfdBody) -> types::builder::ReserveRfdBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `PUT` request to `/group/{group_id}`
+        /// Sends a `POST` request to `/rfd`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
-        {
-            let Self {
-                client,
-                group_id,
-                body,
-            } = self;
-            let group_id = group_id.map_err(Error::InvalidRequest)?;
+        ) -> Result<ResponseValue<types::ReserveRfdResponse>, Error<types::Error>> {
+            let Self { client, body } = self;
             let body = body
-                .and_then(|v| {
-                    types::AccessGroupUpdateParams::try_from(v).map_err(|e| e.to_string())
-                })
+                .and_then(|v| types::ReserveRfdBody::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/group/{}",
-                client.baseurl,
-                encode_path(&group_id.to_string()),
-            );
+            let url = format!("{}/rfd", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .put(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -11707,7 +17805,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -11719,49 +17817,46 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::delete_group`]
+    /// Builder for [`Client::get_rfd`]
     ///
-    /// [`Client::delete_group`]: super::Client::delete_group
+    /// [`Client::get_rfd`]: super::Client::get_rfd
     #[derive(Debug, Clone)]
-    pub struct DeleteGroup<'a> {
+    pub struct GetRfd<'a> {
         client: &'a super::Client,
-        group_id: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
     }
 
-    impl<'a> DeleteGroup<'a> {
+    impl<'a> GetRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                group_id: Err("group_id was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
             }
         }
 
-        pub fn group_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.group_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for group_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `DELETE` request to `/group/{group_id}`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::AccessGroupForApiPermissionResponse>, Error<types::Error>>
-        {
-            let Self { client, group_id } = self;
-            let group_id = group_id.map_err(Error::InvalidRequest)?;
+        /// Sends a `GET` request to `/rfd/{number}`
+        pub async fn send(self) -> Result<ResponseValue<types::FullRfd>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/group/{}",
+                "{}/rfd/{}",
                 client.baseurl,
-                encode_path(&group_id.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .delete(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -11782,290 +17877,362 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::local_login`]
+    /// Builder for [`Client::set_rfd_document`]
     ///
-    /// [`Client::local_login`]: super::Client::local_login
+    /// [`Client::set_rfd_document`]: super::Client::set_rfd_document
     #[derive(Debug, Clone)]
-    pub struct LocalLogin<'a> {
+    pub struct SetRfdDocument<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::LocalLogin, String>,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdUpdateBody, String>,
     }
 
-    impl<'a> LocalLogin<'a> {
+    impl<'a> SetRfdDocument<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::LocalLogin::default()),
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdUpdateBody::default()),
             }
         }
 
+        pub fn number<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self
+        }
+
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::LocalLogin>,
-            <V as std::convert::TryInto<types::LocalLogin>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::RfdUpdateBody>,
+            <V as std::convert::TryInto<types::RfdUpdateBody>>::Error: std::fmt::Display,
         {
             self.body = value
                 .try_into()
                 .map(From::from)
-                .map_err(|s| format!("conversion to `LocalLogin` for body failed: {}", s));
+                .map_err(|s| format!("conversion to `RfdUpdateBody` for body failed: {}", s));
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(types::builder::LocalLogin) -> types::builder::LocalLogin,
+            F: std::ops::FnOnce(types::builder::RfdUpdateBody) -> types::builder::RfdUpdateBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/login/local`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
-            let Self { client, body } = self;
+        /// Sends a `POST` request to `/rfd/{number}`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                body,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::LocalLogin::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| types::RfdUpdateBody::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/login/local", client.baseurl,);
+            let url = format!(
+                "{}/rfd/{}",
+                client.baseurl,
+                encode_path(&number.to_string()),
+            );
             #[allow(unused_mut)]
-            let mut request = client.client.post(url).json(&body).build()?;
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+                202u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
 
-    /// Builder for [`Client::authz_code_redirect`]
+    /// Builder for [`Client::upload_rfd_asset`]
     ///
-    /// [`Client::authz_code_redirect`]: super::Client::authz_code_redirect
+    /// [`Client::upload_rfd_asset`]: super::Client::upload_rfd_asset
     #[derive(Debug, Clone)]
-    pub struct AuthzCodeRedirect<'a> {
+    pub struct UploadRfdAsset<'a> {
         client: &'a super::Client,
-        provider: Result<types::OAuthProviderName, String>,
-        client_id: Result<uuid::Uuid, String>,
-        redirect_uri: Result<String, String>,
-        response_type: Result<String, String>,
-        scope: Result<Option<String>, String>,
-        state: Result<String, String>,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdAssetUploadBody, String>,
     }
 
-    impl<'a> AuthzCodeRedirect<'a> {
+    impl<'a> UploadRfdAsset<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                provider: Err("provider was not initialized".to_string()),
-                client_id: Err("client_id was not initialized".to_string()),
-                redirect_uri: Err("redirect_uri was not initialized".to_string()),
-                response_type: Err("response_type was not initialized".to_string()),
-                scope: Ok(None),
-                state: Err("state was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdAssetUploadBody::default()),
             }
         }
 
-        pub fn provider<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::OAuthProviderName>,
+            V: std::convert::TryInto<String>,
         {
-            self.provider = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<types::RfdAssetUploadBody>,
+            <V as std::convert::TryInto<types::RfdAssetUploadBody>>::Error: std::fmt::Display,
         {
-            self.client_id = value
+            self.body = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+                .map(From::from)
+                .map_err(|s| format!("conversion to `RfdAssetUploadBody` for body failed: {}", s));
             self
         }
 
-        pub fn redirect_uri<V>(mut self, value: V) -> Self
+        pub fn body_map<F>(mut self, f: F) -> Self
         where
-            V: std::convert::TryInto<String>,
+            F: std::ops::FnOnce(
+                types::builder::RfdAssetUploadBody,
+            ) -> types::builder::RfdAssetUploadBody,
         {
-            self.redirect_uri = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for redirect_uri failed".to_string());
+            self.body = self.body.map(f);
             self
         }
 
-        pub fn response_type<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
-        {
-            self.response_type = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for response_type failed".to_string());
-            self
+        /// Sends a `POST` request to `/rfd/{number}/assets`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::RfdAssetUploadResponse>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                body,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::RfdAssetUploadBody::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/assets",
+                client.baseurl,
+                encode_path(&number.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                202u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
         }
+    }
 
-        pub fn scope<V>(mut self, value: V) -> Self
+    /// Builder for [`Client::get_rfd_attr`]
+    ///
+    /// [`Client::get_rfd_attr`]: super::Client::get_rfd_attr
+    #[derive(Debug, Clone)]
+    pub struct GetRfdAttr<'a> {
+        client: &'a super::Client,
+        number: Result<String, String>,
+        attr: Result<types::RfdAttrName, String>,
+    }
+
+    impl<'a> GetRfdAttr<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                number: Err("number was not initialized".to_string()),
+                attr: Err("attr was not initialized".to_string()),
+            }
+        }
+
+        pub fn number<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<String>,
         {
-            self.scope = value
+            self.number = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for scope failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn state<V>(mut self, value: V) -> Self
+        pub fn attr<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::RfdAttrName>,
         {
-            self.state = value
+            self.attr = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for state failed".to_string());
+                .map_err(|_| "conversion to `RfdAttrName` for attr failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/login/oauth/{provider}/code/authorize`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
+        /// Sends a `GET` request to `/rfd/{number}/attr/{attr}`
+        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
             let Self {
                 client,
-                provider,
-                client_id,
-                redirect_uri,
-                response_type,
-                scope,
-                state,
+                number,
+                attr,
             } = self;
-            let provider = provider.map_err(Error::InvalidRequest)?;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
-            let redirect_uri = redirect_uri.map_err(Error::InvalidRequest)?;
-            let response_type = response_type.map_err(Error::InvalidRequest)?;
-            let scope = scope.map_err(Error::InvalidRequest)?;
-            let state = state.map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let attr = attr.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/login/oauth/{}/code/authorize",
+                "{}/rfd/{}/attr/{}",
                 client.baseurl,
-                encode_path(&provider.to_string()),
+                encode_path(&number.to_string()),
+                encode_path(&attr.to_string()),
             );
-            let mut query = Vec::with_capacity(5usize);
-            query.push(("client_id", client_id.to_string()));
-            query.push(("redirect_uri", redirect_uri.to_string()));
-            query.push(("response_type", response_type.to_string()));
-            if let Some(v) = &scope {
-                query.push(("scope", v.to_string()));
-            }
-            query.push(("state", state.to_string()));
             #[allow(unused_mut)]
-            let mut request = client.client.get(url).query(&query).build()?;
+            let mut request = client
+                .client
+                .get(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
-                _ => Err(Error::ErrorResponse(ResponseValue::stream(response))),
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
             }
         }
     }
 
-    /// Builder for [`Client::authz_code_callback`]
+    /// Builder for [`Client::set_rfd_attr`]
     ///
-    /// [`Client::authz_code_callback`]: super::Client::authz_code_callback
+    /// [`Client::set_rfd_attr`]: super::Client::set_rfd_attr
     #[derive(Debug, Clone)]
-    pub struct AuthzCodeCallback<'a> {
+    pub struct SetRfdAttr<'a> {
         client: &'a super::Client,
-        provider: Result<types::OAuthProviderName, String>,
-        code: Result<Option<String>, String>,
-        error: Result<Option<String>, String>,
-        state: Result<Option<String>, String>,
+        number: Result<String, String>,
+        attr: Result<types::RfdAttrName, String>,
+        body: Result<types::builder::RfdAttrValue, String>,
     }
 
-    impl<'a> AuthzCodeCallback<'a> {
+    impl<'a> SetRfdAttr<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                provider: Err("provider was not initialized".to_string()),
-                code: Ok(None),
-                error: Ok(None),
-                state: Ok(None),
+                number: Err("number was not initialized".to_string()),
+                attr: Err("attr was not initialized".to_string()),
+                body: Ok(types::builder::RfdAttrValue::default()),
             }
         }
 
-        pub fn provider<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::OAuthProviderName>,
+            V: std::convert::TryInto<String>,
         {
-            self.provider = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn code<V>(mut self, value: V) -> Self
+        pub fn attr<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::RfdAttrName>,
         {
-            self.code = value
+            self.attr = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for code failed".to_string());
+                .map_err(|_| "conversion to `RfdAttrName` for attr failed".to_string());
             self
         }
 
-        pub fn error<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::RfdAttrValue>,
+            <V as std::convert::TryInto<types::RfdAttrValue>>::Error: std::fmt::Display,
         {
-            self.error = value
+            self.body = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for error failed".to_string());
+                .map(From::from)
+                .map_err(|s| format!("conversion to `RfdAttrValue` for body failed: {}", s));
             self
         }
 
-        pub fn state<V>(mut self, value: V) -> Self
+        pub fn body_map<F>(mut self, f: F) -> Self
         where
-            V: std::convert::TryInto<String>,
+            F: std::ops::FnOnce(types::builder::RfdAttrValue) -> types::builder::RfdAttrValue,
         {
-            self.state = value
-                .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for state failed".to_string());
+            self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `GET` request to `/login/oauth/{provider}/code/callback`
-        pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<types::Error>> {
+        /// Sends a `POST` request to `/rfd/{number}/attr/{attr}`
+        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
             let Self {
                 client,
-                provider,
-                code,
-                error,
-                state,
+                number,
+                attr,
+                body,
             } = self;
-            let provider = provider.map_err(Error::InvalidRequest)?;
-            let code = code.map_err(Error::InvalidRequest)?;
-            let error = error.map_err(Error::InvalidRequest)?;
-            let state = state.map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let attr = attr.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::RfdAttrValue::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/login/oauth/{}/code/callback",
+                "{}/rfd/{}/attr/{}",
                 client.baseurl,
-                encode_path(&provider.to_string()),
+                encode_path(&number.to_string()),
+                encode_path(&attr.to_string()),
             );
-            let mut query = Vec::with_capacity(3usize);
-            if let Some(v) = &code {
-                query.push(("code", v.to_string()));
-            }
-            if let Some(v) = &error {
-                query.push(("error", v.to_string()));
-            }
-            if let Some(v) = &state {
-                query.push(("state", v.to_string()));
-            }
             #[allow(unused_mut)]
-            let mut request = client.client.get(url).query(&query).build()?;
+            let mut request = client
+                .client
+                .post(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .json(&body)
+                .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200..=299 => Ok(ResponseValue::stream(response)),
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12077,44 +18244,43 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::authz_code_exchange`]
+    /// Builder for [`Client::set_rfd_content`]
     ///
-    /// [`Client::authz_code_exchange`]: super::Client::authz_code_exchange
+    /// [`Client::set_rfd_content`]: super::Client::set_rfd_content
     #[derive(Debug, Clone)]
-    pub struct AuthzCodeExchange<'a> {
+    pub struct SetRfdContent<'a> {
         client: &'a super::Client,
-        provider: Result<types::OAuthProviderName, String>,
-        body: Result<types::builder::OAuthAuthzCodeExchangeBody, String>,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdUpdateContentBody, String>,
     }
 
-    impl<'a> AuthzCodeExchange<'a> {
+    impl<'a> SetRfdContent<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                provider: Err("provider was not initialized".to_string()),
-                body: Ok(types::builder::OAuthAuthzCodeExchangeBody::default()),
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdUpdateContentBody::default()),
             }
         }
 
-        pub fn provider<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::OAuthProviderName>,
+            V: std::convert::TryInto<String>,
         {
-            self.provider = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::OAuthAuthzCodeExchangeBody>,
-            <V as std::convert::TryInto<types::OAuthAuthzCodeExchangeBody>>::Error:
-                std::fmt::Display,
+            V: std::convert::TryInto<types::RfdUpdateContentBody>,
+            <V as std::convert::TryInto<types::RfdUpdateContentBody>>::Error: std::fmt::Display,
         {
             self.body = value.try_into().map(From::from).map_err(|s| {
                 format!(
-                    "conversion to `OAuthAuthzCodeExchangeBody` for body failed: {}",
+                    "conversion to `RfdUpdateContentBody` for body failed: {}",
                     s
                 )
             });
@@ -12124,33 +18290,28 @@ pub mod builder {
         pub fn body_map<F>(mut self, f: F) -> Self
         where
             F: std::ops::FnOnce(
-                types::builder::OAuthAuthzCodeExchangeBody,
-            ) -> types::builder::OAuthAuthzCodeExchangeBody,
+                types::builder::RfdUpdateContentBody,
+            ) -> types::builder::RfdUpdateContentBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/login/oauth/{provider}/code/token`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::OAuthAuthzCodeExchangeResponse>, Error<types::Error>>
-        {
+        /// Sends a `POST` request to `/rfd/{number}/content`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
             let Self {
                 client,
-                provider,
+                number,
                 body,
             } = self;
-            let provider = provider.map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| {
-                    types::OAuthAuthzCodeExchangeBody::try_from(v).map_err(|e| e.to_string())
-                })
+                .and_then(|v| types::RfdUpdateContentBody::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/login/oauth/{}/code/token",
+                "{}/rfd/{}/content",
                 client.baseurl,
-                encode_path(&provider.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
@@ -12160,12 +18321,12 @@ pub mod builder {
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .form_urlencoded(&body)?
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12177,48 +18338,46 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_device_provider`]
+    /// Builder for [`Client::discuss_rfd`]
     ///
-    /// [`Client::get_device_provider`]: super::Client::get_device_provider
+    /// [`Client::discuss_rfd`]: super::Client::discuss_rfd
     #[derive(Debug, Clone)]
-    pub struct GetDeviceProvider<'a> {
+    pub struct DiscussRfd<'a> {
         client: &'a super::Client,
-        provider: Result<types::OAuthProviderName, String>,
+        number: Result<String, String>,
     }
 
-    impl<'a> GetDeviceProvider<'a> {
+    impl<'a> DiscussRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                provider: Err("provider was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
             }
         }
 
-        pub fn provider<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::OAuthProviderName>,
+            V: std::convert::TryInto<String>,
         {
-            self.provider = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/login/oauth/{provider}/device`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::OAuthProviderInfo>, Error<types::Error>> {
-            let Self { client, provider } = self;
-            let provider = provider.map_err(Error::InvalidRequest)?;
+        /// Sends a `POST` request to `/rfd/{number}/discuss`
+        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/login/oauth/{}/device",
+                "{}/rfd/{}/discuss",
                 client.baseurl,
-                encode_path(&provider.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12227,7 +18386,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12239,80 +18398,66 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::exchange_device_token`]
+    /// Builder for [`Client::get_rfd_html`]
     ///
-    /// [`Client::exchange_device_token`]: super::Client::exchange_device_token
+    /// [`Client::get_rfd_html`]: super::Client::get_rfd_html
     #[derive(Debug, Clone)]
-    pub struct ExchangeDeviceToken<'a> {
+    pub struct GetRfdHtml<'a> {
         client: &'a super::Client,
-        provider: Result<types::OAuthProviderName, String>,
-        body: Result<types::builder::AccessTokenExchangeRequest, String>,
+        number: Result<String, String>,
+        commit: Result<Option<String>, String>,
     }
 
-    impl<'a> ExchangeDeviceToken<'a> {
+    impl<'a> GetRfdHtml<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                provider: Err("provider was not initialized".to_string()),
-                body: Ok(types::builder::AccessTokenExchangeRequest::default()),
-            }
-        }
-
-        pub fn provider<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::OAuthProviderName>,
-        {
-            self.provider = value
-                .try_into()
-                .map_err(|_| "conversion to `OAuthProviderName` for provider failed".to_string());
-            self
+                number: Err("number was not initialized".to_string()),
+                commit: Ok(None),
+            }
         }
 
-        pub fn body<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::AccessTokenExchangeRequest>,
-            <V as std::convert::TryInto<types::AccessTokenExchangeRequest>>::Error:
-                std::fmt::Display,
+            V: std::convert::TryInto<String>,
         {
-            self.body = value.try_into().map(From::from).map_err(|s| {
-                format!(
-                    "conversion to `AccessTokenExchangeRequest` for body failed: {}",
-                    s
-                )
-            });
+            self.number = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn body_map<F>(mut self, f: F) -> Self
+        pub fn commit<V>(mut self, value: V) -> Self
         where
-            F: std::ops::FnOnce(
-                types::builder::AccessTokenExchangeRequest,
-            ) -> types::builder::AccessTokenExchangeRequest,
+            V: std::convert::TryInto<String>,
         {
-            self.body = self.body.map(f);
+            self.commit = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for commit failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/login/oauth/{provider}/device/exchange`
+        /// Sends a `GET` request to `/rfd/{number}/html`
         pub async fn send(self) -> Result<ResponseValue<ByteStream>, Error<ByteStream>> {
             let Self {
                 client,
-                provider,
-                body,
+                number,
+                commit,
             } = self;
-            let provider = provider.map_err(Error::InvalidRequest)?;
-            let body = body
-                .and_then(|v| {
-                    types::AccessTokenExchangeRequest::try_from(v).map_err(|e| e.to_string())
-                })
-                .map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let commit = commit.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/login/oauth/{}/device/exchange",
+                "{}/rfd/{}/html",
                 client.baseurl,
-                encode_path(&provider.to_string()),
+                encode_path(&number.to_string()),
             );
+            let mut query = Vec::with_capacity(1usize);
+            if let Some(v) = &commit {
+                query.push(("commit", v.to_string()));
+            }
             #[allow(unused_mut)]
-            let mut request = client.client.post(url).form_urlencoded(&body)?.build()?;
+            let mut request = client.client.get(url).query(&query).build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
@@ -12322,60 +18467,73 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_mappers`]
+    /// Builder for [`Client::add_rfd_label`]
     ///
-    /// [`Client::get_mappers`]: super::Client::get_mappers
+    /// [`Client::add_rfd_label`]: super::Client::add_rfd_label
     #[derive(Debug, Clone)]
-    pub struct GetMappers<'a> {
+    pub struct AddRfdLabel<'a> {
         client: &'a super::Client,
-        include_depleted: Result<Option<bool>, String>,
+        number: Result<String, String>,
+        label: Result<String, String>,
     }
 
-    impl<'a> GetMappers<'a> {
+    impl<'a> AddRfdLabel<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                include_depleted: Ok(None),
+                number: Err("number was not initialized".to_string()),
+                label: Err("label was not initialized".to_string()),
             }
         }
 
-        pub fn include_depleted<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<bool>,
+            V: std::convert::TryInto<String>,
         {
-            self.include_depleted = value
+            self.number = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `bool` for include_depleted failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/mapper`
-        pub async fn send(self) -> Result<ResponseValue<Vec<types::Mapper>>, Error<types::Error>> {
+        pub fn label<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.label = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for label failed".to_string());
+            self
+        }
+
+        /// Sends a `POST` request to `/rfd/{number}/labels/{label}`
+        pub async fn send(self) -> Result<ResponseValue<Vec<String>>, Error<types::Error>> {
             let Self {
                 client,
-                include_depleted,
+                number,
+                label,
             } = self;
-            let include_depleted = include_depleted.map_err(Error::InvalidRequest)?;
-            let url = format!("{}/mapper", client.baseurl,);
-            let mut query = Vec::with_capacity(1usize);
-            if let Some(v) = &include_depleted {
-                query.push(("include_depleted", v.to_string()));
-            }
+            let number = number.map_err(Error::InvalidRequest)?;
+            let label = label.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/labels/{}",
+                client.baseurl,
+                encode_path(&number.to_string()),
+                encode_path(&label.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .query(&query)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12387,64 +18545,73 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_mapper`]
+    /// Builder for [`Client::remove_rfd_label`]
     ///
-    /// [`Client::create_mapper`]: super::Client::create_mapper
+    /// [`Client::remove_rfd_label`]: super::Client::remove_rfd_label
     #[derive(Debug, Clone)]
-    pub struct CreateMapper<'a> {
+    pub struct RemoveRfdLabel<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::CreateMapper, String>,
+        number: Result<String, String>,
+        label: Result<String, String>,
     }
 
-    impl<'a> CreateMapper<'a> {
+    impl<'a> RemoveRfdLabel<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::CreateMapper::default()),
+                number: Err("number was not initialized".to_string()),
+                label: Err("label was not initialized".to_string()),
             }
         }
 
-        pub fn body<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::CreateMapper>,
-            <V as std::convert::TryInto<types::CreateMapper>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<String>,
         {
-            self.body = value
+            self.number = value
                 .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `CreateMapper` for body failed: {}", s));
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn body_map<F>(mut self, f: F) -> Self
+        pub fn label<V>(mut self, value: V) -> Self
         where
-            F: std::ops::FnOnce(types::builder::CreateMapper) -> types::builder::CreateMapper,
+            V: std::convert::TryInto<String>,
         {
-            self.body = self.body.map(f);
+            self.label = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for label failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/mapper`
-        pub async fn send(self) -> Result<ResponseValue<types::Mapper>, Error<types::Error>> {
-            let Self { client, body } = self;
-            let body = body
-                .and_then(|v| types::CreateMapper::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/mapper", client.baseurl,);
+        /// Sends a `DELETE` request to `/rfd/{number}/labels/{label}`
+        pub async fn send(self) -> Result<ResponseValue<Vec<String>>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                label,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let label = label.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/labels/{}",
+                client.baseurl,
+                encode_path(&number.to_string()),
+                encode_path(&label.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .delete(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12456,46 +18623,46 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::delete_mapper`]
+    /// Builder for [`Client::publish_rfd`]
     ///
-    /// [`Client::delete_mapper`]: super::Client::delete_mapper
+    /// [`Client::publish_rfd`]: super::Client::publish_rfd
     #[derive(Debug, Clone)]
-    pub struct DeleteMapper<'a> {
+    pub struct PublishRfd<'a> {
         client: &'a super::Client,
-        identifier: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
     }
 
-    impl<'a> DeleteMapper<'a> {
+    impl<'a> PublishRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                identifier: Err("identifier was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
             }
         }
 
-        pub fn identifier<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.identifier = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `DELETE` request to `/mapper/{identifier}`
-        pub async fn send(self) -> Result<ResponseValue<types::Mapper>, Error<types::Error>> {
-            let Self { client, identifier } = self;
-            let identifier = identifier.map_err(Error::InvalidRequest)?;
+        /// Sends a `POST` request to `/rfd/{number}/publish`
+        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/mapper/{}",
+                "{}/rfd/{}/publish",
                 client.baseurl,
-                encode_path(&identifier.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .delete(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12504,7 +18671,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12516,38 +18683,85 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::list_oauth_clients`]
+    /// Builder for [`Client::revert_rfd`]
     ///
-    /// [`Client::list_oauth_clients`]: super::Client::list_oauth_clients
+    /// [`Client::revert_rfd`]: super::Client::revert_rfd
     #[derive(Debug, Clone)]
-    pub struct ListOauthClients<'a> {
+    pub struct RevertRfd<'a> {
         client: &'a super::Client,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdRevertBody, String>,
     }
 
-    impl<'a> ListOauthClients<'a> {
+    impl<'a> RevertRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdRevertBody::default()),
+            }
         }
 
-        /// Sends a `GET` request to `/oauth/client`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<Vec<types::OAuthClient>>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/oauth/client", client.baseurl,);
+        pub fn number<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::RfdRevertBody>,
+            <V as std::convert::TryInto<types::RfdRevertBody>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `RfdRevertBody` for body failed: {}", s));
+            self
+        }
+
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::RfdRevertBody) -> types::builder::RfdRevertBody,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/rfd/{number}/revert`
+        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                body,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::RfdRevertBody::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/revert",
+                client.baseurl,
+                encode_path(&number.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12559,27 +18773,48 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_oauth_client`]
+    /// Builder for [`Client::get_rfd_sections`]
     ///
-    /// [`Client::create_oauth_client`]: super::Client::create_oauth_client
+    /// [`Client::get_rfd_sections`]: super::Client::get_rfd_sections
     #[derive(Debug, Clone)]
-    pub struct CreateOauthClient<'a> {
+    pub struct GetRfdSections<'a> {
         client: &'a super::Client,
+        number: Result<String, String>,
     }
 
-    impl<'a> CreateOauthClient<'a> {
+    impl<'a> GetRfdSections<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+            Self {
+                client: client,
+                number: Err("number was not initialized".to_string()),
+            }
         }
 
-        /// Sends a `POST` request to `/oauth/client`
-        pub async fn send(self) -> Result<ResponseValue<types::OAuthClient>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/oauth/client", client.baseurl,);
+        pub fn number<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self
+        }
+
+        /// Sends a `GET` request to `/rfd/{number}/sections`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::RfdSection>>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/sections",
+                client.baseurl,
+                encode_path(&number.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12588,7 +18823,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                201u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12600,41 +18835,61 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_oauth_client`]
+    /// Builder for [`Client::get_rfd_section`]
     ///
-    /// [`Client::get_oauth_client`]: super::Client::get_oauth_client
+    /// [`Client::get_rfd_section`]: super::Client::get_rfd_section
     #[derive(Debug, Clone)]
-    pub struct GetOauthClient<'a> {
+    pub struct GetRfdSection<'a> {
         client: &'a super::Client,
-        client_id: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
+        anchor: Result<String, String>,
     }
 
-    impl<'a> GetOauthClient<'a> {
+    impl<'a> GetRfdSection<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                client_id: Err("client_id was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
+                anchor: Err("anchor was not initialized".to_string()),
             }
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.client_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/oauth/client/{client_id}`
-        pub async fn send(self) -> Result<ResponseValue<types::OAuthClient>, Error<types::Error>> {
-            let Self { client, client_id } = self;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
+        pub fn anchor<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.anchor = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for anchor failed".to_string());
+            self
+        }
+
+        /// Sends a `GET` request to `/rfd/{number}/sections/{anchor}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::RfdSectionContent>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                anchor,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let anchor = anchor.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/oauth/client/{}",
+                "{}/rfd/{}/sections/{}",
                 client.baseurl,
-                encode_path(&client_id.to_string()),
+                encode_path(&number.to_string()),
+                encode_path(&anchor.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
@@ -12660,44 +18915,55 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_oauth_client_redirect_uri`]
+    /// Builder for [`Client::set_rfd_section`]
     ///
-    /// [`Client::create_oauth_client_redirect_uri`]: super::Client::create_oauth_client_redirect_uri
+    /// [`Client::set_rfd_section`]: super::Client::set_rfd_section
     #[derive(Debug, Clone)]
-    pub struct CreateOauthClientRedirectUri<'a> {
+    pub struct SetRfdSection<'a> {
         client: &'a super::Client,
-        client_id: Result<uuid::Uuid, String>,
-        body: Result<types::builder::AddOAuthClientRedirectBody, String>,
+        number: Result<String, String>,
+        anchor: Result<String, String>,
+        body: Result<types::builder::RfdSectionUpdateBody, String>,
     }
 
-    impl<'a> CreateOauthClientRedirectUri<'a> {
+    impl<'a> SetRfdSection<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                client_id: Err("client_id was not initialized".to_string()),
-                body: Ok(types::builder::AddOAuthClientRedirectBody::default()),
+                number: Err("number was not initialized".to_string()),
+                anchor: Err("anchor was not initialized".to_string()),
+                body: Ok(types::builder::RfdSectionUpdateBody::default()),
             }
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.client_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self
+        }
+
+        pub fn anchor<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.anchor = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for anchor failed".to_string());
             self
         }
 
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::AddOAuthClientRedirectBody>,
-            <V as std::convert::TryInto<types::AddOAuthClientRedirectBody>>::Error:
-                std::fmt::Display,
+            V: std::convert::TryInto<types::RfdSectionUpdateBody>,
+            <V as std::convert::TryInto<types::RfdSectionUpdateBody>>::Error: std::fmt::Display,
         {
             self.body = value.try_into().map(From::from).map_err(|s| {
                 format!(
-                    "conversion to `AddOAuthClientRedirectBody` for body failed: {}",
+                    "conversion to `RfdSectionUpdateBody` for body failed: {}",
                     s
                 )
             });
@@ -12707,37 +18973,38 @@ pub mod builder {
         pub fn body_map<F>(mut self, f: F) -> Self
         where
             F: std::ops::FnOnce(
-                types::builder::AddOAuthClientRedirectBody,
-            ) -> types::builder::AddOAuthClientRedirectBody,
+                types::builder::RfdSectionUpdateBody,
+            ) -> types::builder::RfdSectionUpdateBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/oauth/client/{client_id}/redirect_uri`
+        /// Sends a `PUT` request to `/rfd/{number}/sections/{anchor}`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::OAuthClientRedirectUri>, Error<types::Error>> {
+        ) -> Result<ResponseValue<types::RfdSectionContent>, Error<types::Error>> {
             let Self {
                 client,
-                client_id,
+                number,
+                anchor,
                 body,
             } = self;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let anchor = anchor.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| {
-                    types::AddOAuthClientRedirectBody::try_from(v).map_err(|e| e.to_string())
-                })
+                .and_then(|v| types::RfdSectionUpdateBody::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/oauth/client/{}/redirect_uri",
+                "{}/rfd/{}/sections/{}",
                 client.baseurl,
-                encode_path(&client_id.to_string()),
+                encode_path(&number.to_string()),
+                encode_path(&anchor.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .put(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12747,7 +19014,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12759,67 +19026,48 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::delete_oauth_client_redirect_uri`]
+    /// Builder for [`Client::get_rfd_timeline`]
     ///
-    /// [`Client::delete_oauth_client_redirect_uri`]: super::Client::delete_oauth_client_redirect_uri
+    /// [`Client::get_rfd_timeline`]: super::Client::get_rfd_timeline
     #[derive(Debug, Clone)]
-    pub struct DeleteOauthClientRedirectUri<'a> {
+    pub struct GetRfdTimeline<'a> {
         client: &'a super::Client,
-        client_id: Result<uuid::Uuid, String>,
-        redirect_uri_id: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
     }
 
-    impl<'a> DeleteOauthClientRedirectUri<'a> {
+    impl<'a> GetRfdTimeline<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                client_id: Err("client_id was not initialized".to_string()),
-                redirect_uri_id: Err("redirect_uri_id was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
             }
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<uuid::Uuid>,
-        {
-            self.client_id = value
-                .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
-            self
-        }
-
-        pub fn redirect_uri_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.redirect_uri_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for redirect_uri_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `DELETE` request to
-        /// `/oauth/client/{client_id}/redirect_uri/{redirect_uri_id}`
+        /// Sends a `GET` request to `/rfd/{number}/timeline`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::OAuthClientRedirectUri>, Error<types::Error>> {
-            let Self {
-                client,
-                client_id,
-                redirect_uri_id,
-            } = self;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
-            let redirect_uri_id = redirect_uri_id.map_err(Error::InvalidRequest)?;
+        ) -> Result<ResponseValue<Vec<types::RfdTimelineEvent>>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/oauth/client/{}/redirect_uri/{}",
+                "{}/rfd/{}/timeline",
                 client.baseurl,
-                encode_path(&client_id.to_string()),
-                encode_path(&redirect_uri_id.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .delete(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12840,49 +19088,48 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::create_oauth_client_secret`]
+    /// Builder for [`Client::get_rfd_transitions`]
     ///
-    /// [`Client::create_oauth_client_secret`]: super::Client::create_oauth_client_secret
+    /// [`Client::get_rfd_transitions`]: super::Client::get_rfd_transitions
     #[derive(Debug, Clone)]
-    pub struct CreateOauthClientSecret<'a> {
+    pub struct GetRfdTransitions<'a> {
         client: &'a super::Client,
-        client_id: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
     }
 
-    impl<'a> CreateOauthClientSecret<'a> {
+    impl<'a> GetRfdTransitions<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                client_id: Err("client_id was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
             }
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.client_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/oauth/client/{client_id}/secret`
+        /// Sends a `GET` request to `/rfd/{number}/transition`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::InitialOAuthClientSecretResponse>, Error<types::Error>>
-        {
-            let Self { client, client_id } = self;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
+        ) -> Result<ResponseValue<Vec<types::RfdStateTransition>>, Error<types::Error>> {
+            let Self { client, number } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/oauth/client/{}/secret",
+                "{}/rfd/{}/transition",
                 client.baseurl,
-                encode_path(&client_id.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -12903,76 +19150,89 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::delete_oauth_client_secret`]
+    /// Builder for [`Client::transition_rfd`]
     ///
-    /// [`Client::delete_oauth_client_secret`]: super::Client::delete_oauth_client_secret
+    /// [`Client::transition_rfd`]: super::Client::transition_rfd
     #[derive(Debug, Clone)]
-    pub struct DeleteOauthClientSecret<'a> {
+    pub struct TransitionRfd<'a> {
         client: &'a super::Client,
-        client_id: Result<uuid::Uuid, String>,
-        secret_id: Result<uuid::Uuid, String>,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdTransitionBody, String>,
     }
 
-    impl<'a> DeleteOauthClientSecret<'a> {
+    impl<'a> TransitionRfd<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                client_id: Err("client_id was not initialized".to_string()),
-                secret_id: Err("secret_id was not initialized".to_string()),
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdTransitionBody::default()),
             }
         }
 
-        pub fn client_id<V>(mut self, value: V) -> Self
+        pub fn number<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<String>,
         {
-            self.client_id = value
+            self.number = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for client_id failed".to_string());
+                .map_err(|_| "conversion to `String` for number failed".to_string());
             self
         }
 
-        pub fn secret_id<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<uuid::Uuid>,
+            V: std::convert::TryInto<types::RfdTransitionBody>,
+            <V as std::convert::TryInto<types::RfdTransitionBody>>::Error: std::fmt::Display,
         {
-            self.secret_id = value
+            self.body = value
                 .try_into()
-                .map_err(|_| "conversion to `uuid :: Uuid` for secret_id failed".to_string());
+                .map(From::from)
+                .map_err(|s| format!("conversion to `RfdTransitionBody` for body failed: {}", s));
             self
         }
 
-        /// Sends a `DELETE` request to
-        /// `/oauth/client/{client_id}/secret/{secret_id}`
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::RfdTransitionBody,
+            ) -> types::builder::RfdTransitionBody,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/rfd/{number}/transition`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::OAuthClientSecret>, Error<types::Error>> {
+        ) -> Result<ResponseValue<types::RfdStateTransition>, Error<types::Error>> {
             let Self {
                 client,
-                client_id,
-                secret_id,
+                number,
+                body,
             } = self;
-            let client_id = client_id.map_err(Error::InvalidRequest)?;
-            let secret_id = secret_id.map_err(Error::InvalidRequest)?;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::RfdTransitionBody::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/oauth/client/{}/secret/{}",
+                "{}/rfd/{}/transition",
                 client.baseurl,
-                encode_path(&client_id.to_string()),
-                encode_path(&secret_id.to_string()),
+                encode_path(&number.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .delete(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
+                202u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -12982,33 +19242,82 @@ pub mod builder {
                 _ => Err(Error::UnexpectedResponse(response)),
             }
         }
-    }
-
-    /// Builder for [`Client::get_rfds`]
-    ///
-    /// [`Client::get_rfds`]: super::Client::get_rfds
-    #[derive(Debug, Clone)]
-    pub struct GetRfds<'a> {
-        client: &'a super::Client,
-    }
+    }
+
+    /// Builder for [`Client::update_rfd_visibility`]
+    ///
+    /// [`Client::update_rfd_visibility`]: super::Client::update_rfd_visibility
+    #[derive(Debug, Clone)]
+    pub struct UpdateRfdVisibility<'a> {
+        client: &'a super::Client,
+        number: Result<String, String>,
+        body: Result<types::builder::RfdVisibility, String>,
+    }
+
+    impl<'a> UpdateRfdVisibility<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::RfdVisibility::default()),
+            }
+        }
+
+        pub fn number<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.number = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self
+        }
+
+        pub fn body<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<types::RfdVisibility>,
+            <V as std::convert::TryInto<types::RfdVisibility>>::Error: std::fmt::Display,
+        {
+            self.body = value
+                .try_into()
+                .map(From::from)
+                .map_err(|s| format!("conversion to `RfdVisibility` for body failed: {}", s));
+            self
+        }
 
-    impl<'a> GetRfds<'a> {
-        pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(types::builder::RfdVisibility) -> types::builder::RfdVisibility,
+        {
+            self.body = self.body.map(f);
+            self
         }
 
-        /// Sends a `GET` request to `/rfd`
-        pub async fn send(self) -> Result<ResponseValue<Vec<types::ListRfd>>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/rfd", client.baseurl,);
+        /// Sends a `POST` request to `/rfd/{number}/visibility`
+        pub async fn send(self) -> Result<ResponseValue<types::Rfd>, Error<types::Error>> {
+            let Self {
+                client,
+                number,
+                body,
+            } = self;
+            let number = number.map_err(Error::InvalidRequest)?;
+            let body = body
+                .and_then(|v| types::RfdVisibility::try_from(v).map_err(|e| e.to_string()))
+                .map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/rfd/{}/visibility",
+                client.baseurl,
+                encode_path(&number.to_string()),
+            );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .get(url)
+                .post(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
@@ -13025,52 +19334,52 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::reserve_rfd`]
+    /// Builder for [`Client::get_rfd_batch`]
     ///
-    /// [`Client::reserve_rfd`]: super::Client::reserve_rfd
+    /// [`Client::get_rfd_batch`]: super::Client::get_rfd_batch
     #[derive(Debug, Clone)]
-    pub struct ReserveRfd<'a> {
+    pub struct GetRfdBatch<'a> {
         client: &'a super::Client,
-        body: Result<types::builder::ReserveRfdBody, String>,
+        body: Result<types::builder::RfdBatchBody, String>,
     }
 
-    impl<'a> ReserveRfd<'a> {
+    impl<'a> GetRfdBatch<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                body: Ok(types::builder::ReserveRfdBody::default()),
+                body: Ok(types::builder::RfdBatchBody::default()),
             }
         }
 
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::ReserveRfdBody>,
-            <V as std::convert::TryInto<types::ReserveRfdBody>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::RfdBatchBody>,
+            <V as std::convert::TryInto<types::RfdBatchBody>>::Error: std::fmt::Display,
         {
             self.body = value
                 .try_into()
                 .map(From::from)
-                .map_err(|s| format!("conversion to `ReserveRfdBody` for body failed: {}", s));
+                .map_err(|s| format!("conversion to `RfdBatchBody` for body failed: {}", s));
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(types::builder::ReserveRfdBody) -> types::builder::ReserveRfdBody,
+            F: std::ops::FnOnce(types::builder::RfdBatchBody) -> types::builder::RfdBatchBody,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/rfd`
+        /// Sends a `POST` request to `/rfd-batch`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::ReserveRfdResponse>, Error<types::Error>> {
+        ) -> Result<ResponseValue<types::RfdBatchResponse>, Error<types::Error>> {
             let Self { client, body } = self;
             let body = body
-                .and_then(|v| types::ReserveRfdBody::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| types::RfdBatchBody::try_from(v).map_err(|e| e.to_string()))
                 .map_err(Error::InvalidRequest)?;
-            let url = format!("{}/rfd", client.baseurl,);
+            let url = format!("{}/rfd-batch", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -13084,7 +19393,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13096,42 +19405,135 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_rfd`]
+    /// Builder for [`Client::search_rfds`]
     ///
-    /// [`Client::get_rfd`]: super::Client::get_rfd
+    /// [`Client::search_rfds`]: super::Client::search_rfds
     #[derive(Debug, Clone)]
-    pub struct GetRfd<'a> {
+    pub struct SearchRfds<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
+        attributes_to_crop: Result<Option<String>, String>,
+        highlight_post_tag: Result<Option<String>, String>,
+        highlight_pre_tag: Result<Option<String>, String>,
+        limit: Result<Option<u32>, String>,
+        offset: Result<Option<u32>, String>,
+        q: Result<String, String>,
     }
 
-    impl<'a> GetRfd<'a> {
+    impl<'a> SearchRfds<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                number: Err("number was not initialized".to_string()),
+                attributes_to_crop: Ok(None),
+                highlight_post_tag: Ok(None),
+                highlight_pre_tag: Ok(None),
+                limit: Ok(None),
+                offset: Ok(None),
+                q: Err("q was not initialized".to_string()),
             }
         }
 
-        pub fn number<V>(mut self, value: V) -> Self
+        pub fn attributes_to_crop<V>(mut self, value: V) -> Self
         where
             V: std::convert::TryInto<String>,
         {
-            self.number = value
+            self.attributes_to_crop = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
+                .map(Some)
+                .map_err(|_| "conversion to `String` for attributes_to_crop failed".to_string());
             self
         }
 
-        /// Sends a `GET` request to `/rfd/{number}`
-        pub async fn send(self) -> Result<ResponseValue<types::FullRfd>, Error<types::Error>> {
-            let Self { client, number } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}",
-                client.baseurl,
-                encode_path(&number.to_string()),
-            );
+        pub fn highlight_post_tag<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.highlight_post_tag = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for highlight_post_tag failed".to_string());
+            self
+        }
+
+        pub fn highlight_pre_tag<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.highlight_pre_tag = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `String` for highlight_pre_tag failed".to_string());
+            self
+        }
+
+        pub fn limit<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.limit = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for limit failed".to_string());
+            self
+        }
+
+        pub fn offset<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<u32>,
+        {
+            self.offset = value
+                .try_into()
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for offset failed".to_string());
+            self
+        }
+
+        pub fn q<V>(mut self, value: V) -> Self
+        where
+            V: std::convert::TryInto<String>,
+        {
+            self.q = value
+                .try_into()
+                .map_err(|_| "conversion to `String` for q failed".to_string());
+            self
+        }
+
+        /// Sends a `GET` request to `/rfd-search`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::SearchResults>, Error<types::Error>> {
+            let Self {
+                client,
+                attributes_to_crop,
+                highlight_post_tag,
+                highlight_pre_tag,
+                limit,
+                offset,
+                q,
+            } = self;
+            let attributes_to_crop = attributes_to_crop.map_err(Error::InvalidRequest)?;
+            let highlight_post_tag = highlight_post_tag.map_err(Error::InvalidRequest)?;
+            let highlight_pre_tag = highlight_pre_tag.map_err(Error::InvalidRequest)?;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let offset = offset.map_err(Error::InvalidRequest)?;
+            let q = q.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/rfd-search", client.baseurl,);
+            let mut query = Vec::with_capacity(6usize);
+            if let Some(v) = &attributes_to_crop {
+                query.push(("attributes_to_crop", v.to_string()));
+            }
+            if let Some(v) = &highlight_post_tag {
+                query.push(("highlight_post_tag", v.to_string()));
+            }
+            if let Some(v) = &highlight_pre_tag {
+                query.push(("highlight_pre_tag", v.to_string()));
+            }
+            if let Some(v) = &limit {
+                query.push(("limit", v.to_string()));
+            }
+            if let Some(v) = &offset {
+                query.push(("offset", v.to_string()));
+            }
+            query.push(("q", q.to_string()));
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -13140,6 +19542,7 @@ pub mod builder {
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .query(&query)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
@@ -13156,85 +19559,73 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::set_rfd_document`]
+    /// Builder for [`Client::suggest_rfds`]
     ///
-    /// [`Client::set_rfd_document`]: super::Client::set_rfd_document
+    /// [`Client::suggest_rfds`]: super::Client::suggest_rfds
     #[derive(Debug, Clone)]
-    pub struct SetRfdDocument<'a> {
+    pub struct SuggestRfds<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
-        body: Result<types::builder::RfdUpdateBody, String>,
+        limit: Result<Option<u32>, String>,
+        q: Result<String, String>,
     }
 
-    impl<'a> SetRfdDocument<'a> {
+    impl<'a> SuggestRfds<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                number: Err("number was not initialized".to_string()),
-                body: Ok(types::builder::RfdUpdateBody::default()),
+                limit: Ok(None),
+                q: Err("q was not initialized".to_string()),
             }
         }
 
-        pub fn number<V>(mut self, value: V) -> Self
+        pub fn limit<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<u32>,
         {
-            self.number = value
+            self.limit = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
+                .map(Some)
+                .map_err(|_| "conversion to `u32` for limit failed".to_string());
             self
         }
 
-        pub fn body<V>(mut self, value: V) -> Self
+        pub fn q<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::RfdUpdateBody>,
-            <V as std::convert::TryInto<types::RfdUpdateBody>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<String>,
         {
-            self.body = value
+            self.q = value
                 .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `RfdUpdateBody` for body failed: {}", s));
-            self
-        }
-
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(types::builder::RfdUpdateBody) -> types::builder::RfdUpdateBody,
-        {
-            self.body = self.body.map(f);
+                .map_err(|_| "conversion to `String` for q failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/rfd/{number}`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
-            let Self {
-                client,
-                number,
-                body,
-            } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let body = body
-                .and_then(|v| types::RfdUpdateBody::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}",
-                client.baseurl,
-                encode_path(&number.to_string()),
-            );
+        /// Sends a `GET` request to `/rfd-search/suggest`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::SearchSuggestion>>, Error<types::Error>> {
+            let Self { client, limit, q } = self;
+            let limit = limit.map_err(Error::InvalidRequest)?;
+            let q = q.map_err(Error::InvalidRequest)?;
+            let url = format!("{}/rfd-search/suggest", client.baseurl,);
+            let mut query = Vec::with_capacity(2usize);
+            if let Some(v) = &limit {
+                query.push(("limit", v.to_string()));
+            }
+            query.push(("q", q.to_string()));
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
+                .query(&query)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13246,60 +19637,25 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::get_rfd_attr`]
+    /// Builder for [`Client::get_rfd_templates`]
     ///
-    /// [`Client::get_rfd_attr`]: super::Client::get_rfd_attr
+    /// [`Client::get_rfd_templates`]: super::Client::get_rfd_templates
     #[derive(Debug, Clone)]
-    pub struct GetRfdAttr<'a> {
+    pub struct GetRfdTemplates<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
-        attr: Result<types::RfdAttrName, String>,
     }
 
-    impl<'a> GetRfdAttr<'a> {
+    impl<'a> GetRfdTemplates<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self {
-                client: client,
-                number: Err("number was not initialized".to_string()),
-                attr: Err("attr was not initialized".to_string()),
-            }
-        }
-
-        pub fn number<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
-        {
-            self.number = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
-            self
-        }
-
-        pub fn attr<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::RfdAttrName>,
-        {
-            self.attr = value
-                .try_into()
-                .map_err(|_| "conversion to `RfdAttrName` for attr failed".to_string());
-            self
+            Self { client: client }
         }
 
-        /// Sends a `GET` request to `/rfd/{number}/attr/{attr}`
-        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
-            let Self {
-                client,
-                number,
-                attr,
-            } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let attr = attr.map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}/attr/{}",
-                client.baseurl,
-                encode_path(&number.to_string()),
-                encode_path(&attr.to_string()),
-            );
+        /// Sends a `GET` request to `/rfd-templates`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::RfdTemplateSummary>>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/rfd-templates", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -13324,100 +19680,38 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::set_rfd_attr`]
+    /// Builder for [`Client::get_self`]
     ///
-    /// [`Client::set_rfd_attr`]: super::Client::set_rfd_attr
+    /// [`Client::get_self`]: super::Client::get_self
     #[derive(Debug, Clone)]
-    pub struct SetRfdAttr<'a> {
+    pub struct GetSelf<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
-        attr: Result<types::RfdAttrName, String>,
-        body: Result<types::builder::RfdAttrValue, String>,
     }
 
-    impl<'a> SetRfdAttr<'a> {
+    impl<'a> GetSelf<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self {
-                client: client,
-                number: Err("number was not initialized".to_string()),
-                attr: Err("attr was not initialized".to_string()),
-                body: Ok(types::builder::RfdAttrValue::default()),
-            }
-        }
-
-        pub fn number<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
-        {
-            self.number = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
-            self
-        }
-
-        pub fn attr<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::RfdAttrName>,
-        {
-            self.attr = value
-                .try_into()
-                .map_err(|_| "conversion to `RfdAttrName` for attr failed".to_string());
-            self
-        }
-
-        pub fn body<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::RfdAttrValue>,
-            <V as std::convert::TryInto<types::RfdAttrValue>>::Error: std::fmt::Display,
-        {
-            self.body = value
-                .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `RfdAttrValue` for body failed: {}", s));
-            self
-        }
-
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(types::builder::RfdAttrValue) -> types::builder::RfdAttrValue,
-        {
-            self.body = self.body.map(f);
-            self
+            Self { client: client }
         }
 
-        /// Sends a `POST` request to `/rfd/{number}/attr/{attr}`
-        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
-            let Self {
-                client,
-                number,
-                attr,
-                body,
-            } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let attr = attr.map_err(Error::InvalidRequest)?;
-            let body = body
-                .and_then(|v| types::RfdAttrValue::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}/attr/{}",
-                client.baseurl,
-                encode_path(&number.to_string()),
-                encode_path(&attr.to_string()),
-            );
+        /// Sends a `GET` request to `/self`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::GetUserResponse>, Error<types::Error>> {
+            let Self { client } = self;
+            let url = format!("{}/self", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13429,89 +19723,39 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::set_rfd_content`]
+    /// Builder for [`Client::get_webhook_subscriptions`]
     ///
-    /// [`Client::set_rfd_content`]: super::Client::set_rfd_content
+    /// [`Client::get_webhook_subscriptions`]: super::Client::get_webhook_subscriptions
     #[derive(Debug, Clone)]
-    pub struct SetRfdContent<'a> {
+    pub struct GetWebhookSubscriptions<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
-        body: Result<types::builder::RfdUpdateContentBody, String>,
     }
 
-    impl<'a> SetRfdContent<'a> {
+    impl<'a> GetWebhookSubscriptions<'a> {
         pub fn new(client: &'a super::Client) -> Self {
-            Self {
-                client: client,
-                number: Err("number was not initialized".to_string()),
-                body: Ok(types::builder::RfdUpdateContentBody::default()),
-            }
-        }
-
-        pub fn number<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
-        {
-            self.number = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
-            self
-        }
-
-        pub fn body<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<types::RfdUpdateContentBody>,
-            <V as std::convert::TryInto<types::RfdUpdateContentBody>>::Error: std::fmt::Display,
-        {
-            self.body = value.try_into().map(From::from).map_err(|s| {
-                format!(
-                    "conversion to `RfdUpdateContentBody` for body failed: {}",
-                    s
-                )
-            });
-            self
+            Self { client: client }
         }
 
-        pub fn body_map<F>(mut self, f: F) -> Self
-        where
-            F: std::ops::FnOnce(
-                types::builder::RfdUpdateContentBody,
-            ) -> types::builder::RfdUpdateContentBody,
+        /// Sends a `GET` request to `/webhook-subscription`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<Vec<types::WebhookSubscriptionResponse>>, Error<types::Error>>
         {
-            self.body = self.body.map(f);
-            self
-        }
-
-        /// Sends a `POST` request to `/rfd/{number}/content`
-        pub async fn send(self) -> Result<ResponseValue<()>, Error<types::Error>> {
-            let Self {
-                client,
-                number,
-                body,
-            } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let body = body
-                .and_then(|v| types::RfdUpdateContentBody::try_from(v).map_err(|e| e.to_string()))
-                .map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}/content",
-                client.baseurl,
-                encode_path(&number.to_string()),
-            );
+            let Self { client } = self;
+            let url = format!("{}/webhook-subscription", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
-                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13523,42 +19767,60 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::discuss_rfd`]
+    /// Builder for [`Client::create_webhook_subscription`]
     ///
-    /// [`Client::discuss_rfd`]: super::Client::discuss_rfd
+    /// [`Client::create_webhook_subscription`]: super::Client::create_webhook_subscription
     #[derive(Debug, Clone)]
-    pub struct DiscussRfd<'a> {
+    pub struct CreateWebhookSubscription<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
+        body: Result<types::builder::CreateWebhookSubscription, String>,
     }
 
-    impl<'a> DiscussRfd<'a> {
+    impl<'a> CreateWebhookSubscription<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                number: Err("number was not initialized".to_string()),
+                body: Ok(types::builder::CreateWebhookSubscription::default()),
             }
         }
 
-        pub fn number<V>(mut self, value: V) -> Self
+        pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<types::CreateWebhookSubscription>,
+            <V as std::convert::TryInto<types::CreateWebhookSubscription>>::Error:
+                std::fmt::Display,
         {
-            self.number = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `CreateWebhookSubscription` for body failed: {}",
+                    s
+                )
+            });
             self
         }
 
-        /// Sends a `POST` request to `/rfd/{number}/discuss`
-        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
-            let Self { client, number } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
-            let url = format!(
-                "{}/rfd/{}/discuss",
-                client.baseurl,
-                encode_path(&number.to_string()),
-            );
+        pub fn body_map<F>(mut self, f: F) -> Self
+        where
+            F: std::ops::FnOnce(
+                types::builder::CreateWebhookSubscription,
+            ) -> types::builder::CreateWebhookSubscription,
+        {
+            self.body = self.body.map(f);
+            self
+        }
+
+        /// Sends a `POST` request to `/webhook-subscription`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::WebhookSubscriptionResponse>, Error<types::Error>>
+        {
+            let Self { client, body } = self;
+            let body = body
+                .and_then(|v| {
+                    types::CreateWebhookSubscription::try_from(v).map_err(|e| e.to_string())
+                })
+                .map_err(Error::InvalidRequest)?;
+            let url = format!("{}/webhook-subscription", client.baseurl,);
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -13567,11 +19829,12 @@ pub mod builder {
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
                 )
+                .json(&body)
                 .build()?;
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                201u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13583,46 +19846,49 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::publish_rfd`]
+    /// Builder for [`Client::get_webhook_subscription`]
     ///
-    /// [`Client::publish_rfd`]: super::Client::publish_rfd
+    /// [`Client::get_webhook_subscription`]: super::Client::get_webhook_subscription
     #[derive(Debug, Clone)]
-    pub struct PublishRfd<'a> {
+    pub struct GetWebhookSubscription<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
+        identifier: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> PublishRfd<'a> {
+    impl<'a> GetWebhookSubscription<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                number: Err("number was not initialized".to_string()),
+                identifier: Err("identifier was not initialized".to_string()),
             }
         }
 
-        pub fn number<V>(mut self, value: V) -> Self
+        pub fn identifier<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.number = value
+            self.identifier = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
             self
         }
 
-        /// Sends a `POST` request to `/rfd/{number}/publish`
-        pub async fn send(self) -> Result<ResponseValue<types::RfdAttr>, Error<types::Error>> {
-            let Self { client, number } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
+        /// Sends a `GET` request to `/webhook-subscription/{identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::WebhookSubscriptionResponse>, Error<types::Error>>
+        {
+            let Self { client, identifier } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/rfd/{}/publish",
+                "{}/webhook-subscription/{}",
                 client.baseurl,
-                encode_path(&number.to_string()),
+                encode_path(&identifier.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .get(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -13631,7 +19897,7 @@ pub mod builder {
             let result = client.client.execute(request).await;
             let response = result?;
             match response.status().as_u16() {
-                202u16 => ResponseValue::from_response(response).await,
+                200u16 => ResponseValue::from_response(response).await,
                 400u16..=499u16 => Err(Error::ErrorResponse(
                     ResponseValue::from_response(response).await?,
                 )),
@@ -13643,75 +19909,85 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::update_rfd_visibility`]
+    /// Builder for [`Client::update_webhook_subscription`]
     ///
-    /// [`Client::update_rfd_visibility`]: super::Client::update_rfd_visibility
+    /// [`Client::update_webhook_subscription`]: super::Client::update_webhook_subscription
     #[derive(Debug, Clone)]
-    pub struct UpdateRfdVisibility<'a> {
+    pub struct UpdateWebhookSubscription<'a> {
         client: &'a super::Client,
-        number: Result<String, String>,
-        body: Result<types::builder::RfdVisibility, String>,
+        identifier: Result<uuid::Uuid, String>,
+        body: Result<types::builder::UpdateWebhookSubscription, String>,
     }
 
-    impl<'a> UpdateRfdVisibility<'a> {
+    impl<'a> UpdateWebhookSubscription<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                number: Err("number was not initialized".to_string()),
-                body: Ok(types::builder::RfdVisibility::default()),
+                identifier: Err("identifier was not initialized".to_string()),
+                body: Ok(types::builder::UpdateWebhookSubscription::default()),
             }
         }
 
-        pub fn number<V>(mut self, value: V) -> Self
+        pub fn identifier<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.number = value
+            self.identifier = value
                 .try_into()
-                .map_err(|_| "conversion to `String` for number failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
             self
         }
 
         pub fn body<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<types::RfdVisibility>,
-            <V as std::convert::TryInto<types::RfdVisibility>>::Error: std::fmt::Display,
+            V: std::convert::TryInto<types::UpdateWebhookSubscription>,
+            <V as std::convert::TryInto<types::UpdateWebhookSubscription>>::Error:
+                std::fmt::Display,
         {
-            self.body = value
-                .try_into()
-                .map(From::from)
-                .map_err(|s| format!("conversion to `RfdVisibility` for body failed: {}", s));
+            self.body = value.try_into().map(From::from).map_err(|s| {
+                format!(
+                    "conversion to `UpdateWebhookSubscription` for body failed: {}",
+                    s
+                )
+            });
             self
         }
 
         pub fn body_map<F>(mut self, f: F) -> Self
         where
-            F: std::ops::FnOnce(types::builder::RfdVisibility) -> types::builder::RfdVisibility,
+            F: std::ops::FnOnce(
+                types::builder::UpdateWebhookSubscription,
+            ) -> types::builder::UpdateWebhookSubscription,
         {
             self.body = self.body.map(f);
             self
         }
 
-        /// Sends a `POST` request to `/rfd/{number}/visibility`
-        pub async fn send(self) -> Result<ResponseValue<types::Rfd>, Error<types::Error>> {
+        /// Sends a `PUT` request to `/webhook-subscription/{identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::WebhookSubscriptionResponse>, Error<types::Error>>
+        {
             let Self {
                 client,
-                number,
+                identifier,
                 body,
             } = self;
-            let number = number.map_err(Error::InvalidRequest)?;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
             let body = body
-                .and_then(|v| types::RfdVisibility::try_from(v).map_err(|e| e.to_string()))
+                .and_then(|v| {
+                    types::UpdateWebhookSubscription::try_from(v).map_err(|e| e.to_string())
+                })
                 .map_err(Error::InvalidRequest)?;
             let url = format!(
-                "{}/rfd/{}/visibility",
+                "{}/webhook-subscription/{}",
                 client.baseurl,
-                encode_path(&number.to_string()),
+                encode_path(&identifier.to_string()),
             );
             #[allow(unused_mut)]
             let mut request = client
                 .client
-                .post(url)
+                .put(url)
                 .header(
                     reqwest::header::ACCEPT,
                     reqwest::header::HeaderValue::from_static("application/json"),
@@ -13733,63 +20009,97 @@ pub mod builder {
         }
     }
 
-    /// Builder for [`Client::search_rfds`]
+    /// Builder for [`Client::delete_webhook_subscription`]
     ///
-    /// [`Client::search_rfds`]: super::Client::search_rfds
+    /// [`Client::delete_webhook_subscription`]: super::Client::delete_webhook_subscription
     #[derive(Debug, Clone)]
-    pub struct SearchRfds<'a> {
+    pub struct DeleteWebhookSubscription<'a> {
         client: &'a super::Client,
-        attributes_to_crop: Result<Option<String>, String>,
-        highlight_post_tag: Result<Option<String>, String>,
-        highlight_pre_tag: Result<Option<String>, String>,
-        limit: Result<Option<u32>, String>,
-        offset: Result<Option<u32>, String>,
-        q: Result<String, String>,
+        identifier: Result<uuid::Uuid, String>,
     }
 
-    impl<'a> SearchRfds<'a> {
+    impl<'a> DeleteWebhookSubscription<'a> {
         pub fn new(client: &'a super::Client) -> Self {
             Self {
                 client: client,
-                attributes_to_crop: Ok(None),
-                highlight_post_tag: Ok(None),
-                highlight_pre_tag: Ok(None),
-                limit: Ok(None),
-                offset: Ok(None),
-                q: Err("q was not initialized".to_string()),
+                identifier: Err("identifier was not initialized".to_string()),
             }
         }
 
-        pub fn attributes_to_crop<V>(mut self, value: V) -> Self
+        pub fn identifier<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.attributes_to_crop = value
+            self.identifier = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for attributes_to_crop failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
             self
         }
 
-        pub fn highlight_post_tag<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
+        /// Sends a `DELETE` request to `/webhook-subscription/{identifier}`
+        pub async fn send(
+            self,
+        ) -> Result<ResponseValue<types::WebhookSubscriptionResponse>, Error<types::Error>>
         {
-            self.highlight_post_tag = value
-                .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for highlight_post_tag failed".to_string());
-            self
+            let Self { client, identifier } = self;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
+            let url = format!(
+                "{}/webhook-subscription/{}",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            #[allow(unused_mut)]
+            let mut request = client
+                .client
+                .delete(url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                )
+                .build()?;
+            let result = client.client.execute(request).await;
+            let response = result?;
+            match response.status().as_u16() {
+                200u16 => ResponseValue::from_response(response).await,
+                400u16..=499u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                500u16..=599u16 => Err(Error::ErrorResponse(
+                    ResponseValue::from_response(response).await?,
+                )),
+                _ => Err(Error::UnexpectedResponse(response)),
+            }
         }
+    }
 
-        pub fn highlight_pre_tag<V>(mut self, value: V) -> Self
+    /// Builder for [`Client::get_webhook_deliveries`]
+    ///
+    /// [`Client::get_webhook_deliveries`]: super::Client::get_webhook_deliveries
+    #[derive(Debug, Clone)]
+    pub struct GetWebhookDeliveries<'a> {
+        client: &'a super::Client,
+        identifier: Result<uuid::Uuid, String>,
+        limit: Result<Option<u32>, String>,
+        offset: Result<Option<u32>, String>,
+    }
+
+    impl<'a> GetWebhookDeliveries<'a> {
+        pub fn new(client: &'a super::Client) -> Self {
+            Self {
+                client: client,
+                identifier: Err("identifier was not initialized".to_string()),
+                limit: Ok(None),
+                offset: Ok(None),
+            }
+        }
+
+        pub fn identifier<V>(mut self, value: V) -> Self
         where
-            V: std::convert::TryInto<String>,
+            V: std::convert::TryInto<uuid::Uuid>,
         {
-            self.highlight_pre_tag = value
+            self.identifier = value
                 .try_into()
-                .map(Some)
-                .map_err(|_| "conversion to `String` for highlight_pre_tag failed".to_string());
+                .map_err(|_| "conversion to `uuid :: Uuid` for identifier failed".to_string());
             self
         }
 
@@ -13815,53 +20125,32 @@ pub mod builder {
             self
         }
 
-        pub fn q<V>(mut self, value: V) -> Self
-        where
-            V: std::convert::TryInto<String>,
-        {
-            self.q = value
-                .try_into()
-                .map_err(|_| "conversion to `String` for q failed".to_string());
-            self
-        }
-
-        /// Sends a `GET` request to `/rfd-search`
+        /// Sends a `GET` request to
+        /// `/webhook-subscription/{identifier}/delivery`
         pub async fn send(
             self,
-        ) -> Result<ResponseValue<types::SearchResults>, Error<types::Error>> {
+        ) -> Result<ResponseValue<Vec<types::WebhookDelivery>>, Error<types::Error>> {
             let Self {
                 client,
-                attributes_to_crop,
-                highlight_post_tag,
-                highlight_pre_tag,
+                identifier,
                 limit,
                 offset,
-                q,
             } = self;
-            let attributes_to_crop = attributes_to_crop.map_err(Error::InvalidRequest)?;
-            let highlight_post_tag = highlight_post_tag.map_err(Error::InvalidRequest)?;
-            let highlight_pre_tag = highlight_pre_tag.map_err(Error::InvalidRequest)?;
+            let identifier = identifier.map_err(Error::InvalidRequest)?;
             let limit = limit.map_err(Error::InvalidRequest)?;
             let offset = offset.map_err(Error::InvalidRequest)?;
-            let q = q.map_err(Error::InvalidRequest)?;
-            let url = format!("{}/rfd-search", client.baseurl,);
-            let mut query = Vec::with_capacity(6usize);
-            if let Some(v) = &attributes_to_crop {
-                query.push(("attributes_to_crop", v.to_string()));
-            }
-            if let Some(v) = &highlight_post_tag {
-                query.push(("highlight_post_tag", v.to_string()));
-            }
-            if let Some(v) = &highlight_pre_tag {
-                query.push(("highlight_pre_tag", v.to_string()));
-            }
+            let url = format!(
+                "{}/webhook-subscription/{}/delivery",
+                client.baseurl,
+                encode_path(&identifier.to_string()),
+            );
+            let mut query = Vec::with_capacity(2usize);
             if let Some(v) = &limit {
                 query.push(("limit", v.to_string()));
             }
             if let Some(v) = &offset {
                 query.push(("offset", v.to_string()));
             }
-            query.push(("q", q.to_string()));
             #[allow(unused_mut)]
             let mut request = client
                 .client
@@ -13886,49 +20175,6 @@ pub mod builder {
             }
         }
     }
-
-    /// Builder for [`Client::get_self`]
-    ///
-    /// [`Client::get_self`]: super::Client::get_self
-    #[derive(Debug, Clone)]
-    pub struct GetSelf<'a> {
-        client: &'a super::Client,
-    }
-
-    impl<'a> GetSelf<'a> {
-        pub fn new(client: &'a super::Client) -> Self {
-            Self { client: client }
-        }
-
-        /// Sends a `GET` request to `/self`
-        pub async fn send(
-            self,
-        ) -> Result<ResponseValue<types::GetUserResponse>, Error<types::Error>> {
-            let Self { client } = self;
-            let url = format!("{}/self", client.baseurl,);
-            #[allow(unused_mut)]
-            let mut request = client
-                .client
-                .get(url)
-                .header(
-                    reqwest::header::ACCEPT,
-                    reqwest::header::HeaderValue::from_static("application/json"),
-                )
-                .build()?;
-            let result = client.client.execute(request).await;
-            let response = result?;
-            match response.status().as_u16() {
-                200u16 => ResponseValue::from_response(response).await,
-                400u16..=499u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                500u16..=599u16 => Err(Error::ErrorResponse(
-                    ResponseValue::from_response(response).await?,
-                )),
-                _ => Err(Error::UnexpectedResponse(response)),
-            }
-        }
-    }
 }
 
 /// Items consumers will typically use such as the Client and
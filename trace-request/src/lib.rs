@@ -30,9 +30,9 @@ pub fn trace_request(_attr: TokenStream, input: TokenStream) -> TokenStream {
         {
             use tracing::Instrument;
 
-            fn get_status<T>(res: &Result<T, HttpError>) -> http::StatusCode where T: dropshot::HttpCodedResponse {
+            fn get_status<T>(res: &Result<T, HttpError>) -> http::StatusCode where T: dropshot::HttpResponse {
                 match res {
-                    Ok(_) => T::STATUS_CODE,
+                    Ok(_) => T::response_metadata().success.unwrap_or(http::StatusCode::OK),
                     Err(err) => err.status_code,
                 }
             }